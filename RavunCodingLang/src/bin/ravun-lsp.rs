@@ -0,0 +1,18 @@
+use std::io;
+use std::process;
+
+use ravun::diagnostics::{set_language, Lang};
+
+fn main() {
+    set_language(Lang::from_env());
+    let stdin = io::stdin();
+    let stdout = io::stdout();
+    
+    match ravun::lsp::run(&mut stdin.lock(), &mut stdout.lock()) {
+        Ok(code) => process::exit(code),
+        Err(err) => {
+            eprintln!("ravun-lsp: {}", err);
+            process::exit(1);
+        }
+    }
+}
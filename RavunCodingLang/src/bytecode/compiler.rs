@@ -0,0 +1,661 @@
+use std::collections::HashMap;
+use crate::bytecode::error::BytecodeError;
+use crate::bytecode::instruction::Instruction;
+use crate::bytecode::program::{BytecodeFunction, Program};
+use crate::diagnostics::MessageId;
+use crate::interpreter::interpreter::evaluate_literal;
+use crate::interpreter::value::Value;
+use crate::parser::ast::{AstNode, AstNodeType};
+use crate::semantics::types::Type;
+
+const BUILTINS: [&str; 2] = ["print", "println"];
+const INIT_FUNCTION: &str = "<init>";
+
+#[derive(Debug, Default)]
+struct LoopContext {
+    breaks: Vec<usize>,
+    continues: Vec<usize>,
+}
+
+pub struct BytecodeCompiler {
+    program: Program,
+    function: BytecodeFunction,
+    scopes: Vec<HashMap<String, u32>>,
+    globals: HashMap<String, u32>,
+    loops: Vec<LoopContext>,
+    return_type: Type,
+    in_init: bool,
+    position: (u32, u32),
+}
+
+impl Default for BytecodeCompiler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub fn compile_program(program: &AstNode) -> Result<Program, BytecodeError> {
+    BytecodeCompiler::new().compile(program)
+}
+
+impl BytecodeCompiler {
+    pub fn new() -> Self {
+        BytecodeCompiler {
+            program: Program::new(),
+            function: BytecodeFunction::new(String::new(), 0),
+            scopes: Vec::new(),
+            globals: HashMap::new(),
+            loops: Vec::new(),
+            return_type: Type::Void,
+            in_init: false,
+            position: (0, 0),
+        }
+    }
+    
+    pub fn compile(mut self, program: &AstNode) -> Result<Program, BytecodeError> {
+        let mut declarations = Vec::new();
+        
+        for child in &program.children {
+            match child.node_type {
+                AstNodeType::FuncDecl => {
+                    let name = child.value.clone().ok_or_else(||
+                        BytecodeError::at(MessageId::MissingFunctionName.text().to_string(), child)
+                    )?;
+                    let arity = parameters(child).len() as u32;
+                    
+                    match self.program.function_index(&name) {
+                        Some(index) => self.program.functions[index as usize] = BytecodeFunction::new(name, arity),
+                        None => self.program.functions.push(BytecodeFunction::new(name, arity)),
+                    }
+                    declarations.push(child);
+                },
+                AstNodeType::VarDecl => {
+                    if let Some(name) = &child.value
+                        && !self.globals.contains_key(name) {
+                        self.globals.insert(name.clone(), self.program.globals.len() as u32);
+                        self.program.globals.push(name.clone());
+                    }
+                },
+                _ => {},
+            }
+        }
+        
+        for declaration in declarations {
+            let function = self.compile_function(declaration)?;
+            let index = self.program.function_index(&function.name).unwrap_or_default();
+            self.program.functions[index as usize] = function;
+        }
+        
+        let init = self.compile_init(program)?;
+        self.program.init = self.program.functions.len() as u32;
+        self.program.functions.push(init);
+        
+        self.program.entry = self.program.function_index("main").ok_or_else(||
+            BytecodeError::new(MessageId::MissingMain.text().to_string(), 0, 0)
+        )?;
+        
+        Ok(self.program)
+    }
+    
+    fn compile_init(&mut self, program: &AstNode) -> Result<BytecodeFunction, BytecodeError> {
+        self.begin_function(BytecodeFunction::new(INIT_FUNCTION.to_string(), 0), Type::Void);
+        self.in_init = true;
+        
+        for child in &program.children {
+            if child.node_type != AstNodeType::FuncDecl {
+                self.compile_statement(child)?;
+            }
+        }
+        
+        self.in_init = false;
+        Ok(self.end_function())
+    }
+    
+    fn compile_function(&mut self, node: &AstNode) -> Result<BytecodeFunction, BytecodeError> {
+        let name = node.value.clone().unwrap_or_default();
+        let params = parameters(node);
+        let return_type = node.children.iter()
+            .find(|c| c.node_type == AstNodeType::TypeAnnotation)
+            .and_then(|t| t.value.as_deref())
+            .map_or(Type::Void, Type::from_name);
+        
+        self.begin_function(BytecodeFunction::new(name, params.len() as u32), return_type);
+        self.set_position(node);
+        
+        for (slot, param) in params.iter().enumerate() {
+            let slot = slot as u32;
+            self.scopes[0].insert(param.value.clone().unwrap_or_default(), slot);
+            
+            if type_annotation(param) == Some(Type::Float) {
+                self.emit(Instruction::GetLocal(slot));
+                self.emit(Instruction::ToFloat);
+                self.emit(Instruction::SetLocal(slot));
+                self.emit(Instruction::Pop);
+            }
+        }
+        
+        if let Some(body) = node.children.iter().find(|c| c.node_type == AstNodeType::BlockStmt) {
+            self.compile_statement(body)?;
+        }
+        
+        Ok(self.end_function())
+    }
+    
+    fn begin_function(&mut self, function: BytecodeFunction, return_type: Type) {
+        self.function = function;
+        self.scopes = vec![HashMap::new()];
+        self.loops.clear();
+        self.return_type = return_type;
+    }
+    
+    fn end_function(&mut self) -> BytecodeFunction {
+        self.emit(Instruction::Void);
+        self.emit(Instruction::Return);
+        self.scopes.clear();
+        
+        std::mem::replace(&mut self.function, BytecodeFunction::new(String::new(), 0))
+    }
+    
+    fn set_position(&mut self, node: &AstNode) {
+        if node.line != 0 {
+            self.position = (node.line as u32, node.column as u32);
+        }
+    }
+    
+    fn emit(&mut self, instruction: Instruction) -> usize {
+        self.function.code.push(instruction);
+        self.function.lines.push(self.position);
+        self.function.code.len() - 1
+    }
+    
+    fn here(&self) -> u32 {
+        self.function.code.len() as u32
+    }
+    
+    fn patch(&mut self, at: usize, target: u32) {
+        self.function.code[at] = match self.function.code[at] {
+            Instruction::Jump(_) => Instruction::Jump(target),
+            Instruction::JumpIfFalse(_) => Instruction::JumpIfFalse(target),
+            other => other,
+        };
+    }
+    
+    fn constant(&mut self, value: Value) -> u32 {
+        self.program.add_constant(value)
+    }
+    
+    fn declare_local(&mut self, name: Option<String>) -> u32 {
+        let slot = self.function.locals;
+        self.function.locals += 1;
+        
+        if let (Some(name), Some(scope)) = (name, self.scopes.last_mut()) {
+            scope.insert(name, slot);
+        }
+        
+        slot
+    }
+    
+    fn is_global_scope(&self) -> bool {
+        self.in_init && self.scopes.len() == 1
+    }
+    
+    fn compile_statement(&mut self, node: &AstNode) -> Result<(), BytecodeError> {
+        self.set_position(node);
+        
+        match node.node_type {
+            AstNodeType::VarDecl => self.compile_var_declaration(node),
+            AstNodeType::FuncDecl => Err(BytecodeError::at(
+                MessageId::NestedFunctionInBytecode.text().to_string(),
+                node,
+            )),
+            AstNodeType::StructDecl | AstNodeType::ImplDecl | AstNodeType::ModDecl => Ok(()),
+            AstNodeType::BlockStmt => {
+                self.scopes.push(HashMap::new());
+                let result = node.children.iter().try_for_each(|child| self.compile_statement(child));
+                self.scopes.pop();
+                result
+            },
+            AstNodeType::IfStmt => self.compile_if(node),
+            AstNodeType::WhileStmt => self.compile_while(node),
+            AstNodeType::ForStmt => self.compile_for(node),
+            AstNodeType::ReturnStmt => {
+                match node.children.first() {
+                    Some(expr) => {
+                        self.compile_expression(expr)?;
+                        if self.return_type == Type::Float {
+                            self.emit(Instruction::ToFloat);
+                        }
+                    },
+                    None => {
+                        self.emit(Instruction::Void);
+                    },
+                }
+                self.emit(Instruction::Return);
+                Ok(())
+            },
+            AstNodeType::BreakStmt | AstNodeType::ContinueStmt => {
+                let jump = self.emit(Instruction::Jump(0));
+                let context = self.loops.last_mut().ok_or_else(|| BytecodeError::at(
+                    MessageId::LoopControlOutsideLoop.text().to_string(),
+                    node,
+                ))?;
+                
+                if node.node_type == AstNodeType::BreakStmt {
+                    context.breaks.push(jump);
+                } else {
+                    context.continues.push(jump);
+                }
+                Ok(())
+            },
+            AstNodeType::ExprStmt => {
+                if let Some(expr) = node.children.first() {
+                    self.compile_expression(expr)?;
+                    self.emit(Instruction::Pop);
+                }
+                Ok(())
+            },
+            _ => {
+                self.compile_expression(node)?;
+                self.emit(Instruction::Pop);
+                Ok(())
+            }
+        }
+    }
+    
+    fn compile_var_declaration(&mut self, node: &AstNode) -> Result<(), BytecodeError> {
+        let name = node.value.clone().ok_or_else(||
+            BytecodeError::at(MessageId::MissingVariableName.text().to_string(), node)
+        )?;
+        
+        match node.children.iter().find(|c| c.node_type != AstNodeType::TypeAnnotation) {
+            Some(initializer) => self.compile_expression(initializer)?,
+            None => {
+                self.emit(Instruction::Void);
+            },
+        }
+        
+        if type_annotation(node) == Some(Type::Float) {
+            self.emit(Instruction::ToFloat);
+        }
+        
+        if self.is_global_scope() {
+            let index = self.globals[&name];
+            self.emit(Instruction::SetGlobal(index));
+        } else {
+            let slot = self.declare_local(Some(name));
+            self.emit(Instruction::SetLocal(slot));
+        }
+        self.emit(Instruction::Pop);
+        
+        Ok(())
+    }
+    
+    fn compile_if(&mut self, node: &AstNode) -> Result<(), BytecodeError> {
+        if node.children.len() < 2 {
+            return Err(BytecodeError::at(MessageId::IncompleteIf.text().to_string(), node));
+        }
+        
+        self.compile_expression(&node.children[0])?;
+        let to_else = self.emit(Instruction::JumpIfFalse(0));
+        self.compile_statement(&node.children[1])?;
+        
+        match node.children.get(2) {
+            Some(else_branch) => {
+                let to_end = self.emit(Instruction::Jump(0));
+                let else_start = self.here();
+                self.patch(to_else, else_start);
+                self.compile_statement(else_branch)?;
+                let end = self.here();
+                self.patch(to_end, end);
+            },
+            None => {
+                let end = self.here();
+                self.patch(to_else, end);
+            },
+        }
+        
+        Ok(())
+    }
+    
+    fn compile_while(&mut self, node: &AstNode) -> Result<(), BytecodeError> {
+        if node.children.len() < 2 {
+            return Err(BytecodeError::at(MessageId::IncompleteWhile.text().to_string(), node));
+        }
+        
+        let start = self.here();
+        self.compile_expression(&node.children[0])?;
+        let to_exit = self.emit(Instruction::JumpIfFalse(0));
+        
+        self.loops.push(LoopContext::default());
+        self.compile_statement(&node.children[1])?;
+        self.emit(Instruction::Jump(start));
+        
+        let exit = self.here();
+        self.patch(to_exit, exit);
+        self.finish_loop(start, exit);
+        
+        Ok(())
+    }
+    
+    fn compile_for(&mut self, node: &AstNode) -> Result<(), BytecodeError> {
+        if node.children.len() < 3 {
+            return Err(BytecodeError::at(MessageId::IncompleteFor.text().to_string(), node));
+        }
+        
+        let var_name = node.children[0].value.clone().ok_or_else(||
+            BytecodeError::at(MessageId::MissingLoopVariable.text().to_string(), node)
+        )?;
+        let iterable = &node.children[1];
+        
+        self.scopes.push(HashMap::new());
+        
+        let counter = self.declare_local(None);
+        let limit = self.declare_local(None);
+        let collection = if iterable.node_type == AstNodeType::RangeExpr {
+            if iterable.children.len() < 2 {
+                return Err(BytecodeError::at(MessageId::IncompleteRange.text().to_string(), iterable));
+            }
+            
+            self.compile_expression(&iterable.children[0])?;
+            self.emit(Instruction::SetLocal(counter));
+            self.emit(Instruction::Pop);
+            self.compile_expression(&iterable.children[1])?;
+            self.emit(Instruction::SetLocal(limit));
+            self.emit(Instruction::Pop);
+            None
+        } else {
+            let collection = self.declare_local(None);
+            self.compile_expression(iterable)?;
+            self.emit(Instruction::SetLocal(collection));
+            self.emit(Instruction::Len);
+            self.emit(Instruction::SetLocal(limit));
+            self.emit(Instruction::Pop);
+            let zero = self.constant(Value::Int(0));
+            self.emit(Instruction::Const(zero));
+            self.emit(Instruction::SetLocal(counter));
+            self.emit(Instruction::Pop);
+            Some(collection)
+        };
+        
+        let start = self.here();
+        self.emit(Instruction::GetLocal(counter));
+        self.emit(Instruction::GetLocal(limit));
+        self.emit(Instruction::Lt);
+        let to_exit = self.emit(Instruction::JumpIfFalse(0));
+        
+        self.scopes.push(HashMap::new());
+        let variable = self.declare_local(Some(var_name));
+        if let Some(collection) = collection {
+            self.emit(Instruction::GetLocal(collection));
+            self.emit(Instruction::GetLocal(counter));
+            self.emit(Instruction::GetIndex);
+        } else {
+            self.emit(Instruction::GetLocal(counter));
+        }
+        self.emit(Instruction::SetLocal(variable));
+        self.emit(Instruction::Pop);
+        
+        self.loops.push(LoopContext::default());
+        self.compile_statement(&node.children[2])?;
+        self.scopes.pop();
+        
+        let step = self.here();
+        let one = self.constant(Value::Int(1));
+        self.emit(Instruction::GetLocal(counter));
+        self.emit(Instruction::Const(one));
+        self.emit(Instruction::Add);
+        self.emit(Instruction::SetLocal(counter));
+        self.emit(Instruction::Pop);
+        self.emit(Instruction::Jump(start));
+        
+        let exit = self.here();
+        self.patch(to_exit, exit);
+        self.finish_loop(step, exit);
+        self.scopes.pop();
+        
+        Ok(())
+    }
+    
+    fn finish_loop(&mut self, continue_target: u32, exit: u32) {
+        let context = self.loops.pop().unwrap_or_default();
+        
+        for jump in context.continues {
+            self.patch(jump, continue_target);
+        }
+        
+        for jump in context.breaks {
+            self.patch(jump, exit);
+        }
+    }
+    
+    fn compile_expression(&mut self, node: &AstNode) -> Result<(), BytecodeError> {
+        self.set_position(node);
+        
+        match node.node_type {
+            AstNodeType::LiteralExpr => {
+                let value = evaluate_literal(node).map_err(|err| BytecodeError::at(err.message, node))?;
+                let index = self.constant(value);
+                self.emit(Instruction::Const(index));
+            },
+            AstNodeType::IdentifierExpr => {
+                let name = node.value.as_deref().unwrap_or_default();
+                let load = self.resolve(name, node)?.load();
+                self.emit(load);
+            },
+            AstNodeType::GroupExpr => match node.children.first() {
+                Some(expr) => self.compile_expression(expr)?,
+                None => return Err(BytecodeError::at(MessageId::EmptyGroup.text().to_string(), node)),
+            },
+            AstNodeType::UnaryExpr => {
+                let operand = node.children.first().ok_or_else(||
+                    BytecodeError::at(MessageId::IncompleteUnary.text().to_string(), node)
+                )?;
+                self.compile_expression(operand)?;
+                
+                match node.value.as_deref() {
+                    Some("-") => self.emit(Instruction::Neg),
+                    Some("!") => self.emit(Instruction::Not),
+                    Some("~") => self.emit(Instruction::BitNot),
+                    other => return Err(BytecodeError::at(
+                        MessageId::UnknownUnaryOperator.format(&[&other.unwrap_or_default()]),
+                        node,
+                    )),
+                };
+            },
+            AstNodeType::BinaryExpr => self.compile_binary(node)?,
+            AstNodeType::CallExpr => self.compile_call(node)?,
+            AstNodeType::IndexExpr => {
+                if node.children.len() < 2 {
+                    return Err(BytecodeError::at(MessageId::IncompleteIndex.text().to_string(), node));
+                }
+                
+                self.compile_expression(&node.children[0])?;
+                self.compile_expression(&node.children[1])?;
+                self.set_position(node);
+                self.emit(Instruction::GetIndex);
+            },
+            AstNodeType::MemberExpr => {
+                let object = node.children.first().ok_or_else(||
+                    BytecodeError::at(MessageId::IncompleteMemberAccess.text().to_string(), node)
+                )?;
+                let field = node.value.clone().unwrap_or_default();
+                
+                self.compile_expression(object)?;
+                self.set_position(node);
+                let name = self.constant(Value::String(field));
+                self.emit(Instruction::GetField(name));
+            },
+            AstNodeType::RangeExpr => return Err(BytecodeError::at(
+                MessageId::RangeOutsideFor.text().to_string(),
+                node,
+            )),
+            _ => return Err(BytecodeError::at(
+                MessageId::UnsupportedExpression.format(&[&format!("{:?}", node.node_type)]),
+                node,
+            )),
+        }
+        
+        Ok(())
+    }
+    
+    fn compile_binary(&mut self, node: &AstNode) -> Result<(), BytecodeError> {
+        if node.children.len() < 2 {
+            return Err(BytecodeError::at(MessageId::IncompleteBinary.text().to_string(), node));
+        }
+        
+        let operator = node.value.as_deref().unwrap_or_default();
+        
+        if let "=" | "+=" | "-=" | "*=" | "/=" | "&=" | "|=" | "~=" | "<<=" | ">>=" = operator {
+            return self.compile_assignment(node, operator);
+        }
+        if let "&&" | "||" = operator {
+            return self.compile_logical(node, operator);
+        }
+        
+        let instruction = Instruction::from_operator(operator).ok_or_else(||
+            BytecodeError::at(MessageId::UnknownOperator.format(&[&operator]), node)
+        )?;
+        
+        self.compile_expression(&node.children[0])?;
+        self.compile_expression(&node.children[1])?;
+        self.set_position(node);
+        self.emit(instruction);
+        
+        Ok(())
+    }
+    
+    fn compile_logical(&mut self, node: &AstNode, operator: &str) -> Result<(), BytecodeError> {
+        self.compile_expression(&node.children[0])?;
+        self.set_position(node);
+        let to_right = self.emit(Instruction::JumpIfFalse(0));
+        
+        // Sağ taraf yalnızca sonucu belirleyebildiğinde değerlendirilir.
+        if operator == "&&" {
+            self.compile_expression(&node.children[1])?;
+        } else {
+            let index = self.constant(Value::Bool(true));
+            self.emit(Instruction::Const(index));
+        }
+        let to_end = self.emit(Instruction::Jump(0));
+        
+        let other = self.here();
+        self.patch(to_right, other);
+        if operator == "&&" {
+            let index = self.constant(Value::Bool(false));
+            self.emit(Instruction::Const(index));
+        } else {
+            self.compile_expression(&node.children[1])?;
+        }
+        
+        let end = self.here();
+        self.patch(to_end, end);
+        
+        Ok(())
+    }
+    
+    fn compile_assignment(&mut self, node: &AstNode, operator: &str) -> Result<(), BytecodeError> {
+        let target = &node.children[0];
+        
+        if target.node_type != AstNodeType::IdentifierExpr {
+            return Err(BytecodeError::at(
+                MessageId::NotAssignable.text().to_string(),
+                node,
+            ));
+        }
+        
+        let name = target.value.as_deref().unwrap_or_default();
+        let variable = self.resolve(name, target)?;
+        
+        if operator == "=" {
+            self.compile_expression(&node.children[1])?;
+        } else {
+            let instruction = Instruction::from_operator(&operator[..operator.len() - 1]).ok_or_else(||
+                BytecodeError::at(MessageId::UnknownOperator.format(&[&operator]), node)
+            )?;
+            
+            self.emit(variable.load());
+            self.compile_expression(&node.children[1])?;
+            self.set_position(node);
+            self.emit(instruction);
+        }
+        
+        self.emit(variable.store());
+        
+        Ok(())
+    }
+    
+    fn compile_call(&mut self, node: &AstNode) -> Result<(), BytecodeError> {
+        let name = node.value.as_deref().unwrap_or_default();
+        let argc = node.children.len() as u32;
+        
+        let call = match self.program.function_index(name) {
+            Some(index) => {
+                let arity = self.program.functions[index as usize].arity;
+                if arity != argc {
+                    return Err(BytecodeError::at(
+                        MessageId::ArgumentCountMismatch.format(&[&name, &arity, &argc]),
+                        node,
+                    ));
+                }
+                Instruction::Call(index, argc)
+            },
+            None if BUILTINS.contains(&name) => {
+                let builtin = self.constant(Value::String(name.to_string()));
+                Instruction::CallBuiltin(builtin, argc)
+            },
+            None => return Err(BytecodeError::at(MessageId::UndefinedFunction.format(&[&name]), node)),
+        };
+        
+        for arg in &node.children {
+            self.compile_expression(arg)?;
+        }
+        
+        self.set_position(node);
+        self.emit(call);
+        
+        Ok(())
+    }
+    
+    fn resolve(&self, name: &str, node: &AstNode) -> Result<Variable, BytecodeError> {
+        if let Some(slot) = self.scopes.iter().rev().find_map(|scope| scope.get(name)) {
+            return Ok(Variable::Local(*slot));
+        }
+        
+        self.globals.get(name)
+            .map(|index| Variable::Global(*index))
+            .ok_or_else(|| BytecodeError::at(MessageId::Undefined.format(&[&name]), node))
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Variable {
+    Local(u32),
+    Global(u32),
+}
+
+impl Variable {
+    fn load(self) -> Instruction {
+        match self {
+            Variable::Local(slot) => Instruction::GetLocal(slot),
+            Variable::Global(index) => Instruction::GetGlobal(index),
+        }
+    }
+    
+    fn store(self) -> Instruction {
+        match self {
+            Variable::Local(slot) => Instruction::SetLocal(slot),
+            Variable::Global(index) => Instruction::SetGlobal(index),
+        }
+    }
+}
+
+fn parameters(node: &AstNode) -> Vec<&AstNode> {
+    node.children.iter().filter(|c| c.node_type == AstNodeType::ParamDecl).collect()
+}
+
+fn type_annotation(node: &AstNode) -> Option<Type> {
+    node.children.iter()
+        .find(|c| c.node_type == AstNodeType::TypeAnnotation)
+        .and_then(|t| t.value.as_deref())
+        .map(Type::from_name)
+}
@@ -0,0 +1,40 @@
+use std::fmt;
+use crate::diagnostics::MessageId;
+use crate::parser::ast::AstNode;
+
+#[derive(Debug, Clone)]
+pub struct BytecodeError {
+    pub message: String,
+    pub line: usize,
+    pub column: usize,
+}
+
+impl BytecodeError {
+    pub fn new(message: String, line: usize, column: usize) -> Self {
+        BytecodeError {
+            message,
+            line,
+            column,
+        }
+    }
+    
+    pub fn at(message: String, node: &AstNode) -> Self {
+        BytecodeError::new(message, node.line, node.column)
+    }
+    
+    pub fn format(message: String) -> Self {
+        BytecodeError::new(message, 0, 0)
+    }
+}
+
+impl fmt::Display for BytecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.line == 0 {
+            write!(f, "{}", self.message)
+        } else {
+            write!(f, "{} - {}", MessageId::Position.format(&[&self.line, &self.column]), self.message)
+        }
+    }
+}
+
+impl std::error::Error for BytecodeError {}
@@ -0,0 +1,243 @@
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Instruction {
+    Const(u32),
+    Void,
+    Pop,
+    
+    GetLocal(u32),
+    SetLocal(u32),
+    GetGlobal(u32),
+    SetGlobal(u32),
+    
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Rem,
+    Pow,
+    Neg,
+    Not,
+    ToFloat,
+    BitAnd,
+    BitOr,
+    BitXor,
+    Shl,
+    Shr,
+    BitNot,
+    
+    Eq,
+    Ne,
+    Lt,
+    Gt,
+    Le,
+    Ge,
+    
+    Jump(u32),
+    JumpIfFalse(u32),
+    
+    Call(u32, u32),
+    CallBuiltin(u32, u32),
+    Return,
+    
+    GetIndex,
+    GetField(u32),
+    Len,
+}
+
+impl Instruction {
+    pub fn from_operator(operator: &str) -> Option<Instruction> {
+        match operator {
+            "+" => Some(Instruction::Add),
+            "-" => Some(Instruction::Sub),
+            "*" => Some(Instruction::Mul),
+            "/" => Some(Instruction::Div),
+            "%" => Some(Instruction::Rem),
+            "^" => Some(Instruction::Pow),
+            "&" => Some(Instruction::BitAnd),
+            "|" => Some(Instruction::BitOr),
+            "~" => Some(Instruction::BitXor),
+            "<<" => Some(Instruction::Shl),
+            ">>" => Some(Instruction::Shr),
+            "==" => Some(Instruction::Eq),
+            "!=" => Some(Instruction::Ne),
+            "<" => Some(Instruction::Lt),
+            ">" => Some(Instruction::Gt),
+            "<=" => Some(Instruction::Le),
+            ">=" => Some(Instruction::Ge),
+            _ => None,
+        }
+    }
+    
+    pub fn operator(&self) -> Option<&'static str> {
+        match self {
+            Instruction::Add => Some("+"),
+            Instruction::Sub => Some("-"),
+            Instruction::Mul => Some("*"),
+            Instruction::Div => Some("/"),
+            Instruction::Rem => Some("%"),
+            Instruction::Pow => Some("^"),
+            Instruction::BitAnd => Some("&"),
+            Instruction::BitOr => Some("|"),
+            Instruction::BitXor => Some("~"),
+            Instruction::Shl => Some("<<"),
+            Instruction::Shr => Some(">>"),
+            Instruction::Eq => Some("=="),
+            Instruction::Ne => Some("!="),
+            Instruction::Lt => Some("<"),
+            Instruction::Gt => Some(">"),
+            Instruction::Le => Some("<="),
+            Instruction::Ge => Some(">="),
+            _ => None,
+        }
+    }
+    
+    pub fn opcode(&self) -> u8 {
+        match self {
+            Instruction::Const(_) => 0x01,
+            Instruction::Void => 0x02,
+            Instruction::Pop => 0x03,
+            Instruction::GetLocal(_) => 0x10,
+            Instruction::SetLocal(_) => 0x11,
+            Instruction::GetGlobal(_) => 0x12,
+            Instruction::SetGlobal(_) => 0x13,
+            Instruction::Add => 0x20,
+            Instruction::Sub => 0x21,
+            Instruction::Mul => 0x22,
+            Instruction::Div => 0x23,
+            Instruction::Rem => 0x24,
+            Instruction::Pow => 0x25,
+            Instruction::Neg => 0x26,
+            Instruction::Not => 0x27,
+            Instruction::ToFloat => 0x28,
+            Instruction::BitAnd => 0x29,
+            Instruction::BitOr => 0x2a,
+            Instruction::BitXor => 0x2b,
+            Instruction::Shl => 0x2c,
+            Instruction::Shr => 0x2d,
+            Instruction::BitNot => 0x2e,
+            Instruction::Eq => 0x30,
+            Instruction::Ne => 0x31,
+            Instruction::Lt => 0x32,
+            Instruction::Gt => 0x33,
+            Instruction::Le => 0x34,
+            Instruction::Ge => 0x35,
+            Instruction::Jump(_) => 0x40,
+            Instruction::JumpIfFalse(_) => 0x41,
+            Instruction::Call(_, _) => 0x50,
+            Instruction::CallBuiltin(_, _) => 0x51,
+            Instruction::Return => 0x52,
+            Instruction::GetIndex => 0x60,
+            Instruction::GetField(_) => 0x61,
+            Instruction::Len => 0x62,
+        }
+    }
+    
+    pub fn operands(&self) -> Vec<u32> {
+        match *self {
+            Instruction::Const(a) | Instruction::GetLocal(a) | Instruction::SetLocal(a) |
+            Instruction::GetGlobal(a) | Instruction::SetGlobal(a) | Instruction::Jump(a) |
+            Instruction::JumpIfFalse(a) | Instruction::GetField(a) => vec![a],
+            Instruction::Call(a, b) | Instruction::CallBuiltin(a, b) => vec![a, b],
+            _ => Vec::new(),
+        }
+    }
+    
+    pub fn decode(opcode: u8, operands: &mut impl FnMut() -> Option<u32>) -> Option<Instruction> {
+        let instruction = match opcode {
+            0x01 => Instruction::Const(operands()?),
+            0x02 => Instruction::Void,
+            0x03 => Instruction::Pop,
+            0x10 => Instruction::GetLocal(operands()?),
+            0x11 => Instruction::SetLocal(operands()?),
+            0x12 => Instruction::GetGlobal(operands()?),
+            0x13 => Instruction::SetGlobal(operands()?),
+            0x20 => Instruction::Add,
+            0x21 => Instruction::Sub,
+            0x22 => Instruction::Mul,
+            0x23 => Instruction::Div,
+            0x24 => Instruction::Rem,
+            0x25 => Instruction::Pow,
+            0x26 => Instruction::Neg,
+            0x27 => Instruction::Not,
+            0x28 => Instruction::ToFloat,
+            0x29 => Instruction::BitAnd,
+            0x2a => Instruction::BitOr,
+            0x2b => Instruction::BitXor,
+            0x2c => Instruction::Shl,
+            0x2d => Instruction::Shr,
+            0x2e => Instruction::BitNot,
+            0x30 => Instruction::Eq,
+            0x31 => Instruction::Ne,
+            0x32 => Instruction::Lt,
+            0x33 => Instruction::Gt,
+            0x34 => Instruction::Le,
+            0x35 => Instruction::Ge,
+            0x40 => Instruction::Jump(operands()?),
+            0x41 => Instruction::JumpIfFalse(operands()?),
+            0x50 => Instruction::Call(operands()?, operands()?),
+            0x51 => Instruction::CallBuiltin(operands()?, operands()?),
+            0x52 => Instruction::Return,
+            0x60 => Instruction::GetIndex,
+            0x61 => Instruction::GetField(operands()?),
+            0x62 => Instruction::Len,
+            _ => return None,
+        };
+        
+        Some(instruction)
+    }
+    
+    pub fn mnemonic(&self) -> &'static str {
+        match self {
+            Instruction::Const(_) => "const",
+            Instruction::Void => "void",
+            Instruction::Pop => "pop",
+            Instruction::GetLocal(_) => "get_local",
+            Instruction::SetLocal(_) => "set_local",
+            Instruction::GetGlobal(_) => "get_global",
+            Instruction::SetGlobal(_) => "set_global",
+            Instruction::Add => "add",
+            Instruction::Sub => "sub",
+            Instruction::Mul => "mul",
+            Instruction::Div => "div",
+            Instruction::Rem => "rem",
+            Instruction::Pow => "pow",
+            Instruction::Neg => "neg",
+            Instruction::Not => "not",
+            Instruction::ToFloat => "to_float",
+            Instruction::BitAnd => "bit_and",
+            Instruction::BitOr => "bit_or",
+            Instruction::BitXor => "bit_xor",
+            Instruction::Shl => "shl",
+            Instruction::Shr => "shr",
+            Instruction::BitNot => "bit_not",
+            Instruction::Eq => "eq",
+            Instruction::Ne => "ne",
+            Instruction::Lt => "lt",
+            Instruction::Gt => "gt",
+            Instruction::Le => "le",
+            Instruction::Ge => "ge",
+            Instruction::Jump(_) => "jump",
+            Instruction::JumpIfFalse(_) => "jump_if_false",
+            Instruction::Call(_, _) => "call",
+            Instruction::CallBuiltin(_, _) => "call_builtin",
+            Instruction::Return => "ret",
+            Instruction::GetIndex => "get_index",
+            Instruction::GetField(_) => "get_field",
+            Instruction::Len => "len",
+        }
+    }
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.mnemonic())?;
+        
+        for (i, operand) in self.operands().iter().enumerate() {
+            write!(f, "{}{}", if i == 0 { " " } else { ", " }, operand)?;
+        }
+        
+        Ok(())
+    }
+}
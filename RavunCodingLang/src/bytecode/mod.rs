@@ -0,0 +1,12 @@
+//! Bytecode modülü
+pub mod instruction;
+pub mod program;
+pub mod compiler;
+pub mod vm;
+pub mod error;
+
+pub use compiler::{compile_program, BytecodeCompiler};
+pub use error::BytecodeError;
+pub use instruction::Instruction;
+pub use program::{BytecodeFunction, Program};
+pub use vm::Vm;
@@ -0,0 +1,302 @@
+use std::fmt;
+use crate::diagnostics::MessageId;
+use crate::bytecode::error::BytecodeError;
+use crate::bytecode::instruction::Instruction;
+use crate::interpreter::value::Value;
+
+const MAGIC: [u8; 4] = *b"RVC\0";
+const VERSION: u16 = 1;
+pub const MAX_LOCALS: u32 = 1 << 16;
+
+const TAG_INT: u8 = 0x00;
+const TAG_FLOAT: u8 = 0x01;
+const TAG_BOOL: u8 = 0x02;
+const TAG_STRING: u8 = 0x03;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BytecodeFunction {
+    pub name: String,
+    pub arity: u32,
+    pub locals: u32,
+    pub code: Vec<Instruction>,
+    pub lines: Vec<(u32, u32)>,
+}
+
+impl BytecodeFunction {
+    pub fn new(name: String, arity: u32) -> Self {
+        BytecodeFunction {
+            name,
+            arity,
+            locals: arity,
+            code: Vec::new(),
+            lines: Vec::new(),
+        }
+    }
+    
+    pub fn position(&self, pc: usize) -> (usize, usize) {
+        self.lines.get(pc).map_or((0, 0), |&(line, column)| (line as usize, column as usize))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Program {
+    pub constants: Vec<Value>,
+    pub globals: Vec<String>,
+    pub functions: Vec<BytecodeFunction>,
+    pub init: u32,
+    pub entry: u32,
+}
+
+impl Program {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    pub fn add_constant(&mut self, value: Value) -> u32 {
+        if let Some(index) = self.constants.iter().position(|c| same_constant(c, &value)) {
+            return index as u32;
+        }
+        
+        self.constants.push(value);
+        (self.constants.len() - 1) as u32
+    }
+    
+    pub fn function_index(&self, name: &str) -> Option<u32> {
+        self.functions.iter().position(|f| f.name == name).map(|i| i as u32)
+    }
+    
+    pub fn to_bytes(&self) -> Result<Vec<u8>, BytecodeError> {
+        let mut out = Vec::new();
+        out.extend_from_slice(&MAGIC);
+        out.extend_from_slice(&VERSION.to_le_bytes());
+        
+        write_u32(&mut out, self.constants.len() as u32);
+        for constant in &self.constants {
+            match constant {
+                Value::Int(i) => {
+                    out.push(TAG_INT);
+                    out.extend_from_slice(&i.to_le_bytes());
+                },
+                Value::Float(f) => {
+                    out.push(TAG_FLOAT);
+                    out.extend_from_slice(&f.to_le_bytes());
+                },
+                Value::Bool(b) => {
+                    out.push(TAG_BOOL);
+                    out.push(*b as u8);
+                },
+                Value::String(s) => {
+                    out.push(TAG_STRING);
+                    write_string(&mut out, s);
+                },
+                other => return Err(BytecodeError::format(
+                    MessageId::BytecodeUnwritableConstant.format(&[&other.type_of()])
+                )),
+            }
+        }
+        
+        write_u32(&mut out, self.globals.len() as u32);
+        for global in &self.globals {
+            write_string(&mut out, global);
+        }
+        
+        write_u32(&mut out, self.functions.len() as u32);
+        for function in &self.functions {
+            write_string(&mut out, &function.name);
+            write_u32(&mut out, function.arity);
+            write_u32(&mut out, function.locals);
+            write_u32(&mut out, function.code.len() as u32);
+            for (instruction, &(line, column)) in function.code.iter().zip(&function.lines) {
+                out.push(instruction.opcode());
+                for operand in instruction.operands() {
+                    write_u32(&mut out, operand);
+                }
+                write_u32(&mut out, line);
+                write_u32(&mut out, column);
+            }
+        }
+        
+        write_u32(&mut out, self.init);
+        write_u32(&mut out, self.entry);
+        
+        Ok(out)
+    }
+    
+    pub fn from_bytes(bytes: &[u8]) -> Result<Program, BytecodeError> {
+        let mut reader = Reader { bytes, position: 0 };
+        
+        if reader.take(4)? != MAGIC {
+            return Err(BytecodeError::format(MessageId::BytecodeBadMagic.text().to_string()));
+        }
+        
+        let version = u16::from_le_bytes(reader.array()?);
+        if version != VERSION {
+            return Err(BytecodeError::format(MessageId::BytecodeUnsupportedVersion.format(&[&version, &VERSION])));
+        }
+        
+        let mut program = Program::new();
+        
+        for _ in 0..reader.u32()? {
+            let value = match reader.u8()? {
+                TAG_INT => Value::Int(i32::from_le_bytes(reader.array()?)),
+                TAG_FLOAT => Value::Float(f64::from_le_bytes(reader.array()?)),
+                TAG_BOOL => Value::Bool(reader.u8()? != 0),
+                TAG_STRING => Value::String(reader.string()?),
+                tag => return Err(reader.error(MessageId::BytecodeUnknownConstantTag.format(&[&format!("{:02x}", tag)]))),
+            };
+            program.constants.push(value);
+        }
+        
+        for _ in 0..reader.u32()? {
+            program.globals.push(reader.string()?);
+        }
+        
+        for _ in 0..reader.u32()? {
+            let name = reader.string()?;
+            let mut function = BytecodeFunction::new(name, reader.u32()?);
+            function.locals = reader.u32()?;
+            
+            for _ in 0..reader.u32()? {
+                let opcode = reader.u8()?;
+                let decoded = Instruction::decode(opcode, &mut || reader.u32().ok());
+                let instruction = decoded
+                    .ok_or_else(|| reader.error(MessageId::BytecodeInvalidOpcode.format(&[&format!("{:02x}", opcode)])))?;
+                function.code.push(instruction);
+                function.lines.push((reader.u32()?, reader.u32()?));
+            }
+            
+            program.functions.push(function);
+        }
+        
+        program.init = reader.u32()?;
+        program.entry = reader.u32()?;
+        
+        if reader.position != bytes.len() {
+            return Err(reader.error(MessageId::BytecodeTrailingData.text().to_string()));
+        }
+        
+        program.validate()?;
+        
+        Ok(program)
+    }
+    
+    fn validate(&self) -> Result<(), BytecodeError> {
+        let function_count = self.functions.len() as u32;
+        if self.init >= function_count || self.entry >= function_count {
+            return Err(BytecodeError::format(MessageId::BytecodeMissingEntry.text().to_string()));
+        }
+        
+        for function in &self.functions {
+            if function.locals < function.arity || function.locals > MAX_LOCALS {
+                return Err(BytecodeError::format(MessageId::BytecodeInvalidLocals.format(&[
+                    &function.name, &function.locals, &function.arity, &MAX_LOCALS,
+                ])));
+            }
+            
+            let invalid = |index: u32, limit: usize| index as usize >= limit;
+            
+            for instruction in &function.code {
+                let out_of_range = match *instruction {
+                    Instruction::Const(i) | Instruction::GetField(i) | Instruction::CallBuiltin(i, _) =>
+                        invalid(i, self.constants.len()),
+                    Instruction::GetLocal(i) | Instruction::SetLocal(i) => invalid(i, function.locals as usize),
+                    Instruction::GetGlobal(i) | Instruction::SetGlobal(i) => invalid(i, self.globals.len()),
+                    Instruction::Jump(target) | Instruction::JumpIfFalse(target) => invalid(target, function.code.len() + 1),
+                    Instruction::Call(i, _) => invalid(i, self.functions.len()),
+                    _ => false,
+                };
+                
+                if out_of_range {
+                    return Err(BytecodeError::format(
+                        MessageId::BytecodeOperandOutOfRange.format(&[&function.name, instruction])
+                    ));
+                }
+            }
+        }
+        
+        Ok(())
+    }
+}
+
+impl fmt::Display for Program {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, constant) in self.constants.iter().enumerate() {
+            match constant {
+                Value::String(s) => writeln!(f, "const {} = {:?}", i, s)?,
+                other => writeln!(f, "const {} = {}", i, other)?,
+            }
+        }
+        
+        for (i, global) in self.globals.iter().enumerate() {
+            writeln!(f, "global {} = {}", i, global)?;
+        }
+        
+        for (index, function) in self.functions.iter().enumerate() {
+            writeln!(f)?;
+            writeln!(f, "{}", MessageId::BytecodeFunctionHeader.format(&[&index, &function.name, &function.arity, &function.locals]))?;
+            for (pc, instruction) in function.code.iter().enumerate() {
+                writeln!(f, "    {:04} {}", pc, instruction)?;
+            }
+        }
+        
+        Ok(())
+    }
+}
+
+fn same_constant(a: &Value, b: &Value) -> bool {
+    match (a, b) {
+        (Value::Float(x), Value::Float(y)) => x.to_bits() == y.to_bits(),
+        _ => a == b,
+    }
+}
+
+fn write_u32(out: &mut Vec<u8>, value: u32) {
+    out.extend_from_slice(&value.to_le_bytes());
+}
+
+fn write_string(out: &mut Vec<u8>, value: &str) {
+    write_u32(out, value.len() as u32);
+    out.extend_from_slice(value.as_bytes());
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn error(&self, message: String) -> BytecodeError {
+        BytecodeError::format(MessageId::BytecodeInvalidAt.format(&[&self.position, &message]))
+    }
+    
+    fn take(&mut self, count: usize) -> Result<&'a [u8], BytecodeError> {
+        let end = self.position.checked_add(count)
+            .filter(|&end| end <= self.bytes.len())
+            .ok_or_else(|| self.error(MessageId::BytecodeUnexpectedEof.text().to_string()))?;
+        
+        let slice = &self.bytes[self.position..end];
+        self.position = end;
+        Ok(slice)
+    }
+    
+    fn array<const N: usize>(&mut self) -> Result<[u8; N], BytecodeError> {
+        let mut array = [0; N];
+        array.copy_from_slice(self.take(N)?);
+        Ok(array)
+    }
+    
+    fn u8(&mut self) -> Result<u8, BytecodeError> {
+        Ok(self.take(1)?[0])
+    }
+    
+    fn u32(&mut self) -> Result<u32, BytecodeError> {
+        Ok(u32::from_le_bytes(self.array()?))
+    }
+    
+    fn string(&mut self) -> Result<String, BytecodeError> {
+        let length = self.u32()? as usize;
+        let bytes = self.take(length)?;
+        
+        String::from_utf8(bytes.to_vec()).map_err(|_| self.error(MessageId::BytecodeInvalidUtf8.text().to_string()))
+    }
+}
@@ -0,0 +1,221 @@
+use crate::bytecode::instruction::Instruction;
+use crate::bytecode::program::Program;
+use crate::diagnostics::MessageId;
+use crate::interpreter::error::RuntimeError;
+use crate::interpreter::interpreter::{
+    binary_operation, call_builtin, coerce, index_operation, unary_operation,
+};
+use crate::interpreter::value::Value;
+
+// Çerçeveler Rust yığınında değil vektörde tutulduğu için sınır yorumlayıcınınkinden çok daha yüksektir.
+pub const MAX_CALL_DEPTH: usize = 1 << 16;
+
+#[derive(Debug, Clone, Copy)]
+struct Frame {
+    function: usize,
+    pc: usize,
+    base: usize,
+}
+
+pub struct Vm<'a> {
+    program: &'a Program,
+    stack: Vec<Value>,
+    frames: Vec<Frame>,
+    globals: Vec<Value>,
+}
+
+impl<'a> Vm<'a> {
+    pub fn new(program: &'a Program) -> Self {
+        Vm {
+            program,
+            stack: Vec::new(),
+            frames: Vec::new(),
+            globals: vec![Value::Void; program.globals.len()],
+        }
+    }
+    
+    pub fn run(&mut self) -> Result<Value, RuntimeError> {
+        self.call(self.program.init as usize, 0)?;
+        self.execute()?;
+        
+        self.call(self.program.entry as usize, 0)?;
+        self.execute()
+    }
+    
+    pub fn global(&self, name: &str) -> Option<&Value> {
+        let index = self.program.globals.iter().position(|g| g == name)?;
+        self.globals.get(index)
+    }
+    
+    fn error(&self, message: String) -> RuntimeError {
+        match self.frames.last() {
+            Some(frame) => {
+                let function = &self.program.functions[frame.function];
+                let (line, column) = function.position(frame.pc.saturating_sub(1));
+                RuntimeError::new(message, line, column)
+            },
+            None => RuntimeError::new(message, 0, 0),
+        }
+    }
+    
+    fn pop(&mut self) -> Result<Value, RuntimeError> {
+        match self.stack.pop() {
+            Some(value) => Ok(value),
+            None => Err(self.error(MessageId::BytecodeStackEmpty.text().to_string())),
+        }
+    }
+    
+    fn peek(&self) -> Result<Value, RuntimeError> {
+        match self.stack.last() {
+            Some(value) => Ok(value.clone()),
+            None => Err(self.error(MessageId::BytecodeStackEmpty.text().to_string())),
+        }
+    }
+    
+    fn call(&mut self, function: usize, argc: usize) -> Result<(), RuntimeError> {
+        let callee = &self.program.functions[function];
+        
+        if callee.arity as usize != argc {
+            return Err(self.error(MessageId::ArgumentCountMismatch.format(&[&callee.name, &callee.arity, &argc])));
+        }
+        
+        if self.frames.len() > MAX_CALL_DEPTH {
+            return Err(self.error(MessageId::CallDepthExceeded.format(&[&MAX_CALL_DEPTH])));
+        }
+        
+        let base = self.stack.len().checked_sub(argc)
+            .ok_or_else(|| self.error(MessageId::BytecodeStackEmpty.text().to_string()))?;
+        self.stack.resize(base + callee.locals as usize, Value::Void);
+        self.frames.push(Frame { function, pc: 0, base });
+        
+        Ok(())
+    }
+    
+    fn execute(&mut self) -> Result<Value, RuntimeError> {
+        let depth = self.frames.len() - 1;
+        
+        loop {
+            let frame = *self.frames.last().expect("Çağrı yığını boş");
+            let function = &self.program.functions[frame.function];
+            
+            let instruction = match function.code.get(frame.pc) {
+                Some(instruction) => *instruction,
+                None => Instruction::Return,
+            };
+            
+            if let Some(frame) = self.frames.last_mut() {
+                frame.pc += 1;
+            }
+            
+            match instruction {
+                Instruction::Const(index) => self.stack.push(self.program.constants[index as usize].clone()),
+                Instruction::Void => self.stack.push(Value::Void),
+                Instruction::Pop => {
+                    self.pop()?;
+                },
+                Instruction::GetLocal(slot) => {
+                    let value = self.stack[frame.base + slot as usize].clone();
+                    self.stack.push(value);
+                },
+                Instruction::SetLocal(slot) => {
+                    let value = self.peek()?;
+                    let slot = &mut self.stack[frame.base + slot as usize];
+                    *slot = coerce(value, &slot.type_of());
+                },
+                Instruction::GetGlobal(index) => self.stack.push(self.globals[index as usize].clone()),
+                Instruction::SetGlobal(index) => {
+                    let value = self.peek()?;
+                    let global = &mut self.globals[index as usize];
+                    *global = coerce(value, &global.type_of());
+                },
+                Instruction::Neg | Instruction::Not | Instruction::BitNot => {
+                    let operand = self.pop()?;
+                    let operator = match instruction {
+                        Instruction::Neg => "-",
+                        Instruction::Not => "!",
+                        _ => "~",
+                    };
+                    let result = unary_operation(operator, operand).map_err(|message| self.error(message))?;
+                    self.stack.push(result);
+                },
+                Instruction::ToFloat => {
+                    let value = self.pop()?;
+                    self.stack.push(match value {
+                        Value::Int(i) => Value::Float(i as f64),
+                        other => other,
+                    });
+                },
+                Instruction::Jump(target) => self.jump(target),
+                Instruction::JumpIfFalse(target) => {
+                    let condition = self.pop()?;
+                    match condition.is_truthy() {
+                        Some(true) => {},
+                        Some(false) => self.jump(target),
+                        None => return Err(self.error(MessageId::ConditionNotBool.format(&[&condition.type_of()]))),
+                    }
+                },
+                Instruction::Call(index, argc) => self.call(index as usize, argc as usize)?,
+                Instruction::CallBuiltin(name, argc) => {
+                    let name = match &self.program.constants[name as usize] {
+                        Value::String(name) => name.as_str(),
+                        other => return Err(self.error(MessageId::InvalidBuiltinName.format(&[&other]))),
+                    };
+                    let base = self.stack.len().checked_sub(argc as usize)
+                        .ok_or_else(|| self.error(MessageId::BytecodeStackEmpty.text().to_string()))?;
+                    let args = self.stack.split_off(base);
+                    
+                    let result = call_builtin(name, args)
+                        .unwrap_or_else(|| Err(MessageId::Undefined.format(&[&name])))
+                        .map_err(|message| self.error(message))?;
+                    self.stack.push(result);
+                },
+                Instruction::Return => {
+                    let result = self.pop()?;
+                    self.stack.truncate(frame.base);
+                    self.frames.pop();
+                    
+                    if self.frames.len() == depth {
+                        return Ok(result);
+                    }
+                    self.stack.push(result);
+                },
+                Instruction::GetIndex => {
+                    let index = match self.pop()? {
+                        Value::Int(i) => i,
+                        other => return Err(self.error(MessageId::IndexNotInt.format(&[&other.type_of()]))),
+                    };
+                    let collection = self.pop()?;
+                    let element = index_operation(&collection, index).map_err(|message| self.error(message))?;
+                    self.stack.push(element);
+                },
+                Instruction::GetField(name) => {
+                    let object = self.pop()?;
+                    return Err(self.error(MessageId::FieldNotFound.format(&[
+                        &object.type_of(), &self.program.constants[name as usize],
+                    ])));
+                },
+                Instruction::Len => {
+                    let length = match self.pop()? {
+                        Value::Array(elements) => elements.len(),
+                        Value::String(s) => s.chars().count(),
+                        other => return Err(self.error(MessageId::NotIterable.format(&[&other.type_of()]))),
+                    };
+                    self.stack.push(Value::Int(length as i32));
+                },
+                binary => {
+                    let right = self.pop()?;
+                    let left = self.pop()?;
+                    let operator = binary.operator().unwrap_or_default();
+                    let result = binary_operation(operator, left, right).map_err(|message| self.error(message))?;
+                    self.stack.push(result);
+                },
+            }
+        }
+    }
+    
+    fn jump(&mut self, target: u32) {
+        if let Some(frame) = self.frames.last_mut() {
+            frame.pc = target as usize;
+        }
+    }
+}
@@ -0,0 +1,204 @@
+use std::env;
+use std::io::{self, IsTerminal};
+
+use ravun::codegen::Backend;
+use ravun::diagnostics::{set_language, ErrorFormat, Lang, MessageId};
+use ravun::optimizer::OptLevel;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Command {
+    Check,
+    Build,
+    Run,
+    Tokens,
+    Ast,
+    Ir,
+    Fmt,
+    Explain,
+    Repl,
+    Help,
+}
+
+impl Command {
+    pub fn parse(name: &str) -> Option<Command> {
+        match name {
+            "check" => Some(Command::Check),
+            "build" => Some(Command::Build),
+            "run" => Some(Command::Run),
+            "tokens" => Some(Command::Tokens),
+            "ast" => Some(Command::Ast),
+            "ir" => Some(Command::Ir),
+            "fmt" => Some(Command::Fmt),
+            "explain" => Some(Command::Explain),
+            "repl" => Some(Command::Repl),
+            "help" | "-h" | "--help" => Some(Command::Help),
+            _ => None,
+        }
+    }
+    
+    fn takes_input(&self) -> bool {
+        !matches!(self, Command::Repl | Command::Help)
+    }
+}
+
+pub struct Options {
+    pub command: Command,
+    pub input: String,
+    pub opt_level: OptLevel,
+    pub dump_ir_passes: bool,
+    pub remarks: bool,
+    pub output: Option<String>,
+    pub emit_c: bool,
+    pub emit_asm: bool,
+    pub emit_wat: bool,
+    pub emit_llvm: bool,
+    pub emit_bytecode: bool,
+    pub backend: Backend,
+    pub check: bool,
+    pub color: bool,
+    pub error_format: ErrorFormat,
+    pub verbose: bool,
+}
+
+pub fn parse_options(args: &[String]) -> Result<Options, String> {
+    if let Some(lang) = select_language(args)? {
+        set_language(lang);
+    }
+    
+    let first = args.first().ok_or_else(|| MessageId::NoCommand.text().to_string())?;
+    
+    let (mut command, rest, legacy) = match Command::parse(first) {
+        Some(command) => (command, &args[1..], false),
+        None if first.ends_with(".rv") || first.ends_with(".rvc") => (Command::Run, args, true),
+        None => return Err(MessageId::UnknownCommand.format(&[first])),
+    };
+    
+    let mut input = None;
+    let mut opt_level = OptLevel::O0;
+    let mut dump_ir_passes = false;
+    let mut remarks = false;
+    let mut output = None;
+    let mut emit_c = false;
+    let mut emit_asm = false;
+    let mut emit_wat = false;
+    let mut emit_llvm = false;
+    let mut emit_bytecode = false;
+    let mut backend = None;
+    let mut check = false;
+    let mut color = io::stderr().is_terminal() && env::var_os("NO_COLOR").is_none();
+    let mut error_format = ErrorFormat::default();
+    let mut verbose = false;
+    
+    let mut args = rest.iter();
+    while let Some(arg) = args.next() {
+        let (arg, mut inline) = match arg.split_once('=') {
+            Some((name, value)) if name.starts_with("--") => (name, Some(value.to_string())),
+            _ => (arg.as_str(), None),
+        };
+        
+        if arg == "-o" {
+            output = Some(args.next().ok_or_else(|| MessageId::OutputNeedsPath.text().to_string())?.clone());
+        } else if arg == "--backend" {
+            let name = inline.take().or_else(|| args.next().cloned())
+                .ok_or_else(|| MessageId::BackendNeedsName.text().to_string())?;
+            backend = Some(Backend::parse(&name).ok_or_else(|| MessageId::UnknownBackend.format(&[&name]))?);
+        } else if arg == "--emit-c" {
+            emit_c = true;
+        } else if arg == "--emit-asm" {
+            emit_asm = true;
+        } else if arg == "--emit-wat" {
+            emit_wat = true;
+        } else if arg == "--emit-llvm" {
+            emit_llvm = true;
+        } else if arg == "--emit-bytecode" {
+            emit_bytecode = true;
+        } else if arg == "--dump-ir-passes" {
+            dump_ir_passes = true;
+        } else if arg == "--remarks" {
+            remarks = true;
+        } else if arg == "--color" {
+            color = match inline.take().or_else(|| args.next().cloned()).as_deref() {
+                Some("always") => true,
+                Some("never") => false,
+                Some("auto") => color,
+                Some(when) => return Err(MessageId::InvalidColor.format(&[&when])),
+                None => return Err(MessageId::ColorNeedsValue.text().to_string()),
+            };
+        } else if arg == "--error-format" {
+            let name = inline.take().or_else(|| args.next().cloned())
+                .ok_or_else(|| MessageId::ErrorFormatNeedsValue.text().to_string())?;
+            error_format = ErrorFormat::parse(&name).ok_or_else(|| MessageId::UnknownErrorFormat.format(&[&name]))?;
+        } else if arg == "--lang" {
+            if inline.take().is_none() {
+                args.next();
+            }
+        } else if arg == "--check" {
+            check = true;
+        } else if arg == "-v" || arg == "--verbose" {
+            verbose = true;
+        } else if arg.starts_with("-O") {
+            opt_level = OptLevel::parse(arg).ok_or_else(|| MessageId::InvalidOptLevel.format(&[&arg]))?;
+        } else if arg.starts_with('-') {
+            return Err(MessageId::UnknownOption.format(&[&arg]));
+        } else if input.is_none() && command.takes_input() {
+            input = Some(arg.to_string());
+        } else {
+            return Err(MessageId::ExtraArgument.format(&[&arg]));
+        }
+        
+        if inline.is_some() {
+            return Err(MessageId::OptionTakesNoValue.format(&[&arg]));
+        }
+    }
+    
+    let input = match input {
+        Some(input) => input,
+        None if command == Command::Explain => return Err(MessageId::ExplainNeedsCode.text().to_string()),
+        None if command.takes_input() => return Err(MessageId::MissingInput.text().to_string()),
+        None => String::new(),
+    };
+    
+    if [emit_c, emit_asm, emit_wat, emit_llvm, emit_bytecode].iter().filter(|flag| **flag).count() > 1 {
+        return Err(MessageId::ConflictingEmit.text().to_string());
+    }
+    let emitted = [
+        (emit_c, "--emit-c", Backend::C),
+        (emit_asm, "--emit-asm", Backend::X86_64),
+        (emit_wat, "--emit-wat", Backend::Wasm),
+        (emit_llvm, "--emit-llvm", Backend::Llvm),
+    ].into_iter().find(|(flag, _, _)| *flag);
+    let backend = match (emitted, backend) {
+        (Some((_, option, implied)), Some(chosen)) if implied != chosen => {
+            return Err(MessageId::ConflictingBackend.format(&[&option, &chosen]));
+        },
+        (Some((_, _, implied)), _) => implied,
+        (None, chosen) => chosen.unwrap_or_default(),
+    };
+    if legacy && (output.is_some() || emit_c || emit_asm || emit_wat || emit_llvm || emit_bytecode) {
+        command = Command::Build;
+    }
+    if check && command != Command::Fmt {
+        return Err(MessageId::CheckOnlyForFmt.text().to_string());
+    }
+    
+    Ok(Options {
+        command, input, opt_level, dump_ir_passes, remarks, output,
+        emit_c, emit_asm, emit_wat, emit_llvm, emit_bytecode, backend, check, color, error_format, verbose,
+    })
+}
+
+fn select_language(args: &[String]) -> Result<Option<Lang>, String> {
+    let mut selected = None;
+    
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let name = match arg.strip_prefix("--lang") {
+            Some("") => args.next().ok_or_else(|| MessageId::LangNeedsValue.text().to_string())?,
+            Some(value) if value.starts_with('=') => &value[1..],
+            _ => continue,
+        };
+        selected = Some(Lang::parse(name).ok_or_else(|| MessageId::UnknownLang.format(&[&name]))?);
+    }
+    
+    Ok(selected)
+}
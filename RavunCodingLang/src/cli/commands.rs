@@ -0,0 +1,401 @@
+use std::fmt::Write as _;
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+use ravun::lexer::lexer::Lexer;
+use ravun::parser::ast::AstNode;
+use ravun::parser::error::ParseError;
+use ravun::parser::parser::Parser;
+use ravun::semantics::analyzer::SemanticAnalyzer;
+use ravun::interpreter::Interpreter;
+use ravun::interpreter::Value;
+use ravun::codegen::{Backend, CodegenError};
+use ravun::diagnostics::{to_json, to_sarif, Diagnostic, ErrorCode, ErrorFormat, MessageId, Renderer, SourceFile};
+use ravun::codegen::c::{compile_c, emit_c};
+use ravun::codegen::x86_64::{assemble_and_link, emit_asm};
+use ravun::codegen::wasm::{emit_wasm, encode_module, print_wat};
+use ravun::codegen::llvm::{compile_llvm, emit_llvm};
+use ravun::ir::{lower_program, Module};
+use ravun::bytecode::{compile_program, Program, Vm};
+use ravun::optimizer::{OptimizationHints, PassManager};
+use ravun::semantics::symbol_table::StructDefinition;
+use ravun::utils::format_source;
+
+use crate::cli::args::{Command, Options};
+use crate::cli::repl;
+
+pub enum Failure {
+    Message(String),
+    // Tanılamalar zaten yazıldı; özet satırı yalnızca insan okunur biçimde basılır.
+    Aborted(String),
+}
+
+impl From<String> for Failure {
+    fn from(message: String) -> Self {
+        Failure::Message(message)
+    }
+}
+
+pub fn execute(options: &Options) -> Result<i32, Failure> {
+    match options.command {
+        Command::Check => check(options),
+        Command::Build => build(options),
+        Command::Run => run(options),
+        Command::Tokens => tokens(options),
+        Command::Ast => ast(options),
+        Command::Ir => ir(options),
+        Command::Fmt => fmt(options),
+        Command::Explain => explain(options),
+        Command::Repl => Ok(repl::start()?),
+        Command::Help => {
+            println!("{}", MessageId::Usage.text());
+            Ok(0)
+        },
+    }
+}
+
+fn check(options: &Options) -> Result<i32, Failure> {
+    let file = read_source_file(&options.input)?;
+    analyze(&file, options)?;
+    
+    Ok(0)
+}
+
+fn build(options: &Options) -> Result<i32, Failure> {
+    let file = read_source_file(&options.input)?;
+    let (ast, analyzer) = analyze(&file, options)?;
+    
+    if options.emit_bytecode {
+        let program = compile_program(&ast).map_err(|err| MessageId::BytecodeGenerationFailed.format(&[&err]))?;
+        let bytes = program.to_bytes().map_err(|err| MessageId::BytecodeGenerationFailed.format(&[&err]))?;
+        let output = output_path(options);
+        
+        fs::write(&output, bytes).map_err(|err| MessageId::WriteFailed.format(&[&output.display(), &err]))?;
+        report(options, &MessageId::BytecodeWritten.format(&[&output.display()]));
+        
+        return Ok(0);
+    }
+    
+    let module = optimize(&ast, &analyzer, options)?;
+    let output = output_path(options);
+    let structs = analyzer.get_symbol_table().struct_definitions();
+    generate(&module, &structs, options, &output).map_err(|err| MessageId::CodegenFailed.format(&[&err]))?;
+    
+    Ok(0)
+}
+
+fn run(options: &Options) -> Result<i32, Failure> {
+    if Path::new(&options.input).extension().and_then(|ext| ext.to_str()) == Some("rvc") {
+        return Ok(run_bytecode(&options.input)?);
+    }
+    
+    let file = read_source_file(&options.input)?;
+    let (ast, _) = analyze(&file, options)?;
+    
+    report(options, MessageId::Running.text());
+    let mut interpreter = Interpreter::new();
+    match interpreter.run(&ast) {
+        Ok(Value::Int(code)) => Ok(code),
+        Ok(_) => Ok(0),
+        Err(err) => Err(MessageId::RuntimeError.format(&[&err]).into()),
+    }
+}
+
+fn tokens(options: &Options) -> Result<i32, Failure> {
+    let file = read_source_file(&options.input)?;
+    let mut lexer = Lexer::new(&file.source);
+    let mut listing = String::new();
+    
+    for token in lexer.tokenize() {
+        let _ = writeln!(listing, "{}:{}\t{:?}\t'{}'", token.line, token.column, token.token_type, token.lexeme);
+    }
+    
+    print_stdout(&listing)?;
+    Ok(0)
+}
+
+fn ast(options: &Options) -> Result<i32, Failure> {
+    let file = read_source_file(&options.input)?;
+    let ast = parse(&file, options)?;
+    
+    print_stdout(&format!("{:#?}\n", ast))?;
+    
+    Ok(0)
+}
+
+fn ir(options: &Options) -> Result<i32, Failure> {
+    let file = read_source_file(&options.input)?;
+    let (ast, analyzer) = analyze(&file, options)?;
+    let module = optimize(&ast, &analyzer, options)?;
+    
+    match &options.output {
+        Some(output) => {
+            fs::write(output, module.to_string()).map_err(|err| MessageId::WriteFailed.format(&[output, &err]))?;
+            report(options, &MessageId::IrWritten.format(&[output]));
+        },
+        None => print_stdout(&module.to_string())?,
+    }
+    
+    Ok(0)
+}
+
+fn fmt(options: &Options) -> Result<i32, Failure> {
+    let file = read_source_file(&options.input)?;
+    parse(&file, options)?;
+    
+    let formatted = format_source(&file.source)?;
+    
+    if formatted == file.source {
+        report(options, &MessageId::AlreadyFormatted.format(&[&options.input]));
+        return Ok(0);
+    }
+    
+    if options.check {
+        eprintln!("{}", MessageId::NotFormatted.format(&[&options.input]));
+        return Ok(1);
+    }
+    
+    fs::write(&options.input, formatted).map_err(|err| MessageId::WriteFailed.format(&[&options.input, &err]))?;
+    report(options, &MessageId::Formatted.format(&[&options.input]));
+    
+    Ok(0)
+}
+
+fn explain(options: &Options) -> Result<i32, Failure> {
+    let code = ErrorCode::parse(&options.input)
+        .ok_or_else(|| MessageId::UnknownErrorCode.format(&[&options.input]))?;
+    
+    print_stdout(&code.explain())?;
+    Ok(0)
+}
+
+fn print_stdout(text: &str) -> Result<(), String> {
+    match io::stdout().write_all(text.as_bytes()) {
+        Err(err) if err.kind() != io::ErrorKind::BrokenPipe => Err(MessageId::OutputFailed.format(&[&err])),
+        _ => Ok(()),
+    }
+}
+
+fn report(options: &Options, message: &str) {
+    if options.verbose {
+        eprintln!("{}", message);
+    }
+}
+
+fn read_source_file(file_path: &str) -> Result<SourceFile, String> {
+    let path = Path::new(file_path);
+    
+    if !path.exists() {
+        return Err(MessageId::FileNotFound.format(&[&file_path]));
+    }
+    
+    if path.extension().and_then(|ext| ext.to_str()) != Some("rv") {
+        return Err(MessageId::InvalidExtension.format(&[&file_path]));
+    }
+    
+    fs::read_to_string(path)
+        .map(|source| SourceFile::new(0, file_path, source))
+        .map_err(|err| MessageId::ReadFailed.format(&[&file_path, &err]))
+}
+
+pub fn report_failure(options: &Options, failure: &Failure) {
+    match (failure, options.error_format) {
+        (Failure::Aborted(_), ErrorFormat::Json | ErrorFormat::Sarif) => {},
+        (Failure::Message(message), ErrorFormat::Json) => eprintln!("{}", to_json(&Diagnostic::error(message), None)),
+        (Failure::Message(message) | Failure::Aborted(message), _) => {
+            eprintln!("{}: {}", MessageId::SeverityError.text(), message);
+        },
+    }
+}
+
+fn emit_diagnostics(diagnostics: &[Diagnostic], file: &SourceFile, options: &Options) {
+    match options.error_format {
+        ErrorFormat::Human => {
+            for diagnostic in diagnostics {
+                eprintln!("{}", Renderer::new(options.color).render(diagnostic, file));
+            }
+            
+            let mut codes: Vec<&str> = diagnostics.iter()
+                .filter(|diagnostic| diagnostic.is_error())
+                .filter_map(|diagnostic| diagnostic.code.as_deref())
+                .collect();
+            codes.sort();
+            codes.dedup();
+            if !codes.is_empty() {
+                eprintln!("{}", MessageId::ExplainHint.format(&[&codes.join(", ")]));
+            }
+        },
+        ErrorFormat::Json => {
+            for diagnostic in diagnostics {
+                eprintln!("{}", to_json(diagnostic, Some(file)));
+            }
+        },
+        ErrorFormat::Sarif => {
+            let _ = print_stdout(&format!("{}\n", to_sarif(diagnostics, file)));
+        },
+    }
+}
+
+fn parse(file: &SourceFile, options: &Options) -> Result<AstNode, Failure> {
+    report(options, MessageId::Lexing.text());
+    let mut lexer = Lexer::with_file_id(&file.source, file.id);
+    let tokens = lexer.tokenize();
+    
+    report(options, MessageId::Parsing.text());
+    let mut parser = Parser::new(tokens);
+    let mut errors = lexer.errors().to_vec();
+    let (ast, parser_errors) = parser.parse_recovering();
+    errors.extend(parser_errors.iter().map(ParseError::to_diagnostic));
+    
+    if errors.is_empty() {
+        return Ok(ast);
+    }
+    
+    let syntax_error_count = errors.len();
+    errors.extend(check_recovered(&ast, file));
+    errors.sort_by_key(|error| error.primary_span().map(|span| span.start));
+    emit_diagnostics(&errors, file, options);
+    Err(Failure::Aborted(MessageId::AbortedBySyntaxErrors.format(&[&syntax_error_count])))
+}
+
+fn check_recovered(ast: &AstNode, file: &SourceFile) -> Vec<Diagnostic> {
+    let mut analyzer = SemanticAnalyzer::new();
+    if analyzer.load_std_library().is_err() {
+        return Vec::new();
+    }
+    
+    analyzer.analyze(ast).iter()
+        .filter(|error| !error.is_warning())
+        .map(|error| error.to_diagnostic(file))
+        .collect()
+}
+
+fn analyze(file: &SourceFile, options: &Options) -> Result<(AstNode, SemanticAnalyzer), Failure> {
+    let ast = parse(file, options)?;
+    
+    report(options, MessageId::Analyzing.text());
+    let mut analyzer = SemanticAnalyzer::new();
+    if let Err(err) = analyzer.load_std_library() {
+        return Err(MessageId::StdLibFailed.format(&[&err]).into());
+    }
+    
+    let semantic_errors = analyzer.analyze(&ast);
+    let (mut diagnostics, errors): (Vec<Diagnostic>, Vec<Diagnostic>) = semantic_errors.iter()
+        .map(|error| error.to_diagnostic(file))
+        .partition(|diagnostic| !diagnostic.is_error());
+    
+    let error_count = errors.len();
+    diagnostics.extend(errors);
+    emit_diagnostics(&diagnostics, file, options);
+    
+    if error_count > 0 {
+        return Err(Failure::Aborted(MessageId::AbortedBySemanticErrors.format(&[&error_count])));
+    }
+    
+    report(options, MessageId::AnalysisSucceeded.text());
+    
+    Ok((ast, analyzer))
+}
+
+fn optimize(ast: &AstNode, analyzer: &SemanticAnalyzer, options: &Options) -> Result<Module, String> {
+    report(options, &MessageId::GeneratingIr.format(&[&options.opt_level]));
+    let mut module = lower_program(ast, analyzer.get_symbol_table())
+        .map_err(|err| MessageId::IrGenerationFailed.format(&[&err]))?;
+    
+    let hints = OptimizationHints::from_analyzer(analyzer);
+    let mut pass_manager = PassManager::for_level(options.opt_level, &hints);
+    pass_manager.set_dump_after_each_pass(options.dump_ir_passes);
+    pass_manager.run(&mut module).map_err(|err| MessageId::OptimizationFailed.format(&[&err]))?;
+    
+    if options.remarks {
+        for remark in pass_manager.remarks() {
+            eprintln!("{}: {}", MessageId::NoteLabel.text(), remark);
+        }
+    }
+    
+    Ok(module)
+}
+
+fn run_bytecode(file_path: &str) -> Result<i32, String> {
+    let bytes = fs::read(file_path).map_err(|err| MessageId::ReadFailed.format(&[&file_path, &err]))?;
+    let program = Program::from_bytes(&bytes).map_err(|err| MessageId::BytecodeLoadFailed.format(&[&err]))?;
+    
+    match Vm::new(&program).run() {
+        Ok(Value::Int(code)) => Ok(code),
+        Ok(_) => Ok(0),
+        Err(err) => Err(MessageId::RuntimeError.format(&[&err])),
+    }
+}
+
+fn output_path(options: &Options) -> PathBuf {
+    if let Some(output) = &options.output {
+        return PathBuf::from(output);
+    }
+    
+    let path = Path::new(&options.input);
+    if options.emit_bytecode {
+        path.with_extension("rvc")
+    } else if options.emit_c {
+        path.with_extension("c")
+    } else if options.emit_asm {
+        path.with_extension("s")
+    } else if options.emit_wat {
+        path.with_extension("wat")
+    } else if options.emit_llvm {
+        path.with_extension("ll")
+    } else if options.backend == Backend::Wasm {
+        path.with_extension("wasm")
+    } else {
+        path.with_extension("")
+    }
+}
+
+fn generate(module: &Module, structs: &[&StructDefinition], options: &Options, output: &Path) -> Result<(), CodegenError> {
+    if options.backend == Backend::Wasm {
+        let wasm = emit_wasm(module)?;
+        let (bytes, message) = if options.emit_wat {
+            (print_wat(&wasm).into_bytes(), MessageId::WasmTextWritten)
+        } else {
+            (encode_module(&wasm), MessageId::WasmModuleWritten)
+        };
+        write_output(output, &bytes)?;
+        report(options, &message.format(&[&output.display()]));
+        return Ok(());
+    }
+    
+    if options.backend == Backend::Llvm {
+        let ir = emit_llvm(module, structs)?;
+        if options.emit_llvm {
+            write_output(output, ir.as_bytes())?;
+            report(options, &MessageId::LlvmIrWritten.format(&[&output.display()]));
+        } else {
+            compile_llvm(&ir, output)?;
+            report(options, &MessageId::ExecutableCreated.format(&[&options.backend, &output.display()]));
+        }
+        return Ok(());
+    }
+    
+    let source = match options.backend {
+        Backend::X86_64 => emit_asm(module)?,
+        _ => emit_c(module)?,
+    };
+    
+    if options.emit_c || options.emit_asm {
+        write_output(output, source.as_bytes())?;
+        report(options, &MessageId::SourceWritten.format(&[&if options.emit_c { "C" } else { "Assembly" }, &output.display()]));
+        return Ok(());
+    }
+    
+    match options.backend {
+        Backend::X86_64 => assemble_and_link(&source, output)?,
+        _ => compile_c(&source, output)?,
+    }
+    report(options, &MessageId::ExecutableCreated.format(&[&options.backend, &output.display()]));
+    Ok(())
+}
+
+fn write_output(output: &Path, bytes: &[u8]) -> Result<(), CodegenError> {
+    fs::write(output, bytes)
+        .map_err(|err| CodegenError::new(MessageId::WriteFailed.format(&[&output.display(), &err])))
+}
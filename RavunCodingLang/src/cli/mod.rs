@@ -0,0 +1,4 @@
+//! CLI modülü
+pub mod args;
+pub mod commands;
+pub mod repl;
@@ -0,0 +1,84 @@
+use std::io::{self, BufRead, Write};
+
+use ravun::diagnostics::MessageId;
+use ravun::repl::{is_complete, Session};
+
+const PROMPT: &str = "ravun> ";
+const CONTINUATION_PROMPT: &str = "   ... ";
+
+pub fn start() -> Result<i32, String> {
+    println!("{}", MessageId::ReplBanner.text());
+    
+    let mut session = Session::new()?;
+    let stdin = io::stdin();
+    let mut lines = stdin.lock().lines();
+    let mut buffer = String::new();
+    
+    loop {
+        print!("{}", if buffer.is_empty() { PROMPT } else { CONTINUATION_PROMPT });
+        io::stdout().flush().map_err(|err| err.to_string())?;
+        
+        let line = match lines.next() {
+            Some(line) => line.map_err(|err| err.to_string())?,
+            None => break,
+        };
+        
+        if buffer.is_empty() {
+            let command = line.trim();
+            
+            if command.is_empty() {
+                continue;
+            }
+            if command.starts_with(':') {
+                if matches!(command, ":q" | ":quit") {
+                    break;
+                }
+                run_command(&session, command);
+                continue;
+            }
+        }
+        
+        buffer.push_str(&line);
+        buffer.push('\n');
+        
+        if !is_complete(&buffer) {
+            continue;
+        }
+        
+        let input = std::mem::take(&mut buffer);
+        match session.eval(&input) {
+            Ok(evaluation) => {
+                for warning in &evaluation.warnings {
+                    eprintln!("{}", warning);
+                }
+                if let Some((value, value_type)) = evaluation.value {
+                    println!("{}: {}", value, value_type);
+                }
+            },
+            Err(err) => eprintln!("{}", err),
+        }
+    }
+    
+    Ok(0)
+}
+
+fn run_command(session: &Session, command: &str) {
+    let (name, argument) = match command.split_once(char::is_whitespace) {
+        Some((name, argument)) => (name, argument.trim()),
+        None => (command, ""),
+    };
+    
+    let result = match name {
+        ":type" if !argument.is_empty() => session.type_of(argument).map(|t| t.to_string()),
+        ":ast" if !argument.is_empty() => session.ast(argument).map(|ast| format!("{:#?}", ast)),
+        ":type" | ":ast" => Err(MessageId::ReplCommandNeedsExpression.format(&[&name])),
+        ":symbols" => Ok(session.symbols().trim().to_string()),
+        ":help" => Ok(MessageId::ReplHelp.text().to_string()),
+        _ => Err(MessageId::ReplUnknownCommand.format(&[&name])),
+    };
+    
+    match result {
+        Ok(output) => println!("{}", output),
+        Err(err) => eprintln!("{}", err),
+    }
+}
@@ -0,0 +1,33 @@
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Backend {
+    #[default]
+    C,
+    X86_64,
+    Wasm,
+    Llvm,
+}
+
+impl Backend {
+    pub fn parse(name: &str) -> Option<Backend> {
+        match name {
+            "c" => Some(Backend::C),
+            "x86-64" | "x86_64" => Some(Backend::X86_64),
+            "wasm" => Some(Backend::Wasm),
+            "llvm" => Some(Backend::Llvm),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Backend {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Backend::C => write!(f, "c"),
+            Backend::X86_64 => write!(f, "x86-64"),
+            Backend::Wasm => write!(f, "wasm"),
+            Backend::Llvm => write!(f, "llvm"),
+        }
+    }
+}
@@ -0,0 +1,453 @@
+use std::collections::HashMap;
+use std::fmt::Write;
+use crate::codegen::error::CodegenError;
+use crate::codegen::names::{function_symbol, global_symbol, mangle};
+use crate::diagnostics::MessageId;
+use crate::ir::function::{Function, Module};
+use crate::ir::instruction::{
+    BinaryOp, BlockId, Constant, InstructionKind, Operand, Terminator, UnaryOp, ValueId,
+};
+use crate::semantics::types::Type;
+
+const RUNTIME: &str = include_str!("runtime.h");
+const BUILTINS: [&str; 2] = ["print", "println"];
+
+pub fn emit_c(module: &Module) -> Result<String, CodegenError> {
+    CEmitter::new(module).emit()
+}
+
+pub struct CEmitter<'a> {
+    module: &'a Module,
+    type_declarations: Vec<(String, String)>,
+    print_helpers: Vec<(String, String)>,
+}
+
+impl<'a> CEmitter<'a> {
+    pub fn new(module: &'a Module) -> Self {
+        CEmitter {
+            module,
+            type_declarations: Vec::new(),
+            print_helpers: Vec::new(),
+        }
+    }
+    
+    pub fn emit(mut self) -> Result<String, CodegenError> {
+        let main = self.module.function("main")
+            .ok_or_else(|| CodegenError::new(MessageId::CodegenMissingMain.text().to_string()))?;
+        if !main.params.is_empty() {
+            return Err(CodegenError::in_function("main", MessageId::CodegenMainWithParameters.text().to_string()));
+        }
+        
+        let mut globals = String::new();
+        for global in &self.module.globals {
+            let ty = self.c_type(&global.ty)?;
+            let _ = writeln!(
+                globals,
+                "static {} {} = {};",
+                ty,
+                global_symbol(&global.name),
+                static_initializer(&global.initializer),
+            );
+        }
+        
+        let mut prototypes = String::new();
+        let mut bodies = String::new();
+        for function in &self.module.functions {
+            let signature = self.signature(function)?;
+            let _ = writeln!(prototypes, "static {};", signature);
+            bodies.push('\n');
+            bodies.push_str(&self.emit_function(function)?);
+        }
+        
+        let mut out = String::new();
+        out.push_str(RUNTIME);
+        
+        for (_, declaration) in &self.type_declarations {
+            out.push('\n');
+            out.push_str(declaration);
+        }
+        if !globals.is_empty() {
+            out.push('\n');
+            out.push_str(&globals);
+        }
+        out.push('\n');
+        out.push_str(&prototypes);
+        for (_, helper) in &self.print_helpers {
+            out.push('\n');
+            out.push_str(helper);
+        }
+        out.push_str(&bodies);
+        
+        out.push_str("\nint main(void) {\n");
+        match main.return_type {
+            Type::Int => out.push_str("    return rv_f_main();\n"),
+            _ => out.push_str("    rv_f_main();\n    return 0;\n"),
+        }
+        out.push_str("}\n");
+        
+        Ok(out)
+    }
+    
+    pub fn c_type(&mut self, ty: &Type) -> Result<String, CodegenError> {
+        match ty {
+            Type::Int => Ok("int32_t".to_string()),
+            Type::Float => Ok("double".to_string()),
+            Type::Bool => Ok("bool".to_string()),
+            Type::String => Ok("rv_string".to_string()),
+            Type::Void => Ok("void".to_string()),
+            Type::Array(_, _) => Ok("rv_array".to_string()),
+            Type::Ref(inner) => Ok(format!("{} *", self.c_type(inner)?)),
+            Type::Struct(name) => {
+                let tag = format!("rv_struct_{}", mangle(name));
+                self.declare_type(&tag, format!("struct {};\n", tag));
+                Ok(format!("struct {} *", tag))
+            },
+            Type::Optional(inner) => {
+                let name = format!("rv_opt_{}", type_key(inner)?);
+                let value = self.c_type(inner)?;
+                self.declare_type(&name, format!(
+                    "typedef struct {{\n    bool present;\n    {} value;\n}} {};\n",
+                    value, name,
+                ));
+                Ok(name)
+            },
+            other => Err(CodegenError::new(MessageId::CodegenUnsupportedType.format(&[&"C", &other]))),
+        }
+    }
+    
+    fn declare_type(&mut self, name: &str, declaration: String) {
+        if !self.type_declarations.iter().any(|(n, _)| n == name) {
+            self.type_declarations.push((name.to_string(), declaration));
+        }
+    }
+    
+    fn signature(&mut self, function: &Function) -> Result<String, CodegenError> {
+        let return_type = self.c_type(&function.return_type)?;
+        let params = if function.params.is_empty() {
+            "void".to_string()
+        } else {
+            function.params.iter()
+                .map(|p| Ok(format!("{} {}", self.c_type(&p.ty)?, value_name(p.id))))
+                .collect::<Result<Vec<_>, CodegenError>>()?
+                .join(", ")
+        };
+        
+        Ok(format!("{} {}({})", return_type, function_symbol(&function.name), params))
+    }
+    
+    fn emit_function(&mut self, function: &Function) -> Result<String, CodegenError> {
+        let types = function.value_types();
+        let mut out = String::new();
+        let _ = writeln!(out, "static {} {{", self.signature(function)?);
+        
+        let mut locals: Vec<(ValueId, &Type)> = function.blocks.iter()
+            .flat_map(|b| b.instructions.iter())
+            .filter_map(|i| i.result.map(|r| (r, &i.ty)))
+            .collect();
+        locals.sort_by_key(|(id, _)| *id);
+        
+        for (id, ty) in locals {
+            let c_type = self.c_type(ty).map_err(|e| CodegenError::in_function(&function.name, e.message))?;
+            let _ = writeln!(out, "    {} {};", c_type, value_name(id));
+        }
+        
+        let mut context = FunctionContext { function, types: &types, out: &mut out };
+        
+        for block in &function.blocks {
+            let _ = writeln!(context.out, "{}:", block_label(block.id));
+            
+            for instruction in block.instructions.iter().filter(|i| !i.is_phi()) {
+                let statement = self.emit_instruction(&context, instruction.result, &instruction.ty, &instruction.kind)
+                    .map_err(|e| CodegenError::in_function(&function.name, e.message))?;
+                let _ = writeln!(context.out, "    {}", statement);
+            }
+            
+            let terminator = block.terminator.as_ref().unwrap_or(&Terminator::Unreachable);
+            self.emit_terminator(&mut context, block.id, terminator)
+                .map_err(|e| CodegenError::in_function(&function.name, e.message))?;
+        }
+        
+        out.push_str("}\n");
+        Ok(out)
+    }
+    
+    fn emit_instruction(
+        &mut self,
+        context: &FunctionContext,
+        result: Option<ValueId>,
+        ty: &Type,
+        kind: &InstructionKind,
+    ) -> Result<String, CodegenError> {
+        let expression = match kind {
+            InstructionKind::Binary(op, lhs, rhs) => {
+                let operand_type = context.function.operand_type(lhs, context.types);
+                binary_expression(*op, &operand_type, &operand(lhs), &operand(rhs))?
+            },
+            InstructionKind::Unary(UnaryOp::Neg, value) if *ty == Type::Int => format!("rv_int_neg({})", operand(value)),
+            InstructionKind::Unary(UnaryOp::Neg, value) => format!("-({})", operand(value)),
+            InstructionKind::Unary(UnaryOp::Not, value) if *ty == Type::Int => format!("~{}", operand(value)),
+            InstructionKind::Unary(UnaryOp::Not, value) => format!("!{}", operand(value)),
+            InstructionKind::IntToFloat(value) => format!("(double){}", operand(value)),
+            InstructionKind::Call(name, args) if BUILTINS.contains(&name.as_str()) => {
+                return self.print_statement(context, name == "println", args);
+            },
+            InstructionKind::Call(name, args) => {
+                if self.module.function(name).is_none() {
+                    return Err(CodegenError::new(MessageId::CodegenUnknownFunction.format(&[&name])));
+                }
+                let args: Vec<String> = args.iter().map(operand).collect();
+                format!("{}({})", function_symbol(name), args.join(", "))
+            },
+            InstructionKind::LoadGlobal(name) => global_symbol(name),
+            InstructionKind::StoreGlobal(name, value) => {
+                return Ok(format!("{} = {};", global_symbol(name), operand(value)));
+            },
+            InstructionKind::Length(value) => match context.function.operand_type(value, context.types) {
+                Type::String => format!("rv_string_length({})", operand(value)),
+                Type::Array(_, _) => format!("{}.len", operand(value)),
+                other => return Err(CodegenError::new(MessageId::CodegenNoLength.format(&[&"C", &other]))),
+            },
+            InstructionKind::Index(collection, index) => match context.function.operand_type(collection, context.types) {
+                Type::String => format!("rv_string_index({}, {})", operand(collection), operand(index)),
+                Type::Array(element, _) => {
+                    let element = self.c_type(&element)?;
+                    format!("*({} *)rv_array_at({}, {}, sizeof({}))", element, operand(collection), operand(index), element)
+                },
+                other => return Err(CodegenError::new(MessageId::CodegenNotIndexable.format(&[&"C", &other]))),
+            },
+            InstructionKind::Phi(_) => return Ok(String::new()),
+        };
+        
+        Ok(match result {
+            Some(result) => format!("{} = {};", value_name(result), expression),
+            None => format!("{};", expression),
+        })
+    }
+    
+    fn print_statement(&mut self, context: &FunctionContext, newline: bool, args: &[Operand]) -> Result<String, CodegenError> {
+        let mut parts = Vec::new();
+        
+        for (i, arg) in args.iter().enumerate() {
+            if i > 0 {
+                parts.push("fputs(\" \", stdout);".to_string());
+            }
+            let ty = context.function.operand_type(arg, context.types);
+            parts.push(format!("{};", self.print_call(&ty, &operand(arg))?));
+        }
+        if newline {
+            parts.push("putchar('\\n');".to_string());
+        }
+        
+        Ok(parts.join(" "))
+    }
+    
+    fn print_call(&mut self, ty: &Type, value: &str) -> Result<String, CodegenError> {
+        let helper = match ty {
+            Type::Int => "rv_print_int".to_string(),
+            Type::Float => "rv_print_float".to_string(),
+            Type::Bool => "rv_print_bool".to_string(),
+            Type::String => "rv_print_string".to_string(),
+            Type::Array(element, _) => {
+                let name = format!("rv_print_arr_{}", type_key(element)?);
+                if !self.print_helpers.iter().any(|(n, _)| *n == name) {
+                    let element_type = self.c_type(element)?;
+                    let print_element = self.print_call(element, &format!("(({} *)value.data)[i]", element_type))?;
+                    self.print_helpers.push((name.clone(), format!(
+                        "static void {}(rv_array value) {{\n    int32_t i;\n    putchar('[');\n    for (i = 0; i < value.len; i++) {{\n        if (i > 0) {{\n            fputs(\", \", stdout);\n        }}\n        {};\n    }}\n    putchar(']');\n}}\n",
+                        name, print_element,
+                    )));
+                }
+                name
+            },
+            Type::Optional(inner) => {
+                let name = format!("rv_print_opt_{}", type_key(inner)?);
+                if !self.print_helpers.iter().any(|(n, _)| *n == name) {
+                    let optional_type = self.c_type(ty)?;
+                    let print_inner = self.print_call(inner, "value.value")?;
+                    self.print_helpers.push((name.clone(), format!(
+                        "static void {}({} value) {{\n    if (value.present) {{\n        {};\n    }} else {{\n        fputs(\"null\", stdout);\n    }}\n}}\n",
+                        name, optional_type, print_inner,
+                    )));
+                }
+                name
+            },
+            other => return Err(CodegenError::new(MessageId::CodegenUnprintableType.format(&[&other]))),
+        };
+        
+        Ok(format!("{}({})", helper, value))
+    }
+    
+    fn emit_terminator(&mut self, context: &mut FunctionContext, block: BlockId, terminator: &Terminator) -> Result<(), CodegenError> {
+        match terminator {
+            Terminator::Return(Some(value)) => {
+                let _ = writeln!(context.out, "    return {};", operand(value));
+            },
+            Terminator::Return(None) => {
+                let _ = writeln!(context.out, "    return;");
+            },
+            Terminator::Jump(target) => {
+                let copies = self.phi_copies(context, block, *target)?;
+                let _ = writeln!(context.out, "{}    goto {};", copies.indented("    "), block_label(*target));
+            },
+            Terminator::Branch(condition, then_block, else_block) => {
+                let then_copies = self.phi_copies(context, block, *then_block)?;
+                let else_copies = self.phi_copies(context, block, *else_block)?;
+                let _ = writeln!(context.out, "    if ({}) {{", operand(condition));
+                let _ = writeln!(context.out, "{}        goto {};", then_copies.indented("        "), block_label(*then_block));
+                let _ = writeln!(context.out, "    }} else {{");
+                let _ = writeln!(context.out, "{}        goto {};", else_copies.indented("        "), block_label(*else_block));
+                let _ = writeln!(context.out, "    }}");
+            },
+            Terminator::Unreachable => {
+                let _ = writeln!(context.out, "    rv_unreachable();");
+            },
+        }
+        
+        Ok(())
+    }
+    
+    fn phi_copies(&mut self, context: &FunctionContext, from: BlockId, to: BlockId) -> Result<Copies, CodegenError> {
+        let Some(target) = context.function.block(to) else {
+            return Ok(Copies(Vec::new()));
+        };
+        
+        let mut assignments = Vec::new();
+        for phi in target.phis() {
+            let (Some(result), InstructionKind::Phi(incoming)) = (phi.result, &phi.kind) else {
+                continue;
+            };
+            if let Some((_, value)) = incoming.iter().find(|(pred, _)| *pred == from) {
+                assignments.push((result, self.c_type(&phi.ty)?, operand(value)));
+            }
+        }
+        
+        if assignments.len() <= 1 {
+            return Ok(Copies(assignments.into_iter()
+                .map(|(result, _, value)| format!("{} = {};", value_name(result), value))
+                .collect()));
+        }
+        
+        let mut lines: Vec<String> = assignments.iter().enumerate()
+            .map(|(i, (_, ty, value))| format!("{} t{} = {};", ty, i, value))
+            .collect();
+        lines.extend(assignments.iter().enumerate().map(|(i, (result, _, _))| format!("{} = t{};", value_name(*result), i)));
+        
+        let mut block = vec!["{".to_string()];
+        block.extend(lines.into_iter().map(|line| format!("    {}", line)));
+        block.push("}".to_string());
+        Ok(Copies(block))
+    }
+}
+
+struct FunctionContext<'f> {
+    function: &'f Function,
+    types: &'f HashMap<ValueId, Type>,
+    out: &'f mut String,
+}
+
+struct Copies(Vec<String>);
+
+impl Copies {
+    fn indented(&self, indent: &str) -> String {
+        self.0.iter().map(|line| format!("{}{}\n", indent, line)).collect()
+    }
+}
+
+fn binary_expression(op: BinaryOp, operand_type: &Type, lhs: &str, rhs: &str) -> Result<String, CodegenError> {
+    let symbol = match op {
+        BinaryOp::Eq => "==",
+        BinaryOp::Ne => "!=",
+        BinaryOp::Lt => "<",
+        BinaryOp::Gt => ">",
+        BinaryOp::Le => "<=",
+        BinaryOp::Ge => ">=",
+        BinaryOp::Add => "+",
+        BinaryOp::Sub => "-",
+        BinaryOp::Mul => "*",
+        BinaryOp::Div => "/",
+        BinaryOp::Rem | BinaryOp::Pow => "",
+        BinaryOp::And | BinaryOp::Or | BinaryOp::Xor | BinaryOp::Shl | BinaryOp::Shr => "",
+    };
+    
+    Ok(match (operand_type, op) {
+        (Type::Int, op) if !op.is_comparison() => format!("rv_int_{}({}, {})", op.mnemonic(), lhs, rhs),
+        (Type::Float, BinaryOp::Rem) => format!("fmod({}, {})", lhs, rhs),
+        (Type::Float, BinaryOp::Pow) => format!("pow({}, {})", lhs, rhs),
+        (Type::Int | Type::Float, _) => format!("({} {} {})", lhs, symbol, rhs),
+        (Type::String, BinaryOp::Add) => format!("rv_string_concat({}, {})", lhs, rhs),
+        (Type::String, op) if op.is_comparison() => format!("(rv_string_compare({}, {}) {} 0)", lhs, rhs, symbol),
+        (Type::Bool, BinaryOp::Eq | BinaryOp::Ne) => format!("({} {} {})", lhs, symbol, rhs),
+        (other, op) => return Err(CodegenError::new(MessageId::CodegenUnsupportedOperation.format(&[
+            &"C", &other, &op.mnemonic(),
+        ]))),
+    })
+}
+
+fn type_key(ty: &Type) -> Result<String, CodegenError> {
+    match ty {
+        Type::Int => Ok("int".to_string()),
+        Type::Float => Ok("float".to_string()),
+        Type::Bool => Ok("bool".to_string()),
+        Type::String => Ok("string".to_string()),
+        Type::Array(element, _) => Ok(format!("arr_{}", type_key(element)?)),
+        Type::Optional(inner) => Ok(format!("opt_{}", type_key(inner)?)),
+        Type::Ref(inner) => Ok(format!("ref_{}", type_key(inner)?)),
+        Type::Struct(name) => Ok(format!("struct_{}", mangle(name))),
+        other => Err(CodegenError::new(MessageId::CodegenUnsupportedType.format(&[&"C", &other]))),
+    }
+}
+
+fn operand(operand: &Operand) -> String {
+    match operand {
+        Operand::Value(id) => value_name(*id),
+        Operand::Const(constant) => constant_expression(constant),
+    }
+}
+
+fn constant_expression(constant: &Constant) -> String {
+    match constant {
+        Constant::Int(i32::MIN) => "INT32_MIN".to_string(),
+        Constant::Int(i) => i.to_string(),
+        Constant::Float(x) if x.is_nan() => "NAN".to_string(),
+        Constant::Float(x) if x.is_infinite() => if *x > 0.0 { "INFINITY" } else { "-INFINITY" }.to_string(),
+        Constant::Float(x) => format!("{:?}", x),
+        Constant::Bool(b) => b.to_string(),
+        Constant::String(s) => format!("RV_STR({})", string_literal(s)),
+    }
+}
+
+fn static_initializer(constant: &Constant) -> String {
+    match constant {
+        Constant::String(s) => format!("{{ {}, {} }}", string_literal(s), s.len()),
+        other => constant_expression(other),
+    }
+}
+
+fn string_literal(s: &str) -> String {
+    let mut literal = String::from("\"");
+    
+    for byte in s.bytes() {
+        match byte {
+            b'"' => literal.push_str("\\\""),
+            b'\\' => literal.push_str("\\\\"),
+            b'\n' => literal.push_str("\\n"),
+            b'\t' => literal.push_str("\\t"),
+            b'?' => literal.push_str("\\?"),
+            0x20..=0x7e => literal.push(byte as char),
+            _ => {
+                let _ = write!(literal, "\\{:03o}", byte);
+            },
+        }
+    }
+    
+    literal.push('"');
+    literal
+}
+
+fn value_name(id: ValueId) -> String {
+    format!("v{}", id.0)
+}
+
+fn block_label(id: BlockId) -> String {
+    format!("bb{}", id.0)
+}
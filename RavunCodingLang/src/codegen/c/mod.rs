@@ -0,0 +1,5 @@
+pub mod emitter;
+pub mod toolchain;
+
+pub use emitter::{emit_c, CEmitter};
+pub use toolchain::compile_c;
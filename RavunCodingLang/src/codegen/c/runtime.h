@@ -0,0 +1,259 @@
+/* Ravun C çalışma zamanı */
+#include <stdbool.h>
+#include <stdint.h>
+#include <inttypes.h>
+#include <stdio.h>
+#include <stdlib.h>
+#include <string.h>
+#include <math.h>
+
+typedef struct {
+    const char *data;
+    int32_t len;
+} rv_string;
+
+typedef struct {
+    void *data;
+    int32_t len;
+} rv_array;
+
+#define RV_STR(lit) ((rv_string){ (lit), (int32_t)(sizeof(lit) - 1) })
+
+static void rv_panic(const char *message) {
+    fflush(stdout);
+    fprintf(stderr, "Çalışma zamanı hatası: %s\n", message);
+    exit(1);
+}
+
+static void rv_unreachable(void) {
+    rv_panic("Ulaşılamaz koda ulaşıldı");
+}
+
+static int32_t rv_int_add(int32_t a, int32_t b) {
+    return (int32_t)((uint32_t)a + (uint32_t)b);
+}
+
+static int32_t rv_int_sub(int32_t a, int32_t b) {
+    return (int32_t)((uint32_t)a - (uint32_t)b);
+}
+
+static int32_t rv_int_mul(int32_t a, int32_t b) {
+    return (int32_t)((uint32_t)a * (uint32_t)b);
+}
+
+static int32_t rv_int_neg(int32_t a) {
+    return (int32_t)(0u - (uint32_t)a);
+}
+
+static int32_t rv_int_div(int32_t a, int32_t b) {
+    if (b == 0) {
+        rv_panic("Sıfıra bölme");
+    }
+    if (a == INT32_MIN && b == -1) {
+        return INT32_MIN;
+    }
+    return a / b;
+}
+
+static int32_t rv_int_rem(int32_t a, int32_t b) {
+    if (b == 0) {
+        rv_panic("Sıfıra bölme");
+    }
+    if (b == -1) {
+        return 0;
+    }
+    return a % b;
+}
+
+static int32_t rv_int_pow(int32_t base, int32_t exponent) {
+    uint32_t result = 1;
+    uint32_t factor = (uint32_t)base;
+
+    if (exponent < 0) {
+        if (base == 1) {
+            return 1;
+        }
+        if (base == -1) {
+            return (exponent % 2 == 0) ? 1 : -1;
+        }
+        return 0;
+    }
+
+    while (exponent > 0) {
+        if (exponent & 1) {
+            result *= factor;
+        }
+        factor *= factor;
+        exponent >>= 1;
+    }
+    return (int32_t)result;
+}
+
+static int32_t rv_int_and(int32_t a, int32_t b) {
+    return a & b;
+}
+
+static int32_t rv_int_or(int32_t a, int32_t b) {
+    return a | b;
+}
+
+static int32_t rv_int_xor(int32_t a, int32_t b) {
+    return a ^ b;
+}
+
+static int32_t rv_int_shl(int32_t a, int32_t b) {
+    return (int32_t)((uint32_t)a << (b & 31));
+}
+
+static int32_t rv_int_shr(int32_t a, int32_t b) {
+    return a >> (b & 31);
+}
+
+static int32_t rv_utf8_width(unsigned char lead) {
+    if (lead < 0x80) return 1;
+    if ((lead >> 5) == 0x6) return 2;
+    if ((lead >> 4) == 0xE) return 3;
+    if ((lead >> 3) == 0x1E) return 4;
+    return 1;
+}
+
+static int32_t rv_string_length(rv_string s) {
+    int32_t count = 0;
+    int32_t i;
+
+    for (i = 0; i < s.len; i++) {
+        if ((s.data[i] & 0xC0) != 0x80) {
+            count++;
+        }
+    }
+    return count;
+}
+
+static rv_string rv_string_index(rv_string s, int32_t index) {
+    int32_t offset = 0;
+    int32_t current = 0;
+    rv_string result;
+
+    if (index < 0) {
+        rv_panic("Dizin sınırların dışında");
+    }
+
+    while (offset < s.len) {
+        int32_t width = rv_utf8_width((unsigned char)s.data[offset]);
+        if (current == index) {
+            result.data = s.data + offset;
+            result.len = (offset + width <= s.len) ? width : s.len - offset;
+            return result;
+        }
+        offset += width;
+        current++;
+    }
+
+    rv_panic("Dizin sınırların dışında");
+    return s;
+}
+
+static rv_string rv_string_concat(rv_string a, rv_string b) {
+    char *data = malloc((size_t)a.len + (size_t)b.len + 1);
+    rv_string result;
+
+    if (data == NULL) {
+        rv_panic("Bellek yetersiz");
+    }
+    memcpy(data, a.data, (size_t)a.len);
+    memcpy(data + a.len, b.data, (size_t)b.len);
+    data[a.len + b.len] = '\0';
+
+    result.data = data;
+    result.len = a.len + b.len;
+    return result;
+}
+
+static int rv_string_compare(rv_string a, rv_string b) {
+    int32_t common = a.len < b.len ? a.len : b.len;
+    int order = memcmp(a.data, b.data, (size_t)common);
+
+    if (order != 0) {
+        return order < 0 ? -1 : 1;
+    }
+    if (a.len == b.len) {
+        return 0;
+    }
+    return a.len < b.len ? -1 : 1;
+}
+
+static void *rv_array_at(rv_array a, int32_t index, size_t element_size) {
+    if (index < 0 || index >= a.len) {
+        rv_panic("Dizin sınırların dışında");
+    }
+    return (char *)a.data + (size_t)index * element_size;
+}
+
+static void rv_print_int(int32_t value) {
+    printf("%" PRId32, value);
+}
+
+static void rv_print_bool(bool value) {
+    fputs(value ? "true" : "false", stdout);
+}
+
+static void rv_print_string(rv_string value) {
+    fwrite(value.data, 1, (size_t)value.len, stdout);
+}
+
+static void rv_print_float(double value) {
+    char scientific[40];
+    char digits[24];
+    int precision;
+    int exponent;
+    int count = 0;
+    int point;
+    int i;
+    const char *p;
+
+    if (isnan(value)) {
+        fputs("NaN", stdout);
+        return;
+    }
+    if (isinf(value)) {
+        fputs(value > 0 ? "inf" : "-inf", stdout);
+        return;
+    }
+
+    for (precision = 1; precision <= 17; precision++) {
+        snprintf(scientific, sizeof scientific, "%.*e", precision - 1, value);
+        if (strtod(scientific, NULL) == value) {
+            break;
+        }
+    }
+
+    p = scientific;
+    if (*p == '-') {
+        putchar('-');
+        p++;
+    }
+    for (; *p != 'e'; p++) {
+        if (*p != '.') {
+            digits[count++] = *p;
+        }
+    }
+    exponent = atoi(p + 1);
+    point = exponent + 1;
+
+    if (point <= 0) {
+        fputs("0.", stdout);
+        for (i = 0; i < -point; i++) {
+            putchar('0');
+        }
+        fwrite(digits, 1, (size_t)count, stdout);
+    } else if (point >= count) {
+        fwrite(digits, 1, (size_t)count, stdout);
+        for (i = count; i < point; i++) {
+            putchar('0');
+        }
+    } else {
+        fwrite(digits, 1, (size_t)point, stdout);
+        putchar('.');
+        fwrite(digits + point, 1, (size_t)(count - point), stdout);
+    }
+}
@@ -0,0 +1,40 @@
+use std::env;
+use std::io::Write;
+use std::path::Path;
+use std::process::{Command, Stdio};
+use crate::codegen::error::CodegenError;
+use crate::diagnostics::MessageId;
+
+pub fn c_compiler() -> String {
+    env::var("CC").unwrap_or_else(|_| "cc".to_string())
+}
+
+pub fn compile_c(source: &str, output: &Path) -> Result<(), CodegenError> {
+    let compiler = c_compiler();
+    
+    let mut child = Command::new(&compiler)
+        .args(["-std=c99", "-O2", "-x", "c", "-", "-o"])
+        .arg(output)
+        .arg("-lm")
+        .stdin(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|err| CodegenError::new(MessageId::CCompilerNotRun.format(&[&compiler, &err])))?;
+    
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin.write_all(source.as_bytes())
+            .map_err(|err| CodegenError::new(MessageId::CSourceNotPiped.format(&[&err])))?;
+    }
+    
+    let result = child.wait_with_output()
+        .map_err(|err| CodegenError::new(MessageId::CCompilerWaitFailed.format(&[&err])))?;
+    
+    if !result.status.success() {
+        return Err(CodegenError::new(MessageId::CCompilerFailed.format(&[
+            &result.status,
+            &String::from_utf8_lossy(&result.stderr).trim_end(),
+        ])));
+    }
+    
+    Ok(())
+}
@@ -0,0 +1,34 @@
+use std::fmt;
+
+#[derive(Debug, Clone)]
+pub struct CodegenError {
+    pub function: Option<String>,
+    pub message: String,
+}
+
+impl CodegenError {
+    pub fn new(message: String) -> Self {
+        CodegenError {
+            function: None,
+            message,
+        }
+    }
+    
+    pub fn in_function(function: &str, message: String) -> Self {
+        CodegenError {
+            function: Some(function.to_string()),
+            message,
+        }
+    }
+}
+
+impl fmt::Display for CodegenError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.function {
+            Some(function) => write!(f, "@{}: {}", function, self.message),
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
+
+impl std::error::Error for CodegenError {}
@@ -0,0 +1,480 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt::Write;
+use crate::codegen::error::CodegenError;
+use crate::codegen::llvm::types::{compute_layouts, llvm_type, ARRAY_TYPE, STRING_TYPE};
+use crate::codegen::names::{function_symbol, global_symbol};
+use crate::diagnostics::MessageId;
+use crate::ir::function::{Function, Module};
+use crate::ir::instruction::{
+    BinaryOp, BlockId, Constant, InstructionKind, Operand, Terminator, UnaryOp, ValueId,
+};
+use crate::semantics::symbol_table::StructDefinition;
+use crate::semantics::types::Type;
+
+const BUILTINS: [&str; 2] = ["print", "println"];
+
+// x86-64 arka ucuyla paylaşılan çalışma zamanı (runtime.c) fonksiyonları.
+const RUNTIME_DECLARATIONS: &str = "declare void @rv_rt_division_by_zero()
+declare void @rv_rt_unreachable()
+declare i32 @rv_rt_int_pow(i32, i32)
+declare ptr @rv_rt_string_concat(ptr, ptr)
+declare i32 @rv_rt_string_compare(ptr, ptr)
+declare i32 @rv_rt_string_length(ptr)
+declare ptr @rv_rt_string_index(ptr, i32)
+declare void @rv_rt_print_int(i32)
+declare void @rv_rt_print_float(double)
+declare void @rv_rt_print_bool(i32)
+declare void @rv_rt_print_string(ptr)
+declare void @rv_rt_print_space()
+declare void @rv_rt_print_newline()
+declare double @llvm.pow.f64(double, double)
+";
+
+// Sıfıra bölme ve INT_MIN / -1 taşması C ve x86-64 arka uçlarıyla aynı şekilde ele alınır.
+const DIVISION_HELPERS: &str = "define internal i32 @rv.sdiv(i32 %a, i32 %b) alwaysinline {
+entry:
+  %zero = icmp eq i32 %b, 0
+  br i1 %zero, label %trap, label %check
+trap:
+  call void @rv_rt_division_by_zero()
+  unreachable
+check:
+  %minus_one = icmp eq i32 %b, -1
+  br i1 %minus_one, label %negate, label %divide
+negate:
+  %negated = sub i32 0, %a
+  ret i32 %negated
+divide:
+  %quotient = sdiv i32 %a, %b
+  ret i32 %quotient
+}
+
+define internal i32 @rv.srem(i32 %a, i32 %b) alwaysinline {
+entry:
+  %zero = icmp eq i32 %b, 0
+  br i1 %zero, label %trap, label %check
+trap:
+  call void @rv_rt_division_by_zero()
+  unreachable
+check:
+  %minus_one = icmp eq i32 %b, -1
+  br i1 %minus_one, label %done, label %divide
+done:
+  ret i32 0
+divide:
+  %remainder = srem i32 %a, %b
+  ret i32 %remainder
+}
+";
+
+pub fn emit_llvm(module: &Module, structs: &[&StructDefinition]) -> Result<String, CodegenError> {
+    LlvmEmitter::new(module, structs).emit()
+}
+
+pub struct LlvmEmitter<'a> {
+    module: &'a Module,
+    structs: &'a [&'a StructDefinition],
+    strings: Vec<String>,
+}
+
+impl<'a> LlvmEmitter<'a> {
+    pub fn new(module: &'a Module, structs: &'a [&'a StructDefinition]) -> Self {
+        LlvmEmitter {
+            module,
+            structs,
+            strings: Vec::new(),
+        }
+    }
+    
+    pub fn emit(mut self) -> Result<String, CodegenError> {
+        let main = self.module.function("main")
+            .ok_or_else(|| CodegenError::new(MessageId::CodegenMissingMain.text().to_string()))?;
+        if !main.params.is_empty() {
+            return Err(CodegenError::in_function("main", MessageId::CodegenMainWithParameters.text().to_string()));
+        }
+        
+        let mut types = String::new();
+        let _ = writeln!(types, "{} = type {{ ptr, i32 }}", STRING_TYPE);
+        let _ = writeln!(types, "{} = type {{ ptr, i32 }}", ARRAY_TYPE);
+        for layout in compute_layouts(self.structs)? {
+            let fields: Vec<String> = layout.fields.iter().map(|f| format!("{}@{}", f.name, f.offset)).collect();
+            let _ = writeln!(types, "; {}: {} (boyut {}, hizalama {})", layout.name, fields.join(", "), layout.size, layout.align);
+            let _ = writeln!(types, "{}", layout.definition());
+        }
+        
+        let mut globals = String::new();
+        for global in &self.module.globals {
+            let ty = llvm_type(&global.ty)?;
+            let initializer = self.constant(&global.initializer);
+            let _ = writeln!(globals, "{} = internal global {} {}", global_name(&global.name), ty, initializer);
+        }
+        
+        let mut bodies = String::new();
+        for function in &self.module.functions {
+            bodies.push('\n');
+            let body = self.emit_function(function)
+                .map_err(|e| CodegenError::in_function(&function.name, e.message))?;
+            bodies.push_str(&body);
+        }
+        
+        let mut out = String::new();
+        out.push_str("; ModuleID = 'ravun'\nsource_filename = \"ravun\"\n\n");
+        out.push_str(&types);
+        out.push('\n');
+        for (index, value) in self.strings.iter().enumerate() {
+            let _ = writeln!(
+                out,
+                "@.str.{} = private unnamed_addr constant [{} x i8] c\"{}\"",
+                index, value.len(), escape_bytes(value.as_bytes()),
+            );
+            let _ = writeln!(
+                out,
+                "@.strrec.{} = private unnamed_addr constant {} {{ ptr @.str.{}, i32 {} }}",
+                index, STRING_TYPE, index, value.len(),
+            );
+        }
+        out.push_str(&globals);
+        out.push('\n');
+        out.push_str(RUNTIME_DECLARATIONS);
+        out.push('\n');
+        out.push_str(DIVISION_HELPERS);
+        out.push_str(&bodies);
+        
+        out.push_str("\ndefine i32 @main() {\nentry:\n");
+        match main.return_type {
+            Type::Int => out.push_str("  %result = call i32 @rv_f_main()\n  ret i32 %result\n"),
+            _ => {
+                let _ = writeln!(out, "  call {} @rv_f_main()", llvm_type(&main.return_type)?);
+                out.push_str("  ret i32 0\n");
+            },
+        }
+        out.push_str("}\n");
+        
+        Ok(out)
+    }
+    
+    fn emit_function(&mut self, function: &Function) -> Result<String, CodegenError> {
+        let types = function.value_types();
+        let order = function.reverse_postorder();
+        let reachable: HashSet<BlockId> = order.iter().copied().collect();
+        let predecessors = function.predecessors();
+        
+        let params = function.params.iter()
+            .map(|p| Ok(format!("{} {}", llvm_type(&p.ty)?, value_name(p.id))))
+            .collect::<Result<Vec<_>, CodegenError>>()?;
+        
+        let mut context = FunctionContext {
+            function,
+            types: &types,
+            out: String::new(),
+            temporaries: 0,
+        };
+        let _ = writeln!(
+            context.out,
+            "define internal {} {}({}) {{",
+            llvm_type(&function.return_type)?,
+            function_name(&function.name),
+            params.join(", "),
+        );
+        
+        // LLVM giriş bloğunun öncülü olmasına izin vermez.
+        if let Some(entry) = order.first()
+            && predecessors.get(entry).is_some_and(|p| !p.is_empty()) {
+            let _ = writeln!(context.out, "entry:\n  br label %{}", entry);
+        }
+        
+        for block_id in &order {
+            let Some(block) = function.block(*block_id) else {
+                continue;
+            };
+            let _ = writeln!(context.out, "{}:", block.id);
+            
+            for instruction in &block.instructions {
+                match &instruction.kind {
+                    InstructionKind::Phi(incoming) => {
+                        let result = instruction.result
+                            .ok_or_else(|| CodegenError::new(MessageId::CodegenMissingPhiResult.text().to_string()))?;
+                        let entries: Vec<String> = incoming.iter()
+                            .filter(|(pred, _)| reachable.contains(pred))
+                            .map(|(pred, value)| format!("[ {}, %{} ]", self.operand(value), pred))
+                            .collect();
+                        let _ = writeln!(
+                            context.out,
+                            "  {} = phi {} {}",
+                            value_name(result), llvm_type(&instruction.ty)?, entries.join(", "),
+                        );
+                    },
+                    kind => self.emit_instruction(&mut context, instruction.result, &instruction.ty, kind)?,
+                }
+            }
+            
+            let terminator = block.terminator.as_ref().unwrap_or(&Terminator::Unreachable);
+            self.emit_terminator(&mut context, terminator)?;
+        }
+        
+        context.out.push_str("}\n");
+        Ok(context.out)
+    }
+    
+    fn emit_instruction(
+        &mut self,
+        context: &mut FunctionContext,
+        result: Option<ValueId>,
+        ty: &Type,
+        kind: &InstructionKind,
+    ) -> Result<(), CodegenError> {
+        let assign = match result {
+            Some(result) if *ty != Type::Void => format!("{} = ", value_name(result)),
+            _ => String::new(),
+        };
+        
+        match kind {
+            InstructionKind::Binary(op, lhs, rhs) => {
+                let operand_type = context.operand_type(lhs);
+                let a = self.operand(lhs);
+                let b = self.operand(rhs);
+                let line = match (&operand_type, op) {
+                    (Type::Int, BinaryOp::Add) => format!("add i32 {}, {}", a, b),
+                    (Type::Int, BinaryOp::Sub) => format!("sub i32 {}, {}", a, b),
+                    (Type::Int, BinaryOp::Mul) => format!("mul i32 {}, {}", a, b),
+                    (Type::Int, BinaryOp::Div) => format!("call i32 @rv.sdiv(i32 {}, i32 {})", a, b),
+                    (Type::Int, BinaryOp::Rem) => format!("call i32 @rv.srem(i32 {}, i32 {})", a, b),
+                    (Type::Int, BinaryOp::Pow) => format!("call i32 @rv_rt_int_pow(i32 {}, i32 {})", a, b),
+                    (Type::Int, BinaryOp::And) => format!("and i32 {}, {}", a, b),
+                    (Type::Int, BinaryOp::Or) => format!("or i32 {}, {}", a, b),
+                    (Type::Int, BinaryOp::Xor) => format!("xor i32 {}, {}", a, b),
+                    (Type::Int, BinaryOp::Shl | BinaryOp::Shr) => {
+                        // Sınırı aşan kaydırma LLVM'de poison üretir; miktar diğer arka uçlardaki gibi maskelenir.
+                        let count = context.temporary();
+                        let _ = writeln!(context.out, "  {} = and i32 {}, 31", count, b);
+                        let shift = if *op == BinaryOp::Shl { "shl" } else { "ashr" };
+                        format!("{} i32 {}, {}", shift, a, count)
+                    },
+                    (Type::Int | Type::Bool, op) if op.is_comparison() => {
+                        format!("icmp {} {} {}, {}", int_predicate(*op), llvm_type(&operand_type)?, a, b)
+                    },
+                    (Type::Float, BinaryOp::Add) => format!("fadd double {}, {}", a, b),
+                    (Type::Float, BinaryOp::Sub) => format!("fsub double {}, {}", a, b),
+                    (Type::Float, BinaryOp::Mul) => format!("fmul double {}, {}", a, b),
+                    (Type::Float, BinaryOp::Div) => format!("fdiv double {}, {}", a, b),
+                    (Type::Float, BinaryOp::Rem) => format!("frem double {}, {}", a, b),
+                    (Type::Float, BinaryOp::Pow) => format!("call double @llvm.pow.f64(double {}, double {})", a, b),
+                    (Type::Float, op) if op.is_comparison() => format!("fcmp {} double {}, {}", float_predicate(*op), a, b),
+                    (Type::String, BinaryOp::Add) => format!("call ptr @rv_rt_string_concat(ptr {}, ptr {})", a, b),
+                    (Type::String, op) if op.is_comparison() => {
+                        let order = context.temporary();
+                        let _ = writeln!(context.out, "  {} = call i32 @rv_rt_string_compare(ptr {}, ptr {})", order, a, b);
+                        format!("icmp {} i32 {}, 0", int_predicate(*op), order)
+                    },
+                    (other, op) => return Err(CodegenError::new(MessageId::CodegenUnsupportedOperation.format(&[
+                        &"LLVM", &other, &op.mnemonic(),
+                    ]))),
+                };
+                let _ = writeln!(context.out, "  {}{}", assign, line);
+            },
+            InstructionKind::Unary(UnaryOp::Neg, value) => {
+                let line = match context.operand_type(value) {
+                    Type::Float => format!("fneg double {}", self.operand(value)),
+                    _ => format!("sub i32 0, {}", self.operand(value)),
+                };
+                let _ = writeln!(context.out, "  {}{}", assign, line);
+            },
+            InstructionKind::Unary(UnaryOp::Not, value) => {
+                let line = match context.operand_type(value) {
+                    Type::Int => format!("xor i32 {}, -1", self.operand(value)),
+                    _ => format!("xor i1 {}, true", self.operand(value)),
+                };
+                let _ = writeln!(context.out, "  {}{}", assign, line);
+            },
+            InstructionKind::IntToFloat(value) => {
+                let _ = writeln!(context.out, "  {}sitofp i32 {} to double", assign, self.operand(value));
+            },
+            InstructionKind::Call(name, args) if BUILTINS.contains(&name.as_str()) => {
+                for (i, arg) in args.iter().enumerate() {
+                    if i > 0 {
+                        context.out.push_str("  call void @rv_rt_print_space()\n");
+                    }
+                    let value = self.operand(arg);
+                    match context.operand_type(arg) {
+                        Type::Int => { let _ = writeln!(context.out, "  call void @rv_rt_print_int(i32 {})", value); },
+                        Type::Float => { let _ = writeln!(context.out, "  call void @rv_rt_print_float(double {})", value); },
+                        Type::String => { let _ = writeln!(context.out, "  call void @rv_rt_print_string(ptr {})", value); },
+                        Type::Bool => {
+                            let widened = context.temporary();
+                            let _ = writeln!(context.out, "  {} = zext i1 {} to i32", widened, value);
+                            let _ = writeln!(context.out, "  call void @rv_rt_print_bool(i32 {})", widened);
+                        },
+                        other => return Err(CodegenError::new(MessageId::CodegenUnprintableType.format(&[&other]))),
+                    }
+                }
+                if name == "println" {
+                    context.out.push_str("  call void @rv_rt_print_newline()\n");
+                }
+            },
+            InstructionKind::Call(name, args) => {
+                let callee = self.module.function(name)
+                    .ok_or_else(|| CodegenError::new(MessageId::CodegenUnknownFunction.format(&[&name])))?;
+                let arguments = args.iter()
+                    .map(|arg| Ok(format!("{} {}", llvm_type(&context.operand_type(arg))?, self.operand(arg))))
+                    .collect::<Result<Vec<_>, CodegenError>>()?;
+                let assign = if callee.return_type == Type::Void { String::new() } else { assign };
+                let _ = writeln!(
+                    context.out,
+                    "  {}call {} {}({})",
+                    assign, llvm_type(&callee.return_type)?, function_name(name), arguments.join(", "),
+                );
+            },
+            InstructionKind::LoadGlobal(name) => {
+                let global = self.module.global(name)
+                    .ok_or_else(|| CodegenError::new(MessageId::CodegenUnknownGlobal.format(&[&name])))?;
+                let _ = writeln!(context.out, "  {}load {}, ptr {}", assign, llvm_type(&global.ty)?, global_name(name));
+            },
+            InstructionKind::StoreGlobal(name, value) => {
+                let global = self.module.global(name)
+                    .ok_or_else(|| CodegenError::new(MessageId::CodegenUnknownGlobal.format(&[&name])))?;
+                let _ = writeln!(
+                    context.out,
+                    "  store {} {}, ptr {}",
+                    llvm_type(&global.ty)?, self.operand(value), global_name(name),
+                );
+            },
+            InstructionKind::Length(value) => match context.operand_type(value) {
+                Type::String => {
+                    let _ = writeln!(context.out, "  {}call i32 @rv_rt_string_length(ptr {})", assign, self.operand(value));
+                },
+                other => return Err(CodegenError::new(MessageId::CodegenNoLength.format(&[&"LLVM", &other]))),
+            },
+            InstructionKind::Index(collection, index) => match context.operand_type(collection) {
+                Type::String => {
+                    let _ = writeln!(
+                        context.out,
+                        "  {}call ptr @rv_rt_string_index(ptr {}, i32 {})",
+                        assign, self.operand(collection), self.operand(index),
+                    );
+                },
+                other => return Err(CodegenError::new(MessageId::CodegenNotIndexable.format(&[&"LLVM", &other]))),
+            },
+            InstructionKind::Phi(_) => {},
+        }
+        
+        Ok(())
+    }
+    
+    fn emit_terminator(&mut self, context: &mut FunctionContext, terminator: &Terminator) -> Result<(), CodegenError> {
+        match terminator {
+            Terminator::Return(Some(value)) => {
+                let ty = llvm_type(&context.operand_type(value))?;
+                let _ = writeln!(context.out, "  ret {} {}", ty, self.operand(value));
+            },
+            Terminator::Return(None) => context.out.push_str("  ret void\n"),
+            Terminator::Jump(target) => {
+                let _ = writeln!(context.out, "  br label %{}", target);
+            },
+            // Aynı hedefe giden iki kenar phi girişlerini çoğaltacağından koşulsuz dala çevrilir.
+            Terminator::Branch(_, then_block, else_block) if then_block == else_block => {
+                let _ = writeln!(context.out, "  br label %{}", then_block);
+            },
+            Terminator::Branch(condition, then_block, else_block) => {
+                let _ = writeln!(
+                    context.out,
+                    "  br i1 {}, label %{}, label %{}",
+                    self.operand(condition), then_block, else_block,
+                );
+            },
+            Terminator::Unreachable => context.out.push_str("  call void @rv_rt_unreachable()\n  unreachable\n"),
+        }
+        
+        Ok(())
+    }
+    
+    fn operand(&mut self, operand: &Operand) -> String {
+        match operand {
+            Operand::Value(id) => value_name(*id),
+            Operand::Const(constant) => self.constant(constant),
+        }
+    }
+    
+    fn constant(&mut self, constant: &Constant) -> String {
+        match constant {
+            Constant::Int(value) => value.to_string(),
+            Constant::Bool(value) => value.to_string(),
+            Constant::Float(value) => format!("0x{:016X}", value.to_bits()),
+            Constant::String(value) => format!("@.strrec.{}", self.intern(value)),
+        }
+    }
+    
+    fn intern(&mut self, value: &str) -> usize {
+        match self.strings.iter().position(|s| s == value) {
+            Some(index) => index,
+            None => {
+                self.strings.push(value.to_string());
+                self.strings.len() - 1
+            },
+        }
+    }
+}
+
+struct FunctionContext<'f> {
+    function: &'f Function,
+    types: &'f HashMap<ValueId, Type>,
+    out: String,
+    temporaries: usize,
+}
+
+impl FunctionContext<'_> {
+    fn operand_type(&self, operand: &Operand) -> Type {
+        self.function.operand_type(operand, self.types)
+    }
+    
+    fn temporary(&mut self) -> String {
+        self.temporaries += 1;
+        format!("%t{}", self.temporaries)
+    }
+}
+
+fn int_predicate(op: BinaryOp) -> &'static str {
+    match op {
+        BinaryOp::Eq => "eq",
+        BinaryOp::Ne => "ne",
+        BinaryOp::Lt => "slt",
+        BinaryOp::Gt => "sgt",
+        BinaryOp::Le => "sle",
+        _ => "sge",
+    }
+}
+
+// NaN ile eşitsizlik doğru olmalı; bu yüzden 'ne' sırasız (une) karşılaştırılır.
+fn float_predicate(op: BinaryOp) -> &'static str {
+    match op {
+        BinaryOp::Eq => "oeq",
+        BinaryOp::Ne => "une",
+        BinaryOp::Lt => "olt",
+        BinaryOp::Gt => "ogt",
+        BinaryOp::Le => "ole",
+        _ => "oge",
+    }
+}
+
+fn escape_bytes(bytes: &[u8]) -> String {
+    let mut out = String::new();
+    for byte in bytes {
+        match byte {
+            b'"' | b'\\' => { let _ = write!(out, "\\{:02X}", byte); },
+            0x20..=0x7e => out.push(*byte as char),
+            _ => { let _ = write!(out, "\\{:02X}", byte); },
+        }
+    }
+    out
+}
+
+fn function_name(name: &str) -> String {
+    format!("@{}", function_symbol(name))
+}
+
+fn global_name(name: &str) -> String {
+    format!("@{}", global_symbol(name))
+}
+
+fn value_name(id: ValueId) -> String {
+    format!("%v{}", id.0)
+}
@@ -0,0 +1,7 @@
+pub mod types;
+pub mod emitter;
+pub mod toolchain;
+
+pub use emitter::{emit_llvm, LlvmEmitter};
+pub use toolchain::compile_llvm;
+pub use types::{compute_layouts, llvm_type, StructLayout};
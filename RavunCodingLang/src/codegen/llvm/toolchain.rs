@@ -0,0 +1,109 @@
+use std::env;
+use std::fs;
+use std::io::Write;
+use std::path::Path;
+use std::process::{self, Command, Stdio};
+use crate::codegen::c::toolchain::c_compiler;
+use crate::codegen::error::CodegenError;
+use crate::diagnostics::MessageId;
+
+const RUNTIME_HEADER: &str = include_str!("../c/runtime.h");
+const RUNTIME_SOURCE: &str = include_str!("../x86_64/runtime.c");
+
+pub fn llvm_tool(name: &str) -> String {
+    env::var(name.to_uppercase()).unwrap_or_else(|_| name.to_string())
+}
+
+pub fn compile_llvm(ir: &str, output: &Path) -> Result<(), CodegenError> {
+    let base = env::temp_dir().join(format!("ravun-{}", process::id()));
+    let ir_path = base.with_extension("ll");
+    let bitcode_path = base.with_extension("bc");
+    let object_path = base.with_extension("o");
+    
+    fs::write(&ir_path, ir)
+        .map_err(|err| CodegenError::new(MessageId::WriteFailed.format(&[&ir_path.display(), &err])))?;
+    
+    let result = optimize(&ir_path, &bitcode_path)
+        .and_then(|_| compile_object(&bitcode_path, &object_path))
+        .and_then(|_| link(&object_path, output));
+    
+    for path in [ir_path, bitcode_path, object_path] {
+        let _ = fs::remove_file(path);
+    }
+    result
+}
+
+fn optimize(ir_path: &Path, bitcode_path: &Path) -> Result<(), CodegenError> {
+    let opt = llvm_tool("opt");
+    let mut command = Command::new(&opt);
+    command.args(opaque_pointer_flags(&opt)).arg("-O2").arg(ir_path).arg("-o").arg(bitcode_path);
+    run(command, &opt)
+}
+
+fn compile_object(bitcode_path: &Path, object_path: &Path) -> Result<(), CodegenError> {
+    let llc = llvm_tool("llc");
+    let mut command = Command::new(&llc);
+    command.args(opaque_pointer_flags(&llc))
+        .args(["-O2", "-filetype=obj", "-relocation-model=pic"])
+        .arg(bitcode_path)
+        .arg("-o")
+        .arg(object_path);
+    run(command, &llc)
+}
+
+fn link(object_path: &Path, output: &Path) -> Result<(), CodegenError> {
+    let compiler = c_compiler();
+    let mut child = Command::new(&compiler)
+        .arg("-O2")
+        .arg(object_path)
+        .args(["-x", "c", "-std=c99", "-", "-o"])
+        .arg(output)
+        .arg("-lm")
+        .stdin(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|err| CodegenError::new(MessageId::LinkerNotRun.format(&[&compiler, &err])))?;
+    
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin.write_all(RUNTIME_HEADER.as_bytes())
+            .and_then(|_| stdin.write_all(RUNTIME_SOURCE.as_bytes()))
+            .map_err(|err| CodegenError::new(MessageId::RuntimeSourceNotWritten.format(&[&err])))?;
+    }
+    
+    let result = child.wait_with_output()
+        .map_err(|err| CodegenError::new(MessageId::LinkerWaitFailed.format(&[&err])))?;
+    check_status(&result, &compiler)
+}
+
+fn run(mut command: Command, tool: &str) -> Result<(), CodegenError> {
+    let result = command.stderr(Stdio::piped()).output()
+        .map_err(|err| CodegenError::new(MessageId::ToolNotRun.format(&[&tool, &err])))?;
+    check_status(&result, tool)
+}
+
+fn check_status(result: &process::Output, tool: &str) -> Result<(), CodegenError> {
+    if !result.status.success() {
+        return Err(CodegenError::new(MessageId::ToolFailed.format(&[
+            &tool,
+            &result.status,
+            &String::from_utf8_lossy(&result.stderr).trim_end(),
+        ])));
+    }
+    
+    Ok(())
+}
+
+// Üretilen IR opak işaretçiler (ptr) kullanır; LLVM 15 öncesinde bunun açıkça istenmesi gerekir.
+fn opaque_pointer_flags(tool: &str) -> Vec<&'static str> {
+    match llvm_major_version(tool) {
+        Some(version) if version < 15 => vec!["-opaque-pointers"],
+        _ => Vec::new(),
+    }
+}
+
+fn llvm_major_version(tool: &str) -> Option<u32> {
+    let output = Command::new(tool).arg("--version").output().ok()?;
+    let text = String::from_utf8_lossy(&output.stdout);
+    let version = text.split("LLVM version ").nth(1)?;
+    version.split('.').next()?.trim().parse().ok()
+}
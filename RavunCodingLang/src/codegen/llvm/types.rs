@@ -0,0 +1,137 @@
+use std::collections::HashMap;
+use crate::codegen::error::CodegenError;
+use crate::codegen::names::mangle;
+use crate::diagnostics::MessageId;
+use crate::semantics::symbol_table::StructDefinition;
+use crate::semantics::types::Type;
+
+pub const STRING_TYPE: &str = "%rv.string";
+pub const ARRAY_TYPE: &str = "%rv.array";
+
+pub fn struct_type_name(name: &str) -> String {
+    format!("%struct.{}", mangle(name))
+}
+
+// Değer tipleri: string'ler çalışma zamanındaki { ptr, i32 } kaydına işaretçi olarak taşınır.
+pub fn llvm_type(ty: &Type) -> Result<String, CodegenError> {
+    match ty {
+        Type::Int => Ok("i32".to_string()),
+        Type::Float => Ok("double".to_string()),
+        Type::Bool => Ok("i1".to_string()),
+        Type::Void => Ok("void".to_string()),
+        Type::String | Type::Ref(_) | Type::Function(_, _) => Ok("ptr".to_string()),
+        Type::Array(element, Some(size)) => Ok(format!("[{} x {}]", size, llvm_type(element)?)),
+        Type::Array(_, None) => Ok(ARRAY_TYPE.to_string()),
+        Type::Struct(name) => Ok(struct_type_name(name)),
+        Type::Optional(inner) => Ok(format!("{{ i1, {} }}", llvm_type(inner)?)),
+        other => Err(CodegenError::new(MessageId::CodegenUnsupportedType.format(&[&"LLVM", &other]))),
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldLayout {
+    pub name: String,
+    pub ty: String,
+    pub offset: usize,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct StructLayout {
+    pub name: String,
+    pub fields: Vec<FieldLayout>,
+    pub size: usize,
+    pub align: usize,
+}
+
+impl StructLayout {
+    pub fn type_name(&self) -> String {
+        struct_type_name(&self.name)
+    }
+    
+    pub fn definition(&self) -> String {
+        let fields: Vec<&str> = self.fields.iter().map(|f| f.ty.as_str()).collect();
+        format!("{} = type {{ {} }}", self.type_name(), fields.join(", "))
+    }
+}
+
+// x86-64 System V hizalama kurallarıyla struct yerleşimlerini hesaplar.
+pub fn compute_layouts(definitions: &[&StructDefinition]) -> Result<Vec<StructLayout>, CodegenError> {
+    let by_name: HashMap<&str, &StructDefinition> = definitions.iter().map(|d| (d.name.as_str(), *d)).collect();
+    let mut computed: HashMap<String, StructLayout> = HashMap::new();
+    
+    for definition in definitions {
+        layout_of(definition, &by_name, &mut computed, &mut Vec::new())?;
+    }
+    
+    Ok(definitions.iter().filter_map(|d| computed.remove(&d.name)).collect())
+}
+
+fn layout_of(
+    definition: &StructDefinition,
+    by_name: &HashMap<&str, &StructDefinition>,
+    computed: &mut HashMap<String, StructLayout>,
+    visiting: &mut Vec<String>,
+) -> Result<(usize, usize), CodegenError> {
+    if let Some(layout) = computed.get(&definition.name) {
+        return Ok((layout.size, layout.align));
+    }
+    if visiting.contains(&definition.name) {
+        return Err(CodegenError::new(MessageId::CodegenRecursiveStruct.format(&[&definition.name])));
+    }
+    visiting.push(definition.name.clone());
+    
+    let mut fields = Vec::new();
+    let mut offset: usize = 0;
+    let mut align: usize = 1;
+    for field in &definition.fields {
+        let (field_size, field_align) = size_and_align(&field.type_info, by_name, computed, visiting)?;
+        offset = offset.next_multiple_of(field_align);
+        fields.push(FieldLayout {
+            name: field.name.clone(),
+            ty: llvm_type(&field.type_info)?,
+            offset,
+        });
+        offset += field_size;
+        align = align.max(field_align);
+    }
+    
+    visiting.pop();
+    let layout = StructLayout {
+        name: definition.name.clone(),
+        fields,
+        size: offset.next_multiple_of(align),
+        align,
+    };
+    let result = (layout.size, layout.align);
+    computed.insert(definition.name.clone(), layout);
+    Ok(result)
+}
+
+fn size_and_align(
+    ty: &Type,
+    by_name: &HashMap<&str, &StructDefinition>,
+    computed: &mut HashMap<String, StructLayout>,
+    visiting: &mut Vec<String>,
+) -> Result<(usize, usize), CodegenError> {
+    match ty {
+        Type::Int => Ok((4, 4)),
+        Type::Float => Ok((8, 8)),
+        Type::Bool => Ok((1, 1)),
+        Type::String | Type::Ref(_) | Type::Function(_, _) => Ok((8, 8)),
+        Type::Array(_, None) => Ok((16, 8)),
+        Type::Array(element, Some(count)) => {
+            let (size, align) = size_and_align(element, by_name, computed, visiting)?;
+            Ok((size * count, align))
+        },
+        Type::Optional(inner) => {
+            let (size, align) = size_and_align(inner, by_name, computed, visiting)?;
+            Ok(((align + size).next_multiple_of(align), align))
+        },
+        Type::Struct(name) => {
+            let definition = by_name.get(name.as_str())
+                .ok_or_else(|| CodegenError::new(MessageId::CodegenUndefinedStruct.format(&[&name])))?;
+            layout_of(definition, by_name, computed, visiting)
+        },
+        other => Err(CodegenError::new(MessageId::CodegenUnsupportedType.format(&[&"LLVM", &other]))),
+    }
+}
@@ -1 +1,11 @@
 //! Codegen modülü
+pub mod backend;
+pub mod names;
+pub mod c;
+pub mod x86_64;
+pub mod wasm;
+pub mod llvm;
+pub mod error;
+
+pub use backend::Backend;
+pub use error::CodegenError;
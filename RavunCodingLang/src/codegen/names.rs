@@ -0,0 +1,23 @@
+use std::fmt::Write;
+
+pub fn mangle(name: &str) -> String {
+    let mut mangled = String::new();
+    
+    for c in name.chars() {
+        if c.is_ascii_alphanumeric() || c == '_' {
+            mangled.push(c);
+        } else {
+            let _ = write!(mangled, "_u{:04x}", c as u32);
+        }
+    }
+    
+    mangled
+}
+
+pub fn function_symbol(name: &str) -> String {
+    format!("rv_f_{}", mangle(name))
+}
+
+pub fn global_symbol(name: &str) -> String {
+    format!("rv_g_{}", mangle(name))
+}
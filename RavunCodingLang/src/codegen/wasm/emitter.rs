@@ -0,0 +1,481 @@
+use std::collections::HashMap;
+use crate::codegen::error::CodegenError;
+use crate::codegen::names::{function_symbol, global_symbol};
+use crate::codegen::wasm::instructions::*;
+use crate::codegen::wasm::memory::{StringPool, DATA_START, PAGE_SIZE};
+use crate::codegen::wasm::module::{DataSegment, Export, ExportKind, WasmGlobal, WasmModule};
+use crate::codegen::wasm::runtime::{Runtime, HEAP_GLOBAL};
+use crate::diagnostics::MessageId;
+use crate::ir::function::{Function, Module};
+use crate::ir::instruction::{
+    BinaryOp, BlockId, Constant, InstructionKind, Operand, Terminator, UnaryOp, ValueId,
+};
+use crate::semantics::types::Type;
+
+const BUILTINS: [&str; 2] = ["print", "println"];
+
+type FunctionBody = (Vec<(String, ValType)>, Vec<Instr>);
+
+pub fn emit_wasm(module: &Module) -> Result<WasmModule, CodegenError> {
+    WasmEmitter::new(module).emit()
+}
+
+pub struct WasmEmitter<'a> {
+    module: &'a Module,
+    wasm: WasmModule,
+    strings: StringPool,
+    functions: HashMap<String, u32>,
+    globals: HashMap<String, u32>,
+}
+
+impl<'a> WasmEmitter<'a> {
+    pub fn new(module: &'a Module) -> Self {
+        WasmEmitter {
+            module,
+            wasm: WasmModule::new(),
+            strings: StringPool::new(),
+            functions: HashMap::new(),
+            globals: HashMap::new(),
+        }
+    }
+    
+    pub fn emit(mut self) -> Result<WasmModule, CodegenError> {
+        let main = self.module.function("main")
+            .ok_or_else(|| CodegenError::new(MessageId::CodegenMissingMain.text().to_string()))?;
+        if !main.params.is_empty() {
+            return Err(CodegenError::in_function("main", MessageId::CodegenMainWithParameters.text().to_string()));
+        }
+        
+        let runtime = Runtime::install(&mut self.wasm, &mut self.strings);
+        
+        self.wasm.globals.push(WasmGlobal {
+            name: "rv_heap".to_string(),
+            ty: ValType::I32,
+            mutable: true,
+            init: Instr::I32Const(0),
+        });
+        for global in &self.module.globals {
+            let init = match &global.initializer {
+                Constant::Int(value) => Instr::I32Const(*value),
+                Constant::Bool(value) => Instr::I32Const(*value as i32),
+                Constant::Float(value) => Instr::F64Const(*value),
+                Constant::String(value) => Instr::I32Const(self.strings.intern(value) as i32),
+            };
+            self.globals.insert(global.name.clone(), self.wasm.globals.len() as u32);
+            self.wasm.globals.push(WasmGlobal {
+                name: global_symbol(&global.name),
+                ty: ValType::of(&global.ty)?,
+                mutable: true,
+                init,
+            });
+        }
+        
+        for function in &self.module.functions {
+            let params = function.params.iter()
+                .map(|p| Ok((value_name(p.id), ValType::of(&p.ty)?)))
+                .collect::<Result<Vec<_>, CodegenError>>()
+                .map_err(|e| CodegenError::in_function(&function.name, e.message))?;
+            let results = result_types(&function.return_type)
+                .map_err(|e| CodegenError::in_function(&function.name, e.message))?;
+            let index = self.wasm.declare_function(&function_symbol(&function.name), params, results);
+            self.functions.insert(function.name.clone(), index);
+        }
+        
+        let start = self.wasm.declare_function("_start", Vec::new(), Vec::new());
+        let mut start_body = vec![Instr::Call(self.functions["main"])];
+        if main.return_type != Type::Void {
+            start_body.push(Instr::Call(runtime.proc_exit));
+        }
+        self.wasm.define_function(start, Vec::new(), start_body);
+        
+        for function in &self.module.functions {
+            let (locals, body) = self.emit_function(function, &runtime)
+                .map_err(|e| CodegenError::in_function(&function.name, e.message))?;
+            self.wasm.define_function(self.functions[&function.name], locals, body);
+        }
+        
+        self.wasm.exports.push(Export { name: "memory".to_string(), kind: ExportKind::Memory, index: 0 });
+        self.wasm.exports.push(Export { name: "_start".to_string(), kind: ExportKind::Function, index: start });
+        for function in &self.module.functions {
+            self.wasm.exports.push(Export {
+                name: function.name.clone(),
+                kind: ExportKind::Function,
+                index: self.functions[&function.name],
+            });
+        }
+        
+        let heap_start = self.strings.end().next_multiple_of(8);
+        self.wasm.globals[HEAP_GLOBAL as usize].init = Instr::I32Const(heap_start as i32);
+        self.wasm.memory_pages = heap_start.div_ceil(PAGE_SIZE).max(1);
+        self.wasm.data.push(DataSegment { offset: DATA_START, bytes: self.strings.bytes().to_vec() });
+        
+        Ok(self.wasm)
+    }
+    
+    fn emit_function(&mut self, function: &Function, runtime: &Runtime) -> Result<FunctionBody, CodegenError> {
+        let types = function.value_types();
+        let order = function.reverse_postorder();
+        
+        let mut context = FunctionContext {
+            function,
+            types: &types,
+            locals: HashMap::new(),
+            local_types: Vec::new(),
+            order: order.clone(),
+            pc: 0,
+            body: Vec::new(),
+        };
+        
+        for (index, param) in function.params.iter().enumerate() {
+            context.locals.insert(param.id, index as u32);
+        }
+        let mut next_local = function.params.len() as u32;
+        for block in order.iter().filter_map(|id| function.block(*id)) {
+            for instruction in &block.instructions {
+                if let Some(result) = instruction.result && instruction.ty != Type::Void {
+                    context.locals.insert(result, next_local);
+                    context.local_types.push((value_name(result), ValType::of(&instruction.ty)?));
+                    next_local += 1;
+                }
+            }
+        }
+        
+        let dispatch = order.len() > 1;
+        if dispatch {
+            context.pc = next_local;
+            context.local_types.push(("pc".to_string(), ValType::I32));
+        }
+        
+        // Yapısız CFG, pc yerel değişkeni üzerinden br_table ile dallanan bir döngüye çevrilir:
+        // i. bloğun kodu, iç içe geçmiş i. `block` yapısının bitişinden hemen sonra gelir.
+        if dispatch {
+            context.body.push(Instr::Loop(BlockType::Empty));
+            for _ in 0..order.len() {
+                context.body.push(Instr::Block(BlockType::Empty));
+            }
+            context.body.push(Instr::LocalGet(context.pc));
+            let targets: Vec<u32> = (0..order.len() as u32).collect();
+            context.body.push(Instr::BrTable(targets, order.len() as u32 - 1));
+        }
+        
+        for (position, block_id) in order.iter().enumerate() {
+            let Some(block) = function.block(*block_id) else {
+                continue;
+            };
+            if dispatch {
+                context.body.push(Instr::End);
+            }
+            
+            for instruction in block.instructions.iter().filter(|i| !i.is_phi()) {
+                self.emit_instruction(&mut context, runtime, instruction.result, &instruction.kind)?;
+            }
+            
+            let loop_depth = (order.len() - 1 - position) as u32;
+            let next = order.get(position + 1).copied();
+            let terminator = block.terminator.as_ref().unwrap_or(&Terminator::Unreachable);
+            self.emit_terminator(&mut context, block.id, terminator, loop_depth, next)?;
+        }
+        
+        if dispatch {
+            context.body.push(Instr::End);
+            context.body.push(Instr::Plain(UNREACHABLE));
+        }
+        
+        Ok((context.local_types, context.body))
+    }
+    
+    fn emit_instruction(
+        &mut self,
+        context: &mut FunctionContext,
+        runtime: &Runtime,
+        result: Option<ValueId>,
+        kind: &InstructionKind,
+    ) -> Result<(), CodegenError> {
+        match kind {
+            InstructionKind::Binary(op, lhs, rhs) => {
+                let operand_type = context.operand_type(lhs);
+                self.push_operand(context, lhs);
+                self.push_operand(context, rhs);
+                binary_instructions(&mut context.body, runtime, *op, &operand_type)?;
+            },
+            InstructionKind::Unary(UnaryOp::Neg, value) => match context.operand_type(value) {
+                Type::Float => {
+                    self.push_operand(context, value);
+                    context.body.push(Instr::Plain(F64_NEG));
+                },
+                _ => {
+                    context.body.push(Instr::I32Const(0));
+                    self.push_operand(context, value);
+                    context.body.push(Instr::Plain(I32_SUB));
+                },
+            },
+            InstructionKind::Unary(UnaryOp::Not, value) => {
+                self.push_operand(context, value);
+                if context.operand_type(value) == Type::Int {
+                    context.body.push(Instr::I32Const(-1));
+                    context.body.push(Instr::Plain(I32_XOR));
+                } else {
+                    context.body.push(Instr::Plain(I32_EQZ));
+                }
+            },
+            InstructionKind::IntToFloat(value) => {
+                self.push_operand(context, value);
+                context.body.push(Instr::Plain(F64_CONVERT_I32_S));
+            },
+            InstructionKind::Call(name, args) if BUILTINS.contains(&name.as_str()) => {
+                for (i, arg) in args.iter().enumerate() {
+                    if i > 0 {
+                        context.body.push(Instr::I32Const(1));
+                        context.body.push(Instr::I32Const(runtime.space as i32));
+                        context.body.push(Instr::Call(runtime.write_str));
+                    }
+                    let print = match context.operand_type(arg) {
+                        Type::Int => runtime.print_i32,
+                        Type::Float => runtime.print_f64,
+                        Type::Bool => runtime.print_bool,
+                        Type::String => {
+                            context.body.push(Instr::I32Const(1));
+                            runtime.write_str
+                        },
+                        other => return Err(CodegenError::new(MessageId::CodegenUnprintableType.format(&[&other]))),
+                    };
+                    self.push_operand(context, arg);
+                    context.body.push(Instr::Call(print));
+                }
+                if name == "println" {
+                    context.body.push(Instr::I32Const(1));
+                    context.body.push(Instr::I32Const(runtime.newline as i32));
+                    context.body.push(Instr::Call(runtime.write_str));
+                }
+                return Ok(());
+            },
+            InstructionKind::Call(name, args) => {
+                let callee = self.module.function(name)
+                    .ok_or_else(|| CodegenError::new(MessageId::CodegenUnknownFunction.format(&[&name])))?;
+                for arg in args {
+                    self.push_operand(context, arg);
+                }
+                context.body.push(Instr::Call(self.functions[name]));
+                if callee.return_type == Type::Void {
+                    return Ok(());
+                }
+                if result.is_none() {
+                    context.body.push(Instr::Plain(DROP));
+                }
+            },
+            InstructionKind::LoadGlobal(name) => {
+                let index = self.global_index(name)?;
+                context.body.push(Instr::GlobalGet(index));
+            },
+            InstructionKind::StoreGlobal(name, value) => {
+                let index = self.global_index(name)?;
+                self.push_operand(context, value);
+                context.body.push(Instr::GlobalSet(index));
+            },
+            InstructionKind::Length(value) => match context.operand_type(value) {
+                Type::String => {
+                    self.push_operand(context, value);
+                    context.body.push(Instr::Call(runtime.str_length));
+                },
+                other => return Err(CodegenError::new(MessageId::CodegenNoLength.format(&[&"WebAssembly", &other]))),
+            },
+            InstructionKind::Index(collection, index) => match context.operand_type(collection) {
+                Type::String => {
+                    self.push_operand(context, collection);
+                    self.push_operand(context, index);
+                    context.body.push(Instr::Call(runtime.str_index));
+                },
+                other => return Err(CodegenError::new(MessageId::CodegenNotIndexable.format(&[&"WebAssembly", &other]))),
+            },
+            InstructionKind::Phi(_) => return Ok(()),
+        }
+        
+        if let Some(result) = result {
+            let local = context.local(result)?;
+            context.body.push(Instr::LocalSet(local));
+        }
+        
+        Ok(())
+    }
+    
+    fn emit_terminator(
+        &mut self,
+        context: &mut FunctionContext,
+        block: BlockId,
+        terminator: &Terminator,
+        loop_depth: u32,
+        next: Option<BlockId>,
+    ) -> Result<(), CodegenError> {
+        match terminator {
+            Terminator::Return(value) => {
+                if let Some(value) = value {
+                    self.push_operand(context, value);
+                }
+                context.body.push(Instr::Plain(RETURN));
+            },
+            Terminator::Jump(target) => {
+                self.phi_copies(context, block, *target)?;
+                // Sıradaki bloğun kodu hemen ardından geldiği için akış oraya düşer.
+                if next != Some(*target) {
+                    self.jump_to(context, *target, loop_depth)?;
+                }
+            },
+            Terminator::Branch(condition, then_block, else_block) => {
+                self.push_operand(context, condition);
+                context.body.push(Instr::If(BlockType::Empty));
+                self.phi_copies(context, block, *then_block)?;
+                self.set_pc(context, *then_block)?;
+                context.body.push(Instr::Else);
+                self.phi_copies(context, block, *else_block)?;
+                self.set_pc(context, *else_block)?;
+                context.body.push(Instr::End);
+                context.body.push(Instr::Br(loop_depth));
+            },
+            Terminator::Unreachable => context.body.push(Instr::Plain(UNREACHABLE)),
+        }
+        
+        Ok(())
+    }
+    
+    fn set_pc(&mut self, context: &mut FunctionContext, target: BlockId) -> Result<(), CodegenError> {
+        let position = context.order.iter().position(|b| *b == target)
+            .ok_or_else(|| CodegenError::new(MessageId::CodegenMissingBlock.format(&[&target.0])))?;
+        context.body.push(Instr::I32Const(position as i32));
+        context.body.push(Instr::LocalSet(context.pc));
+        Ok(())
+    }
+    
+    fn jump_to(&mut self, context: &mut FunctionContext, target: BlockId, loop_depth: u32) -> Result<(), CodegenError> {
+        self.set_pc(context, target)?;
+        context.body.push(Instr::Br(loop_depth));
+        Ok(())
+    }
+    
+    // Kaynakların hepsi önce yığına alınıp sonra ters sırada atandığı için kopyalar paraleldir.
+    fn phi_copies(&mut self, context: &mut FunctionContext, from: BlockId, to: BlockId) -> Result<(), CodegenError> {
+        let Some(target) = context.function.block(to) else {
+            return Ok(());
+        };
+        
+        let mut destinations = Vec::new();
+        for phi in target.phis() {
+            let (Some(result), InstructionKind::Phi(incoming)) = (phi.result, &phi.kind) else {
+                continue;
+            };
+            if let Some((_, value)) = incoming.iter().find(|(pred, _)| *pred == from) {
+                self.push_operand(context, value);
+                destinations.push(context.local(result)?);
+            }
+        }
+        
+        for local in destinations.into_iter().rev() {
+            context.body.push(Instr::LocalSet(local));
+        }
+        
+        Ok(())
+    }
+    
+    fn push_operand(&mut self, context: &mut FunctionContext, operand: &Operand) {
+        let instruction = match operand {
+            Operand::Const(Constant::Int(value)) => Instr::I32Const(*value),
+            Operand::Const(Constant::Bool(value)) => Instr::I32Const(*value as i32),
+            Operand::Const(Constant::Float(value)) => Instr::F64Const(*value),
+            Operand::Const(Constant::String(value)) => Instr::I32Const(self.strings.intern(value) as i32),
+            Operand::Value(id) => match context.locals.get(id) {
+                Some(local) => Instr::LocalGet(*local),
+                None => Instr::Plain(UNREACHABLE),
+            },
+        };
+        context.body.push(instruction);
+    }
+    
+    fn global_index(&self, name: &str) -> Result<u32, CodegenError> {
+        self.globals.get(name).copied()
+            .ok_or_else(|| CodegenError::new(MessageId::CodegenUnknownGlobal.format(&[&name])))
+    }
+}
+
+struct FunctionContext<'f> {
+    function: &'f Function,
+    types: &'f HashMap<ValueId, Type>,
+    locals: HashMap<ValueId, u32>,
+    local_types: Vec<(String, ValType)>,
+    order: Vec<BlockId>,
+    pc: u32,
+    body: Vec<Instr>,
+}
+
+impl FunctionContext<'_> {
+    fn operand_type(&self, operand: &Operand) -> Type {
+        self.function.operand_type(operand, self.types)
+    }
+    
+    fn local(&self, value: ValueId) -> Result<u32, CodegenError> {
+        self.locals.get(&value).copied()
+            .ok_or_else(|| CodegenError::new(MessageId::CodegenNoLocal.format(&[&value.0])))
+    }
+}
+
+fn binary_instructions(body: &mut Vec<Instr>, runtime: &Runtime, op: BinaryOp, operand_type: &Type) -> Result<(), CodegenError> {
+    let instruction = match (operand_type, op) {
+        (Type::Int, BinaryOp::Add) => Instr::Plain(I32_ADD),
+        (Type::Int, BinaryOp::Sub) => Instr::Plain(I32_SUB),
+        (Type::Int, BinaryOp::Mul) => Instr::Plain(I32_MUL),
+        (Type::Int, BinaryOp::Div) => Instr::Call(runtime.div_i32),
+        (Type::Int, BinaryOp::Rem) => Instr::Call(runtime.rem_i32),
+        (Type::Int, BinaryOp::Pow) => Instr::Call(runtime.pow_i32),
+        (Type::Int, BinaryOp::And) => Instr::Plain(I32_AND),
+        (Type::Int, BinaryOp::Or) => Instr::Plain(I32_OR),
+        (Type::Int, BinaryOp::Xor) => Instr::Plain(I32_XOR),
+        (Type::Int, BinaryOp::Shl) => Instr::Plain(I32_SHL),
+        (Type::Int, BinaryOp::Shr) => Instr::Plain(I32_SHR_S),
+        (Type::Int | Type::Bool, BinaryOp::Eq) => Instr::Plain(I32_EQ),
+        (Type::Int | Type::Bool, BinaryOp::Ne) => Instr::Plain(I32_NE),
+        (Type::Int, BinaryOp::Lt) => Instr::Plain(I32_LT_S),
+        (Type::Int, BinaryOp::Gt) => Instr::Plain(I32_GT_S),
+        (Type::Int, BinaryOp::Le) => Instr::Plain(I32_LE_S),
+        (Type::Int, BinaryOp::Ge) => Instr::Plain(I32_GE_S),
+        (Type::Float, BinaryOp::Add) => Instr::Plain(F64_ADD),
+        (Type::Float, BinaryOp::Sub) => Instr::Plain(F64_SUB),
+        (Type::Float, BinaryOp::Mul) => Instr::Plain(F64_MUL),
+        (Type::Float, BinaryOp::Div) => Instr::Plain(F64_DIV),
+        (Type::Float, BinaryOp::Rem) => Instr::Call(runtime.rem_f64),
+        (Type::Float, BinaryOp::Pow) => Instr::Call(runtime.pow_f64),
+        (Type::Float, BinaryOp::Eq) => Instr::Plain(F64_EQ),
+        (Type::Float, BinaryOp::Ne) => Instr::Plain(F64_NE),
+        (Type::Float, BinaryOp::Lt) => Instr::Plain(F64_LT),
+        (Type::Float, BinaryOp::Gt) => Instr::Plain(F64_GT),
+        (Type::Float, BinaryOp::Le) => Instr::Plain(F64_LE),
+        (Type::Float, BinaryOp::Ge) => Instr::Plain(F64_GE),
+        (Type::String, BinaryOp::Add) => Instr::Call(runtime.str_concat),
+        (Type::String, op) if op.is_comparison() => {
+            body.push(Instr::Call(runtime.str_compare));
+            body.push(Instr::I32Const(0));
+            match op {
+                BinaryOp::Eq => Instr::Plain(I32_EQ),
+                BinaryOp::Ne => Instr::Plain(I32_NE),
+                BinaryOp::Lt => Instr::Plain(I32_LT_S),
+                BinaryOp::Gt => Instr::Plain(I32_GT_S),
+                BinaryOp::Le => Instr::Plain(I32_LE_S),
+                _ => Instr::Plain(I32_GE_S),
+            }
+        },
+        (other, op) => return Err(CodegenError::new(MessageId::CodegenUnsupportedOperation.format(&[
+            &"WebAssembly", &other, &op.mnemonic(),
+        ]))),
+    };
+    
+    body.push(instruction);
+    Ok(())
+}
+
+fn result_types(ty: &Type) -> Result<Vec<ValType>, CodegenError> {
+    match ty {
+        Type::Void => Ok(Vec::new()),
+        other => Ok(vec![ValType::of(other)?]),
+    }
+}
+
+fn value_name(id: ValueId) -> String {
+    format!("v{}", id.0)
+}
@@ -0,0 +1,246 @@
+use crate::codegen::wasm::instructions::{BlockType, Instr};
+use crate::codegen::wasm::module::{ExportKind, WasmFunction, WasmModule};
+
+const MAGIC: [u8; 4] = [0x00, 0x61, 0x73, 0x6d];
+const VERSION: [u8; 4] = [0x01, 0x00, 0x00, 0x00];
+
+pub fn encode_module(module: &WasmModule) -> Vec<u8> {
+    let mut out = Vec::new();
+    out.extend_from_slice(&MAGIC);
+    out.extend_from_slice(&VERSION);
+    
+    let mut types = Vec::new();
+    write_u32(&mut types, module.types.len() as u32);
+    for ty in &module.types {
+        types.push(0x60);
+        write_u32(&mut types, ty.params.len() as u32);
+        types.extend(ty.params.iter().map(|p| p.code()));
+        write_u32(&mut types, ty.results.len() as u32);
+        types.extend(ty.results.iter().map(|r| r.code()));
+    }
+    section(&mut out, 1, &types);
+    
+    if !module.imports.is_empty() {
+        let mut imports = Vec::new();
+        write_u32(&mut imports, module.imports.len() as u32);
+        for import in &module.imports {
+            write_name(&mut imports, &import.module);
+            write_name(&mut imports, &import.name);
+            imports.push(0x00);
+            write_u32(&mut imports, import.type_index);
+        }
+        section(&mut out, 2, &imports);
+    }
+    
+    let mut functions = Vec::new();
+    write_u32(&mut functions, module.functions.len() as u32);
+    for function in &module.functions {
+        write_u32(&mut functions, function.type_index);
+    }
+    section(&mut out, 3, &functions);
+    
+    let mut memory = Vec::new();
+    write_u32(&mut memory, 1);
+    memory.push(0x00);
+    write_u32(&mut memory, module.memory_pages);
+    section(&mut out, 5, &memory);
+    
+    if !module.globals.is_empty() {
+        let mut globals = Vec::new();
+        write_u32(&mut globals, module.globals.len() as u32);
+        for global in &module.globals {
+            globals.push(global.ty.code());
+            globals.push(global.mutable as u8);
+            write_instruction(&mut globals, &global.init);
+            globals.push(0x0b);
+        }
+        section(&mut out, 6, &globals);
+    }
+    
+    let mut exports = Vec::new();
+    write_u32(&mut exports, module.exports.len() as u32);
+    for export in &module.exports {
+        write_name(&mut exports, &export.name);
+        exports.push(match export.kind {
+            ExportKind::Function => 0x00,
+            ExportKind::Memory => 0x02,
+        });
+        write_u32(&mut exports, export.index);
+    }
+    section(&mut out, 7, &exports);
+    
+    let mut code = Vec::new();
+    write_u32(&mut code, module.functions.len() as u32);
+    for function in &module.functions {
+        let body = encode_body(function);
+        write_u32(&mut code, body.len() as u32);
+        code.extend(body);
+    }
+    section(&mut out, 10, &code);
+    
+    if !module.data.is_empty() {
+        let mut data = Vec::new();
+        write_u32(&mut data, module.data.len() as u32);
+        for segment in &module.data {
+            data.push(0x00);
+            write_instruction(&mut data, &Instr::I32Const(segment.offset as i32));
+            data.push(0x0b);
+            write_u32(&mut data, segment.bytes.len() as u32);
+            data.extend_from_slice(&segment.bytes);
+        }
+        section(&mut out, 11, &data);
+    }
+    
+    out
+}
+
+fn encode_body(function: &WasmFunction) -> Vec<u8> {
+    let mut groups: Vec<(u32, u8)> = Vec::new();
+    for (_, ty) in &function.locals {
+        match groups.last_mut() {
+            Some((count, code)) if *code == ty.code() => *count += 1,
+            _ => groups.push((1, ty.code())),
+        }
+    }
+    
+    let mut body = Vec::new();
+    write_u32(&mut body, groups.len() as u32);
+    for (count, code) in groups {
+        write_u32(&mut body, count);
+        body.push(code);
+    }
+    
+    for instruction in &function.body {
+        write_instruction(&mut body, instruction);
+    }
+    body.push(0x0b);
+    body
+}
+
+fn write_instruction(out: &mut Vec<u8>, instruction: &Instr) {
+    match instruction {
+        Instr::Plain(opcode) => out.push(opcode.code),
+        Instr::Block(ty) => {
+            out.push(0x02);
+            write_block_type(out, ty);
+        },
+        Instr::Loop(ty) => {
+            out.push(0x03);
+            write_block_type(out, ty);
+        },
+        Instr::If(ty) => {
+            out.push(0x04);
+            write_block_type(out, ty);
+        },
+        Instr::Else => out.push(0x05),
+        Instr::End => out.push(0x0b),
+        Instr::Br(depth) => {
+            out.push(0x0c);
+            write_u32(out, *depth);
+        },
+        Instr::BrIf(depth) => {
+            out.push(0x0d);
+            write_u32(out, *depth);
+        },
+        Instr::BrTable(targets, default) => {
+            out.push(0x0e);
+            write_u32(out, targets.len() as u32);
+            for target in targets {
+                write_u32(out, *target);
+            }
+            write_u32(out, *default);
+        },
+        Instr::Call(index) => {
+            out.push(0x10);
+            write_u32(out, *index);
+        },
+        Instr::LocalGet(index) => {
+            out.push(0x20);
+            write_u32(out, *index);
+        },
+        Instr::LocalSet(index) => {
+            out.push(0x21);
+            write_u32(out, *index);
+        },
+        Instr::LocalTee(index) => {
+            out.push(0x22);
+            write_u32(out, *index);
+        },
+        Instr::GlobalGet(index) => {
+            out.push(0x23);
+            write_u32(out, *index);
+        },
+        Instr::GlobalSet(index) => {
+            out.push(0x24);
+            write_u32(out, *index);
+        },
+        Instr::I32Load(offset) => write_memory(out, 0x28, 2, *offset),
+        Instr::I32Load8U(offset) => write_memory(out, 0x2d, 0, *offset),
+        Instr::I32Store(offset) => write_memory(out, 0x36, 2, *offset),
+        Instr::I32Store8(offset) => write_memory(out, 0x3a, 0, *offset),
+        Instr::MemorySize => out.extend_from_slice(&[0x3f, 0x00]),
+        Instr::MemoryGrow => out.extend_from_slice(&[0x40, 0x00]),
+        Instr::MemoryCopy => out.extend_from_slice(&[0xfc, 0x0a, 0x00, 0x00]),
+        Instr::I32Const(value) => {
+            out.push(0x41);
+            write_i64(out, *value as i64);
+        },
+        Instr::I64Const(value) => {
+            out.push(0x42);
+            write_i64(out, *value);
+        },
+        Instr::F64Const(value) => {
+            out.push(0x44);
+            out.extend_from_slice(&value.to_le_bytes());
+        },
+    }
+}
+
+fn write_block_type(out: &mut Vec<u8>, ty: &BlockType) {
+    match ty {
+        BlockType::Empty => out.push(0x40),
+        BlockType::Value(value) => out.push(value.code()),
+    }
+}
+
+fn write_memory(out: &mut Vec<u8>, opcode: u8, align: u32, offset: u32) {
+    out.push(opcode);
+    write_u32(out, align);
+    write_u32(out, offset);
+}
+
+fn section(out: &mut Vec<u8>, id: u8, contents: &[u8]) {
+    out.push(id);
+    write_u32(out, contents.len() as u32);
+    out.extend_from_slice(contents);
+}
+
+fn write_name(out: &mut Vec<u8>, name: &str) {
+    write_u32(out, name.len() as u32);
+    out.extend_from_slice(name.as_bytes());
+}
+
+pub fn write_u32(out: &mut Vec<u8>, mut value: u32) {
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+        if value == 0 {
+            out.push(byte);
+            return;
+        }
+        out.push(byte | 0x80);
+    }
+}
+
+pub fn write_i64(out: &mut Vec<u8>, mut value: i64) {
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+        let done = (value == 0 && byte & 0x40 == 0) || (value == -1 && byte & 0x40 != 0);
+        if done {
+            out.push(byte);
+            return;
+        }
+        out.push(byte | 0x80);
+    }
+}
@@ -0,0 +1,147 @@
+use std::fmt;
+use crate::codegen::error::CodegenError;
+use crate::diagnostics::MessageId;
+use crate::semantics::types::Type;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ValType {
+    I32,
+    I64,
+    F64,
+}
+
+impl ValType {
+    // String değerleri doğrusal bellekteki { ptr, len } dilim kaydının adresidir.
+    pub fn of(ty: &Type) -> Result<ValType, CodegenError> {
+        match ty {
+            Type::Int | Type::Bool | Type::String => Ok(ValType::I32),
+            Type::Float => Ok(ValType::F64),
+            other => Err(CodegenError::new(MessageId::CodegenUnsupportedType.format(&[&"WebAssembly", &other]))),
+        }
+    }
+    
+    pub fn code(&self) -> u8 {
+        match self {
+            ValType::I32 => 0x7f,
+            ValType::I64 => 0x7e,
+            ValType::F64 => 0x7c,
+        }
+    }
+}
+
+impl fmt::Display for ValType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ValType::I32 => write!(f, "i32"),
+            ValType::I64 => write!(f, "i64"),
+            ValType::F64 => write!(f, "f64"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BlockType {
+    Empty,
+    Value(ValType),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Opcode {
+    pub name: &'static str,
+    pub code: u8,
+}
+
+const fn op(name: &'static str, code: u8) -> Opcode {
+    Opcode { name, code }
+}
+
+pub const UNREACHABLE: Opcode = op("unreachable", 0x00);
+pub const RETURN: Opcode = op("return", 0x0f);
+pub const DROP: Opcode = op("drop", 0x1a);
+pub const SELECT: Opcode = op("select", 0x1b);
+
+pub const I32_EQZ: Opcode = op("i32.eqz", 0x45);
+pub const I32_EQ: Opcode = op("i32.eq", 0x46);
+pub const I32_NE: Opcode = op("i32.ne", 0x47);
+pub const I32_LT_S: Opcode = op("i32.lt_s", 0x48);
+pub const I32_LT_U: Opcode = op("i32.lt_u", 0x49);
+pub const I32_GT_S: Opcode = op("i32.gt_s", 0x4a);
+pub const I32_GT_U: Opcode = op("i32.gt_u", 0x4b);
+pub const I32_LE_S: Opcode = op("i32.le_s", 0x4c);
+pub const I32_GE_S: Opcode = op("i32.ge_s", 0x4e);
+pub const I32_GE_U: Opcode = op("i32.ge_u", 0x4f);
+
+pub const I64_EQZ: Opcode = op("i64.eqz", 0x50);
+pub const I64_LT_S: Opcode = op("i64.lt_s", 0x53);
+
+pub const F64_EQ: Opcode = op("f64.eq", 0x61);
+pub const F64_NE: Opcode = op("f64.ne", 0x62);
+pub const F64_LT: Opcode = op("f64.lt", 0x63);
+pub const F64_GT: Opcode = op("f64.gt", 0x64);
+pub const F64_LE: Opcode = op("f64.le", 0x65);
+pub const F64_GE: Opcode = op("f64.ge", 0x66);
+
+pub const I32_ADD: Opcode = op("i32.add", 0x6a);
+pub const I32_SUB: Opcode = op("i32.sub", 0x6b);
+pub const I32_MUL: Opcode = op("i32.mul", 0x6c);
+pub const I32_DIV_S: Opcode = op("i32.div_s", 0x6d);
+pub const I32_REM_S: Opcode = op("i32.rem_s", 0x6f);
+pub const I32_AND: Opcode = op("i32.and", 0x71);
+pub const I32_OR: Opcode = op("i32.or", 0x72);
+pub const I32_XOR: Opcode = op("i32.xor", 0x73);
+pub const I32_SHL: Opcode = op("i32.shl", 0x74);
+pub const I32_SHR_S: Opcode = op("i32.shr_s", 0x75);
+pub const I32_SHR_U: Opcode = op("i32.shr_u", 0x76);
+
+pub const I64_ADD: Opcode = op("i64.add", 0x7c);
+pub const I64_SUB: Opcode = op("i64.sub", 0x7d);
+pub const I64_MUL: Opcode = op("i64.mul", 0x7e);
+pub const I64_DIV_U: Opcode = op("i64.div_u", 0x80);
+pub const I64_REM_U: Opcode = op("i64.rem_u", 0x82);
+pub const I64_AND: Opcode = op("i64.and", 0x83);
+pub const I64_SHR_U: Opcode = op("i64.shr_u", 0x88);
+
+pub const F64_ABS: Opcode = op("f64.abs", 0x99);
+pub const F64_NEG: Opcode = op("f64.neg", 0x9a);
+pub const F64_FLOOR: Opcode = op("f64.floor", 0x9c);
+pub const F64_TRUNC: Opcode = op("f64.trunc", 0x9d);
+pub const F64_NEAREST: Opcode = op("f64.nearest", 0x9e);
+pub const F64_ADD: Opcode = op("f64.add", 0xa0);
+pub const F64_SUB: Opcode = op("f64.sub", 0xa1);
+pub const F64_MUL: Opcode = op("f64.mul", 0xa2);
+pub const F64_DIV: Opcode = op("f64.div", 0xa3);
+
+pub const I32_WRAP_I64: Opcode = op("i32.wrap_i64", 0xa7);
+pub const I64_EXTEND_I32_S: Opcode = op("i64.extend_i32_s", 0xac);
+pub const I64_TRUNC_F64_S: Opcode = op("i64.trunc_f64_s", 0xb0);
+pub const F64_CONVERT_I32_S: Opcode = op("f64.convert_i32_s", 0xb7);
+pub const F64_CONVERT_I64_S: Opcode = op("f64.convert_i64_s", 0xb9);
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Instr {
+    Plain(Opcode),
+    Block(BlockType),
+    Loop(BlockType),
+    If(BlockType),
+    Else,
+    End,
+    Br(u32),
+    BrIf(u32),
+    BrTable(Vec<u32>, u32),
+    Call(u32),
+    LocalGet(u32),
+    LocalSet(u32),
+    LocalTee(u32),
+    GlobalGet(u32),
+    GlobalSet(u32),
+    I32Load(u32),
+    I32Load8U(u32),
+    I32Store(u32),
+    I32Store8(u32),
+    MemorySize,
+    MemoryGrow,
+    MemoryCopy,
+    I32Const(i32),
+    I64Const(i64),
+    F64Const(f64),
+}
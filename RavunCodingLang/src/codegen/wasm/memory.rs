@@ -0,0 +1,57 @@
+use std::collections::HashMap;
+
+// Doğrusal bellek düzeni: 0..16 fd_write için iovec ve yazılan bayt sayısı,
+// 16..96 sayı biçimlendirme tamponu, 128'den itibaren statik veriler ve ardından yığın (heap).
+pub const IOVEC: i32 = 0;
+pub const NWRITTEN: i32 = 8;
+pub const NUMBER_BUFFER_END: i32 = 96;
+pub const DATA_START: u32 = 128;
+pub const PAGE_SIZE: u32 = 65536;
+
+#[derive(Debug, Clone)]
+pub struct StringPool {
+    bytes: Vec<u8>,
+    records: HashMap<String, u32>,
+}
+
+impl StringPool {
+    pub fn new() -> Self {
+        StringPool {
+            bytes: Vec::new(),
+            records: HashMap::new(),
+        }
+    }
+    
+    // Her string için veri baytları ve { ptr: i32, len: i32 } dilim kaydı yerleştirilir;
+    // dönen değer kaydın adresidir.
+    pub fn intern(&mut self, value: &str) -> u32 {
+        if let Some(record) = self.records.get(value) {
+            return *record;
+        }
+        
+        let data = DATA_START + self.bytes.len() as u32;
+        self.bytes.extend_from_slice(value.as_bytes());
+        self.bytes.resize(self.bytes.len().next_multiple_of(8), 0);
+        
+        let record = DATA_START + self.bytes.len() as u32;
+        self.bytes.extend_from_slice(&data.to_le_bytes());
+        self.bytes.extend_from_slice(&(value.len() as u32).to_le_bytes());
+        
+        self.records.insert(value.to_string(), record);
+        record
+    }
+    
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+    
+    pub fn end(&self) -> u32 {
+        DATA_START + self.bytes.len() as u32
+    }
+}
+
+impl Default for StringPool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,12 @@
+pub mod instructions;
+pub mod module;
+pub mod memory;
+pub mod runtime;
+pub mod encoder;
+pub mod wat;
+pub mod emitter;
+
+pub use emitter::{emit_wasm, WasmEmitter};
+pub use encoder::encode_module;
+pub use module::WasmModule;
+pub use wat::print_wat;
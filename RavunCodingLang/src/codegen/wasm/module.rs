@@ -0,0 +1,118 @@
+use crate::codegen::wasm::instructions::{Instr, ValType};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FuncType {
+    pub params: Vec<ValType>,
+    pub results: Vec<ValType>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Import {
+    pub module: String,
+    pub name: String,
+    pub type_index: u32,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct WasmFunction {
+    pub name: String,
+    pub type_index: u32,
+    pub locals: Vec<(String, ValType)>,
+    pub param_names: Vec<String>,
+    pub body: Vec<Instr>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct WasmGlobal {
+    pub name: String,
+    pub ty: ValType,
+    pub mutable: bool,
+    pub init: Instr,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportKind {
+    Function,
+    Memory,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Export {
+    pub name: String,
+    pub kind: ExportKind,
+    pub index: u32,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DataSegment {
+    pub offset: u32,
+    pub bytes: Vec<u8>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct WasmModule {
+    pub types: Vec<FuncType>,
+    pub imports: Vec<Import>,
+    pub functions: Vec<WasmFunction>,
+    pub memory_pages: u32,
+    pub globals: Vec<WasmGlobal>,
+    pub exports: Vec<Export>,
+    pub data: Vec<DataSegment>,
+}
+
+impl WasmModule {
+    pub fn new() -> Self {
+        WasmModule::default()
+    }
+    
+    pub fn type_index(&mut self, params: Vec<ValType>, results: Vec<ValType>) -> u32 {
+        let ty = FuncType { params, results };
+        match self.types.iter().position(|t| *t == ty) {
+            Some(index) => index as u32,
+            None => {
+                self.types.push(ty);
+                self.types.len() as u32 - 1
+            },
+        }
+    }
+    
+    pub fn add_import(&mut self, module: &str, name: &str, params: Vec<ValType>, results: Vec<ValType>) -> u32 {
+        let type_index = self.type_index(params, results);
+        self.imports.push(Import { module: module.to_string(), name: name.to_string(), type_index });
+        self.imports.len() as u32 - 1
+    }
+    
+    pub fn declare_function(&mut self, name: &str, params: Vec<(String, ValType)>, results: Vec<ValType>) -> u32 {
+        let type_index = self.type_index(params.iter().map(|(_, ty)| *ty).collect(), results);
+        self.functions.push(WasmFunction {
+            name: name.to_string(),
+            type_index,
+            locals: Vec::new(),
+            param_names: params.into_iter().map(|(name, _)| name).collect(),
+            body: Vec::new(),
+        });
+        self.function_index(self.functions.len() - 1)
+    }
+    
+    pub fn define_function(&mut self, index: u32, locals: Vec<(String, ValType)>, body: Vec<Instr>) {
+        let position = index as usize - self.imports.len();
+        if let Some(function) = self.functions.get_mut(position) {
+            function.locals = locals;
+            function.body = body;
+        }
+    }
+    
+    // Fonksiyon indeksleri içe aktarılan fonksiyonlardan sonra başlar.
+    pub fn function_index(&self, position: usize) -> u32 {
+        (self.imports.len() + position) as u32
+    }
+    
+    pub fn function_name(&self, index: u32) -> Option<&str> {
+        let index = index as usize;
+        if index < self.imports.len() {
+            Some(&self.imports[index].name)
+        } else {
+            self.functions.get(index - self.imports.len()).map(|f| f.name.as_str())
+        }
+    }
+}
@@ -0,0 +1,380 @@
+use crate::codegen::wasm::instructions::BlockType::{Empty, Value};
+use crate::codegen::wasm::instructions::Instr::*;
+use crate::codegen::wasm::instructions::*;
+use crate::codegen::wasm::memory::{StringPool, IOVEC, NUMBER_BUFFER_END, NWRITTEN};
+use crate::codegen::wasm::module::WasmModule;
+
+pub const WASI_MODULE: &str = "wasi_snapshot_preview1";
+pub const HEAP_GLOBAL: u32 = 0;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Runtime {
+    pub fd_write: u32,
+    pub proc_exit: u32,
+    pub write: u32,
+    pub write_str: u32,
+    pub panic: u32,
+    pub alloc: u32,
+    pub print_u64: u32,
+    pub print_i32: u32,
+    pub print_bool: u32,
+    pub print_f64: u32,
+    pub pow_i32: u32,
+    pub div_i32: u32,
+    pub rem_i32: u32,
+    pub pow_f64: u32,
+    pub rem_f64: u32,
+    pub str_concat: u32,
+    pub str_compare: u32,
+    pub str_length: u32,
+    pub utf8_width: u32,
+    pub str_index: u32,
+    pub space: u32,
+    pub newline: u32,
+}
+
+fn params(names: &[(&str, ValType)]) -> Vec<(String, ValType)> {
+    names.iter().map(|(name, ty)| (name.to_string(), *ty)).collect()
+}
+
+impl Runtime {
+    // İçe aktarmalar modüle ilk fonksiyonlardan önce eklenmelidir.
+    pub fn install(module: &mut WasmModule, strings: &mut StringPool) -> Runtime {
+        use ValType::{F64, I32, I64};
+        
+        let fd_write = module.add_import(WASI_MODULE, "fd_write", vec![I32, I32, I32, I32], vec![I32]);
+        let proc_exit = module.add_import(WASI_MODULE, "proc_exit", vec![I32], vec![]);
+        
+        let runtime = Runtime {
+            fd_write,
+            proc_exit,
+            write: module.declare_function("rv_write", params(&[("fd", I32), ("ptr", I32), ("len", I32)]), vec![]),
+            write_str: module.declare_function("rv_write_str", params(&[("fd", I32), ("s", I32)]), vec![]),
+            panic: module.declare_function("rv_panic", params(&[("message", I32)]), vec![]),
+            alloc: module.declare_function("rv_alloc", params(&[("size", I32)]), vec![I32]),
+            print_u64: module.declare_function("rv_print_u64", params(&[("value", I64)]), vec![]),
+            print_i32: module.declare_function("rv_print_i32", params(&[("value", I32)]), vec![]),
+            print_bool: module.declare_function("rv_print_bool", params(&[("value", I32)]), vec![]),
+            print_f64: module.declare_function("rv_print_f64", params(&[("value", F64)]), vec![]),
+            pow_i32: module.declare_function("rv_pow_i32", params(&[("base", I32), ("exponent", I32)]), vec![I32]),
+            div_i32: module.declare_function("rv_div_i32", params(&[("a", I32), ("b", I32)]), vec![I32]),
+            rem_i32: module.declare_function("rv_rem_i32", params(&[("a", I32), ("b", I32)]), vec![I32]),
+            pow_f64: module.declare_function("rv_pow_f64", params(&[("base", F64), ("exponent", F64)]), vec![F64]),
+            rem_f64: module.declare_function("rv_rem_f64", params(&[("a", F64), ("b", F64)]), vec![F64]),
+            str_concat: module.declare_function("rv_str_concat", params(&[("a", I32), ("b", I32)]), vec![I32]),
+            str_compare: module.declare_function("rv_str_compare", params(&[("a", I32), ("b", I32)]), vec![I32]),
+            str_length: module.declare_function("rv_str_length", params(&[("s", I32)]), vec![I32]),
+            utf8_width: module.declare_function("rv_utf8_width", params(&[("lead", I32)]), vec![I32]),
+            str_index: module.declare_function("rv_str_index", params(&[("s", I32), ("index", I32)]), vec![I32]),
+            space: strings.intern(" "),
+            newline: strings.intern("\n"),
+        };
+        
+        runtime.define(module, strings);
+        runtime
+    }
+    
+    fn define(&self, module: &mut WasmModule, strings: &mut StringPool) {
+        use ValType::{F64, I32, I64};
+        
+        let minus = strings.intern("-") as i32;
+        let panic_prefix = strings.intern("Çalışma zamanı hatası: ") as i32;
+        let division_by_zero = strings.intern("Sıfıra bölme") as i32;
+        let out_of_bounds = strings.intern("Dizin sınırların dışında") as i32;
+        let out_of_memory = strings.intern("Bellek yetersiz") as i32;
+        let fractional_exponent = strings.intern("Ondalık üs desteklenmiyor") as i32;
+        
+        module.define_function(self.write, vec![], vec![
+            I32Const(IOVEC), LocalGet(1), I32Store(0),
+            I32Const(IOVEC), LocalGet(2), I32Store(4),
+            LocalGet(0), I32Const(IOVEC), I32Const(1), I32Const(NWRITTEN), Call(self.fd_write), Plain(DROP),
+        ]);
+        
+        module.define_function(self.write_str, vec![], vec![
+            LocalGet(0), LocalGet(1), I32Load(0), LocalGet(1), I32Load(4), Call(self.write),
+        ]);
+        
+        module.define_function(self.panic, vec![], vec![
+            I32Const(2), I32Const(panic_prefix), Call(self.write_str),
+            I32Const(2), LocalGet(0), Call(self.write_str),
+            I32Const(2), I32Const(self.newline as i32), Call(self.write_str),
+            I32Const(1), Call(self.proc_exit),
+            Plain(UNREACHABLE),
+        ]);
+        
+        module.define_function(self.alloc, params(&[("ptr", I32), ("end", I32)]), vec![
+            GlobalGet(HEAP_GLOBAL), LocalSet(1),
+            LocalGet(1), LocalGet(0), Plain(I32_ADD), I32Const(7), Plain(I32_ADD), I32Const(-8), Plain(I32_AND), LocalSet(2),
+            LocalGet(2), MemorySize, I32Const(16), Plain(I32_SHL), Plain(I32_GT_U), If(Empty),
+                LocalGet(2), MemorySize, I32Const(16), Plain(I32_SHL), Plain(I32_SUB),
+                I32Const(65535), Plain(I32_ADD), I32Const(16), Plain(I32_SHR_U), MemoryGrow,
+                I32Const(-1), Plain(I32_EQ), If(Empty),
+                    I32Const(out_of_memory), Call(self.panic),
+                End,
+            End,
+            LocalGet(2), GlobalSet(HEAP_GLOBAL),
+            LocalGet(1),
+        ]);
+        
+        module.define_function(self.print_u64, params(&[("pos", I32)]), vec![
+            I32Const(NUMBER_BUFFER_END), LocalSet(1),
+            Loop(Empty),
+                LocalGet(1), I32Const(1), Plain(I32_SUB), LocalSet(1),
+                LocalGet(1), LocalGet(0), I64Const(10), Plain(I64_REM_U), Plain(I32_WRAP_I64),
+                I32Const(48), Plain(I32_ADD), I32Store8(0),
+                LocalGet(0), I64Const(10), Plain(I64_DIV_U), LocalTee(0), Plain(I64_EQZ), Plain(I32_EQZ), BrIf(0),
+            End,
+            I32Const(1), LocalGet(1), I32Const(NUMBER_BUFFER_END), LocalGet(1), Plain(I32_SUB), Call(self.write),
+        ]);
+        
+        module.define_function(self.print_i32, params(&[("wide", I64)]), vec![
+            LocalGet(0), I32Const(0), Plain(I32_LT_S), If(Empty),
+                I32Const(1), I32Const(minus), Call(self.write_str),
+            End,
+            LocalGet(0), Plain(I64_EXTEND_I32_S), LocalSet(1),
+            I64Const(0), LocalGet(1), Plain(I64_SUB), LocalGet(1), LocalGet(1), I64Const(0), Plain(I64_LT_S), Plain(SELECT),
+            Call(self.print_u64),
+        ]);
+        
+        let true_text = strings.intern("true") as i32;
+        let false_text = strings.intern("false") as i32;
+        module.define_function(self.print_bool, vec![], vec![
+            I32Const(1), I32Const(true_text), I32Const(false_text), LocalGet(0), Plain(SELECT), Call(self.write_str),
+        ]);
+        
+        self.define_print_f64(module, strings, minus);
+        
+        module.define_function(self.pow_i32, params(&[("result", I32)]), vec![
+            LocalGet(1), I32Const(0), Plain(I32_LT_S), If(Empty),
+                LocalGet(0), I32Const(1), Plain(I32_EQ), If(Empty),
+                    I32Const(1), Plain(RETURN),
+                End,
+                LocalGet(0), I32Const(-1), Plain(I32_EQ), If(Empty),
+                    I32Const(1), I32Const(-1), LocalGet(1), I32Const(1), Plain(I32_AND), Plain(I32_EQZ), Plain(SELECT), Plain(RETURN),
+                End,
+                I32Const(0), Plain(RETURN),
+            End,
+            I32Const(1), LocalSet(2),
+            Block(Empty),
+                Loop(Empty),
+                    LocalGet(1), Plain(I32_EQZ), BrIf(1),
+                    LocalGet(1), I32Const(1), Plain(I32_AND), If(Empty),
+                        LocalGet(2), LocalGet(0), Plain(I32_MUL), LocalSet(2),
+                    End,
+                    LocalGet(0), LocalGet(0), Plain(I32_MUL), LocalSet(0),
+                    LocalGet(1), I32Const(1), Plain(I32_SHR_U), LocalSet(1),
+                    Br(0),
+                End,
+            End,
+            LocalGet(2),
+        ]);
+        
+        // i32.div_s sıfıra bölmede ve INT32_MIN / -1 taşmasında tuzağa düşer; ikisi de burada ele alınır.
+        module.define_function(self.div_i32, vec![], vec![
+            LocalGet(1), Plain(I32_EQZ), If(Empty),
+                I32Const(division_by_zero), Call(self.panic),
+            End,
+            LocalGet(1), I32Const(-1), Plain(I32_EQ), If(Empty),
+                I32Const(0), LocalGet(0), Plain(I32_SUB), Plain(RETURN),
+            End,
+            LocalGet(0), LocalGet(1), Plain(I32_DIV_S),
+        ]);
+        
+        module.define_function(self.rem_i32, vec![], vec![
+            LocalGet(1), Plain(I32_EQZ), If(Empty),
+                I32Const(division_by_zero), Call(self.panic),
+            End,
+            LocalGet(1), I32Const(-1), Plain(I32_EQ), If(Empty),
+                I32Const(0), Plain(RETURN),
+            End,
+            LocalGet(0), LocalGet(1), Plain(I32_REM_S),
+        ]);
+        
+        // WebAssembly'de pow bulunmadığından yalnızca tam sayı üsler desteklenir.
+        module.define_function(self.pow_f64, params(&[("result", F64), ("count", I64)]), vec![
+            LocalGet(1), LocalGet(1), Plain(F64_TRUNC), Plain(F64_NE),
+            LocalGet(1), Plain(F64_ABS), F64Const(9.0e18), Plain(F64_GE), Plain(I32_OR), If(Empty),
+                I32Const(fractional_exponent), Call(self.panic),
+            End,
+            LocalGet(1), Plain(F64_ABS), Plain(I64_TRUNC_F64_S), LocalSet(3),
+            F64Const(1.0), LocalSet(2),
+            Block(Empty),
+                Loop(Empty),
+                    LocalGet(3), Plain(I64_EQZ), BrIf(1),
+                    LocalGet(3), I64Const(1), Plain(I64_AND), Plain(I64_EQZ), Plain(I32_EQZ), If(Empty),
+                        LocalGet(2), LocalGet(0), Plain(F64_MUL), LocalSet(2),
+                    End,
+                    LocalGet(0), LocalGet(0), Plain(F64_MUL), LocalSet(0),
+                    LocalGet(3), I64Const(1), Plain(I64_SHR_U), LocalSet(3),
+                    Br(0),
+                End,
+            End,
+            LocalGet(1), F64Const(0.0), Plain(F64_LT), If(Value(F64)),
+                F64Const(1.0), LocalGet(2), Plain(F64_DIV),
+            Else,
+                LocalGet(2),
+            End,
+        ]);
+        
+        module.define_function(self.rem_f64, vec![], vec![
+            LocalGet(0), LocalGet(0), LocalGet(1), Plain(F64_DIV), Plain(F64_TRUNC), LocalGet(1), Plain(F64_MUL), Plain(F64_SUB),
+        ]);
+        
+        module.define_function(self.str_concat, params(&[("left_len", I32), ("right_len", I32), ("data", I32), ("record", I32)]), vec![
+            LocalGet(0), I32Load(4), LocalSet(2),
+            LocalGet(1), I32Load(4), LocalSet(3),
+            LocalGet(2), LocalGet(3), Plain(I32_ADD), Call(self.alloc), LocalSet(4),
+            LocalGet(4), LocalGet(0), I32Load(0), LocalGet(2), MemoryCopy,
+            LocalGet(4), LocalGet(2), Plain(I32_ADD), LocalGet(1), I32Load(0), LocalGet(3), MemoryCopy,
+            I32Const(8), Call(self.alloc), LocalTee(5), LocalGet(4), I32Store(0),
+            LocalGet(5), LocalGet(2), LocalGet(3), Plain(I32_ADD), I32Store(4),
+            LocalGet(5),
+        ]);
+        
+        module.define_function(self.str_compare, params(&[("i", I32), ("common", I32), ("left", I32), ("right", I32)]), vec![
+            LocalGet(0), I32Load(4), LocalGet(1), I32Load(4),
+            LocalGet(0), I32Load(4), LocalGet(1), I32Load(4), Plain(I32_LT_U), Plain(SELECT), LocalSet(3),
+            Block(Empty),
+                Loop(Empty),
+                    LocalGet(2), LocalGet(3), Plain(I32_GE_U), BrIf(1),
+                    LocalGet(0), I32Load(0), LocalGet(2), Plain(I32_ADD), I32Load8U(0), LocalSet(4),
+                    LocalGet(1), I32Load(0), LocalGet(2), Plain(I32_ADD), I32Load8U(0), LocalSet(5),
+                    LocalGet(4), LocalGet(5), Plain(I32_NE), If(Empty),
+                        I32Const(-1), I32Const(1), LocalGet(4), LocalGet(5), Plain(I32_LT_U), Plain(SELECT), Plain(RETURN),
+                    End,
+                    LocalGet(2), I32Const(1), Plain(I32_ADD), LocalSet(2),
+                    Br(0),
+                End,
+            End,
+            LocalGet(0), I32Load(4), LocalGet(1), I32Load(4), Plain(I32_EQ), If(Empty),
+                I32Const(0), Plain(RETURN),
+            End,
+            I32Const(-1), I32Const(1), LocalGet(0), I32Load(4), LocalGet(1), I32Load(4), Plain(I32_LT_U), Plain(SELECT),
+        ]);
+        
+        module.define_function(self.str_length, params(&[("i", I32), ("count", I32)]), vec![
+            Block(Empty),
+                Loop(Empty),
+                    LocalGet(1), LocalGet(0), I32Load(4), Plain(I32_GE_U), BrIf(1),
+                    LocalGet(0), I32Load(0), LocalGet(1), Plain(I32_ADD), I32Load8U(0),
+                    I32Const(0xc0), Plain(I32_AND), I32Const(0x80), Plain(I32_NE),
+                    LocalGet(2), Plain(I32_ADD), LocalSet(2),
+                    LocalGet(1), I32Const(1), Plain(I32_ADD), LocalSet(1),
+                    Br(0),
+                End,
+            End,
+            LocalGet(2),
+        ]);
+        
+        module.define_function(self.utf8_width, vec![], vec![
+            LocalGet(0), I32Const(0x80), Plain(I32_LT_U), If(Empty), I32Const(1), Plain(RETURN), End,
+            LocalGet(0), I32Const(5), Plain(I32_SHR_U), I32Const(0x6), Plain(I32_EQ), If(Empty), I32Const(2), Plain(RETURN), End,
+            LocalGet(0), I32Const(4), Plain(I32_SHR_U), I32Const(0xe), Plain(I32_EQ), If(Empty), I32Const(3), Plain(RETURN), End,
+            LocalGet(0), I32Const(3), Plain(I32_SHR_U), I32Const(0x1e), Plain(I32_EQ), If(Empty), I32Const(4), Plain(RETURN), End,
+            I32Const(1),
+        ]);
+        
+        // Dizinleme yeni bayt kopyalamaz; sonuç aynı veriyi gösteren yeni bir dilim kaydıdır.
+        module.define_function(self.str_index, params(&[("offset", I32), ("current", I32), ("width", I32), ("record", I32), ("len", I32)]), vec![
+            LocalGet(0), I32Load(4), LocalSet(6),
+            LocalGet(1), I32Const(0), Plain(I32_LT_S), If(Empty),
+                I32Const(out_of_bounds), Call(self.panic),
+            End,
+            Loop(Empty),
+                LocalGet(2), LocalGet(6), Plain(I32_GE_U), If(Empty),
+                    I32Const(out_of_bounds), Call(self.panic),
+                End,
+                LocalGet(0), I32Load(0), LocalGet(2), Plain(I32_ADD), I32Load8U(0), Call(self.utf8_width), LocalSet(4),
+                LocalGet(3), LocalGet(1), Plain(I32_EQ), If(Empty),
+                    I32Const(8), Call(self.alloc), LocalTee(5), LocalGet(0), I32Load(0), LocalGet(2), Plain(I32_ADD), I32Store(0),
+                    LocalGet(5),
+                    LocalGet(4), LocalGet(6), LocalGet(2), Plain(I32_SUB),
+                    LocalGet(4), LocalGet(6), LocalGet(2), Plain(I32_SUB), Plain(I32_LT_U), Plain(SELECT),
+                    I32Store(4),
+                    LocalGet(5), Plain(RETURN),
+                End,
+                LocalGet(2), LocalGet(4), Plain(I32_ADD), LocalSet(2),
+                LocalGet(3), I32Const(1), Plain(I32_ADD), LocalSet(3),
+                Br(0),
+            End,
+            Plain(UNREACHABLE),
+        ]);
+    }
+    
+    // Rust'ın f64 Display biçimini yaklaşık olarak izler: tam sayılar kesirsiz yazılır,
+    // diğer değerler geri okunduğunda aynı sayıyı veren en kısa ondalık hane sayısıyla yazılır.
+    fn define_print_f64(&self, module: &mut WasmModule, strings: &mut StringPool, minus: i32) {
+        use ValType::{F64, I32, I64};
+        
+        let nan = strings.intern("NaN") as i32;
+        let infinity = strings.intern("inf") as i32;
+        let zero = strings.intern("0") as i32;
+        
+        let locals = params(&[("scale", F64), ("scaled", F64), ("digits", I32), ("pos", I32), ("whole", I64)]);
+        module.define_function(self.print_f64, locals, vec![
+            LocalGet(0), LocalGet(0), Plain(F64_NE), If(Empty),
+                I32Const(1), I32Const(nan), Call(self.write_str), Plain(RETURN),
+            End,
+            LocalGet(0), F64Const(0.0), Plain(F64_LT),
+            LocalGet(0), F64Const(0.0), Plain(F64_EQ), F64Const(1.0), LocalGet(0), Plain(F64_DIV), F64Const(0.0), Plain(F64_LT), Plain(I32_AND),
+            Plain(I32_OR), If(Empty),
+                I32Const(1), I32Const(minus), Call(self.write_str),
+                LocalGet(0), Plain(F64_NEG), LocalSet(0),
+            End,
+            LocalGet(0), F64Const(f64::INFINITY), Plain(F64_EQ), If(Empty),
+                I32Const(1), I32Const(infinity), Call(self.write_str), Plain(RETURN),
+            End,
+            LocalGet(0), LocalGet(0), Plain(F64_FLOOR), Plain(F64_EQ), If(Empty),
+                I32Const(0), LocalSet(3),
+                Block(Empty),
+                    Loop(Empty),
+                        LocalGet(0), F64Const(1.0e18), Plain(F64_LT), BrIf(1),
+                        LocalGet(0), F64Const(10.0), Plain(F64_DIV), Plain(F64_NEAREST), LocalSet(0),
+                        LocalGet(3), I32Const(1), Plain(I32_ADD), LocalSet(3),
+                        Br(0),
+                    End,
+                End,
+                LocalGet(0), Plain(I64_TRUNC_F64_S), Call(self.print_u64),
+                Block(Empty),
+                    Loop(Empty),
+                        LocalGet(3), Plain(I32_EQZ), BrIf(1),
+                        I32Const(1), I32Const(zero), Call(self.write_str),
+                        LocalGet(3), I32Const(1), Plain(I32_SUB), LocalSet(3),
+                        Br(0),
+                    End,
+                End,
+                Plain(RETURN),
+            End,
+            F64Const(1.0), LocalSet(1),
+            I32Const(0), LocalSet(3),
+            Block(Empty),
+                Loop(Empty),
+                    LocalGet(1), F64Const(10.0), Plain(F64_MUL), LocalSet(1),
+                    LocalGet(3), I32Const(1), Plain(I32_ADD), LocalSet(3),
+                    LocalGet(0), LocalGet(1), Plain(F64_MUL), Plain(F64_NEAREST), LocalSet(2),
+                    LocalGet(2), LocalGet(1), Plain(F64_DIV), LocalGet(0), Plain(F64_EQ), BrIf(1),
+                    LocalGet(2), F64Const(1.0e17), Plain(F64_GE), BrIf(1),
+                    LocalGet(3), I32Const(40), Plain(I32_GE_S), BrIf(1),
+                    Br(0),
+                End,
+            End,
+            LocalGet(2), Plain(I64_TRUNC_F64_S), LocalSet(5),
+            I32Const(NUMBER_BUFFER_END), LocalSet(4),
+            Loop(Empty),
+                LocalGet(4), I32Const(1), Plain(I32_SUB), LocalSet(4),
+                LocalGet(4), LocalGet(5), I64Const(10), Plain(I64_REM_U), Plain(I32_WRAP_I64),
+                I32Const(48), Plain(I32_ADD), I32Store8(0),
+                LocalGet(5), I64Const(10), Plain(I64_DIV_U), LocalSet(5),
+                LocalGet(3), I32Const(1), Plain(I32_SUB), LocalTee(3), BrIf(0),
+            End,
+            LocalGet(4), I32Const(1), Plain(I32_SUB), LocalTee(4), I32Const(46), I32Store8(0),
+            Loop(Empty),
+                LocalGet(4), I32Const(1), Plain(I32_SUB), LocalSet(4),
+                LocalGet(4), LocalGet(5), I64Const(10), Plain(I64_REM_U), Plain(I32_WRAP_I64),
+                I32Const(48), Plain(I32_ADD), I32Store8(0),
+                LocalGet(5), I64Const(10), Plain(I64_DIV_U), LocalTee(5), Plain(I64_EQZ), Plain(I32_EQZ), BrIf(0),
+            End,
+            I32Const(1), LocalGet(4), I32Const(NUMBER_BUFFER_END), LocalGet(4), Plain(I32_SUB), Call(self.write),
+        ]);
+    }
+}
@@ -0,0 +1,173 @@
+use std::fmt::Write;
+use crate::codegen::wasm::instructions::{BlockType, Instr};
+use crate::codegen::wasm::module::{ExportKind, FuncType, WasmFunction, WasmModule};
+
+pub fn print_wat(module: &WasmModule) -> String {
+    let mut out = String::from("(module\n");
+    
+    for (index, ty) in module.types.iter().enumerate() {
+        let _ = writeln!(out, "  (type (;{};) (func{}))", index, signature(ty, None));
+    }
+    
+    for import in &module.imports {
+        let _ = writeln!(
+            out,
+            "  (import \"{}\" \"{}\" (func ${} (type {})))",
+            import.module, import.name, import.name, import.type_index,
+        );
+    }
+    
+    let _ = writeln!(out, "  (memory (;0;) {})", module.memory_pages);
+    
+    for global in &module.globals {
+        let ty = if global.mutable { format!("(mut {})", global.ty) } else { global.ty.to_string() };
+        let _ = writeln!(out, "  (global ${} {} ({}))", global.name, ty, instruction_text(module, None, &global.init));
+    }
+    
+    for export in &module.exports {
+        let target = match export.kind {
+            ExportKind::Function => format!("func ${}", module.function_name(export.index).unwrap_or("?")),
+            ExportKind::Memory => format!("memory {}", export.index),
+        };
+        let _ = writeln!(out, "  (export \"{}\" ({}))", export.name, target);
+    }
+    
+    for function in &module.functions {
+        print_function(&mut out, module, function);
+    }
+    
+    for segment in &module.data {
+        let _ = writeln!(out, "  (data (i32.const {}) \"{}\")", segment.offset, escape_bytes(&segment.bytes));
+    }
+    
+    out.push_str(")\n");
+    out
+}
+
+fn print_function(out: &mut String, module: &WasmModule, function: &WasmFunction) {
+    let ty = &module.types[function.type_index as usize];
+    let _ = writeln!(
+        out,
+        "  (func ${} (type {}){}",
+        function.name,
+        function.type_index,
+        signature(ty, Some(&function.param_names)),
+    );
+    
+    for (name, ty) in &function.locals {
+        let _ = writeln!(out, "    (local ${} {})", name, ty);
+    }
+    
+    let mut depth = 2;
+    for instruction in &function.body {
+        if matches!(instruction, Instr::End | Instr::Else) {
+            depth -= 1;
+        }
+        let _ = writeln!(out, "{}{}", "  ".repeat(depth), instruction_text(module, Some(function), instruction));
+        if matches!(instruction, Instr::Block(_) | Instr::Loop(_) | Instr::If(_) | Instr::Else) {
+            depth += 1;
+        }
+    }
+    
+    out.push_str("  )\n");
+}
+
+fn signature(ty: &FuncType, names: Option<&Vec<String>>) -> String {
+    let mut text = String::new();
+    
+    for (index, param) in ty.params.iter().enumerate() {
+        match names.and_then(|n| n.get(index)) {
+            Some(name) => {
+                let _ = write!(text, " (param ${} {})", name, param);
+            },
+            None => {
+                let _ = write!(text, " (param {})", param);
+            },
+        }
+    }
+    for result in &ty.results {
+        let _ = write!(text, " (result {})", result);
+    }
+    
+    text
+}
+
+fn instruction_text(module: &WasmModule, function: Option<&WasmFunction>, instruction: &Instr) -> String {
+    let local = |index: &u32| -> String {
+        let index = *index as usize;
+        let name = function.and_then(|f| {
+            f.param_names.get(index).or_else(|| f.locals.get(index - f.param_names.len()).map(|(n, _)| n))
+        });
+        match name {
+            Some(name) => format!("${}", name),
+            None => index.to_string(),
+        }
+    };
+    
+    match instruction {
+        Instr::Plain(opcode) => opcode.name.to_string(),
+        Instr::Block(ty) => format!("block{}", block_type(ty)),
+        Instr::Loop(ty) => format!("loop{}", block_type(ty)),
+        Instr::If(ty) => format!("if{}", block_type(ty)),
+        Instr::Else => "else".to_string(),
+        Instr::End => "end".to_string(),
+        Instr::Br(depth) => format!("br {}", depth),
+        Instr::BrIf(depth) => format!("br_if {}", depth),
+        Instr::BrTable(targets, default) => {
+            let targets: Vec<String> = targets.iter().map(|t| t.to_string()).collect();
+            format!("br_table {} {}", targets.join(" "), default)
+        },
+        Instr::Call(index) => format!("call ${}", module.function_name(*index).unwrap_or("?")),
+        Instr::LocalGet(index) => format!("local.get {}", local(index)),
+        Instr::LocalSet(index) => format!("local.set {}", local(index)),
+        Instr::LocalTee(index) => format!("local.tee {}", local(index)),
+        Instr::GlobalGet(index) => format!("global.get ${}", module.globals[*index as usize].name),
+        Instr::GlobalSet(index) => format!("global.set ${}", module.globals[*index as usize].name),
+        Instr::I32Load(offset) => memory_text("i32.load", *offset),
+        Instr::I32Load8U(offset) => memory_text("i32.load8_u", *offset),
+        Instr::I32Store(offset) => memory_text("i32.store", *offset),
+        Instr::I32Store8(offset) => memory_text("i32.store8", *offset),
+        Instr::MemorySize => "memory.size".to_string(),
+        Instr::MemoryGrow => "memory.grow".to_string(),
+        Instr::MemoryCopy => "memory.copy".to_string(),
+        Instr::I32Const(value) => format!("i32.const {}", value),
+        Instr::I64Const(value) => format!("i64.const {}", value),
+        Instr::F64Const(value) if value.is_nan() => "f64.const nan".to_string(),
+        Instr::F64Const(value) if value.is_infinite() => {
+            format!("f64.const {}inf", if *value < 0.0 { "-" } else { "" })
+        },
+        Instr::F64Const(value) => format!("f64.const {:?}", value),
+    }
+}
+
+fn block_type(ty: &BlockType) -> String {
+    match ty {
+        BlockType::Empty => String::new(),
+        BlockType::Value(value) => format!(" (result {})", value),
+    }
+}
+
+fn memory_text(name: &str, offset: u32) -> String {
+    if offset == 0 {
+        name.to_string()
+    } else {
+        format!("{} offset={}", name, offset)
+    }
+}
+
+fn escape_bytes(bytes: &[u8]) -> String {
+    let mut text = String::new();
+    
+    for byte in bytes {
+        match byte {
+            b'"' => text.push_str("\\\""),
+            b'\\' => text.push_str("\\\\"),
+            0x20..=0x7e => text.push(*byte as char),
+            _ => {
+                let _ = write!(text, "\\{:02x}", byte);
+            },
+        }
+    }
+    
+    text
+}
@@ -0,0 +1,70 @@
+use crate::codegen::error::CodegenError;
+use crate::codegen::x86_64::registers::{Gpr, RegClass};
+use crate::diagnostics::MessageId;
+use crate::ir::function::Function;
+use crate::semantics::types::Type;
+
+pub const ARGUMENT_REGISTERS: [Gpr; 6] = [Gpr::Rdi, Gpr::Rsi, Gpr::Rdx, Gpr::Rcx, Gpr::R8, Gpr::R9];
+pub const FLOAT_ARGUMENT_REGISTERS: u8 = 8;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArgLocation {
+    Gpr(Gpr),
+    Xmm(u8),
+    Stack(usize),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CallingConvention {
+    pub args: Vec<(ArgLocation, Type)>,
+    pub stack_slots: usize,
+    pub return_class: Option<RegClass>,
+}
+
+impl CallingConvention {
+    pub fn for_signature(signature: &Type) -> Result<CallingConvention, CodegenError> {
+        let Type::Function(params, return_type) = signature else {
+            return Err(CodegenError::new(MessageId::CodegenNotFunctionType.format(&[&signature])));
+        };
+        
+        let mut args = Vec::with_capacity(params.len());
+        let mut general = 0;
+        let mut float = 0;
+        let mut stack_slots = 0;
+        
+        for param in params {
+            let location = match RegClass::of(param)? {
+                RegClass::General if general < ARGUMENT_REGISTERS.len() => {
+                    general += 1;
+                    ArgLocation::Gpr(ARGUMENT_REGISTERS[general - 1])
+                },
+                RegClass::Float if float < FLOAT_ARGUMENT_REGISTERS => {
+                    float += 1;
+                    ArgLocation::Xmm(float - 1)
+                },
+                _ => {
+                    stack_slots += 1;
+                    ArgLocation::Stack(stack_slots - 1)
+                },
+            };
+            args.push((location, param.clone()));
+        }
+        
+        let return_class = match return_type.as_ref() {
+            Type::Void => None,
+            other => Some(RegClass::of(other)?),
+        };
+        
+        Ok(CallingConvention { args, stack_slots, return_class })
+    }
+    
+    pub fn for_function(function: &Function) -> Result<CallingConvention, CodegenError> {
+        let signature = Type::Function(
+            function.params.iter().map(|p| p.ty.clone()).collect(),
+            Box::new(function.return_type.clone()),
+        );
+        
+        CallingConvention::for_signature(&signature)
+            .map_err(|err| CodegenError::in_function(&function.name, err.message))
+    }
+}
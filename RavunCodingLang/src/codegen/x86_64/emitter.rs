@@ -0,0 +1,857 @@
+use std::collections::HashMap;
+use std::fmt::Write;
+use crate::codegen::error::CodegenError;
+use crate::codegen::names::{function_symbol, global_symbol};
+use crate::codegen::x86_64::abi::{ArgLocation, CallingConvention};
+use crate::codegen::x86_64::frame::FrameLayout;
+use crate::codegen::x86_64::regalloc::{allocate_registers, Location};
+use crate::codegen::x86_64::registers::{Gpr, RegClass, Width};
+use crate::diagnostics::MessageId;
+use crate::ir::function::{Function, Module};
+use crate::ir::instruction::{
+    BinaryOp, BlockId, Constant, Instruction, InstructionKind, Operand, Terminator, UnaryOp, ValueId,
+};
+use crate::semantics::types::Type;
+
+const BUILTINS: [&str; 2] = ["print", "println"];
+
+pub fn emit_asm(module: &Module) -> Result<String, CodegenError> {
+    AsmEmitter::new(module).emit()
+}
+
+pub struct AsmEmitter<'a> {
+    module: &'a Module,
+    strings: Vec<String>,
+    floats: Vec<u64>,
+}
+
+impl<'a> AsmEmitter<'a> {
+    pub fn new(module: &'a Module) -> Self {
+        AsmEmitter {
+            module,
+            strings: Vec::new(),
+            floats: Vec::new(),
+        }
+    }
+    
+    pub fn emit(mut self) -> Result<String, CodegenError> {
+        let main = self.module.function("main")
+            .ok_or_else(|| CodegenError::new(MessageId::CodegenMissingMain.text().to_string()))?;
+        if !main.params.is_empty() {
+            return Err(CodegenError::in_function("main", MessageId::CodegenMainWithParameters.text().to_string()));
+        }
+        
+        let mut text = String::new();
+        for function in &self.module.functions {
+            let body = self.emit_function(function)
+                .map_err(|e| CodegenError::in_function(&function.name, e.message))?;
+            text.push_str(&body);
+        }
+        
+        let mut globals = String::new();
+        for global in &self.module.globals {
+            let directive = match &global.initializer {
+                Constant::Int(value) => format!(".long {}", value),
+                Constant::Bool(value) => format!(".byte {}", *value as u8),
+                Constant::Float(value) => format!(".quad 0x{:016x}", value.to_bits()),
+                Constant::String(value) => format!(".quad {}", self.string_label(value)),
+            };
+            let _ = writeln!(globals, "    .p2align 3\n{}:\n    {}", global_symbol(&global.name), directive);
+        }
+        
+        let mut out = String::new();
+        out.push_str("    .text\n");
+        out.push_str(&text);
+        
+        out.push_str("\n    .globl main\n    .type main, @function\nmain:\n");
+        out.push_str("    pushq %rbp\n    movq %rsp, %rbp\n    call rv_f_main\n");
+        if main.return_type != Type::Int {
+            out.push_str("    xorl %eax, %eax\n");
+        }
+        out.push_str("    popq %rbp\n    ret\n    .size main, .-main\n");
+        
+        if !globals.is_empty() || !self.strings.is_empty() {
+            out.push_str("\n    .data\n");
+            out.push_str(&globals);
+            for index in 0..self.strings.len() {
+                let _ = writeln!(
+                    out,
+                    "    .p2align 3\n.Lstr{0}:\n    .quad .Lstr{0}_data\n    .long {1}\n    .long 0",
+                    index,
+                    self.strings[index].len(),
+                );
+            }
+        }
+        
+        if !self.strings.is_empty() || !self.floats.is_empty() {
+            out.push_str("\n    .section .rodata\n");
+            for (index, bits) in self.floats.iter().enumerate() {
+                let _ = writeln!(out, "    .p2align 3\n.Lflt{}:\n    .quad 0x{:016x}", index, bits);
+            }
+            for (index, value) in self.strings.iter().enumerate() {
+                let _ = writeln!(out, ".Lstr{}_data:\n    .asciz {}", index, string_literal(value));
+            }
+        }
+        
+        out.push_str("\n    .section .note.GNU-stack,\"\",@progbits\n");
+        Ok(out)
+    }
+    
+    fn string_label(&mut self, value: &str) -> String {
+        let index = match self.strings.iter().position(|s| s == value) {
+            Some(index) => index,
+            None => {
+                self.strings.push(value.to_string());
+                self.strings.len() - 1
+            },
+        };
+        format!(".Lstr{}", index)
+    }
+    
+    fn float_label(&mut self, value: f64) -> String {
+        let bits = value.to_bits();
+        let index = match self.floats.iter().position(|b| *b == bits) {
+            Some(index) => index,
+            None => {
+                self.floats.push(bits);
+                self.floats.len() - 1
+            },
+        };
+        format!(".Lflt{}", index)
+    }
+    
+    fn emit_function(&mut self, function: &Function) -> Result<String, CodegenError> {
+        let order = function.reverse_postorder();
+        let types = function.value_types();
+        let allocation = allocate_registers(function, &order)?;
+        let convention = CallingConvention::for_function(function)?;
+        
+        let mut frame = FrameLayout::new(allocation.callee_saved.clone());
+        let mut locations = allocation.registers.clone();
+        for value in &allocation.spilled {
+            let ty = types.get(value).cloned().unwrap_or(Type::Error);
+            locations.insert(*value, Location::Stack(frame.allocate(&ty)));
+        }
+        
+        let max_phis = function.blocks.iter().map(|b| b.phis().count()).max().unwrap_or(0);
+        let scratch = if max_phis > 1 { frame.allocate_scratch(max_phis) } else { Vec::new() };
+        
+        let symbol = function_symbol(&function.name);
+        let mut context = FunctionContext {
+            function,
+            types: &types,
+            locations,
+            scratch,
+            symbol: symbol.clone(),
+            labels: 0,
+            divides: false,
+            out: String::new(),
+        };
+        
+        let _ = writeln!(context.out, "\n    .p2align 4\n    .type {0}, @function\n{0}:", symbol);
+        context.emit("pushq %rbp");
+        context.emit("movq %rsp, %rbp");
+        for register in &frame.saved_registers {
+            context.emit(format!("pushq {}", register.name64()));
+        }
+        let adjustment = frame.stack_adjustment();
+        if adjustment > 0 {
+            context.emit(format!("subq ${}, %rsp", adjustment));
+        }
+        
+        for (param, (argument, ty)) in function.params.iter().zip(&convention.args) {
+            let Some(location) = context.locations.get(&param.id).copied() else {
+                continue;
+            };
+            match argument {
+                ArgLocation::Gpr(register) => store_general(&mut context, ty, *register, location),
+                ArgLocation::Xmm(register) => store_float(&mut context, *register, location),
+                ArgLocation::Stack(slot) => {
+                    let address = format!("{}(%rbp)", 16 + 8 * slot);
+                    match RegClass::of(ty)? {
+                        RegClass::General => {
+                            context.emit(format!("{} {}, {}", load_mnemonic(Width::of(ty)), address, general_name(Gpr::Rax, Width::of(ty))));
+                            store_general(&mut context, ty, Gpr::Rax, location);
+                        },
+                        RegClass::Float => {
+                            context.emit(format!("movsd {}, %xmm0", address));
+                            store_float(&mut context, 0, location);
+                        },
+                    }
+                },
+            }
+        }
+        
+        for (position, block_id) in order.iter().enumerate() {
+            let Some(block) = function.block(*block_id) else {
+                continue;
+            };
+            let next = order.get(position + 1).copied();
+            
+            let _ = writeln!(context.out, "{}:", context.block_label(block.id));
+            
+            for instruction in block.instructions.iter().filter(|i| !i.is_phi()) {
+                self.emit_instruction(&mut context, instruction)?;
+            }
+            
+            let terminator = block.terminator.as_ref().unwrap_or(&Terminator::Unreachable);
+            self.emit_terminator(&mut context, block.id, terminator, next)?;
+        }
+        
+        let _ = writeln!(context.out, ".L{}_ret:", symbol);
+        if frame.saved_registers.is_empty() {
+            context.emit("movq %rbp, %rsp");
+        } else {
+            context.emit(format!("leaq -{}(%rbp), %rsp", frame.saved_registers.len() * 8));
+        }
+        for register in frame.saved_registers.iter().rev() {
+            context.emit(format!("popq {}", register.name64()));
+        }
+        context.emit("popq %rbp");
+        context.emit("ret");
+        
+        if context.divides {
+            let _ = writeln!(context.out, ".L{}_divzero:", symbol);
+            context.emit("call rv_rt_division_by_zero@PLT");
+        }
+        let _ = writeln!(context.out, "    .size {0}, .-{0}", symbol);
+        
+        Ok(context.out)
+    }
+    
+    fn emit_instruction(&mut self, context: &mut FunctionContext, instruction: &Instruction) -> Result<(), CodegenError> {
+        let result = instruction.result;
+        
+        match &instruction.kind {
+            InstructionKind::Binary(op, lhs, rhs) => self.emit_binary(context, result, *op, lhs, rhs)?,
+            InstructionKind::Unary(UnaryOp::Neg, value) if instruction.ty == Type::Float => {
+                self.load_float(context, value, 0)?;
+                context.emit("movq %xmm0, %rax");
+                context.emit("btcq $63, %rax");
+                context.emit("movq %rax, %xmm0");
+                context.store_result_float(result)?;
+            },
+            InstructionKind::Unary(op, value) => {
+                self.load_general(context, value, Gpr::Rax)?;
+                match op {
+                    UnaryOp::Neg => context.emit("negl %eax"),
+                    UnaryOp::Not if instruction.ty == Type::Int => context.emit("notl %eax"),
+                    UnaryOp::Not => context.emit("xorl $1, %eax"),
+                }
+                context.store_result_general(result, Gpr::Rax)?;
+            },
+            InstructionKind::IntToFloat(value) => {
+                self.load_general(context, value, Gpr::Rax)?;
+                context.emit("cvtsi2sdl %eax, %xmm0");
+                context.store_result_float(result)?;
+            },
+            InstructionKind::Call(name, args) if BUILTINS.contains(&name.as_str()) => {
+                self.emit_print(context, name == "println", args)?;
+            },
+            InstructionKind::Call(name, args) => self.emit_call(context, result, name, args)?,
+            InstructionKind::LoadGlobal(name) => {
+                let address = format!("{}(%rip)", global_symbol(name));
+                match RegClass::of(&instruction.ty)? {
+                    RegClass::General => {
+                        let width = Width::of(&instruction.ty);
+                        context.emit(format!("{} {}, {}", load_mnemonic(width), address, general_name(Gpr::Rax, width)));
+                        context.store_result_general(result, Gpr::Rax)?;
+                    },
+                    RegClass::Float => {
+                        context.emit(format!("movsd {}, %xmm0", address));
+                        context.store_result_float(result)?;
+                    },
+                }
+            },
+            InstructionKind::StoreGlobal(name, value) => {
+                let global = self.module.global(name)
+                    .ok_or_else(|| CodegenError::new(MessageId::CodegenUnknownGlobal.format(&[&name])))?;
+                let address = format!("{}(%rip)", global_symbol(name));
+                match RegClass::of(&global.ty)? {
+                    RegClass::General => {
+                        self.load_general(context, value, Gpr::Rax)?;
+                        let width = Width::of(&global.ty);
+                        context.emit(format!("{} {}, {}", store_mnemonic(width), store_name(Gpr::Rax, width), address));
+                    },
+                    RegClass::Float => {
+                        self.load_float(context, value, 0)?;
+                        context.emit(format!("movsd %xmm0, {}", address));
+                    },
+                }
+            },
+            InstructionKind::Length(value) => match context.operand_type(value) {
+                Type::String => {
+                    self.load_general(context, value, Gpr::Rdi)?;
+                    context.emit("call rv_rt_string_length@PLT");
+                    context.store_result_general(result, Gpr::Rax)?;
+                },
+                other => return Err(CodegenError::new(MessageId::CodegenNoLength.format(&[&"x86-64", &other]))),
+            },
+            InstructionKind::Index(collection, index) => match context.operand_type(collection) {
+                Type::String => {
+                    self.load_general(context, collection, Gpr::Rdi)?;
+                    self.load_general(context, index, Gpr::Rsi)?;
+                    context.emit("call rv_rt_string_index@PLT");
+                    context.store_result_general(result, Gpr::Rax)?;
+                },
+                other => return Err(CodegenError::new(MessageId::CodegenNotIndexable.format(&[&"x86-64", &other]))),
+            },
+            InstructionKind::Phi(_) => {},
+        }
+        
+        Ok(())
+    }
+    
+    fn emit_binary(
+        &mut self,
+        context: &mut FunctionContext,
+        result: Option<ValueId>,
+        op: BinaryOp,
+        lhs: &Operand,
+        rhs: &Operand,
+    ) -> Result<(), CodegenError> {
+        match context.operand_type(lhs) {
+            Type::Int => match op {
+                BinaryOp::Pow => {
+                    self.load_general(context, lhs, Gpr::Rdi)?;
+                    self.load_general(context, rhs, Gpr::Rsi)?;
+                    context.emit("call rv_rt_int_pow@PLT");
+                },
+                BinaryOp::Div | BinaryOp::Rem => {
+                    self.load_general(context, lhs, Gpr::Rax)?;
+                    self.load_general(context, rhs, Gpr::Rcx)?;
+                    let divide = context.new_label();
+                    let done = context.new_label();
+                    context.divides = true;
+                    context.emit("testl %ecx, %ecx");
+                    context.emit(format!("je .L{}_divzero", context.symbol));
+                    // INT32_MIN / -1 idivl ile taşar; sarmalı sonuç doğrudan üretilir.
+                    context.emit("cmpl $-1, %ecx");
+                    context.emit(format!("jne {}", divide));
+                    context.emit(if op == BinaryOp::Div { "negl %eax" } else { "xorl %eax, %eax" });
+                    context.emit(format!("jmp {}", done));
+                    let _ = writeln!(context.out, "{}:", divide);
+                    context.emit("cltd");
+                    context.emit("idivl %ecx");
+                    if op == BinaryOp::Rem {
+                        context.emit("movl %edx, %eax");
+                    }
+                    let _ = writeln!(context.out, "{}:", done);
+                },
+                _ => {
+                    self.load_general(context, lhs, Gpr::Rax)?;
+                    let source = match rhs {
+                        Operand::Const(Constant::Int(value)) if matches!(op, BinaryOp::Shl | BinaryOp::Shr) => {
+                            format!("${}", value & 31)
+                        },
+                        Operand::Const(Constant::Int(value)) => format!("${}", value),
+                        _ if matches!(op, BinaryOp::Shl | BinaryOp::Shr) => {
+                            self.load_general(context, rhs, Gpr::Rcx)?;
+                            "%cl".to_string()
+                        },
+                        _ => {
+                            self.load_general(context, rhs, Gpr::Rcx)?;
+                            "%ecx".to_string()
+                        },
+                    };
+                    match op {
+                        BinaryOp::Add => context.emit(format!("addl {}, %eax", source)),
+                        BinaryOp::Sub => context.emit(format!("subl {}, %eax", source)),
+                        BinaryOp::Mul => context.emit(format!("imull {}, %eax", source)),
+                        BinaryOp::And => context.emit(format!("andl {}, %eax", source)),
+                        BinaryOp::Or => context.emit(format!("orl {}, %eax", source)),
+                        BinaryOp::Xor => context.emit(format!("xorl {}, %eax", source)),
+                        BinaryOp::Shl => context.emit(format!("sall {}, %eax", source)),
+                        BinaryOp::Shr => context.emit(format!("sarl {}, %eax", source)),
+                        _ => {
+                            context.emit(format!("cmpl {}, %eax", source));
+                            set_condition(context, op);
+                        },
+                    }
+                },
+            },
+            Type::Bool if matches!(op, BinaryOp::Eq | BinaryOp::Ne) => {
+                self.load_general(context, lhs, Gpr::Rax)?;
+                self.load_general(context, rhs, Gpr::Rcx)?;
+                context.emit("cmpl %ecx, %eax");
+                set_condition(context, op);
+            },
+            Type::Float => {
+                self.load_float(context, lhs, 0)?;
+                self.load_float(context, rhs, 1)?;
+                match op {
+                    BinaryOp::Add => context.emit("addsd %xmm1, %xmm0"),
+                    BinaryOp::Sub => context.emit("subsd %xmm1, %xmm0"),
+                    BinaryOp::Mul => context.emit("mulsd %xmm1, %xmm0"),
+                    BinaryOp::Div => context.emit("divsd %xmm1, %xmm0"),
+                    BinaryOp::Rem => context.emit("call fmod@PLT"),
+                    BinaryOp::Pow => context.emit("call pow@PLT"),
+                    BinaryOp::Eq => {
+                        context.emit("ucomisd %xmm1, %xmm0");
+                        context.emit("sete %al");
+                        context.emit("setnp %cl");
+                        context.emit("andb %cl, %al");
+                    },
+                    BinaryOp::Ne => {
+                        context.emit("ucomisd %xmm1, %xmm0");
+                        context.emit("setne %al");
+                        context.emit("setp %cl");
+                        context.emit("orb %cl, %al");
+                    },
+                    BinaryOp::Gt | BinaryOp::Ge => {
+                        context.emit("ucomisd %xmm1, %xmm0");
+                        context.emit(if op == BinaryOp::Gt { "seta %al" } else { "setae %al" });
+                    },
+                    BinaryOp::Lt | BinaryOp::Le => {
+                        context.emit("ucomisd %xmm0, %xmm1");
+                        context.emit(if op == BinaryOp::Lt { "seta %al" } else { "setae %al" });
+                    },
+                    _ => return Err(CodegenError::new(MessageId::CodegenUnsupportedOperation.format(&[
+                        &"x86-64", &Type::Float, &op.mnemonic(),
+                    ]))),
+                }
+                
+                if op.is_comparison() {
+                    context.emit("movzbl %al, %eax");
+                    return context.store_result_general(result, Gpr::Rax);
+                }
+                return context.store_result_float(result);
+            },
+            Type::String => {
+                self.load_general(context, lhs, Gpr::Rdi)?;
+                self.load_general(context, rhs, Gpr::Rsi)?;
+                match op {
+                    BinaryOp::Add => context.emit("call rv_rt_string_concat@PLT"),
+                    _ if op.is_comparison() => {
+                        context.emit("call rv_rt_string_compare@PLT");
+                        context.emit("cmpl $0, %eax");
+                        set_condition(context, op);
+                    },
+                    _ => return Err(CodegenError::new(MessageId::CodegenUnsupportedOperation.format(&[&"x86-64", &Type::String, &op.mnemonic()]))),
+                }
+            },
+            other => return Err(CodegenError::new(MessageId::CodegenUnsupportedOperation.format(&[
+                &"x86-64", &other, &op.mnemonic(),
+            ]))),
+        }
+        
+        context.store_result_general(result, Gpr::Rax)
+    }
+    
+    fn emit_print(&mut self, context: &mut FunctionContext, newline: bool, args: &[Operand]) -> Result<(), CodegenError> {
+        for (i, arg) in args.iter().enumerate() {
+            if i > 0 {
+                context.emit("call rv_rt_print_space@PLT");
+            }
+            match context.operand_type(arg) {
+                Type::Float => {
+                    self.load_float(context, arg, 0)?;
+                    context.emit("call rv_rt_print_float@PLT");
+                },
+                ty @ (Type::Int | Type::Bool | Type::String) => {
+                    self.load_general(context, arg, Gpr::Rdi)?;
+                    let helper = match ty {
+                        Type::Int => "rv_rt_print_int",
+                        Type::Bool => "rv_rt_print_bool",
+                        _ => "rv_rt_print_string",
+                    };
+                    context.emit(format!("call {}@PLT", helper));
+                },
+                other => return Err(CodegenError::new(MessageId::CodegenUnprintableType.format(&[&other]))),
+            }
+        }
+        if newline {
+            context.emit("call rv_rt_print_newline@PLT");
+        }
+        
+        Ok(())
+    }
+    
+    fn emit_call(
+        &mut self,
+        context: &mut FunctionContext,
+        result: Option<ValueId>,
+        name: &str,
+        args: &[Operand],
+    ) -> Result<(), CodegenError> {
+        let callee = self.module.function(name)
+            .ok_or_else(|| CodegenError::new(MessageId::CodegenUnknownFunction.format(&[&name])))?;
+        let convention = CallingConvention::for_function(callee)?;
+        if convention.args.len() != args.len() {
+            return Err(CodegenError::new(MessageId::CodegenArgumentCount.format(&[
+                &name, &convention.args.len(), &args.len(),
+            ])));
+        }
+        
+        // Çağrı anında %rsp 16 bayta hizalı olmalı.
+        let padding = convention.stack_slots % 2;
+        if padding == 1 {
+            context.emit("subq $8, %rsp");
+        }
+        
+        for (arg, (location, ty)) in args.iter().zip(&convention.args).rev() {
+            if !matches!(location, ArgLocation::Stack(_)) {
+                continue;
+            }
+            match RegClass::of(ty)? {
+                RegClass::General => {
+                    self.load_general(context, arg, Gpr::Rax)?;
+                    context.emit("pushq %rax");
+                },
+                RegClass::Float => {
+                    self.load_float(context, arg, 0)?;
+                    context.emit("subq $8, %rsp");
+                    context.emit("movsd %xmm0, (%rsp)");
+                },
+            }
+        }
+        
+        for (arg, (location, _)) in args.iter().zip(&convention.args) {
+            match location {
+                ArgLocation::Gpr(register) => self.load_general(context, arg, *register)?,
+                ArgLocation::Xmm(register) => self.load_float(context, arg, *register)?,
+                ArgLocation::Stack(_) => {},
+            }
+        }
+        
+        context.emit(format!("call {}", function_symbol(name)));
+        
+        let stack_bytes = (convention.stack_slots + padding) * 8;
+        if stack_bytes > 0 {
+            context.emit(format!("addq ${}, %rsp", stack_bytes));
+        }
+        
+        match convention.return_class {
+            Some(RegClass::General) => context.store_result_general(result, Gpr::Rax),
+            Some(RegClass::Float) => context.store_result_float(result),
+            None => Ok(()),
+        }
+    }
+    
+    fn emit_terminator(
+        &mut self,
+        context: &mut FunctionContext,
+        block: BlockId,
+        terminator: &Terminator,
+        next: Option<BlockId>,
+    ) -> Result<(), CodegenError> {
+        match terminator {
+            Terminator::Return(value) => {
+                if let Some(value) = value {
+                    match RegClass::of(&context.function.return_type)? {
+                        RegClass::General => self.load_general(context, value, Gpr::Rax)?,
+                        RegClass::Float => self.load_float(context, value, 0)?,
+                    }
+                }
+                context.emit(format!("jmp .L{}_ret", context.symbol));
+            },
+            Terminator::Jump(target) => {
+                self.phi_copies(context, block, *target)?;
+                if next != Some(*target) {
+                    context.emit(format!("jmp {}", context.block_label(*target)));
+                }
+            },
+            Terminator::Branch(condition, then_block, else_block) => {
+                self.load_general(context, condition, Gpr::Rax)?;
+                context.emit("testl %eax, %eax");
+                
+                let needs_copies = context.has_phi_copies(block, *then_block) || context.has_phi_copies(block, *else_block);
+                if !needs_copies && next == Some(*then_block) {
+                    context.emit(format!("je {}", context.block_label(*else_block)));
+                } else if !needs_copies {
+                    context.emit(format!("jne {}", context.block_label(*then_block)));
+                    if next != Some(*else_block) {
+                        context.emit(format!("jmp {}", context.block_label(*else_block)));
+                    }
+                } else {
+                    let else_label = context.new_label();
+                    context.emit(format!("je {}", else_label));
+                    self.phi_copies(context, block, *then_block)?;
+                    context.emit(format!("jmp {}", context.block_label(*then_block)));
+                    let _ = writeln!(context.out, "{}:", else_label);
+                    self.phi_copies(context, block, *else_block)?;
+                    if next != Some(*else_block) {
+                        context.emit(format!("jmp {}", context.block_label(*else_block)));
+                    }
+                }
+            },
+            Terminator::Unreachable => context.emit("call rv_rt_unreachable@PLT"),
+        }
+        
+        Ok(())
+    }
+    
+    fn phi_copies(&mut self, context: &mut FunctionContext, from: BlockId, to: BlockId) -> Result<(), CodegenError> {
+        let copies = context.phi_copy_list(from, to)?;
+        
+        if let [(destination, ty, source)] = copies.as_slice() {
+            return self.copy(context, source, ty, *destination);
+        }
+        
+        // Birden fazla kopya paralel yapılır: önce tüm kaynaklar geçici alanlara alınır.
+        for (index, (_, ty, source)) in copies.iter().enumerate() {
+            let slot = context.scratch[index];
+            match RegClass::of(ty)? {
+                RegClass::General => {
+                    self.load_general(context, source, Gpr::Rax)?;
+                    context.emit(format!("movq %rax, {}(%rbp)", slot));
+                },
+                RegClass::Float => {
+                    self.load_float(context, source, 0)?;
+                    context.emit(format!("movsd %xmm0, {}(%rbp)", slot));
+                },
+            }
+        }
+        for (index, (destination, ty, _)) in copies.iter().enumerate() {
+            let slot = context.scratch[index];
+            match RegClass::of(ty)? {
+                RegClass::General => {
+                    context.emit(format!("movq {}(%rbp), %rax", slot));
+                    store_general(context, ty, Gpr::Rax, *destination);
+                },
+                RegClass::Float => {
+                    context.emit(format!("movsd {}(%rbp), %xmm0", slot));
+                    store_float(context, 0, *destination);
+                },
+            }
+        }
+        
+        Ok(())
+    }
+    
+    fn copy(&mut self, context: &mut FunctionContext, source: &Operand, ty: &Type, destination: Location) -> Result<(), CodegenError> {
+        match RegClass::of(ty)? {
+            RegClass::General => {
+                self.load_general(context, source, Gpr::Rax)?;
+                store_general(context, ty, Gpr::Rax, destination);
+            },
+            RegClass::Float => {
+                self.load_float(context, source, 0)?;
+                store_float(context, 0, destination);
+            },
+        }
+        Ok(())
+    }
+    
+    fn load_general(&mut self, context: &mut FunctionContext, operand: &Operand, register: Gpr) -> Result<(), CodegenError> {
+        match operand {
+            Operand::Const(Constant::Int(value)) => context.emit(format!("movl ${}, {}", value, register.name32())),
+            Operand::Const(Constant::Bool(value)) => context.emit(format!("movl ${}, {}", *value as i32, register.name32())),
+            Operand::Const(Constant::String(value)) => {
+                let label = self.string_label(value);
+                context.emit(format!("leaq {}(%rip), {}", label, register.name64()));
+            },
+            Operand::Const(Constant::Float(_)) => {
+                return Err(CodegenError::new(MessageId::CodegenFloatInGeneralRegister.text().to_string()));
+            },
+            Operand::Value(id) => {
+                let ty = context.value_type(*id)?;
+                let width = Width::of(&ty);
+                let source = match context.location(*id)? {
+                    Location::Gpr(source) if source == register => return Ok(()),
+                    Location::Gpr(source) => general_name(source, width).to_string(),
+                    Location::Stack(offset) => format!("{}(%rbp)", offset),
+                    Location::Xmm(_) => return Err(CodegenError::new(MessageId::CodegenWrongRegisterClass.format(&[&id.0]))),
+                };
+                let mnemonic = match (width, context.location(*id)?) {
+                    (Width::Byte, Location::Gpr(_)) => "movl",
+                    (width, _) => load_mnemonic(width),
+                };
+                context.emit(format!("{} {}, {}", mnemonic, source, general_name(register, width)));
+            },
+        }
+        Ok(())
+    }
+    
+    fn load_float(&mut self, context: &mut FunctionContext, operand: &Operand, register: u8) -> Result<(), CodegenError> {
+        match operand {
+            Operand::Const(Constant::Float(value)) => {
+                let label = self.float_label(*value);
+                context.emit(format!("movsd {}(%rip), %xmm{}", label, register));
+            },
+            Operand::Const(_) => return Err(CodegenError::new(MessageId::CodegenNonFloatInXmmRegister.text().to_string())),
+            Operand::Value(id) => match context.location(*id)? {
+                Location::Xmm(source) if source == register => {},
+                Location::Xmm(source) => context.emit(format!("movapd %xmm{}, %xmm{}", source, register)),
+                Location::Stack(offset) => context.emit(format!("movsd {}(%rbp), %xmm{}", offset, register)),
+                Location::Gpr(_) => return Err(CodegenError::new(MessageId::CodegenWrongRegisterClass.format(&[&id.0]))),
+            },
+        }
+        Ok(())
+    }
+}
+
+struct FunctionContext<'f> {
+    function: &'f Function,
+    types: &'f HashMap<ValueId, Type>,
+    locations: HashMap<ValueId, Location>,
+    scratch: Vec<i32>,
+    symbol: String,
+    labels: usize,
+    divides: bool,
+    out: String,
+}
+
+impl FunctionContext<'_> {
+    fn emit(&mut self, line: impl AsRef<str>) {
+        let _ = writeln!(self.out, "    {}", line.as_ref());
+    }
+    
+    fn new_label(&mut self) -> String {
+        self.labels += 1;
+        format!(".L{}_{}", self.symbol, self.labels)
+    }
+    
+    fn block_label(&self, block: BlockId) -> String {
+        format!(".L{}_bb{}", self.symbol, block.0)
+    }
+    
+    fn operand_type(&self, operand: &Operand) -> Type {
+        self.function.operand_type(operand, self.types)
+    }
+    
+    fn value_type(&self, value: ValueId) -> Result<Type, CodegenError> {
+        self.types.get(&value).cloned()
+            .ok_or_else(|| CodegenError::new(MessageId::CodegenUnknownValueType.format(&[&value.0])))
+    }
+    
+    fn location(&self, value: ValueId) -> Result<Location, CodegenError> {
+        self.locations.get(&value).copied()
+            .ok_or_else(|| CodegenError::new(MessageId::CodegenNoLocation.format(&[&value.0])))
+    }
+    
+    fn store_result_general(&mut self, result: Option<ValueId>, register: Gpr) -> Result<(), CodegenError> {
+        if let Some(result) = result {
+            let ty = self.value_type(result)?;
+            let location = self.location(result)?;
+            store_general(self, &ty, register, location);
+        }
+        Ok(())
+    }
+    
+    fn store_result_float(&mut self, result: Option<ValueId>) -> Result<(), CodegenError> {
+        if let Some(result) = result {
+            let location = self.location(result)?;
+            store_float(self, 0, location);
+        }
+        Ok(())
+    }
+    
+    fn phi_copy_list(&self, from: BlockId, to: BlockId) -> Result<Vec<(Location, Type, Operand)>, CodegenError> {
+        let Some(target) = self.function.block(to) else {
+            return Ok(Vec::new());
+        };
+        
+        let mut copies = Vec::new();
+        for phi in target.phis() {
+            let (Some(result), InstructionKind::Phi(incoming)) = (phi.result, &phi.kind) else {
+                continue;
+            };
+            let Some((_, value)) = incoming.iter().find(|(pred, _)| *pred == from) else {
+                continue;
+            };
+            let destination = self.location(result)?;
+            if let Operand::Value(source) = value
+                && self.locations.get(source) == Some(&destination) {
+                continue;
+            }
+            copies.push((destination, phi.ty.clone(), value.clone()));
+        }
+        
+        Ok(copies)
+    }
+    
+    fn has_phi_copies(&self, from: BlockId, to: BlockId) -> bool {
+        self.phi_copy_list(from, to).map_or(true, |copies| !copies.is_empty())
+    }
+}
+
+fn set_condition(context: &mut FunctionContext, op: BinaryOp) {
+    let condition = match op {
+        BinaryOp::Eq => "e",
+        BinaryOp::Ne => "ne",
+        BinaryOp::Lt => "l",
+        BinaryOp::Gt => "g",
+        BinaryOp::Le => "le",
+        _ => "ge",
+    };
+    context.emit(format!("set{} %al", condition));
+    context.emit("movzbl %al, %eax");
+}
+
+fn store_general(context: &mut FunctionContext, ty: &Type, register: Gpr, location: Location) {
+    let width = Width::of(ty);
+    match location {
+        Location::Gpr(destination) if destination == register => {},
+        Location::Gpr(destination) => {
+            let mnemonic = if width == Width::Quad { "movq" } else { "movl" };
+            let (source, destination) = match width {
+                Width::Quad => (register.name64(), destination.name64()),
+                _ => (register.name32(), destination.name32()),
+            };
+            context.emit(format!("{} {}, {}", mnemonic, source, destination));
+        },
+        Location::Stack(offset) => {
+            context.emit(format!("{} {}, {}(%rbp)", store_mnemonic(width), store_name(register, width), offset));
+        },
+        Location::Xmm(_) => {},
+    }
+}
+
+fn store_float(context: &mut FunctionContext, register: u8, location: Location) {
+    match location {
+        Location::Xmm(destination) if destination == register => {},
+        Location::Xmm(destination) => context.emit(format!("movapd %xmm{}, %xmm{}", register, destination)),
+        Location::Stack(offset) => context.emit(format!("movsd %xmm{}, {}(%rbp)", register, offset)),
+        Location::Gpr(_) => {},
+    }
+}
+
+fn load_mnemonic(width: Width) -> &'static str {
+    match width {
+        Width::Byte => "movzbl",
+        Width::Long => "movl",
+        Width::Quad => "movq",
+    }
+}
+
+fn store_mnemonic(width: Width) -> &'static str {
+    match width {
+        Width::Byte => "movb",
+        Width::Long => "movl",
+        Width::Quad => "movq",
+    }
+}
+
+fn general_name(register: Gpr, width: Width) -> &'static str {
+    match width {
+        Width::Quad => register.name64(),
+        _ => register.name32(),
+    }
+}
+
+fn store_name(register: Gpr, width: Width) -> &'static str {
+    match width {
+        Width::Byte => register.name8(),
+        Width::Long => register.name32(),
+        Width::Quad => register.name64(),
+    }
+}
+
+fn string_literal(s: &str) -> String {
+    let mut literal = String::from("\"");
+    
+    for byte in s.bytes() {
+        match byte {
+            b'"' => literal.push_str("\\\""),
+            b'\\' => literal.push_str("\\\\"),
+            0x20..=0x7e => literal.push(byte as char),
+            _ => {
+                let _ = write!(literal, "\\{:03o}", byte);
+            },
+        }
+    }
+    
+    literal.push('"');
+    literal
+}
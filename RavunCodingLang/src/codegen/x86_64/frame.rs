@@ -0,0 +1,37 @@
+use crate::codegen::x86_64::registers::Gpr;
+use crate::semantics::types::Type;
+
+#[derive(Debug, Clone)]
+pub struct FrameLayout {
+    pub saved_registers: Vec<Gpr>,
+    used: usize,
+}
+
+impl FrameLayout {
+    pub fn new(saved_registers: Vec<Gpr>) -> Self {
+        let used = saved_registers.len() * 8;
+        FrameLayout { saved_registers, used }
+    }
+    
+    // String değerleri yığında { data, len } kaydına işaretçi olarak tutulur.
+    pub fn slot_size(ty: &Type) -> usize {
+        match ty {
+            Type::String => 8,
+            other => other.size_in_bytes().max(1),
+        }
+    }
+    
+    pub fn allocate(&mut self, ty: &Type) -> i32 {
+        let size = FrameLayout::slot_size(ty);
+        self.used = (self.used + size).next_multiple_of(size);
+        -(self.used as i32)
+    }
+    
+    pub fn allocate_scratch(&mut self, count: usize) -> Vec<i32> {
+        (0..count).map(|_| self.allocate(&Type::Float)).collect()
+    }
+    
+    pub fn stack_adjustment(&self) -> usize {
+        self.used.next_multiple_of(16) - self.saved_registers.len() * 8
+    }
+}
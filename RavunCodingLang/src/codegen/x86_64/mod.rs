@@ -0,0 +1,11 @@
+pub mod registers;
+pub mod abi;
+pub mod frame;
+pub mod regalloc;
+pub mod emitter;
+pub mod toolchain;
+
+pub use abi::CallingConvention;
+pub use emitter::{emit_asm, AsmEmitter};
+pub use regalloc::{allocate_registers, Allocation, Location};
+pub use toolchain::assemble_and_link;
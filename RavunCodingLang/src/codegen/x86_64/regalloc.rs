@@ -0,0 +1,282 @@
+use std::collections::{HashMap, HashSet};
+use crate::codegen::error::CodegenError;
+use crate::codegen::x86_64::registers::{Gpr, RegClass, CALLEE_SAVED_POOL, CALLER_SAVED_POOL, XMM_POOL};
+use crate::diagnostics::MessageId;
+use crate::ir::function::{BasicBlock, Function};
+use crate::ir::instruction::{BinaryOp, BlockId, Instruction, InstructionKind, ValueId};
+use crate::semantics::types::Type;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Location {
+    Gpr(Gpr),
+    Xmm(u8),
+    Stack(i32),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Interval {
+    pub value: ValueId,
+    pub class: RegClass,
+    pub start: usize,
+    pub end: usize,
+    pub crosses_call: bool,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Allocation {
+    pub registers: HashMap<ValueId, Location>,
+    pub spilled: Vec<ValueId>,
+    pub callee_saved: Vec<Gpr>,
+}
+
+pub fn calls_runtime(instruction: &Instruction, function: &Function, types: &HashMap<ValueId, Type>) -> bool {
+    match &instruction.kind {
+        InstructionKind::Call(_, _) => true,
+        InstructionKind::Binary(op, lhs, _) => match function.operand_type(lhs, types) {
+            Type::Int => *op == BinaryOp::Pow,
+            Type::Float => matches!(op, BinaryOp::Rem | BinaryOp::Pow),
+            Type::String => true,
+            _ => false,
+        },
+        InstructionKind::Length(_) | InstructionKind::Index(_, _) => true,
+        _ => false,
+    }
+}
+
+pub fn allocate_registers(function: &Function, order: &[BlockId]) -> Result<Allocation, CodegenError> {
+    let intervals = build_intervals(function, order)?;
+    Ok(linear_scan(intervals))
+}
+
+pub fn build_intervals(function: &Function, order: &[BlockId]) -> Result<Vec<Interval>, CodegenError> {
+    let types = function.value_types();
+    let blocks: Vec<_> = order.iter().filter_map(|id| function.block(*id)).collect();
+    
+    let mut block_start = HashMap::new();
+    let mut block_end = HashMap::new();
+    let mut call_positions = Vec::new();
+    let mut positions: HashMap<ValueId, (usize, usize)> = HashMap::new();
+    let mut touch = |value: ValueId, position: usize| {
+        let range = positions.entry(value).or_insert((position, position));
+        range.0 = range.0.min(position);
+        range.1 = range.1.max(position);
+    };
+    
+    // Kullanılmayan parametre de giriş taşımaları bitene kadar canlı kalmalı; yoksa registerı başka parametreye verilir.
+    for param in &function.params {
+        touch(param.id, 0);
+        touch(param.id, 1);
+    }
+    
+    let mut position = 0;
+    for block in &blocks {
+        block_start.insert(block.id, position);
+        position += 1;
+        
+        for instruction in &block.instructions {
+            if !instruction.is_phi() && calls_runtime(instruction, function, &types) {
+                call_positions.push(position);
+            }
+            position += 1;
+        }
+        
+        block_end.insert(block.id, position);
+        position += 1;
+    }
+    
+    let live_in = liveness(function, &blocks);
+    
+    for block in &blocks {
+        let start = block_start[&block.id];
+        let end = block_end[&block.id];
+        
+        for value in &live_in[&block.id] {
+            touch(*value, start);
+        }
+        
+        for (offset, instruction) in block.instructions.iter().enumerate() {
+            let position = start + 1 + offset;
+            
+            if let InstructionKind::Phi(incoming) = &instruction.kind {
+                for (pred, operand) in incoming {
+                    let Some(&pred_end) = block_end.get(pred) else {
+                        continue;
+                    };
+                    if let Some(value) = operand.as_value() {
+                        touch(value, pred_end);
+                    }
+                    // Phi değeri öncülün sonunda kopyalanır; aralık o noktayı da kapsamalı.
+                    if let Some(result) = instruction.result {
+                        touch(result, pred_end);
+                    }
+                }
+                if let Some(result) = instruction.result {
+                    touch(result, start);
+                }
+                continue;
+            }
+            
+            for operand in instruction.operands() {
+                if let Some(value) = operand.as_value() {
+                    touch(value, position);
+                }
+            }
+            if let Some(result) = instruction.result {
+                touch(result, position);
+            }
+        }
+        
+        if let Some(terminator) = &block.terminator {
+            for operand in terminator.operands() {
+                if let Some(value) = operand.as_value() {
+                    touch(value, end);
+                }
+            }
+        }
+        
+        for successor in block.successors() {
+            for value in live_in.get(&successor).into_iter().flatten() {
+                touch(*value, end);
+            }
+        }
+    }
+    
+    let mut intervals = Vec::with_capacity(positions.len());
+    for (value, (start, end)) in positions {
+        let ty = types.get(&value).ok_or_else(|| CodegenError::in_function(
+            &function.name,
+            MessageId::CodegenUnknownValueType.format(&[&value.0]),
+        ))?;
+        let class = RegClass::of(ty).map_err(|err| CodegenError::in_function(&function.name, err.message))?;
+        let crosses_call = call_positions.iter().any(|p| start < *p && *p < end);
+        
+        intervals.push(Interval { value, class, start, end, crosses_call });
+    }
+    
+    intervals.sort_by_key(|i| (i.start, i.end, i.value));
+    Ok(intervals)
+}
+
+fn liveness(function: &Function, blocks: &[&BasicBlock]) -> HashMap<BlockId, HashSet<ValueId>> {
+    let mut uses: HashMap<BlockId, HashSet<ValueId>> = HashMap::new();
+    let mut defs: HashMap<BlockId, HashSet<ValueId>> = HashMap::new();
+    let mut phi_uses: HashMap<(BlockId, BlockId), Vec<ValueId>> = HashMap::new();
+    
+    for block in blocks {
+        let block_uses = uses.entry(block.id).or_default();
+        let block_defs = defs.entry(block.id).or_default();
+        
+        for instruction in &block.instructions {
+            if let InstructionKind::Phi(incoming) = &instruction.kind {
+                for (pred, operand) in incoming {
+                    if let Some(value) = operand.as_value() {
+                        phi_uses.entry((*pred, block.id)).or_default().push(value);
+                    }
+                }
+            } else {
+                for value in instruction.operands().iter().filter_map(|o| o.as_value()) {
+                    if !block_defs.contains(&value) {
+                        block_uses.insert(value);
+                    }
+                }
+            }
+            if let Some(result) = instruction.result {
+                block_defs.insert(result);
+            }
+        }
+        
+        if let Some(terminator) = &block.terminator {
+            for value in terminator.operands().iter().filter_map(|o| o.as_value()) {
+                if !block_defs.contains(&value) {
+                    block_uses.insert(value);
+                }
+            }
+        }
+    }
+    
+    if let Some(entry) = function.entry() {
+        for param in &function.params {
+            defs.entry(entry).or_default().insert(param.id);
+        }
+    }
+    
+    let mut live_in: HashMap<BlockId, HashSet<ValueId>> = blocks.iter().map(|b| (b.id, HashSet::new())).collect();
+    let mut changed = true;
+    
+    while changed {
+        changed = false;
+        
+        for block in blocks.iter().rev() {
+            let mut live: HashSet<ValueId> = HashSet::new();
+            for successor in block.successors() {
+                live.extend(live_in.get(&successor).into_iter().flatten().copied());
+                live.extend(phi_uses.get(&(block.id, successor)).into_iter().flatten().copied());
+            }
+            
+            live.retain(|v| !defs[&block.id].contains(v));
+            live.extend(uses[&block.id].iter().copied());
+            
+            if live != live_in[&block.id] {
+                live_in.insert(block.id, live);
+                changed = true;
+            }
+        }
+    }
+    
+    live_in
+}
+
+pub fn linear_scan(intervals: Vec<Interval>) -> Allocation {
+    let mut allocation = Allocation::default();
+    let mut active: Vec<(Interval, Location)> = Vec::new();
+    
+    for interval in intervals {
+        active.retain(|(other, _)| other.end > interval.start);
+        
+        let candidates: Vec<Location> = match (interval.class, interval.crosses_call) {
+            (RegClass::General, false) => CALLER_SAVED_POOL.iter().chain(CALLEE_SAVED_POOL.iter())
+                .map(|r| Location::Gpr(*r))
+                .collect(),
+            (RegClass::General, true) => CALLEE_SAVED_POOL.iter().map(|r| Location::Gpr(*r)).collect(),
+            (RegClass::Float, false) => XMM_POOL.iter().map(|r| Location::Xmm(*r)).collect(),
+            (RegClass::Float, true) => Vec::new(),
+        };
+        
+        let free = candidates.iter().find(|c| !active.iter().any(|(_, l)| l == *c)).copied();
+        
+        let location = match free {
+            Some(location) => Some(location),
+            None => {
+                let victim = active.iter()
+                    .enumerate()
+                    .filter(|(_, (_, l))| candidates.contains(l))
+                    .max_by_key(|(_, (other, _))| other.end)
+                    .filter(|(_, (other, _))| other.end > interval.end)
+                    .map(|(index, _)| index);
+                
+                victim.map(|index| {
+                    let (spilled, location) = active.remove(index);
+                    allocation.registers.remove(&spilled.value);
+                    allocation.spilled.push(spilled.value);
+                    location
+                })
+            },
+        };
+        
+        match location {
+            Some(location) => {
+                if let Location::Gpr(register) = location
+                    && register.is_callee_saved()
+                    && !allocation.callee_saved.contains(&register) {
+                    allocation.callee_saved.push(register);
+                }
+                allocation.registers.insert(interval.value, location);
+                active.push((interval, location));
+            },
+            None => allocation.spilled.push(interval.value),
+        }
+    }
+    
+    allocation.callee_saved.sort_by_key(|r| CALLEE_SAVED_POOL.iter().position(|p| p == r));
+    allocation
+}
@@ -0,0 +1,130 @@
+use std::fmt;
+use crate::codegen::error::CodegenError;
+use crate::diagnostics::MessageId;
+use crate::semantics::types::Type;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Gpr {
+    Rax,
+    Rbx,
+    Rcx,
+    Rdx,
+    Rsi,
+    Rdi,
+    R8,
+    R9,
+    R10,
+    R11,
+    R12,
+    R13,
+    R14,
+    R15,
+}
+
+impl Gpr {
+    pub fn name64(&self) -> &'static str {
+        match self {
+            Gpr::Rax => "%rax",
+            Gpr::Rbx => "%rbx",
+            Gpr::Rcx => "%rcx",
+            Gpr::Rdx => "%rdx",
+            Gpr::Rsi => "%rsi",
+            Gpr::Rdi => "%rdi",
+            Gpr::R8 => "%r8",
+            Gpr::R9 => "%r9",
+            Gpr::R10 => "%r10",
+            Gpr::R11 => "%r11",
+            Gpr::R12 => "%r12",
+            Gpr::R13 => "%r13",
+            Gpr::R14 => "%r14",
+            Gpr::R15 => "%r15",
+        }
+    }
+    
+    pub fn name32(&self) -> &'static str {
+        match self {
+            Gpr::Rax => "%eax",
+            Gpr::Rbx => "%ebx",
+            Gpr::Rcx => "%ecx",
+            Gpr::Rdx => "%edx",
+            Gpr::Rsi => "%esi",
+            Gpr::Rdi => "%edi",
+            Gpr::R8 => "%r8d",
+            Gpr::R9 => "%r9d",
+            Gpr::R10 => "%r10d",
+            Gpr::R11 => "%r11d",
+            Gpr::R12 => "%r12d",
+            Gpr::R13 => "%r13d",
+            Gpr::R14 => "%r14d",
+            Gpr::R15 => "%r15d",
+        }
+    }
+    
+    pub fn name8(&self) -> &'static str {
+        match self {
+            Gpr::Rax => "%al",
+            Gpr::Rbx => "%bl",
+            Gpr::Rcx => "%cl",
+            Gpr::Rdx => "%dl",
+            Gpr::Rsi => "%sil",
+            Gpr::Rdi => "%dil",
+            Gpr::R8 => "%r8b",
+            Gpr::R9 => "%r9b",
+            Gpr::R10 => "%r10b",
+            Gpr::R11 => "%r11b",
+            Gpr::R12 => "%r12b",
+            Gpr::R13 => "%r13b",
+            Gpr::R14 => "%r14b",
+            Gpr::R15 => "%r15b",
+        }
+    }
+    
+    pub fn is_callee_saved(&self) -> bool {
+        matches!(self, Gpr::Rbx | Gpr::R12 | Gpr::R13 | Gpr::R14 | Gpr::R15)
+    }
+}
+
+impl fmt::Display for Gpr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name64())
+    }
+}
+
+// rax, rcx, rdx ve xmm0-xmm1 komut seçimi için geçici register olarak ayrılır;
+// argüman registerları çağrı hazırlığında ezildiği için havuzda yer almaz.
+pub const CALLEE_SAVED_POOL: [Gpr; 5] = [Gpr::Rbx, Gpr::R12, Gpr::R13, Gpr::R14, Gpr::R15];
+pub const CALLER_SAVED_POOL: [Gpr; 2] = [Gpr::R10, Gpr::R11];
+pub const XMM_POOL: [u8; 8] = [8, 9, 10, 11, 12, 13, 14, 15];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RegClass {
+    General,
+    Float,
+}
+
+impl RegClass {
+    pub fn of(ty: &Type) -> Result<RegClass, CodegenError> {
+        match ty {
+            Type::Int | Type::Bool | Type::String => Ok(RegClass::General),
+            Type::Float => Ok(RegClass::Float),
+            other => Err(CodegenError::new(MessageId::CodegenUnsupportedType.format(&[&"x86-64", &other]))),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Width {
+    Byte,
+    Long,
+    Quad,
+}
+
+impl Width {
+    pub fn of(ty: &Type) -> Width {
+        match ty {
+            Type::Bool => Width::Byte,
+            Type::Int => Width::Long,
+            _ => Width::Quad,
+        }
+    }
+}
@@ -0,0 +1,65 @@
+
+/* x86-64 arka ucu için dışa açık çalışma zamanı fonksiyonları.
+   String değerleri { data, len } kaydına işaretçi olarak taşınır. */
+
+static const rv_string *rv_rt_box(rv_string value) {
+    rv_string *box = malloc(sizeof *box);
+
+    if (box == NULL) {
+        rv_panic("Bellek yetersiz");
+    }
+    *box = value;
+    return box;
+}
+
+void rv_rt_division_by_zero(void) {
+    rv_panic("Sıfıra bölme");
+}
+
+void rv_rt_unreachable(void) {
+    rv_unreachable();
+}
+
+int32_t rv_rt_int_pow(int32_t base, int32_t exponent) {
+    return rv_int_pow(base, exponent);
+}
+
+const rv_string *rv_rt_string_concat(const rv_string *a, const rv_string *b) {
+    return rv_rt_box(rv_string_concat(*a, *b));
+}
+
+int32_t rv_rt_string_compare(const rv_string *a, const rv_string *b) {
+    return rv_string_compare(*a, *b);
+}
+
+int32_t rv_rt_string_length(const rv_string *s) {
+    return rv_string_length(*s);
+}
+
+const rv_string *rv_rt_string_index(const rv_string *s, int32_t index) {
+    return rv_rt_box(rv_string_index(*s, index));
+}
+
+void rv_rt_print_int(int32_t value) {
+    rv_print_int(value);
+}
+
+void rv_rt_print_float(double value) {
+    rv_print_float(value);
+}
+
+void rv_rt_print_bool(int32_t value) {
+    rv_print_bool(value != 0);
+}
+
+void rv_rt_print_string(const rv_string *value) {
+    rv_print_string(*value);
+}
+
+void rv_rt_print_space(void) {
+    putchar(' ');
+}
+
+void rv_rt_print_newline(void) {
+    putchar('\n');
+}
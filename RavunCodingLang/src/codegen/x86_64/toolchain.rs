@@ -0,0 +1,53 @@
+use std::fs;
+use std::io::Write;
+use std::path::Path;
+use std::process::{self, Command, Stdio};
+use crate::codegen::c::toolchain::c_compiler;
+use crate::codegen::error::CodegenError;
+use crate::diagnostics::MessageId;
+
+const RUNTIME_HEADER: &str = include_str!("../c/runtime.h");
+const RUNTIME_SOURCE: &str = include_str!("runtime.c");
+
+pub fn assemble_and_link(assembly: &str, output: &Path) -> Result<(), CodegenError> {
+    let compiler = c_compiler();
+    let assembly_path = std::env::temp_dir().join(format!("ravun-{}.s", process::id()));
+    
+    fs::write(&assembly_path, assembly)
+        .map_err(|err| CodegenError::new(MessageId::WriteFailed.format(&[&assembly_path.display(), &err])))?;
+    
+    let result = link(&compiler, &assembly_path, output);
+    let _ = fs::remove_file(&assembly_path);
+    result
+}
+
+fn link(compiler: &str, assembly_path: &Path, output: &Path) -> Result<(), CodegenError> {
+    let mut child = Command::new(compiler)
+        .args(["-O2", "-x", "assembler"])
+        .arg(assembly_path)
+        .args(["-x", "c", "-std=c99", "-", "-o"])
+        .arg(output)
+        .arg("-lm")
+        .stdin(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|err| CodegenError::new(MessageId::LinkerNotRun.format(&[&compiler, &err])))?;
+    
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin.write_all(RUNTIME_HEADER.as_bytes())
+            .and_then(|_| stdin.write_all(RUNTIME_SOURCE.as_bytes()))
+            .map_err(|err| CodegenError::new(MessageId::RuntimeSourceNotWritten.format(&[&err])))?;
+    }
+    
+    let result = child.wait_with_output()
+        .map_err(|err| CodegenError::new(MessageId::LinkerWaitFailed.format(&[&err])))?;
+    
+    if !result.status.success() {
+        return Err(CodegenError::new(MessageId::LinkFailed.format(&[
+            &result.status,
+            &String::from_utf8_lossy(&result.stderr).trim_end(),
+        ])));
+    }
+    
+    Ok(())
+}
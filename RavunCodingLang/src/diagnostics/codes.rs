@@ -0,0 +1,755 @@
+use std::fmt;
+use crate::diagnostics::messages::{language, Lang, MessageId};
+
+macro_rules! codes {
+    ($($id:ident = $number:literal {
+        title: $title_tr:literal, $title_en:literal;
+        explanation: $explanation_tr:literal, $explanation_en:literal;
+        failing: $failing:literal;
+        fixed: $fixed:literal;
+    })*) => {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        pub enum ErrorCode {
+            $($id,)*
+        }
+        
+        impl ErrorCode {
+            pub const ALL: &'static [ErrorCode] = &[$(ErrorCode::$id,)*];
+            
+            pub fn number(self) -> u16 {
+                match self {
+                    $(ErrorCode::$id => $number,)*
+                }
+            }
+            
+            pub fn title_in(self, lang: Lang) -> &'static str {
+                match (self, lang) {
+                    $(
+                        (ErrorCode::$id, Lang::Tr) => $title_tr,
+                        (ErrorCode::$id, Lang::En) => $title_en,
+                    )*
+                }
+            }
+            
+            pub fn explanation_in(self, lang: Lang) -> &'static str {
+                match (self, lang) {
+                    $(
+                        (ErrorCode::$id, Lang::Tr) => $explanation_tr,
+                        (ErrorCode::$id, Lang::En) => $explanation_en,
+                    )*
+                }
+            }
+            
+            pub fn failing_example(self) -> &'static str {
+                match self {
+                    $(ErrorCode::$id => $failing,)*
+                }
+            }
+            
+            pub fn fixed_example(self) -> &'static str {
+                match self {
+                    $(ErrorCode::$id => $fixed,)*
+                }
+            }
+        }
+    };
+}
+
+codes! {
+    UnterminatedString = 1 {
+        title: "Kapanmamış dizge", "Unterminated string";
+        explanation: "\
+Bir dizge sabiti '\"' ile başladı fakat dosya sonuna kadar kapanış tırnağı bulunamadı.
+Dizgeler tek bir '\"' ile kapatılmalıdır; dizge içinde tırnak kullanmak için '\\\"' yazın.", "\
+A string literal was opened with '\"' but no closing quote was found before the end of the file.
+Strings must be closed with a single '\"'; write '\\\"' to put a quote inside a string.";
+        failing: "\
+fn main() -> int {
+    println(\"merhaba);
+    return 0;
+}";
+        fixed: "\
+fn main() -> int {
+    println(\"merhaba\");
+    return 0;
+}";
+    }
+    
+    InvalidNumber = 2 {
+        title: "Geçersiz sayı", "Invalid number";
+        explanation: "\
+Ondalık bir sayıda noktadan sonra en az bir rakam bulunmalıdır.
+'3.' gibi yarım kalmış sayılar yerine '3.0' yazın.", "\
+A decimal number must have at least one digit after the decimal point.
+Write '3.0' instead of an unfinished number such as '3.'.";
+        failing: "\
+fn main() -> int {
+    let oran = 3.;
+    return 0;
+}";
+        fixed: "\
+fn main() -> int {
+    let oran = 3.0;
+    return 0;
+}";
+    }
+    
+    InvalidCharacter = 3 {
+        title: "Geçersiz karakter", "Invalid character";
+        explanation: "\
+Kaynak kodda Ravun'un tanımadığı bir karakter var.
+Karakter yalnızca dizge sabitleri ve yorumlar içinde kullanılabilir.", "\
+The source contains a character that Ravun does not recognize.
+Such characters may only appear inside string literals and comments.";
+        failing: "\
+fn main() -> int {
+    let toplam = 1 $ 2;
+    return toplam;
+}";
+        fixed: "\
+fn main() -> int {
+    let toplam = 1 + 2;
+    return toplam;
+}";
+    }
+    
+    ExpectedToken = 100 {
+        title: "Beklenen token bulunamadı", "Expected token not found";
+        explanation: "\
+Ayrıştırıcı belirli bir token beklerken başka bir token ya da dosya sonu buldu.
+En sık nedenler eksik ';', kapatılmamış parantez veya süslü parantezdir.", "\
+The parser expected a particular token but found a different one or the end of the file.
+The most common causes are a missing ';' or an unclosed parenthesis or brace.";
+        failing: "\
+fn main() -> int {
+    let x = 1
+    return x;
+}";
+        fixed: "\
+fn main() -> int {
+    let x = 1;
+    return x;
+}";
+    }
+    
+    UnexpectedEof = 101 {
+        title: "Beklenmeyen dosya sonu", "Unexpected end of file";
+        explanation: "\
+Dosya, bir bildirim ya da ifade tamamlanmadan bitti.
+Son satırdaki ifadenin eksiksiz olduğundan emin olun.", "\
+The file ended before a declaration or expression was complete.
+Make sure the last expression in the file is finished.";
+        failing: "\
+fn main() -> int {
+    return 1 +";
+        fixed: "\
+fn main() -> int {
+    return 1 + 2;
+}";
+    }
+    
+    ExpectedExpression = 102 {
+        title: "İfade bekleniyor", "Expected an expression";
+        explanation: "\
+Bir ifadenin gelmesi gereken yerde ifade başlatamayan bir token bulundu.
+Örneğin bir atamanın sağ tarafı boş bırakılamaz.", "\
+A token that cannot start an expression was found where an expression was required.
+For example, the right-hand side of an assignment cannot be left empty.";
+        failing: "\
+fn main() -> int {
+    let x = ;
+    return x;
+}";
+        fixed: "\
+fn main() -> int {
+    let x = 0;
+    return x;
+}";
+    }
+    
+    MisplacedAttribute = 103 {
+        title: "Yanlış yerde nitelik", "Misplaced attribute";
+        explanation: "\
+'#[...]' nitelikleri yalnızca fonksiyon bildirimlerinden önce yazılabilir.", "\
+'#[...]' attributes can only be written before function declarations.";
+        failing: "\
+#[inline]
+let x = 1;
+
+fn main() -> int {
+    return x;
+}";
+        fixed: "\
+let x = 1;
+
+#[inline]
+fn main() -> int {
+    return x;
+}";
+    }
+    
+    InvalidAssignmentTarget = 104 {
+        title: "Geçersiz atama hedefi", "Invalid assignment target";
+        explanation: "\
+Atama operatörünün sol tarafında bir değişken, alan ya da dizin ifadesi bulunmalıdır.
+Sabitlere ya da hesaplanmış değerlere atama yapılamaz.", "\
+The left-hand side of an assignment must be a variable, a field or an index expression.
+Literals and computed values cannot be assigned to.";
+        failing: "\
+fn main() -> int {
+    let mut x = 1;
+    x + 1 = 2;
+    return x;
+}";
+        fixed: "\
+fn main() -> int {
+    let mut x = 1;
+    x = 2;
+    return x;
+}";
+    }
+    
+    UndefinedVariable = 200 {
+        title: "Tanımsız değişken", "Undefined variable";
+        explanation: "\
+Kullanılan ad, bulunduğu kapsamda ya da dış kapsamlarda tanımlanmamış.
+Adın doğru yazıldığından ve kullanılmadan önce 'let' ile tanımlandığından emin olun.", "\
+The name is not defined in the current scope or any enclosing scope.
+Check the spelling and make sure it is declared with 'let' before it is used.";
+        failing: "\
+fn main() -> int {
+    return y;
+}";
+        fixed: "\
+fn main() -> int {
+    let y = 0;
+    return y;
+}";
+    }
+    
+    UndefinedFunction = 201 {
+        title: "Tanımsız fonksiyon", "Undefined function";
+        explanation: "\
+Çağrılan fonksiyon hiçbir yerde tanımlanmamış.
+Fonksiyonu 'fn' ile tanımlayın ya da adını düzeltin.", "\
+The called function is not defined anywhere.
+Define it with 'fn' or correct its name.";
+        failing: "\
+fn main() -> int {
+    return topla(1, 2);
+}";
+        fixed: "\
+fn topla(a: int, b: int) -> int {
+    return a + b;
+}
+
+fn main() -> int {
+    return topla(1, 2);
+}";
+    }
+    
+    UndefinedType = 202 {
+        title: "Tanımsız tip", "Undefined type";
+        explanation: "\
+Kullanılan tip adı ne yerleşik bir tip ne de tanımlanmış bir struct.
+Yerleşik tipler int, float, string, bool ve void'dir.", "\
+The type name is neither a built-in type nor a declared struct.
+The built-in types are int, float, string, bool and void.";
+        failing: "\
+impl Nokta {
+    fn sifir() -> int {
+        return 0;
+    }
+}
+
+fn main() -> int {
+    return 0;
+}";
+        fixed: "\
+struct Nokta {
+    x: int,
+}
+
+impl Nokta {
+    fn sifir() -> int {
+        return 0;
+    }
+}
+
+fn main() -> int {
+    return 0;
+}";
+    }
+    
+    Redefinition = 203 {
+        title: "Yeniden tanımlama", "Redefinition";
+        explanation: "\
+Aynı kapsamda aynı ada sahip iki tanım var.
+İkinci tanıma farklı bir ad verin ya da değeri değiştirmek için 'let mut' ve atama kullanın.", "\
+Two definitions with the same name exist in the same scope.
+Give the second one a different name, or use 'let mut' and an assignment to change the value.";
+        failing: "\
+fn main() -> int {
+    let x = 1;
+    let x = 2;
+    return x;
+}";
+        fixed: "\
+fn main() -> int {
+    let mut x = 1;
+    x = 2;
+    return x;
+}";
+    }
+    
+    ImmutableAssignment = 204 {
+        title: "Değiştirilemez değişkene atama", "Assignment to an immutable variable";
+        explanation: "\
+Değişkenler varsayılan olarak değiştirilemez.
+Sonradan değer atanacak değişkenleri 'let mut' ile tanımlayın.", "\
+Variables are immutable by default.
+Declare variables that are assigned later with 'let mut'.";
+        failing: "\
+fn main() -> int {
+    let x = 1;
+    x = 2;
+    return x;
+}";
+        fixed: "\
+fn main() -> int {
+    let mut x = 1;
+    x = 2;
+    return x;
+}";
+    }
+    
+    NotAFunction = 205 {
+        title: "Fonksiyon olmayan değer çağrıldı", "Called a value that is not a function";
+        explanation: "\
+Çağrı ifadesinde kullanılan ad bir fonksiyona değil, değişken ya da tip gibi başka bir sembole ait.", "\
+The name used in the call expression refers to a variable, a type or another kind of symbol rather than a function.";
+        failing: "\
+fn main() -> int {
+    let sayi = 1;
+    return sayi(2);
+}";
+        fixed: "\
+fn sayi(x: int) -> int {
+    return x;
+}
+
+fn main() -> int {
+    return sayi(2);
+}";
+    }
+    
+    NotAType = 206 {
+        title: "Tip olmayan ad", "Name is not a type";
+        explanation: "\
+Tip beklenen bir yerde tip olmayan bir sembol kullanıldı.", "\
+A symbol that is not a type was used where a type was expected.";
+        failing: "\
+fn main() -> int {
+    let Sinir = 1;
+    let x: Sinir = 2;
+    return x;
+}";
+        fixed: "\
+fn main() -> int {
+    let Sinir = 1;
+    let x: int = Sinir;
+    return x;
+}";
+    }
+    
+    TypeMismatch = 300 {
+        title: "Tip uyuşmazlığı", "Type mismatch";
+        explanation: "\
+Bir değerin tipi, kullanıldığı yerde beklenen tiple uyuşmuyor.
+Ravun tipler arasında örtük dönüşüm yapmaz; yalnızca int değerler float yerine kullanılabilir.", "\
+The type of a value does not match the type expected where it is used.
+Ravun does not convert between types implicitly; only int values may be used where a float is expected.";
+        failing: "\
+fn main() -> int {
+    let x: int = \"bir\";
+    return x;
+}";
+        fixed: "\
+fn main() -> int {
+    let x: int = 1;
+    return x;
+}";
+    }
+    
+    InvalidReturn = 301 {
+        title: "Geçersiz dönüş değeri", "Invalid return value";
+        explanation: "\
+'return' ile döndürülen değerin tipi fonksiyonun bildirilen dönüş tipiyle uyuşmuyor.", "\
+The type of the value given to 'return' does not match the declared return type of the function.";
+        failing: "\
+fn main() -> int {
+    return \"tamam\";
+}";
+        fixed: "\
+fn main() -> int {
+    return 0;
+}";
+    }
+    
+    InvalidOperands = 302 {
+        title: "Geçersiz işlenen tipleri", "Invalid operand types";
+        explanation: "\
+Operatör, işlenenlerinin tipleri için tanımlı değil.
+Aritmetik operatörler sayılarla, mantıksal operatörler bool değerlerle çalışır.", "\
+The operator is not defined for the types of its operands.
+Arithmetic operators work on numbers and logical operators work on bool values.";
+        failing: "\
+fn main() -> int {
+    let x = true * 2;
+    return 0;
+}";
+        fixed: "\
+fn main() -> int {
+    let x = 3 * 2;
+    return 0;
+}";
+    }
+    
+    NonBoolCondition = 303 {
+        title: "Koşul bool değil", "Condition is not a bool";
+        explanation: "\
+'if' ve 'while' koşulları bool tipinde olmalıdır.
+Sayıları koşul olarak kullanmak için açıkça bir karşılaştırma yazın.", "\
+Conditions of 'if' and 'while' must be of type bool.
+Write an explicit comparison to use a number as a condition.";
+        failing: "\
+fn main() -> int {
+    let x = 1;
+    if x {
+        return 1;
+    }
+    return 0;
+}";
+        fixed: "\
+fn main() -> int {
+    let x = 1;
+    if x != 0 {
+        return 1;
+    }
+    return 0;
+}";
+    }
+    
+    NotIterable = 304 {
+        title: "Üzerinde döngü kurulamayan tip", "Type is not iterable";
+        explanation: "\
+'for' döngüleri yalnızca aralıklar, diziler ve dizgeler üzerinde çalışır.", "\
+'for' loops can only iterate over ranges, arrays and strings.";
+        failing: "\
+fn main() -> int {
+    let mut toplam = 0;
+    for i in 10 {
+        toplam = toplam + i;
+    }
+    return toplam;
+}";
+        fixed: "\
+fn main() -> int {
+    let mut toplam = 0;
+    for i in 0..10 {
+        toplam = toplam + i;
+    }
+    return toplam;
+}";
+    }
+    
+    InvalidRangeBound = 305 {
+        title: "Geçersiz aralık sınırı", "Invalid range bound";
+        explanation: "\
+'a..b' aralığının iki sınırı da int tipinde olmalıdır.", "\
+Both bounds of an 'a..b' range must be of type int.";
+        failing: "\
+fn main() -> int {
+    let mut toplam = 0;
+    for i in 0..2.5 {
+        toplam = toplam + i;
+    }
+    return toplam;
+}";
+        fixed: "\
+fn main() -> int {
+    let mut toplam = 0;
+    for i in 0..3 {
+        toplam = toplam + i;
+    }
+    return toplam;
+}";
+    }
+    
+    ArgumentCountMismatch = 306 {
+        title: "Yanlış argüman sayısı", "Wrong number of arguments";
+        explanation: "\
+Fonksiyon, bildiriminde yer alan parametre sayısından farklı sayıda argümanla çağrıldı.", "\
+The function was called with a different number of arguments than it declares parameters.";
+        failing: "\
+fn kare(x: int) -> int {
+    return x * x;
+}
+
+fn main() -> int {
+    return kare(2, 3);
+}";
+        fixed: "\
+fn kare(x: int) -> int {
+    return x * x;
+}
+
+fn main() -> int {
+    return kare(2);
+}";
+    }
+    
+    ArgumentTypeMismatch = 307 {
+        title: "Argüman tipi uyuşmazlığı", "Argument type mismatch";
+        explanation: "\
+Bir argümanın tipi, karşılık gelen parametrenin tipiyle uyuşmuyor.", "\
+The type of an argument does not match the type of the corresponding parameter.";
+        failing: "\
+fn kare(x: int) -> int {
+    return x * x;
+}
+
+fn main() -> int {
+    return kare(\"iki\");
+}";
+        fixed: "\
+fn kare(x: int) -> int {
+    return x * x;
+}
+
+fn main() -> int {
+    return kare(2);
+}";
+    }
+    
+    MissingMain = 400 {
+        title: "'main' fonksiyonu yok", "Missing 'main' function";
+        explanation: "\
+Her Ravun programı, programın giriş noktası olan bir 'main' fonksiyonu tanımlamalıdır.
+'main' fonksiyonunun döndürdüğü int değer programın çıkış kodu olur.", "\
+Every Ravun program must define a 'main' function, which is the entry point of the program.
+The int value returned by 'main' becomes the exit code of the program.";
+        failing: "\
+fn baslat() -> int {
+    return 0;
+}";
+        fixed: "\
+fn main() -> int {
+    return 0;
+}";
+    }
+    
+    MainNotFunction = 401 {
+        title: "'main' bir fonksiyon değil", "'main' is not a function";
+        explanation: "\
+'main' adı programın giriş noktası için ayrılmıştır ve bir fonksiyon olmalıdır.", "\
+The name 'main' is reserved for the entry point of the program and must be a function.";
+        failing: "\
+let main = 0;";
+        fixed: "\
+fn main() -> int {
+    return 0;
+}";
+    }
+    
+    ReturnOutsideFunction = 402 {
+        title: "Fonksiyon dışında 'return'", "'return' outside of a function";
+        explanation: "\
+'return' yalnızca bir fonksiyon gövdesi içinde kullanılabilir.", "\
+'return' can only be used inside a function body.";
+        failing: "\
+return 0;
+
+fn main() -> int {
+    return 0;
+}";
+        fixed: "\
+fn main() -> int {
+    return 0;
+}";
+    }
+    
+    BreakOutsideLoop = 403 {
+        title: "Döngü dışında 'break'", "'break' outside of a loop";
+        explanation: "\
+'break' yalnızca bir 'for' ya da 'while' döngüsü içinde kullanılabilir.", "\
+'break' can only be used inside a 'for' or 'while' loop.";
+        failing: "\
+fn main() -> int {
+    let mut i = 0;
+    if i < 10 {
+        break;
+    }
+    return i;
+}";
+        fixed: "\
+fn main() -> int {
+    let mut i = 0;
+    while i < 10 {
+        i += 1;
+        if i == 5 {
+            break;
+        }
+    }
+    return i;
+}";
+    }
+    
+    ContinueOutsideLoop = 404 {
+        title: "Döngü dışında 'continue'", "'continue' outside of a loop";
+        explanation: "\
+'continue' yalnızca bir 'for' ya da 'while' döngüsü içinde kullanılabilir.", "\
+'continue' can only be used inside a 'for' or 'while' loop.";
+        failing: "\
+fn main() -> int {
+    let mut toplam = 0;
+    if toplam == 0 {
+        continue;
+    }
+    return toplam;
+}";
+        fixed: "\
+fn main() -> int {
+    let mut toplam = 0;
+    for i in 0..10 {
+        if i % 2 == 0 {
+            continue;
+        }
+        toplam += i;
+    }
+    return toplam;
+}";
+    }
+    
+    UnknownAttribute = 406 {
+        title: "Bilinmeyen nitelik", "Unknown attribute";
+        explanation: "\
+Ravun yalnızca '#[inline]' ve '#[noinline]' niteliklerini tanır.", "\
+Ravun only recognizes the '#[inline]' and '#[noinline]' attributes.";
+        failing: "\
+#[hizli]
+fn main() -> int {
+    return 0;
+}";
+        fixed: "\
+#[inline]
+fn main() -> int {
+    return 0;
+}";
+    }
+    
+    ConflictingAttributes = 407 {
+        title: "Çelişen nitelikler", "Conflicting attributes";
+        explanation: "\
+Bir fonksiyon aynı anda hem '#[inline]' hem '#[noinline]' olarak işaretlenemez.", "\
+A function cannot be marked both '#[inline]' and '#[noinline]'.";
+        failing: "\
+#[inline]
+#[noinline]
+fn main() -> int {
+    return 0;
+}";
+        fixed: "\
+#[noinline]
+fn main() -> int {
+    return 0;
+}";
+    }
+    
+    UnusedSymbol = 500 {
+        title: "Kullanılmayan sembol", "Unused symbol";
+        explanation: "\
+Tanımlanan global değişken ya da tip hiçbir yerde kullanılmıyor.
+Bu bir uyarıdır; derleme devam eder. Gereksizse tanımı silin.", "\
+The declared global variable or type is never used.
+This is a warning and compilation continues. Remove the declaration if it is not needed.";
+        failing: "\
+let sinir = 10;
+
+fn main() -> int {
+    return 0;
+}";
+        fixed: "\
+let sinir = 10;
+
+fn main() -> int {
+    return sinir;
+}";
+    }
+    
+    UnusedVariable = 501 {
+        title: "Kullanılmayan değişken", "Unused variable";
+        explanation: "\
+Tanımlanan değişken hiçbir yerde okunmuyor.
+Bu bir uyarıdır; derleme devam eder. Adı '_' ile başlayan değişkenler için uyarı verilmez.", "\
+The declared variable is never read.
+This is a warning and compilation continues. Variables whose names start with '_' are not reported.";
+        failing: "\
+fn main() -> int {
+    let sonuc = 42;
+    return 0;
+}";
+        fixed: "\
+fn main() -> int {
+    let sonuc = 42;
+    return sonuc;
+}";
+    }
+}
+
+impl ErrorCode {
+    pub fn parse(text: &str) -> Option<ErrorCode> {
+        let digits = text.strip_prefix("RV").or_else(|| text.strip_prefix("rv")).unwrap_or(text);
+        let number: u16 = digits.parse().ok()?;
+        
+        ErrorCode::ALL.iter().copied().find(|code| code.number() == number)
+    }
+    
+    pub fn title(self) -> &'static str {
+        self.title_in(language())
+    }
+    
+    pub fn explanation(self) -> &'static str {
+        self.explanation_in(language())
+    }
+    
+    pub fn explain(self) -> String {
+        let mut text = format!("{}: {}\n\n{}\n", self, self.title(), self.explanation());
+        
+        for (heading, example) in [
+            (MessageId::FailingExample, self.failing_example()),
+            (MessageId::FixedExample, self.fixed_example()),
+        ] {
+            if example.is_empty() {
+                continue;
+            }
+            
+            text.push_str(&format!("\n{}\n\n", heading.text()));
+            for line in example.lines() {
+                if line.is_empty() {
+                    text.push('\n');
+                } else {
+                    text.push_str(&format!("    {}\n", line));
+                }
+            }
+        }
+        
+        text
+    }
+}
+
+impl fmt::Display for ErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "RV{:04}", self.number())
+    }
+}
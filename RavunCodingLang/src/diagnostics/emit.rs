@@ -0,0 +1,163 @@
+use crate::diagnostics::codes::ErrorCode;
+use crate::diagnostics::diagnostic::{Diagnostic, Label, Severity};
+use crate::diagnostics::source::SourceFile;
+use crate::lexer::span::Span;
+use crate::utils::Json;
+
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ErrorFormat {
+    #[default]
+    Human,
+    Json,
+    Sarif,
+}
+
+impl ErrorFormat {
+    pub fn parse(name: &str) -> Option<ErrorFormat> {
+        match name {
+            "human" => Some(ErrorFormat::Human),
+            "json" => Some(ErrorFormat::Json),
+            "sarif" => Some(ErrorFormat::Sarif),
+            _ => None,
+        }
+    }
+}
+
+pub fn to_json(diagnostic: &Diagnostic, file: Option<&SourceFile>) -> Json {
+    let span = match (diagnostic.primary_span(), file) {
+        (Some(span), Some(file)) => span_json(span, file),
+        _ => Json::Null,
+    };
+    let labels = match file {
+        Some(file) => diagnostic.labels.iter().map(|label| label_json(label, file)).collect(),
+        None => Vec::new(),
+    };
+    
+    Json::object(vec![
+        ("code", diagnostic.code.clone().map_or(Json::Null, Json::String)),
+        ("severity", Json::string(severity_name(diagnostic.severity))),
+        ("is_warning", Json::Bool(diagnostic.severity == Severity::Warning)),
+        ("file", file.map_or(Json::Null, |file| Json::string(file.name.clone()))),
+        ("span", span),
+        ("message", Json::string(diagnostic.message.clone())),
+        ("labels", Json::Array(labels)),
+        ("notes", Json::Array(diagnostic.notes.iter().cloned().map(Json::String).collect())),
+        ("help", diagnostic.help.clone().map_or(Json::Null, Json::String)),
+    ])
+}
+
+pub fn to_sarif(diagnostics: &[Diagnostic], file: &SourceFile) -> Json {
+    let mut rules: Vec<&str> = diagnostics.iter().filter_map(|d| d.code.as_deref()).collect();
+    rules.sort();
+    rules.dedup();
+    
+    let results = diagnostics.iter().map(|diagnostic| {
+        let mut fields = Vec::new();
+        if let Some(code) = &diagnostic.code {
+            fields.push(("ruleId", Json::string(code.clone())));
+        }
+        fields.push(("level", Json::string(severity_name(diagnostic.severity))));
+        fields.push(("message", Json::object(vec![("text", Json::string(diagnostic.message.clone()))])));
+        
+        let locations: Vec<Json> = diagnostic.primary_span()
+            .map(|span| sarif_location(span, file, None))
+            .into_iter()
+            .collect();
+        fields.push(("locations", Json::Array(locations)));
+        
+        let related = diagnostic.labels.iter()
+            .filter(|label| !label.primary)
+            .map(|label| sarif_location(label.span, file, Some(&label.message)))
+            .collect();
+        fields.push(("relatedLocations", Json::Array(related)));
+        
+        fields.push(("properties", Json::object(vec![
+            ("is_warning", Json::Bool(diagnostic.severity == Severity::Warning)),
+            ("notes", Json::Array(diagnostic.notes.iter().cloned().map(Json::String).collect())),
+            ("help", diagnostic.help.clone().map_or(Json::Null, Json::String)),
+        ])));
+        
+        Json::object(fields)
+    }).collect();
+    
+    let driver = Json::object(vec![
+        ("name", Json::string("ravun")),
+        ("version", Json::string(env!("CARGO_PKG_VERSION"))),
+        ("rules", Json::Array(rules.into_iter().map(sarif_rule).collect())),
+    ]);
+    
+    Json::object(vec![
+        ("$schema", Json::string(SARIF_SCHEMA)),
+        ("version", Json::string("2.1.0")),
+        ("runs", Json::Array(vec![Json::object(vec![
+            ("tool", Json::object(vec![("driver", driver)])),
+            ("columnKind", Json::string("unicodeCodePoints")),
+            ("results", Json::Array(results)),
+        ])])),
+    ])
+}
+
+fn sarif_rule(id: &str) -> Json {
+    let mut fields = vec![("id", Json::string(id))];
+    if let Some(code) = ErrorCode::parse(id) {
+        fields.push(("shortDescription", Json::object(vec![("text", Json::string(code.title()))])));
+    }
+    
+    Json::object(fields)
+}
+
+fn severity_name(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Error => "error",
+        Severity::Warning => "warning",
+    }
+}
+
+fn span_json(span: Span, file: &SourceFile) -> Json {
+    let (line, column) = file.location(span.start);
+    let (end_line, end_column) = file.location(span.end);
+    
+    Json::object(vec![
+        ("file_id", Json::Number(span.file_id as f64)),
+        ("start", Json::Number(span.start as f64)),
+        ("end", Json::Number(span.end as f64)),
+        ("line", Json::Number(line as f64)),
+        ("column", Json::Number(column as f64)),
+        ("end_line", Json::Number(end_line as f64)),
+        ("end_column", Json::Number(end_column as f64)),
+    ])
+}
+
+fn label_json(label: &Label, file: &SourceFile) -> Json {
+    Json::object(vec![
+        ("span", span_json(label.span, file)),
+        ("message", Json::string(label.message.clone())),
+        ("primary", Json::Bool(label.primary)),
+    ])
+}
+
+fn sarif_location(span: Span, file: &SourceFile, message: Option<&str>) -> Json {
+    let (line, column) = file.location(span.start);
+    let (end_line, end_column) = file.location(span.end);
+    
+    let mut fields = vec![
+        ("physicalLocation", Json::object(vec![
+            ("artifactLocation", Json::object(vec![("uri", Json::string(file.name.clone()))])),
+            ("region", Json::object(vec![
+                ("startLine", Json::Number(line as f64)),
+                ("startColumn", Json::Number(column as f64)),
+                ("endLine", Json::Number(end_line as f64)),
+                ("endColumn", Json::Number(end_column as f64)),
+                ("byteOffset", Json::Number(span.start as f64)),
+                ("byteLength", Json::Number(span.len() as f64)),
+            ])),
+        ])),
+    ];
+    if let Some(message) = message.filter(|message| !message.is_empty()) {
+        fields.push(("message", Json::object(vec![("text", Json::string(message))])));
+    }
+    
+    Json::object(fields)
+}
@@ -0,0 +1,563 @@
+use std::env;
+use std::fmt;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Lang {
+    #[default]
+    Tr,
+    En,
+}
+
+static LANGUAGE: AtomicU8 = AtomicU8::new(0);
+
+impl Lang {
+    pub fn parse(name: &str) -> Option<Lang> {
+        let code = name.split(['_', '-', '.', '@']).next().unwrap_or_default().to_ascii_lowercase();
+        match code.as_str() {
+            "tr" => Some(Lang::Tr),
+            "en" => Some(Lang::En),
+            _ => None,
+        }
+    }
+    
+    pub fn from_env() -> Lang {
+        ["LC_ALL", "LC_MESSAGES", "LANG"].iter()
+            .filter_map(|name| env::var(name).ok())
+            .find(|value| !value.is_empty())
+            .and_then(|value| Lang::parse(&value))
+            .unwrap_or_default()
+    }
+}
+
+pub fn set_language(lang: Lang) {
+    LANGUAGE.store(lang as u8, Ordering::Relaxed);
+}
+
+pub fn language() -> Lang {
+    match LANGUAGE.load(Ordering::Relaxed) {
+        1 => Lang::En,
+        _ => Lang::Tr,
+    }
+}
+
+macro_rules! catalog {
+    ($($id:ident: $tr:literal, $en:literal;)*) => {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub enum MessageId {
+            $($id,)*
+        }
+        
+        impl MessageId {
+            pub const ALL: &'static [MessageId] = &[$(MessageId::$id,)*];
+            
+            pub fn template(self, lang: Lang) -> &'static str {
+                match (self, lang) {
+                    $(
+                        (MessageId::$id, Lang::Tr) => $tr,
+                        (MessageId::$id, Lang::En) => $en,
+                    )*
+                }
+            }
+        }
+    };
+}
+
+catalog! {
+    SeverityError: "hata", "error";
+    SeverityWarning: "uyarı", "warning";
+    NoteLabel: "not", "note";
+    HelpLabel: "yardım", "help";
+    Position: "satır {0}, sütun {1}", "line {0}, column {1}";
+    TypeUnknown: "bilinmeyen", "unknown";
+    
+    UnterminatedString: "Kapanmamış dizge", "Unterminated string";
+    StringStartsHere: "dizge burada başlıyor", "string starts here";
+    InvalidNumber: "Geçersiz sayı: '{0}'", "Invalid number: '{0}'";
+    ExpectedFractionDigit: "noktadan sonra rakam bekleniyor", "expected a digit after the decimal point";
+    InvalidCharacter: "Geçersiz karakter: '{0}'", "Invalid character: '{0}'";
+    UnrecognizedCharacter: "tanınmayan karakter", "unrecognized character";
+    
+    ExpectedToken: "Beklenen: {0}, bulunan: {1}", "Expected {0}, found {1}";
+    ExpectedTokenAtEof: "Beklenen: {0}, dosya sonu bulundu", "Expected {0}, found end of file";
+    ExpectedTokenLabel: "{0} bekleniyor", "expected {0}";
+    UnexpectedEof: "Beklenmeyen dosya sonu", "Unexpected end of file";
+    UnexpectedToken: "Beklenmeyen token: {0}", "Unexpected token: {0}";
+    ExpectedExpression: "ifade bekleniyor", "expected an expression";
+    AttributeOnNonFunction: "Nitelikler yalnızca fonksiyonlara uygulanabilir", "Attributes can only be applied to functions";
+    ExpectedFunction: "fonksiyon bekleniyor", "expected a function";
+    AttributesHere: "nitelikler burada", "attributes here";
+    InvalidAssignmentTarget: "Geçersiz atama hedefi", "Invalid assignment target";
+    CannotAssignToExpression: "bu ifadeye atama yapılamaz", "cannot assign to this expression";
+    ParsingContext: "{0} ayrıştırılırken", "while parsing {0}";
+    Or: "veya", "or";
+    
+    TokenIdentifier: "tanımlayıcı", "identifier";
+    TokenIntLiteral: "tam sayı", "integer literal";
+    TokenFloatLiteral: "ondalık sayı", "float literal";
+    TokenStringLiteral: "dizge", "string literal";
+    TokenCharLiteral: "karakter", "character literal";
+    TokenBoolLiteral: "bool değeri", "boolean literal";
+    TokenComment: "yorum", "comment";
+    TokenWhitespace: "boşluk", "whitespace";
+    TokenEof: "dosya sonu", "end of file";
+    TokenInvalid: "geçersiz token", "invalid token";
+    TokenExpression: "ifade", "an expression";
+    
+    ContextVarDeclaration: "değişken tanımı", "a variable declaration";
+    ContextFunctionDeclaration: "fonksiyon tanımı", "a function declaration";
+    ContextFunctionParameters: "fonksiyon parametreleri", "function parameters";
+    ContextAttributes: "nitelikler", "attributes";
+    ContextStructDeclaration: "struct tanımı", "a struct declaration";
+    ContextStructFields: "struct alanları", "struct fields";
+    ContextImplBlock: "impl bloğu", "an impl block";
+    ContextModuleBlock: "modül tanımı", "a module declaration";
+    ContextIfStatement: "if ifadesi", "an if statement";
+    ContextWhileStatement: "while döngüsü", "a while loop";
+    ContextForStatement: "for döngüsü", "a for loop";
+    ContextReturnStatement: "return ifadesi", "a return statement";
+    ContextBlock: "blok", "a block";
+    ContextCallArguments: "çağrı argümanları", "call arguments";
+    ContextGroupExpression: "parantezli ifade", "a parenthesized expression";
+    
+    KindVariable: "değişken", "variable";
+    KindFunction: "fonksiyon", "function";
+    KindParameter: "parametre", "parameter";
+    KindType: "tür", "type";
+    KindModule: "modül", "module";
+    KindTypeParameter: "tip parametresi", "type parameter";
+    Redefinition: "'{0}' daha önce {1} olarak tanımlanmış (satır: {2}, sütun: {3})", "'{0}' is already defined as a {1} (line: {2}, column: {3})";
+    FirstDefinedHere: "ilk tanım burada", "first defined here";
+    ScopeNotFound: "Kapsam bulunamadı", "Scope not found";
+    Undefined: "'{0}' tanımlı değil", "'{0}' is not defined";
+    UndefinedInCurrentScope: "'{0}' mevcut kapsamda tanımlı değil", "'{0}' is not defined in the current scope";
+    UndefinedCannotUse: "'{0}' tanımlı değil, kullanılamaz", "'{0}' is not defined and cannot be used";
+    UndefinedCannotAssign: "'{0}' tanımlı değil, değer atanamaz", "'{0}' is not defined and cannot be assigned";
+    ImmutableAssignment: "'{0}' değiştirilemez (mut değil)", "'{0}' cannot be modified (not declared mut)";
+    NotAFunctionKind: "'{0}' bir fonksiyon değil, {1} türünde", "'{0}' is not a function, it is a {1}";
+    NotATypeKind: "'{0}' bir tür değil, {1} türünde", "'{0}' is not a type, it is a {1}";
+    DidYouMean: "şunu mu demek istediniz: '{0}'?", "did you mean '{0}'?";
+    
+    AssignTypeMismatch: "Tip uyuşmazlığı: '{0}' tipine '{1}' tipi atanamaz", "Type mismatch: a value of type '{1}' cannot be assigned to '{0}'";
+    InvalidReturnType: "Geçersiz dönüş tipi: Beklenen '{0}', bulunan '{1}'", "Invalid return type: expected '{0}', found '{1}'";
+    InvalidArithmeticOperands: "'{0}' operatörü '{1}' ve '{2}' tipleri için geçerli değil", "Operator '{0}' is not valid for types '{1}' and '{2}'";
+    InvalidIntegerOperands: "'{0}' operatörü yalnızca int tipleri için geçerli, bulunan: '{1}' ve '{2}'", "Operator '{0}' is only valid for int types, found '{1}' and '{2}'";
+    InvalidComparisonOperands: "'{0}' karşılaştırma operatörü '{1}' ve '{2}' tipleri için geçerli değil", "Comparison operator '{0}' is not valid for types '{1}' and '{2}'";
+    ExpectedTypeAnnotation: "Tip tanımlaması bekleniyordu, {0} bulundu", "Expected a type annotation, found {0}";
+    MissingTypeName: "Tip adı bulunamadı", "Missing type name";
+    
+    UnusedSymbol: "'{0}' {1} tanımlandı fakat hiç kullanılmadı", "{1} '{0}' is defined but never used";
+    UnusedVariable: "Kullanılmayan değişken: {0}", "Unused variable: {0}";
+    UninitializedVariable: "'{0}' değişkeni kullanılmadan önce başlatılmamış", "Variable '{0}' is used before being initialized";
+    MainNotFunction: "'main' bir fonksiyon olmalıdır", "'main' must be a function";
+    MissingMain: "'main' fonksiyonu tanımlanmamış", "Function 'main' is not defined";
+    UninferableType: "'{0}' değişkeninin tipi belirtilmemiş ve çıkarsanamadı", "The type of variable '{0}' is not given and cannot be inferred";
+    VariableTypeMismatch: "'{0}' değişkeni için tip uyuşmazlığı: {1}", "Type mismatch for variable '{0}': {1}";
+    MissingParameterType: "'{0}' parametresinin tipi belirtilmemiş", "The type of parameter '{0}' is not given";
+    UnexpectedNode: "Beklenmeyen düğüm tipi: {0}", "Unexpected node type: {0}";
+    UndefinedType: "'{0}' tipi tanımlanmamış", "Type '{0}' is not defined";
+    IncompleteIf: "If ifadesi eksik", "Incomplete if statement";
+    IfConditionNotBool: "If koşulu boolean tipinde olmalı, bulunan: {0}", "If condition must be of type bool, found: {0}";
+    IncompleteWhile: "While ifadesi eksik", "Incomplete while statement";
+    WhileConditionNotBool: "While koşulu boolean tipinde olmalı, bulunan: {0}", "While condition must be of type bool, found: {0}";
+    IncompleteFor: "For ifadesi eksik", "Incomplete for statement";
+    NotIterable: "For döngüsünün '{0}' tipi üzerinde döngülenemez", "A for loop cannot iterate over type '{0}'";
+    IncompleteRange: "Aralık ifadesi eksik", "Incomplete range expression";
+    RangeBoundNotInt: "Aralık sınırları int tipinde olmalı, bulunan: {0}", "Range bounds must be of type int, found: {0}";
+    RangeOutsideFor: "Aralık ifadeleri yalnızca for döngülerinde kullanılabilir", "Range expressions can only be used in for loops";
+    ReturnTypeMismatch: "Dönüş tipi uyuşmazlığı: {0}", "Return type mismatch: {0}";
+    ReturnOutsideFunction: "Return ifadesi yalnızca fonksiyon içinde kullanılabilir", "Return can only be used inside a function";
+    IncompleteBinary: "Eksik ikili ifade", "Incomplete binary expression";
+    LogicalOperandsNotBool: "Mantıksal operatör '{0}' için boolean değerler gerekli, bulunan: {1} ve {2}", "Logical operator '{0}' requires bool operands, found: {1} and {2}";
+    TypeMismatch: "Tip uyuşmazlığı: {0}", "Type mismatch: {0}";
+    CompoundAssignTypeMismatch: "Bileşik atama için tip uyuşmazlığı: {0}", "Type mismatch in compound assignment: {0}";
+    NotAssignable: "Sol taraf atama için geçerli bir hedef değil", "The left-hand side is not a valid assignment target";
+    UnknownOperator: "Bilinmeyen operatör: {0}", "Unknown operator: {0}";
+    IncompleteUnary: "Eksik tekli ifade", "Incomplete unary expression";
+    InvalidUnaryOperand: "Operatör '{0}' tip {1} için geçerli değil", "Operator '{0}' is not valid for type {1}";
+    InvalidUnaryOperandExpectedBool: "Operatör '{0}' tip {1} için geçerli değil, bool bekleniyor", "Operator '{0}' is not valid for type {1}, expected bool";
+    InvalidUnaryOperandExpectedInt: "Operatör '{0}' tip {1} için geçerli değil, int bekleniyor", "Operator '{0}' is not valid for type {1}, expected int";
+    UnknownUnaryOperator: "Bilinmeyen tekli operatör: {0}", "Unknown unary operator: {0}";
+    UnsupportedLiteral: "Desteklenmeyen literal tipi: {0}", "Unsupported literal type: {0}";
+    UnknownLiteral: "Bilinmeyen literal: {0}", "Unknown literal: {0}";
+    ArgumentCountMismatch: "Fonksiyon '{0}' {1} argüman alır, {2} verilmiş", "Function '{0}' takes {1} argument(s) but {2} were given";
+    ArgumentTypeMismatch: "Argüman {0} için tip uyuşmazlığı: {1}", "Type mismatch for argument {0}: {1}";
+    NotAFunction: "'{0}' bir fonksiyon değil", "'{0}' is not a function";
+    EmptyGroup: "Boş grup ifadesi", "Empty group expression";
+    UnexpectedStructNode: "Struct içinde beklenmeyen düğüm tipi: {0}", "Unexpected node type in struct: {0}";
+    UnexpectedImplNode: "Impl içinde beklenmeyen düğüm tipi: {0}", "Unexpected node type in impl: {0}";
+    ImplForUndefinedStruct: "'{0}' struct'ı tanımlı değil, impl yapılamaz", "Struct '{0}' is not defined, cannot implement it";
+    IncompleteMemberAccess: "Üye erişimi ifadesi eksik", "Incomplete member access expression";
+    UnknownField: "'{0}' struct'ında '{1}' adında bir alan yok", "Struct '{0}' has no field named '{1}'";
+    MemberAccessOnNonStruct: "'.' operatörü struct tipi beklerken '{0}' tipi bulundu", "The '.' operator expects a struct type, found '{0}'";
+    IncompleteIndex: "Dizin erişimi ifadesi eksik", "Incomplete index expression";
+    IndexNotInt: "Dizin ifadesi integer tipinde olmalı, bulunan: {0}", "Index must be of type int, found: {0}";
+    NotIndexable: "'[]' operatörü dizi veya string tipi beklerken '{0}' tipi bulundu", "The '[]' operator expects an array or string, found '{0}'";
+    BreakOutsideLoop: "Break ifadesi sadece döngü içinde kullanılabilir", "Break can only be used inside a loop";
+    ContinueOutsideLoop: "Continue ifadesi sadece döngü içinde kullanılabilir", "Continue can only be used inside a loop";
+    UnexpectedType: "{0} için beklenmeyen tip: {1} (beklenen: {2})", "Unexpected type for {0}: {1} (expected: {2})";
+    ModuleNotFound: "Modül bulunamadı: {0}", "Module not found: {0}";
+    UnknownAttribute: "Bilinmeyen nitelik: #[{0}]", "Unknown attribute: #[{0}]";
+    ConflictingInlineAttributes: "#[inline] ve #[noinline] nitelikleri birlikte kullanılamaz", "#[inline] and #[noinline] cannot be used together";
+    UnexpectedExpression: "Beklenmeyen ifade tipi: {0}", "Unexpected expression type: {0}";
+    HintConstantExpression: "Sabit ifade: {0} - Derleme zamanında hesaplanabilir", "Constant expression: {0} - can be evaluated at compile time";
+    HintUnrollableLoop: "Döngü açılabilir (loop unrolling): {0}", "Loop can be unrolled: {0}";
+    HintInlinableFunction: "İnline edilebilir fonksiyon: {0}", "Inlinable function: {0}";
+    
+    SymbolSummary: "{0} {1} (tip: {2}, kapsam: {3})", "{0} {1} (type: {2}, scope: {3})";
+    SymbolMutable: "değiştirilebilir", "mutable";
+    SymbolUninitialized: "başlatılmamış", "uninitialized";
+    SymbolUnused: "kullanılmıyor", "unused";
+    ScopeGlobal: "global", "global";
+    ScopeFunction: "fonksiyon", "function";
+    ScopeBlock: "blok", "block";
+    ScopeLoop: "döngü", "loop";
+    ScopeIf: "if", "if";
+    ScopeStruct: "struct", "struct";
+    ScopeImpl: "impl", "impl";
+    ScopeModule: "modül", "module";
+    ScopeHeader: "=== Kapsam {0} ({1}) ===", "=== Scope {0} ({1}) ===";
+    ScopeParameters: "Parametreler:", "Parameters:";
+    
+    PassDumpHeader: "; ---- '{0}' geçişinden sonra ({1}) ----", "; ---- after pass '{0}' ({1}) ----";
+    PassChanged: "değişti", "changed";
+    PassUnchanged: "değişmedi", "unchanged";
+    VerifyFailedAfterPass: "'{0}' geçişinden sonra IR doğrulaması başarısız", "IR verification failed after pass '{0}'";
+    VerifyFailedBeforePasses: "Optimizasyon öncesi IR doğrulaması başarısız", "IR verification failed before optimization";
+    RemarkInlined: "@{0} çağrısı inline edildi ({1})", "call to @{0} was inlined ({1})";
+    RemarkNotInlined: "@{0} inline edilmedi: {1}", "@{0} was not inlined: {1}";
+    InlineMarkedNoinline: "#[noinline] ile işaretli", "marked #[noinline]";
+    InlineMarkedInline: "#[inline] ile işaretli", "marked #[inline]";
+    InlineRecursive: "özyinelemeli fonksiyon", "recursive function";
+    InlineNotSmall: "küçük fonksiyon olarak işaretlenmemiş", "not marked as a small function";
+    InlineWithinThreshold: "maliyet {0} <= eşik {1}", "cost {0} <= threshold {1}";
+    InlineOverThreshold: "maliyet {0} > eşik {1}", "cost {0} > threshold {1}";
+    InlineBudgetExceeded: "inline bütçesi aşıldı", "inline budget exceeded";
+    RemarkHoisted: "{0} başlıklı döngüden {1} değişmez komut {2} bloğuna taşındı", "moved {1} invariant instruction(s) out of the loop headed by {0} into {2}";
+    RemarkFullyUnrolled: "{0} başlıklı döngü tamamen açıldı ({1} yineleme)", "loop headed by {0} was fully unrolled ({1} iterations)";
+    RemarkPartiallyUnrolled: "{0} başlıklı döngü {1} katsayısıyla açıldı ({2} yineleme)", "loop headed by {0} was unrolled by a factor of {1} ({2} iterations)";
+    RemarkNotUnrolled: "{0} başlıklı döngü açılmadı: {1}", "loop headed by {0} was not unrolled: {1}";
+    LoopHeadedBy: "{0} başlıklı döngü", "loop headed by {0}";
+    UnrollTooLarge: "{0} yineleme, boyut {1}", "{0} iterations, size {1}";
+    UnrollMultipleLatches: "birden fazla geri kenar var", "it has more than one back edge";
+    UnrollNoPreheader: "ön blok bulunamadı", "no preheader was found";
+    UnrollHeaderPredecessors: "başlık bloğunun beklenmeyen öncülleri var", "the header has unexpected predecessors";
+    UnrollMultipleExits: "döngünün birden fazla çıkışı var", "the loop has more than one exit";
+    UnrollExitsOutsideHeader: "döngü başlık dışından terk ediliyor", "the loop is left from outside the header";
+    UnrollNoHeaderBlock: "başlık bloğu bulunamadı", "the header block was not found";
+    UnrollNoConditionalBranch: "başlık koşullu dallanma ile bitmiyor", "the header does not end with a conditional branch";
+    UnrollNoConstantBound: "döngü koşulu sabit bir sınırla karşılaştırma değil", "the loop condition is not a comparison against a constant bound";
+    UnrollNoCounter: "sayaç değişkeni bulunamadı", "no counter variable was found";
+    UnrollNonConstantStart: "sayacın başlangıç değeri sabit değil", "the counter does not start at a constant";
+    UnrollCounterNotUpdated: "sayaç döngü içinde güncellenmiyor", "the counter is not updated inside the loop";
+    UnrollNonConstantStep: "sayaç sabit adımla değişmiyor", "the counter does not change by a constant step";
+    UnrollTooManyIterations: "yineleme sayısı çok büyük", "the trip count is too large";
+    UnrollCounterOverflow: "sayaç taşıyor", "the counter overflows";
+    
+    MissingFunctionName: "Fonksiyon adı bulunamadı", "Missing function name";
+    MissingVariableName: "Değişken adı bulunamadı", "Missing variable name";
+    MissingLoopVariable: "Döngü değişkeni adı bulunamadı", "Missing loop variable name";
+    UnsupportedTopLevel: "Fonksiyon dışında desteklenmeyen ifade: {0}", "Unsupported statement outside a function: {0}";
+    GlobalNeedsInitializer: "Global '{0}' değişkeni için başlangıç değeri gerekli", "Global variable '{0}' needs an initial value";
+    VariableNeedsInitializer: "'{0}' değişkeni için başlangıç değeri gerekli", "Variable '{0}' needs an initial value";
+    UnknownVariableType: "'{0}' değişkeninin tipi belirlenemedi", "Could not determine the type of variable '{0}'";
+    LoopControlOutsideLoop: "Break/continue ifadesi döngü dışında kullanıldı", "break/continue used outside a loop";
+    NestedDeclaration: "İç içe tanımlamalar IR'a dönüştürülemez", "Nested declarations cannot be lowered to IR";
+    ConditionNotBool: "Koşul boolean tipinde olmalı, bulunan: {0}", "Condition must be of type bool, found: {0}";
+    InvalidLiteral: "Geçersiz literal: {0}", "Invalid literal: {0}";
+    FunctionReturnsNothing: "'{0}' fonksiyonu değer döndürmüyor", "Function '{0}' does not return a value";
+    UnsupportedExpression: "Desteklenmeyen ifade tipi: {0}", "Unsupported expression type: {0}";
+    UndefinedFunction: "'{0}' fonksiyonu tanımlı değil", "Function '{0}' is not defined";
+    
+    VerifyDuplicateFunction: "Fonksiyon birden fazla tanımlanmış", "Function is defined more than once";
+    VerifyNoBlocks: "Fonksiyonun hiç bloğu yok", "Function has no blocks";
+    VerifyDuplicateValue: "{0} birden fazla tanımlanmış", "{0} is defined more than once";
+    VerifyDuplicateBlock: "Blok birden fazla tanımlanmış", "Block is defined more than once";
+    VerifyMissingTerminator: "Blokta sonlandırıcı yok", "Block has no terminator";
+    VerifyUndefinedTarget: "Tanımsız hedef blok: {0}", "Undefined target block: {0}";
+    VerifyPhiNotAtStart: "Phi düğümleri bloğun başında olmalı", "Phi nodes must be at the start of the block";
+    VerifyPhiInEntry: "Giriş bloğunda phi düğümü olamaz", "The entry block cannot contain phi nodes";
+    VerifyStoreWithResult: "store komutu değer üretmez, ancak {0} tanımlıyor", "store does not produce a value, but defines {0}";
+    VerifyMissingResult: "'{0}' komutunun sonucu bir değere atanmalı", "The result of '{0}' must be assigned to a value";
+    VerifyVoidResult: "void tipli {0} değeri tanımlanamaz", "{0} cannot be defined with type void";
+    VerifyPhiDuplicateIncoming: "'{0}' içinde {1} birden fazla kez geçiyor", "{1} appears more than once in '{0}'";
+    VerifyPhiNotPredecessor: "'{0}' içinde {1} bir öncül blok değil", "{1} in '{0}' is not a predecessor";
+    VerifyPhiMissingIncoming: "'{0}' öncül blok {1} için değer içermiyor", "'{0}' has no value for predecessor {1}";
+    VerifyUseBeforeDefinition: "{0} tanımlanmadan kullanılıyor", "{0} is used before it is defined";
+    VerifyNotDominated: "{0} kullanımı tanımı tarafından domine edilmiyor", "The use of {0} is not dominated by its definition";
+    VerifyTypeMismatch: "'{0}' içinde tip uyuşmazlığı: beklenen '{1}', bulunan '{2}'", "Type mismatch in '{0}': expected '{1}', found '{2}'";
+    VerifyInvalidOperandType: "'{0}' komutu '{1}' tipi için geçerli değil", "'{0}' is not valid for type '{1}'";
+    VerifyUndefinedFunction: "Tanımsız fonksiyon: @{0}", "Undefined function: @{0}";
+    VerifyArgumentCount: "'{0}' içinde @{1} {2} argüman alır, {3} verilmiş", "In '{0}', @{1} takes {2} argument(s) but {3} were given";
+    VerifyUndefinedGlobal: "Tanımsız global: @{0}", "Undefined global: @{0}";
+    VerifyImmutableGlobal: "Değiştirilemez global @{0} değerine yazılıyor", "Writing to the immutable global @{0}";
+    VerifyMissingReturnValue: "'{0}' tipli fonksiyon değer döndürmeli", "A function returning '{0}' must return a value";
+    
+    IrExpectedValueNumber: "'%' sonrasında değer numarası bekleniyor", "Expected a value number after '%'";
+    IrExpectedName: "'@' sonrasında isim bekleniyor", "Expected a name after '@'";
+    IrUnexpectedCharacter: "Beklenmeyen karakter: '{0}'", "Unexpected character: '{0}'";
+    IrInvalidFloat: "Geçersiz ondalık sayı: {0}", "Invalid float: {0}";
+    IrInvalidInteger: "Geçersiz tamsayı: {0}", "Invalid integer: {0}";
+    IrExpected: "'{0}' bekleniyor, bulunan: {1}", "Expected '{0}', found {1}";
+    IrExpectedGlobalName: "'@isim' bekleniyor, bulunan: {0}", "Expected '@name', found {0}";
+    IrExpectedItem: "'global' veya 'fn' bekleniyor, bulunan: {0}", "Expected 'global' or 'fn', found {0}";
+    IrGlobalNotConstant: "Global başlangıç değeri sabit olmalı", "The initial value of a global must be a constant";
+    IrExpectedType: "Tip bekleniyor, bulunan: {0}", "Expected a type, found {0}";
+    IrUnknownType: "Bilinmeyen tip: {0}", "Unknown type: {0}";
+    IrExpectedBlockLabel: "Blok etiketi bekleniyor, bulunan: {0}", "Expected a block label, found {0}";
+    IrDuplicateBlock: "{0} bloğu birden fazla tanımlanmış", "Block {0} is defined more than once";
+    IrExpectedTerminator: "Sonlandırıcı bekleniyor, bulunan: {0}", "Expected a terminator, found {0}";
+    IrExpectedOperand: "İşlenen bekleniyor, bulunan: {0}", "Expected an operand, found {0}";
+    IrExpectedInstruction: "Komut bekleniyor, bulunan: {0}", "Expected an instruction, found {0}";
+    IrUnknownInstruction: "Bilinmeyen komut: {0}", "Unknown instruction: {0}";
+    
+    BytecodeStackEmpty: "Bytecode yığını boş", "The bytecode stack is empty";
+    NestedFunctionInBytecode: "İç içe fonksiyon tanımları bytecode'a dönüştürülemez", "Nested function declarations cannot be compiled to bytecode";
+    InvalidBuiltinName: "Geçersiz yerleşik fonksiyon adı: {0}", "Invalid builtin function name: {0}";
+    FieldNotFound: "'{0}' tipinde '{1}' alanı bulunamadı", "Type '{0}' has no field '{1}'";
+    InvalidIntegerLiteral: "Geçersiz tamsayı literali: {0}", "Invalid integer literal: {0}";
+    InvalidFloatLiteral: "Geçersiz ondalık sayı literali: {0}", "Invalid float literal: {0}";
+    
+    LoopDescription: "for {0} in {1}..{2} (satır {3})", "for {0} in {1}..{2} (line {3})";
+    FormatInvalidToken: "Geçersiz token '{0}' (satır: {1}, sütun: {2}), dosya biçimlendirilemez", "Invalid token '{0}' (line: {1}, column: {2}), the file cannot be formatted";
+    InvalidJson: "Geçersiz JSON (karakter {0}): {1}", "Invalid JSON (character {0}): {1}";
+    JsonTrailingCharacters: "fazladan karakter", "trailing characters";
+    JsonExpectedValue: "değer bekleniyor", "expected a value";
+    JsonInvalidSurrogate: "geçersiz vekil çifti", "invalid surrogate pair";
+    JsonInvalidEscape: "geçersiz kaçış dizisi", "invalid escape sequence";
+    JsonUnterminatedString: "kapanmamış dizge", "unterminated string";
+    JsonInvalidUnicodeEscape: "geçersiz \\u kaçışı", "invalid \\u escape";
+    JsonInvalidNumber: "geçersiz sayı", "invalid number";
+    LspShutDown: "Sunucu kapatıldı", "The server has been shut down";
+    LspNotInitialized: "Sunucu henüz başlatılmadı", "The server has not been initialized";
+    LspUnsupportedMethod: "Desteklenmeyen metot: {0}", "Unsupported method: {0}";
+    LspExpectedPosition: "textDocument.uri ve position bekleniyor", "Expected textDocument.uri and position";
+    LspDocumentNotOpen: "Açık olmayan belge: {0}", "Document is not open: {0}";
+    
+    CodegenMissingMain: "'main' fonksiyonu bulunamadı", "Function 'main' was not found";
+    CodegenMainWithParameters: "'main' parametre almamalı", "'main' must not take parameters";
+    CodegenUnprintableType: "'{0}' tipi yazdırılamaz", "Values of type '{0}' cannot be printed";
+    CodegenUnknownFunction: "Bilinmeyen fonksiyon: @{0}", "Unknown function: @{0}";
+    CodegenUnknownGlobal: "Bilinmeyen global: @{0}", "Unknown global: @{0}";
+    CodegenNoLength: "{0} arka ucu '{1}' tipinin uzunluğunu alamıyor", "The {0} backend cannot take the length of type '{1}'";
+    CodegenNotIndexable: "{0} arka ucu '{1}' tipini dizinleyemiyor", "The {0} backend cannot index type '{1}'";
+    CodegenUnsupportedType: "{0} arka ucu '{1}' tipini desteklemiyor", "The {0} backend does not support type '{1}'";
+    CodegenUnsupportedOperation: "{0} arka ucu '{1}' tipi için '{2}' işlemini desteklemiyor", "The {0} backend does not support '{2}' on type '{1}'";
+    CodegenMissingBlock: "bb{0} bloğu bulunamadı", "Block bb{0} was not found";
+    CodegenNoLocal: "%{0} için yerel değişken atanmadı", "No local was assigned to %{0}";
+    CodegenNoLocation: "%{0} için konum atanmadı", "No location was assigned to %{0}";
+    CodegenUnknownValueType: "%{0} değerinin tipi bilinmiyor", "The type of %{0} is unknown";
+    CodegenWrongRegisterClass: "%{0} yanlış register sınıfında", "%{0} is in the wrong register class";
+    CodegenFloatInGeneralRegister: "Ondalık sabit genel amaçlı registera yüklenemez", "A float constant cannot be loaded into a general-purpose register";
+    CodegenNonFloatInXmmRegister: "Ondalık olmayan sabit xmm registerına yüklenemez", "A non-float constant cannot be loaded into an xmm register";
+    CodegenArgumentCount: "@{0} {1} argüman bekliyor, {2} verildi", "@{0} expects {1} argument(s) but {2} were given";
+    CodegenNotFunctionType: "'{0}' bir fonksiyon tipi değil", "'{0}' is not a function type";
+    CodegenMissingPhiResult: "phi sonucu eksik", "missing phi result";
+    CodegenRecursiveStruct: "'{0}' struct'ı kendini değer olarak içeriyor", "Struct '{0}' contains itself by value";
+    CodegenUndefinedStruct: "'{0}' struct'ının tanımı bulunamadı", "The definition of struct '{0}' was not found";
+    CCompilerNotRun: "C derleyicisi '{0}' çalıştırılamadı: {1}", "Could not run the C compiler '{0}': {1}";
+    CSourceNotPiped: "C kaynağı derleyiciye yazılamadı: {0}", "Could not pass the C source to the compiler: {0}";
+    CCompilerWaitFailed: "C derleyicisi beklenirken hata: {0}", "Error while waiting for the C compiler: {0}";
+    CCompilerFailed: "C derleyicisi başarısız oldu ({0}):\n{1}", "The C compiler failed ({0}):\n{1}";
+    LinkerNotRun: "Bağlayıcı '{0}' çalıştırılamadı: {1}", "Could not run the linker '{0}': {1}";
+    LinkerWaitFailed: "Bağlayıcı beklenirken hata: {0}", "Error while waiting for the linker: {0}";
+    LinkFailed: "Derleme ve bağlama başarısız oldu ({0}):\n{1}", "Compiling and linking failed ({0}):\n{1}";
+    RuntimeSourceNotWritten: "Çalışma zamanı kaynağı yazılamadı: {0}", "Could not write the runtime source: {0}";
+    ToolNotRun: "'{0}' çalıştırılamadı: {1}", "Could not run '{0}': {1}";
+    ToolFailed: "'{0}' başarısız oldu ({1}):\n{2}", "'{0}' failed ({1}):\n{2}";
+    
+    Usage: "\
+Kullanım: ravun <komut> [seçenekler]
+
+Komutlar:
+    check <dosya.rv>          Dosyayı ayrıştırır ve semantik analiz yapar
+    build <dosya.rv>          Dosyayı derler (varsayılan: C arka ucu ile yürütülebilir dosya)
+    run <dosya.rv|dosya.rvc>  Dosyayı yorumlayıcı veya bytecode VM ile çalıştırır
+    tokens <dosya.rv>         Token listesini yazdırır
+    ast <dosya.rv>            Soyut sözdizimi ağacını yazdırır
+    ir <dosya.rv>             Optimize edilmiş IR modülünü yazdırır
+    fmt <dosya.rv> [--check]  Dosyayı standart biçime getirir
+    explain <RVxxxx>          Hata kodunun ayrıntılı açıklamasını örneklerle gösterir
+    repl                      Etkileşimli kabuk başlatır
+    help                      Bu mesajı gösterir
+
+Seçenekler:
+    -o <çıktı>                Çıktı dosyası
+    -O0, -O1, -O2             Optimizasyon seviyesi (varsayılan: -O0)
+    --backend <ad>            Arka uç: c, x86-64, wasm, llvm
+    --emit-c, --emit-asm, --emit-wat, --emit-llvm, --emit-bytecode
+                              Yürütülebilir dosya yerine ara çıktı üretir
+    --dump-ir-passes          Her optimizasyon geçişinden sonra IR'ı yazdırır
+    --remarks                 Optimizasyon notlarını yazdırır
+    --check                   fmt: dosyayı değiştirmez, biçimsizse 1 ile çıkar
+    --color <ne zaman>        Renkli tanılama çıktısı: auto, always, never (varsayılan: auto)
+    --error-format <biçim>    Tanılama biçimi: human, json (stderr, satır başına bir nesne),
+                              sarif (stdout, SARIF 2.1.0 günlüğü)
+    --lang <dil>              Mesaj dili: tr, en (varsayılan: LC_ALL, LC_MESSAGES veya LANG)
+    -v, --verbose             Derleme aşamalarını ve üretilen dosyaları bildirir
+
+Değer alan uzun seçenekler '--seçenek=değer' biçiminde de yazılabilir.
+
+Geriye dönük uyumluluk için 'ravun <dosya.rv>' komutu 'ravun run <dosya.rv>' ile aynıdır;
+-o veya --emit-* seçenekleri verilirse 'ravun build <dosya.rv>' gibi davranır.", "\
+Usage: ravun <command> [options]
+
+Commands:
+    check <file.rv>           Parses the file and runs semantic analysis
+    build <file.rv>           Compiles the file (default: executable via the C backend)
+    run <file.rv|file.rvc>    Runs the file with the interpreter or the bytecode VM
+    tokens <file.rv>          Prints the token list
+    ast <file.rv>             Prints the abstract syntax tree
+    ir <file.rv>              Prints the optimized IR module
+    fmt <file.rv> [--check]   Rewrites the file in the standard format
+    explain <RVxxxx>          Shows a detailed explanation of an error code with examples
+    repl                      Starts the interactive shell
+    help                      Shows this message
+
+Options:
+    -o <output>               Output file
+    -O0, -O1, -O2             Optimization level (default: -O0)
+    --backend <name>          Backend: c, x86-64, wasm, llvm
+    --emit-c, --emit-asm, --emit-wat, --emit-llvm, --emit-bytecode
+                              Emits intermediate output instead of an executable
+    --dump-ir-passes          Prints the IR after every optimization pass
+    --remarks                 Prints optimization remarks
+    --check                   fmt: leaves the file untouched, exits with 1 if unformatted
+    --color <when>            Colored diagnostics: auto, always, never (default: auto)
+    --error-format <format>   Diagnostic format: human, json (stderr, one object per line),
+                              sarif (stdout, SARIF 2.1.0 log)
+    --lang <language>         Message language: tr, en (default: LC_ALL, LC_MESSAGES or LANG)
+    -v, --verbose             Reports compilation stages and generated files
+
+Long options that take a value may also be written as '--option=value'.
+
+For backward compatibility 'ravun <file.rv>' is the same as 'ravun run <file.rv>';
+with -o or an --emit-* option it behaves like 'ravun build <file.rv>'.";
+    NoCommand: "Komut belirtilmedi", "No command given";
+    UnknownCommand: "Bilinmeyen komut: {0}", "Unknown command: {0}";
+    OutputNeedsPath: "-o seçeneği bir dosya yolu bekliyor", "-o expects a file path";
+    BackendNeedsName: "--backend seçeneği bir arka uç adı bekliyor", "--backend expects a backend name";
+    UnknownBackend: "Bilinmeyen arka uç: {0}", "Unknown backend: {0}";
+    ColorNeedsValue: "--color seçeneği auto, always veya never bekliyor", "--color expects auto, always or never";
+    InvalidColor: "Geçersiz --color değeri: {0}", "Invalid --color value: {0}";
+    ErrorFormatNeedsValue: "--error-format seçeneği human, json veya sarif bekliyor", "--error-format expects human, json or sarif";
+    UnknownErrorFormat: "Bilinmeyen tanılama biçimi: {0}", "Unknown diagnostic format: {0}";
+    LangNeedsValue: "--lang seçeneği tr veya en bekliyor", "--lang expects tr or en";
+    UnknownLang: "Bilinmeyen dil: {0}", "Unknown language: {0}";
+    InvalidOptLevel: "Geçersiz optimizasyon seviyesi: {0}", "Invalid optimization level: {0}";
+    UnknownOption: "Bilinmeyen seçenek: {0}", "Unknown option: {0}";
+    ExtraArgument: "Fazladan argüman: {0}", "Unexpected argument: {0}";
+    OptionTakesNoValue: "{0} seçeneği değer almaz", "{0} does not take a value";
+    MissingInput: "Kaynak dosya belirtilmedi", "No source file given";
+    ConflictingEmit: "--emit-c, --emit-asm, --emit-wat, --emit-llvm ve --emit-bytecode seçeneklerinden yalnızca biri kullanılabilir", "Only one of --emit-c, --emit-asm, --emit-wat, --emit-llvm and --emit-bytecode may be used";
+    ConflictingBackend: "{0} seçeneği '--backend {1}' ile birlikte kullanılamaz", "{0} cannot be used with '--backend {1}'";
+    ExplainNeedsCode: "'explain' komutu bir hata kodu bekliyor (örneğin RV0200)", "'explain' expects an error code (for example RV0200)";
+    UnknownErrorCode: "Bilinmeyen hata kodu: {0}", "Unknown error code: {0}";
+    CheckOnlyForFmt: "--check seçeneği yalnızca 'fmt' komutu ile kullanılabilir", "--check can only be used with the 'fmt' command";
+    
+    FileNotFound: "Dosya bulunamadı: {0}", "File not found: {0}";
+    InvalidExtension: "Geçersiz dosya uzantısı: {0}, .rv bekleniyor", "Invalid file extension: {0}, expected .rv";
+    ReadFailed: "Dosya okunamadı: {0}: {1}", "Could not read file: {0}: {1}";
+    WriteFailed: "'{0}' yazılamadı: {1}", "Could not write '{0}': {1}";
+    OutputFailed: "Çıktı yazılamadı: {0}", "Could not write output: {0}";
+    Lexing: "Lexical analiz yapılıyor...", "Running lexical analysis...";
+    Parsing: "Parsing işlemi yapılıyor...", "Parsing...";
+    Analyzing: "Semantik analiz yapılıyor...", "Running semantic analysis...";
+    AnalysisSucceeded: "Semantik analiz başarılı.", "Semantic analysis succeeded.";
+    AbortedBySyntaxErrors: "{0} sözdizimi hatası nedeniyle durduruldu", "Aborting due to {0} syntax error(s)";
+    AbortedBySemanticErrors: "{0} semantik hata nedeniyle durduruldu", "Aborting due to {0} semantic error(s)";
+    StdLibFailed: "Standart kütüphane yüklenemedi: {0}", "Could not load the standard library: {0}";
+    GeneratingIr: "IR üretiliyor ({0})...", "Generating IR ({0})...";
+    IrGenerationFailed: "IR üretim hatası: {0}", "IR generation error: {0}";
+    OptimizationFailed: "Optimizasyon hatası: {0}", "Optimization error: {0}";
+    IrWritten: "IR yazıldı: {0}", "IR written: {0}";
+    BytecodeGenerationFailed: "Bytecode üretim hatası: {0}", "Bytecode generation error: {0}";
+    BytecodeWritten: "Bytecode yazıldı: {0}", "Bytecode written: {0}";
+    BytecodeLoadFailed: "Bytecode yüklenemedi: {0}", "Could not load bytecode: {0}";
+    BytecodeUnwritableConstant: "'{0}' tipindeki değer sabit havuzuna yazılamaz", "A value of type '{0}' cannot be written to the constant pool";
+    BytecodeBadMagic: "Geçersiz bytecode dosyası: sihirli sayı eşleşmiyor", "Invalid bytecode file: magic number mismatch";
+    BytecodeUnsupportedVersion: "Desteklenmeyen bytecode sürümü: {0} (beklenen: {1})", "Unsupported bytecode version: {0} (expected {1})";
+    BytecodeInvalidAt: "Geçersiz bytecode dosyası (bayt {0}): {1}", "Invalid bytecode file (byte {0}): {1}";
+    BytecodeUnknownConstantTag: "bilinmeyen sabit etiketi 0x{0}", "unknown constant tag 0x{0}";
+    BytecodeInvalidOpcode: "geçersiz işlem kodu 0x{0}", "invalid opcode 0x{0}";
+    BytecodeTrailingData: "dosya sonunda fazladan veri", "trailing data at the end of the file";
+    BytecodeUnexpectedEof: "beklenmeyen dosya sonu", "unexpected end of file";
+    BytecodeInvalidUtf8: "geçersiz UTF-8 dizgesi", "invalid UTF-8 string";
+    BytecodeMissingEntry: "Geçersiz bytecode: giriş fonksiyonu bulunamadı", "Invalid bytecode: entry function not found";
+    BytecodeInvalidLocals: "Geçersiz bytecode: '{0}' fonksiyonunun yerel sayısı {1} geçersiz (argüman: {2}, sınır: {3})", "Invalid bytecode: function '{0}' has an invalid local count {1} (arguments: {2}, limit: {3})";
+    BytecodeOperandOutOfRange: "Geçersiz bytecode: '{0}' fonksiyonunda '{1}' sınırların dışında", "Invalid bytecode: '{1}' is out of range in function '{0}'";
+    BytecodeFunctionHeader: "fn {0} {1} (argüman: {2}, yerel: {3}):", "fn {0} {1} (arguments: {2}, locals: {3}):";
+    CodegenFailed: "Kod üretim hatası: {0}", "Code generation error: {0}";
+    WasmTextWritten: "WebAssembly metni yazıldı: {0}", "WebAssembly text written: {0}";
+    WasmModuleWritten: "WebAssembly modülü yazıldı: {0}", "WebAssembly module written: {0}";
+    LlvmIrWritten: "LLVM IR yazıldı: {0}", "LLVM IR written: {0}";
+    SourceWritten: "{0} kaynağı yazıldı: {1}", "{0} source written: {1}";
+    ExecutableCreated: "Yürütülebilir dosya oluşturuldu ({0}): {1}", "Executable created ({0}): {1}";
+    Running: "Program çalıştırılıyor...", "Running program...";
+    RuntimeError: "Çalışma zamanı hatası: {0}", "Runtime error: {0}";
+    DivisionByZero: "Sıfıra bölme", "Division by zero";
+    IndexOutOfBounds: "Dizin sınırların dışında: {0}", "Index out of bounds: {0}";
+    CallDepthExceeded: "Maksimum çağrı derinliği aşıldı ({0})", "Maximum call depth exceeded ({0})";
+    AlreadyFormatted: "Zaten biçimli: {0}", "Already formatted: {0}";
+    NotFormatted: "Biçimlendirilmemiş dosya: {0}", "File is not formatted: {0}";
+    Formatted: "Biçimlendirildi: {0}", "Formatted: {0}";
+    ExplainHint: "Bir hata hakkında ayrıntılı bilgi için 'ravun explain <kod>' çalıştırın: {0}", "For more information about an error, try 'ravun explain <code>': {0}";
+    FailingExample: "Hatalı örnek:", "Failing example:";
+    FixedExample: "Düzeltilmiş örnek:", "Fixed example:";
+    
+    ReplBanner: "Ravun etkileşimli kabuğu. Yardım için ':help', çıkmak için ':q' yazın.", "Ravun interactive shell. Type ':help' for help, ':q' to quit.";
+    ReplHelp: "\
+Komutlar:
+    :type <ifade>   İfadenin tipini gösterir
+    :ast <ifade>    İfadenin sözdizimi ağacını gösterir
+    :symbols        Sembol tablosunu gösterir
+    :help           Bu mesajı gösterir
+    :q, :quit       Kabuktan çıkar", "\
+Commands:
+    :type <expr>    Shows the type of the expression
+    :ast <expr>     Shows the syntax tree of the expression
+    :symbols        Shows the symbol table
+    :help           Shows this message
+    :q, :quit       Leaves the shell";
+    ReplCommandNeedsExpression: "{0} komutu bir ifade bekliyor", "{0} expects an expression";
+    ReplUnknownCommand: "Bilinmeyen komut: {0} (yardım için ':help')", "Unknown command: {0} (see ':help')";
+    ReplNotAnExpression: "'{0}' bir ifade değil", "'{0}' is not an expression";
+    ControlFlowOutsideFunction: "Fonksiyon dışında kontrol akışı ifadesi kullanılamaz", "Control flow statements cannot be used outside a function";
+}
+
+impl MessageId {
+    pub fn text(self) -> &'static str {
+        self.template(language())
+    }
+    
+    pub fn format(self, args: &[&dyn fmt::Display]) -> String {
+        self.format_in(language(), args)
+    }
+    
+    pub fn format_in(self, lang: Lang, args: &[&dyn fmt::Display]) -> String {
+        let template = self.template(lang);
+        let mut result = String::with_capacity(template.len());
+        let mut rest = template;
+        
+        while let Some(open) = rest.find('{') {
+            result.push_str(&rest[..open]);
+            let after = &rest[open + 1..];
+            
+            let close = after.find('}');
+            let index = close.and_then(|close| after[..close].parse::<usize>().ok());
+            match (close, index.and_then(|index| args.get(index))) {
+                (Some(close), Some(arg)) => {
+                    result.push_str(&arg.to_string());
+                    rest = &after[close + 1..];
+                },
+                _ => {
+                    result.push('{');
+                    rest = after;
+                },
+            }
+        }
+        
+        result.push_str(rest);
+        result
+    }
+}
@@ -0,0 +1,14 @@
+//! Diagnostics modülü
+pub mod codes;
+pub mod diagnostic;
+pub mod emit;
+pub mod messages;
+pub mod render;
+pub mod source;
+
+pub use codes::ErrorCode;
+pub use diagnostic::{Diagnostic, Label, Severity};
+pub use emit::{to_json, to_sarif, ErrorFormat};
+pub use messages::{language, set_language, Lang, MessageId};
+pub use render::Renderer;
+pub use source::SourceFile;
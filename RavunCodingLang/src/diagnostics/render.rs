@@ -0,0 +1,113 @@
+use std::fmt::Write;
+use crate::diagnostics::diagnostic::{Diagnostic, Label, Severity};
+use crate::diagnostics::messages::MessageId;
+use crate::diagnostics::source::SourceFile;
+
+const RESET: &str = "\x1b[0m";
+const BOLD: &str = "\x1b[1m";
+const RED: &str = "\x1b[1;31m";
+const YELLOW: &str = "\x1b[1;33m";
+const BLUE: &str = "\x1b[1;34m";
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Renderer {
+    color: bool,
+}
+
+impl Renderer {
+    pub fn new(color: bool) -> Self {
+        Renderer { color }
+    }
+    
+    pub fn render(&self, diagnostic: &Diagnostic, file: &SourceFile) -> String {
+        let severity_style = match diagnostic.severity {
+            Severity::Error => RED,
+            Severity::Warning => YELLOW,
+        };
+        
+        let mut out = String::new();
+        let title = match &diagnostic.code {
+            Some(code) => format!("{}[{}]", diagnostic.severity, code),
+            None => diagnostic.severity.to_string(),
+        };
+        let _ = writeln!(out, "{}{}", self.paint(severity_style, &title), self.paint(BOLD, &format!(": {}", diagnostic.message)));
+        
+        let mut labels: Vec<(usize, &Label)> = diagnostic.labels.iter()
+            .filter(|label| label.span.file_id == file.id)
+            .map(|label| (file.line_index(label.span.start), label))
+            .collect();
+        labels.sort_by_key(|(line, label)| (*line, label.span.start, !label.primary));
+        
+        let width = labels.iter().map(|(line, _)| (line + 1).to_string().len()).max().unwrap_or(1);
+        let pad = " ".repeat(width);
+        let gutter = self.paint(BLUE, "|");
+        
+        if let Some(anchor) = labels.iter().find(|(_, label)| label.primary).or(labels.first()) {
+            let (line, column) = file.location(anchor.1.span.start);
+            let _ = writeln!(out, "{}{} {}:{}:{}", pad, self.paint(BLUE, "-->"), file.name, line, column);
+            let _ = writeln!(out, "{} {}", pad, gutter);
+            
+            let mut previous = None;
+            for (line, label) in &labels {
+                if previous != Some(*line) {
+                    if previous.is_some_and(|previous| line > &(previous + 1)) {
+                        let _ = writeln!(out, "{}", self.paint(BLUE, "..."));
+                    }
+                    let number = format!("{:>width$}", line + 1);
+                    let source_line = format!("{} {} {}", self.paint(BLUE, &number), gutter, file.line_text(*line));
+                    let _ = writeln!(out, "{}", source_line.trim_end());
+                    previous = Some(*line);
+                }
+                
+                let _ = writeln!(out, "{} {} {}", pad, gutter, self.marker(file, *line, label, severity_style));
+            }
+        }
+        
+        for note in &diagnostic.notes {
+            let _ = writeln!(out, "{} {} {}", pad, self.paint(BLUE, "="), self.paint(BOLD, &format!("{}: {}", MessageId::NoteLabel.text(), note)));
+        }
+        if let Some(help) = &diagnostic.help {
+            let _ = writeln!(out, "{} {} {}", pad, self.paint(BLUE, "="), self.paint(BOLD, &format!("{}: {}", MessageId::HelpLabel.text(), help)));
+        }
+        
+        out
+    }
+    
+    pub fn render_all(&self, diagnostics: &[Diagnostic], file: &SourceFile) -> String {
+        diagnostics.iter()
+            .map(|diagnostic| self.render(diagnostic, file))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+    
+    fn marker(&self, file: &SourceFile, line: usize, label: &Label, severity_style: &str) -> String {
+        let text = file.line_text(line);
+        let (_, start) = file.location(label.span.start);
+        let end = if file.line_index(label.span.end) == line {
+            file.location(label.span.end).1
+        } else {
+            text.chars().count() + 1
+        };
+        
+        let indent: String = text.chars().take(start - 1)
+            .map(|c| if c == '\t' { '\t' } else { ' ' })
+            .collect();
+        let (symbol, style) = if label.primary { ('^', severity_style) } else { ('-', BLUE) };
+        let marks = symbol.to_string().repeat(end.saturating_sub(start).max(1));
+        
+        let marker = if label.message.is_empty() {
+            marks
+        } else {
+            format!("{} {}", marks, label.message)
+        };
+        format!("{}{}", indent, self.paint(style, &marker))
+    }
+    
+    fn paint(&self, style: &str, text: &str) -> String {
+        if self.color {
+            format!("{}{}{}", style, text, RESET)
+        } else {
+            text.to_string()
+        }
+    }
+}
@@ -0,0 +1,78 @@
+use crate::lexer::span::Span;
+
+#[derive(Debug, Clone)]
+pub struct SourceFile {
+    pub id: usize,
+    pub name: String,
+    pub source: String,
+    line_starts: Vec<usize>,
+}
+
+impl SourceFile {
+    pub fn new(id: usize, name: impl Into<String>, source: impl Into<String>) -> Self {
+        let source = source.into();
+        let line_starts = std::iter::once(0)
+            .chain(source.match_indices('\n').map(|(index, _)| index + 1))
+            .collect();
+        
+        SourceFile { id, name: name.into(), source, line_starts }
+    }
+    
+    pub fn line_count(&self) -> usize {
+        self.line_starts.len()
+    }
+    
+    pub fn line_index(&self, offset: usize) -> usize {
+        match self.line_starts.binary_search(&offset) {
+            Ok(index) => index,
+            Err(index) => index - 1,
+        }
+    }
+    
+    pub fn line_text(&self, index: usize) -> &str {
+        let Some(&start) = self.line_starts.get(index) else {
+            return "";
+        };
+        let end = self.line_starts.get(index + 1).map_or(self.source.len(), |next| next - 1);
+        self.source[start..end].trim_end_matches('\r')
+    }
+    
+    pub fn location(&self, offset: usize) -> (usize, usize) {
+        let offset = self.clamp(offset);
+        let index = self.line_index(offset);
+        let column = self.source[self.line_starts[index]..offset].chars().count();
+        (index + 1, column + 1)
+    }
+    
+    pub fn offset(&self, line: usize, column: usize) -> usize {
+        let Some(&start) = self.line_starts.get(line.saturating_sub(1)) else {
+            return self.source.len();
+        };
+        let text = self.line_text(line.saturating_sub(1));
+        start + text.char_indices().nth(column.saturating_sub(1)).map_or(text.len(), |(index, _)| index)
+    }
+    
+    pub fn word_span(&self, line: usize, column: usize) -> Span {
+        let start = self.offset(line, column);
+        let rest = &self.source[start..];
+        
+        let word = rest.char_indices()
+            .take_while(|(_, c)| c.is_alphanumeric() || *c == '_')
+            .last()
+            .map(|(index, c)| index + c.len_utf8());
+        let len = match word {
+            Some(len) => len,
+            None => rest.chars().next().filter(|c| *c != '\n').map_or(0, char::len_utf8),
+        };
+        
+        Span::new(self.id, start, start + len)
+    }
+    
+    fn clamp(&self, offset: usize) -> usize {
+        let mut offset = offset.min(self.source.len());
+        while !self.source.is_char_boundary(offset) {
+            offset -= 1;
+        }
+        offset
+    }
+}
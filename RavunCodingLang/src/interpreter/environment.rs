@@ -0,0 +1,45 @@
+use std::collections::HashMap;
+use crate::interpreter::value::Value;
+
+#[derive(Debug, Clone)]
+pub struct Environment {
+    scopes: Vec<HashMap<String, Value>>,
+}
+
+impl Default for Environment {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Environment {
+    pub fn new() -> Self {
+        Environment {
+            scopes: vec![HashMap::new()],
+        }
+    }
+    
+    pub fn enter_scope(&mut self) {
+        self.scopes.push(HashMap::new());
+    }
+    
+    pub fn exit_scope(&mut self) {
+        if self.scopes.len() > 1 {
+            self.scopes.pop();
+        }
+    }
+    
+    pub fn define(&mut self, name: String, value: Value) {
+        if let Some(scope) = self.scopes.last_mut() {
+            scope.insert(name, value);
+        }
+    }
+    
+    pub fn get(&self, name: &str) -> Option<&Value> {
+        self.scopes.iter().rev().find_map(|scope| scope.get(name))
+    }
+    
+    pub fn get_mut(&mut self, name: &str) -> Option<&mut Value> {
+        self.scopes.iter_mut().rev().find_map(|scope| scope.get_mut(name))
+    }
+}
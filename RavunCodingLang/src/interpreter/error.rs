@@ -0,0 +1,32 @@
+use std::fmt;
+use crate::diagnostics::MessageId;
+use crate::parser::ast::AstNode;
+
+#[derive(Debug, Clone)]
+pub struct RuntimeError {
+    pub message: String,
+    pub line: usize,
+    pub column: usize,
+}
+
+impl RuntimeError {
+    pub fn new(message: String, line: usize, column: usize) -> Self {
+        RuntimeError {
+            message,
+            line,
+            column,
+        }
+    }
+    
+    pub fn at(message: String, node: &AstNode) -> Self {
+        RuntimeError::new(message, node.line, node.column)
+    }
+}
+
+impl fmt::Display for RuntimeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} - {}", MessageId::Position.format(&[&self.line, &self.column]), self.message)
+    }
+}
+
+impl std::error::Error for RuntimeError {}
@@ -0,0 +1,636 @@
+use std::collections::HashMap;
+use std::io::{self, Write};
+use std::rc::Rc;
+use crate::diagnostics::MessageId;
+use crate::interpreter::environment::Environment;
+use crate::interpreter::error::RuntimeError;
+use crate::interpreter::value::Value;
+use crate::lexer::token::TokenType;
+use crate::parser::ast::{AstNode, AstNodeType};
+use crate::semantics::types::Type;
+
+pub const MAX_CALL_DEPTH: usize = 512;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Flow {
+    Normal,
+    Return(Value),
+    Break,
+    Continue,
+}
+
+#[derive(Clone)]
+pub struct Interpreter {
+    functions: HashMap<String, Rc<AstNode>>,
+    frames: Vec<Environment>,
+}
+
+impl Default for Interpreter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Interpreter {
+    pub fn new() -> Self {
+        Interpreter {
+            functions: HashMap::new(),
+            frames: vec![Environment::new()],
+        }
+    }
+    
+    pub fn run(&mut self, program: &AstNode) -> Result<Value, RuntimeError> {
+        self.load_program(program)?;
+        
+        if !self.functions.contains_key("main") {
+            return Err(RuntimeError::new(MessageId::MissingMain.text().to_string(), 0, 0));
+        }
+        
+        self.call_function("main", Vec::new(), program)
+    }
+    
+    pub fn load_program(&mut self, program: &AstNode) -> Result<(), RuntimeError> {
+        for child in &program.children {
+            if child.node_type == AstNodeType::FuncDecl {
+                self.define_function(child);
+            }
+        }
+        
+        for child in &program.children {
+            if child.node_type == AstNodeType::FuncDecl {
+                continue;
+            }
+            
+            match self.execute(child)? {
+                Flow::Normal => {},
+                _ => return Err(RuntimeError::at(
+                    MessageId::ControlFlowOutsideFunction.text().to_string(),
+                    child,
+                )),
+            }
+        }
+        
+        Ok(())
+    }
+    
+    pub fn define_function(&mut self, node: &AstNode) {
+        if let Some(name) = &node.value {
+            self.functions.insert(name.clone(), Rc::new(node.clone()));
+        }
+    }
+    
+    fn env(&mut self) -> &mut Environment {
+        self.frames.last_mut().expect("Çağrı yığını boş")
+    }
+    
+    fn lookup(&self, name: &str) -> Option<&Value> {
+        let frame = self.frames.last()?;
+        
+        frame.get(name).or_else(|| {
+            if self.frames.len() > 1 {
+                self.frames[0].get(name)
+            } else {
+                None
+            }
+        })
+    }
+    
+    fn lookup_mut(&mut self, name: &str) -> Option<&mut Value> {
+        let last = self.frames.len() - 1;
+        
+        if self.frames[last].get(name).is_some() {
+            self.frames[last].get_mut(name)
+        } else {
+            self.frames[0].get_mut(name)
+        }
+    }
+    
+    pub fn execute(&mut self, node: &AstNode) -> Result<Flow, RuntimeError> {
+        match node.node_type {
+            AstNodeType::VarDecl => self.execute_var_declaration(node),
+            AstNodeType::FuncDecl => {
+                self.define_function(node);
+                Ok(Flow::Normal)
+            },
+            AstNodeType::StructDecl | AstNodeType::ImplDecl | AstNodeType::ModDecl => Ok(Flow::Normal),
+            AstNodeType::BlockStmt => self.execute_block(node),
+            AstNodeType::IfStmt => self.execute_if(node),
+            AstNodeType::WhileStmt => self.execute_while(node),
+            AstNodeType::ForStmt => self.execute_for(node),
+            AstNodeType::ReturnStmt => {
+                let value = match node.children.first() {
+                    Some(expr) => self.evaluate(expr)?,
+                    None => Value::Void,
+                };
+                Ok(Flow::Return(value))
+            },
+            AstNodeType::BreakStmt => Ok(Flow::Break),
+            AstNodeType::ContinueStmt => Ok(Flow::Continue),
+            AstNodeType::ExprStmt => {
+                if let Some(expr) = node.children.first() {
+                    self.evaluate(expr)?;
+                }
+                Ok(Flow::Normal)
+            },
+            _ => {
+                self.evaluate(node)?;
+                Ok(Flow::Normal)
+            }
+        }
+    }
+    
+    fn execute_var_declaration(&mut self, node: &AstNode) -> Result<Flow, RuntimeError> {
+        let name = node.value.clone().ok_or_else(||
+            RuntimeError::at(MessageId::MissingVariableName.text().to_string(), node)
+        )?;
+        
+        let mut declared_type = None;
+        let mut value = Value::Void;
+        
+        for child in &node.children {
+            if child.node_type == AstNodeType::TypeAnnotation {
+                declared_type = child.value.as_deref().map(Type::from_name);
+            } else {
+                value = self.evaluate(child)?;
+            }
+        }
+        
+        if let Some(var_type) = declared_type {
+            value = coerce(value, &var_type);
+        }
+        
+        self.env().define(name, value);
+        
+        Ok(Flow::Normal)
+    }
+    
+    fn execute_block(&mut self, node: &AstNode) -> Result<Flow, RuntimeError> {
+        self.env().enter_scope();
+        let result = self.execute_statements(&node.children);
+        self.env().exit_scope();
+        
+        result
+    }
+    
+    fn execute_statements(&mut self, statements: &[AstNode]) -> Result<Flow, RuntimeError> {
+        for statement in statements {
+            let flow = self.execute(statement)?;
+            
+            if flow != Flow::Normal {
+                return Ok(flow);
+            }
+        }
+        
+        Ok(Flow::Normal)
+    }
+    
+    fn execute_if(&mut self, node: &AstNode) -> Result<Flow, RuntimeError> {
+        if node.children.len() < 2 {
+            return Err(RuntimeError::at(MessageId::IncompleteIf.text().to_string(), node));
+        }
+        
+        if self.evaluate_condition(&node.children[0])? {
+            self.execute(&node.children[1])
+        } else if let Some(else_branch) = node.children.get(2) {
+            self.execute(else_branch)
+        } else {
+            Ok(Flow::Normal)
+        }
+    }
+    
+    fn execute_while(&mut self, node: &AstNode) -> Result<Flow, RuntimeError> {
+        if node.children.len() < 2 {
+            return Err(RuntimeError::at(MessageId::IncompleteWhile.text().to_string(), node));
+        }
+        
+        while self.evaluate_condition(&node.children[0])? {
+            match self.execute(&node.children[1])? {
+                Flow::Break => break,
+                Flow::Return(value) => return Ok(Flow::Return(value)),
+                Flow::Normal | Flow::Continue => {},
+            }
+        }
+        
+        Ok(Flow::Normal)
+    }
+    
+    fn execute_for(&mut self, node: &AstNode) -> Result<Flow, RuntimeError> {
+        if node.children.len() < 3 {
+            return Err(RuntimeError::at(MessageId::IncompleteFor.text().to_string(), node));
+        }
+        
+        let var_name = node.children[0].value.clone().ok_or_else(||
+            RuntimeError::at(MessageId::MissingLoopVariable.text().to_string(), node)
+        )?;
+        
+        if node.children[1].node_type == AstNodeType::RangeExpr {
+            return self.execute_range_for(var_name, node);
+        }
+        
+        let items = match self.evaluate(&node.children[1])? {
+            Value::Array(elements) => elements,
+            Value::String(s) => s.chars().map(|c| Value::String(c.to_string())).collect(),
+            other => return Err(RuntimeError::at(
+                MessageId::NotIterable.format(&[&other.type_of()]),
+                &node.children[1],
+            )),
+        };
+        
+        for item in items {
+            self.env().enter_scope();
+            self.env().define(var_name.clone(), item);
+            let result = self.execute(&node.children[2]);
+            self.env().exit_scope();
+            
+            match result? {
+                Flow::Break => break,
+                Flow::Return(value) => return Ok(Flow::Return(value)),
+                Flow::Normal | Flow::Continue => {},
+            }
+        }
+        
+        Ok(Flow::Normal)
+    }
+    
+    fn execute_range_for(&mut self, var_name: String, node: &AstNode) -> Result<Flow, RuntimeError> {
+        let range = &node.children[1];
+        if range.children.len() < 2 {
+            return Err(RuntimeError::at(MessageId::IncompleteRange.text().to_string(), range));
+        }
+        
+        let (start, end) = match (self.evaluate(&range.children[0])?, self.evaluate(&range.children[1])?) {
+            (Value::Int(start), Value::Int(end)) => (start, end),
+            (start, end) => return Err(RuntimeError::at(
+                MessageId::RangeBoundNotInt.format(&[&format!("{}..{}", start.type_of(), end.type_of())]),
+                range,
+            )),
+        };
+        
+        for i in start..end {
+            self.env().enter_scope();
+            self.env().define(var_name.clone(), Value::Int(i));
+            let result = self.execute(&node.children[2]);
+            self.env().exit_scope();
+            
+            match result? {
+                Flow::Break => break,
+                Flow::Return(value) => return Ok(Flow::Return(value)),
+                Flow::Normal | Flow::Continue => {},
+            }
+        }
+        
+        Ok(Flow::Normal)
+    }
+    
+    fn evaluate_condition(&mut self, node: &AstNode) -> Result<bool, RuntimeError> {
+        let value = self.evaluate(node)?;
+        
+        value.is_truthy().ok_or_else(|| RuntimeError::at(
+            MessageId::ConditionNotBool.format(&[&value.type_of()]),
+            node,
+        ))
+    }
+    
+    pub fn evaluate(&mut self, node: &AstNode) -> Result<Value, RuntimeError> {
+        match node.node_type {
+            AstNodeType::LiteralExpr => evaluate_literal(node),
+            AstNodeType::IdentifierExpr => {
+                let name = node.value.as_deref().unwrap_or_default();
+                
+                self.lookup(name).cloned().ok_or_else(||
+                    RuntimeError::at(MessageId::Undefined.format(&[&name]), node)
+                )
+            },
+            AstNodeType::GroupExpr => match node.children.first() {
+                Some(expr) => self.evaluate(expr),
+                None => Err(RuntimeError::at(MessageId::EmptyGroup.text().to_string(), node)),
+            },
+            AstNodeType::UnaryExpr => self.evaluate_unary(node),
+            AstNodeType::BinaryExpr => self.evaluate_binary(node),
+            AstNodeType::CallExpr => self.evaluate_call(node),
+            AstNodeType::IndexExpr => self.evaluate_index(node),
+            _ => Err(RuntimeError::at(
+                MessageId::UnsupportedExpression.format(&[&format!("{:?}", node.node_type)]),
+                node,
+            )),
+        }
+    }
+    
+    fn evaluate_unary(&mut self, node: &AstNode) -> Result<Value, RuntimeError> {
+        let operand = match node.children.first() {
+            Some(expr) => self.evaluate(expr)?,
+            None => return Err(RuntimeError::at(MessageId::IncompleteUnary.text().to_string(), node)),
+        };
+        
+        let operator = node.value.as_deref().unwrap_or_default();
+        
+        unary_operation(operator, operand).map_err(|message| RuntimeError::at(message, node))
+    }
+    
+    fn evaluate_binary(&mut self, node: &AstNode) -> Result<Value, RuntimeError> {
+        if node.children.len() < 2 {
+            return Err(RuntimeError::at(MessageId::IncompleteBinary.text().to_string(), node));
+        }
+        
+        let operator = node.value.as_deref().unwrap_or_default();
+        
+        match operator {
+            "=" | "+=" | "-=" | "*=" | "/=" | "&=" | "|=" | "~=" | "<<=" | ">>=" => return self.evaluate_assignment(node, operator),
+            "&&" | "||" => return self.evaluate_logical(node, operator),
+            _ => {}
+        }
+        
+        let left = self.evaluate(&node.children[0])?;
+        let right = self.evaluate(&node.children[1])?;
+        
+        binary_operation(operator, left, right).map_err(|message| RuntimeError::at(message, node))
+    }
+    
+    fn evaluate_logical(&mut self, node: &AstNode, operator: &str) -> Result<Value, RuntimeError> {
+        let left = self.evaluate_condition(&node.children[0])?;
+        
+        // `false && ...` ve `true || ...` sağ tarafı hiç değerlendirmez.
+        if left == (operator == "||") {
+            return Ok(Value::Bool(left));
+        }
+        
+        self.evaluate_condition(&node.children[1]).map(Value::Bool)
+    }
+    
+    fn evaluate_assignment(&mut self, node: &AstNode, operator: &str) -> Result<Value, RuntimeError> {
+        let target = &node.children[0];
+        
+        if target.node_type != AstNodeType::IdentifierExpr {
+            return Err(RuntimeError::at(
+                MessageId::NotAssignable.text().to_string(),
+                node,
+            ));
+        }
+        
+        let name = target.value.as_deref().unwrap_or_default();
+        
+        // Bileşik atamada hedef, VM ve derlenen kodda olduğu gibi sağ taraftan önce okunur.
+        let current = self.lookup(name).cloned().ok_or_else(||
+            RuntimeError::at(MessageId::Undefined.format(&[&name]), target)
+        )?;
+        let right = self.evaluate(&node.children[1])?;
+        
+        let new_value = match operator {
+            "=" => coerce(right, &current.type_of()),
+            _ => {
+                let op = &operator[..operator.len() - 1];
+                binary_operation(op, current.clone(), right)
+                    .map(|value| coerce(value, &current.type_of()))
+                    .map_err(|message| RuntimeError::at(message, node))?
+            }
+        };
+        
+        if let Some(slot) = self.lookup_mut(name) {
+            *slot = new_value.clone();
+        }
+        
+        Ok(new_value)
+    }
+    
+    fn evaluate_index(&mut self, node: &AstNode) -> Result<Value, RuntimeError> {
+        if node.children.len() < 2 {
+            return Err(RuntimeError::at(MessageId::IncompleteIndex.text().to_string(), node));
+        }
+        
+        let collection = self.evaluate(&node.children[0])?;
+        let index = match self.evaluate(&node.children[1])? {
+            Value::Int(i) => i,
+            other => return Err(RuntimeError::at(
+                MessageId::IndexNotInt.format(&[&other.type_of()]),
+                &node.children[1],
+            )),
+        };
+        
+        index_operation(&collection, index).map_err(|message| RuntimeError::at(message, node))
+    }
+    
+    fn evaluate_call(&mut self, node: &AstNode) -> Result<Value, RuntimeError> {
+        let name = node.value.as_deref().unwrap_or_default();
+        
+        let mut args = Vec::new();
+        for child in &node.children {
+            args.push(self.evaluate(child)?);
+        }
+        
+        self.call_function(name, args, node)
+    }
+    
+    pub fn call_function(&mut self, name: &str, args: Vec<Value>, node: &AstNode) -> Result<Value, RuntimeError> {
+        let function = match self.functions.get(name) {
+            Some(function) => Rc::clone(function),
+            None => return call_builtin(name, args).unwrap_or_else(|| Err(
+                MessageId::Undefined.format(&[&name])
+            )).map_err(|message| RuntimeError::at(message, node)),
+        };
+        
+        if self.frames.len() > MAX_CALL_DEPTH {
+            return Err(RuntimeError::at(
+                MessageId::CallDepthExceeded.format(&[&MAX_CALL_DEPTH]),
+                node,
+            ));
+        }
+        
+        let params: Vec<&AstNode> = function.children.iter()
+            .filter(|c| c.node_type == AstNodeType::ParamDecl)
+            .collect();
+        
+        if params.len() != args.len() {
+            return Err(RuntimeError::at(
+                MessageId::ArgumentCountMismatch.format(&[&name, &params.len(), &args.len()]),
+                node,
+            ));
+        }
+        
+        let mut frame = Environment::new();
+        for (param, arg) in params.iter().zip(args) {
+            let param_name = param.value.clone().unwrap_or_default();
+            let param_type = param.children.first()
+                .and_then(|t| t.value.as_deref())
+                .map_or(Type::Any, Type::from_name);
+            
+            frame.define(param_name, coerce(arg, &param_type));
+        }
+        
+        let return_type = function.children.iter()
+            .find(|c| c.node_type == AstNodeType::TypeAnnotation)
+            .and_then(|t| t.value.as_deref())
+            .map_or(Type::Void, Type::from_name);
+        
+        let body = function.children.iter()
+            .find(|c| c.node_type == AstNodeType::BlockStmt);
+        
+        self.frames.push(frame);
+        let result = match body {
+            Some(body) => self.execute(body),
+            None => Ok(Flow::Normal),
+        };
+        self.frames.pop();
+        
+        match result? {
+            Flow::Return(value) => Ok(coerce(value, &return_type)),
+            Flow::Normal => Ok(Value::Void),
+            Flow::Break | Flow::Continue => Err(RuntimeError::at(
+                MessageId::LoopControlOutsideLoop.text().to_string(),
+                node,
+            )),
+        }
+    }
+}
+
+pub fn evaluate_literal(node: &AstNode) -> Result<Value, RuntimeError> {
+    let lexeme = node.value.clone().unwrap_or_default();
+    let token_type = node.token.as_ref().map(|t| t.token_type.clone());
+    
+    match token_type {
+        Some(TokenType::IntLiteral) => lexeme.parse::<i32>().map(Value::Int).map_err(|_|
+            RuntimeError::at(MessageId::InvalidIntegerLiteral.format(&[&lexeme]), node)
+        ),
+        Some(TokenType::FloatLiteral) => lexeme.parse::<f64>().map(Value::Float).map_err(|_|
+            RuntimeError::at(MessageId::InvalidFloatLiteral.format(&[&lexeme]), node)
+        ),
+        Some(TokenType::BoolLiteral) => Ok(Value::Bool(lexeme == "true")),
+        Some(TokenType::StringLiteral) => Ok(Value::String(lexeme)),
+        _ => Err(RuntimeError::at(MessageId::UnknownLiteral.format(&[&lexeme]), node)),
+    }
+}
+
+pub fn coerce(value: Value, target: &Type) -> Value {
+    match (value, target) {
+        (Value::Int(i), Type::Float) => Value::Float(i as f64),
+        (value, _) => value,
+    }
+}
+
+pub fn unary_operation(operator: &str, operand: Value) -> Result<Value, String> {
+    match (operator, &operand) {
+        ("-", Value::Int(i)) => Ok(Value::Int(i.wrapping_neg())),
+        ("-", Value::Float(f)) => Ok(Value::Float(-f)),
+        ("!", Value::Bool(b)) => Ok(Value::Bool(!b)),
+        ("~", Value::Int(i)) => Ok(Value::Int(!i)),
+        _ => Err(MessageId::InvalidUnaryOperand.format(&[&operator, &operand.type_of()])),
+    }
+}
+
+pub fn binary_operation(operator: &str, left: Value, right: Value) -> Result<Value, String> {
+    match operator {
+        "+" | "-" | "*" | "/" | "%" | "^" => arithmetic(operator, left, right),
+        "&" | "|" | "~" | "<<" | ">>" => bitwise(operator, left, right),
+        "==" => Ok(Value::Bool(values_equal(&left, &right))),
+        "!=" => Ok(Value::Bool(!values_equal(&left, &right))),
+        "<" | ">" | "<=" | ">=" => compare(operator, left, right),
+        _ => Err(MessageId::UnknownOperator.format(&[&operator])),
+    }
+}
+
+fn arithmetic(operator: &str, left: Value, right: Value) -> Result<Value, String> {
+    match (left, right) {
+        (Value::Int(a), Value::Int(b)) => match operator {
+            "+" => Ok(Value::Int(a.wrapping_add(b))),
+            "-" => Ok(Value::Int(a.wrapping_sub(b))),
+            "*" => Ok(Value::Int(a.wrapping_mul(b))),
+            "/" if b == 0 => Err(MessageId::DivisionByZero.text().to_string()),
+            "/" => Ok(Value::Int(a.wrapping_div(b))),
+            "%" if b == 0 => Err(MessageId::DivisionByZero.text().to_string()),
+            "%" => Ok(Value::Int(a.wrapping_rem(b))),
+            _ => Ok(Value::Int(int_pow(a, b))),
+        },
+        (Value::String(a), Value::String(b)) if operator == "+" => Ok(Value::String(a + &b)),
+        (left, right) => match (left.as_float(), right.as_float()) {
+            (Some(a), Some(b)) => Ok(Value::Float(match operator {
+                "+" => a + b,
+                "-" => a - b,
+                "*" => a * b,
+                "/" => a / b,
+                "%" => a % b,
+                _ => a.powf(b),
+            })),
+            _ => Err(MessageId::InvalidArithmeticOperands.format(&[&operator, &left.type_of(), &right.type_of()])),
+        },
+    }
+}
+
+// Derlenmiş arka uçlardaki rv_int_pow ile aynı anlam: negatif üste yalnızca 1 ve -1 tabanları sıfırdan farklıdır.
+pub fn int_pow(base: i32, exponent: i32) -> i32 {
+    match u32::try_from(exponent) {
+        Ok(exponent) => base.wrapping_pow(exponent),
+        Err(_) if base == 1 => 1,
+        Err(_) if base == -1 => if exponent % 2 == 0 { 1 } else { -1 },
+        Err(_) => 0,
+    }
+}
+
+fn bitwise(operator: &str, left: Value, right: Value) -> Result<Value, String> {
+    match (left, right) {
+        (Value::Int(a), Value::Int(b)) => Ok(Value::Int(match operator {
+            "&" => a & b,
+            "|" => a | b,
+            "~" => a ^ b,
+            // Kaydırma miktarı, yerel arka uçlarda olduğu gibi 32'ye göre sarılır.
+            "<<" => a.wrapping_shl(b as u32),
+            _ => a.wrapping_shr(b as u32),
+        })),
+        (left, right) => Err(MessageId::InvalidIntegerOperands.format(&[&operator, &left.type_of(), &right.type_of()])),
+    }
+}
+
+pub fn index_operation(collection: &Value, index: i32) -> Result<Value, String> {
+    let element = match collection {
+        Value::Array(elements) => usize::try_from(index).ok().and_then(|i| elements.get(i).cloned()),
+        Value::String(s) => usize::try_from(index).ok()
+            .and_then(|i| s.chars().nth(i))
+            .map(|c| Value::String(c.to_string())),
+        other => return Err(MessageId::NotIndexable.format(&[&other.type_of()])),
+    };
+    
+    element.ok_or_else(|| MessageId::IndexOutOfBounds.format(&[&index]))
+}
+
+fn values_equal(left: &Value, right: &Value) -> bool {
+    match (left.as_float(), right.as_float()) {
+        (Some(a), Some(b)) => a == b,
+        _ => left == right,
+    }
+}
+
+fn compare(operator: &str, left: Value, right: Value) -> Result<Value, String> {
+    let ordering = match (&left, &right) {
+        (Value::Int(a), Value::Int(b)) => a.partial_cmp(b),
+        (Value::String(a), Value::String(b)) => a.partial_cmp(b),
+        _ => match (left.as_float(), right.as_float()) {
+            (Some(a), Some(b)) => a.partial_cmp(&b),
+            _ => return Err(MessageId::InvalidComparisonOperands.format(&[&operator, &left.type_of(), &right.type_of()])),
+        },
+    };
+    
+    let result = match ordering {
+        Some(ordering) => match operator {
+            "<" => ordering.is_lt(),
+            ">" => ordering.is_gt(),
+            "<=" => ordering.is_le(),
+            _ => ordering.is_ge(),
+        },
+        None => false,
+    };
+    
+    Ok(Value::Bool(result))
+}
+
+pub fn call_builtin(name: &str, args: Vec<Value>) -> Option<Result<Value, String>> {
+    let text = args.iter().map(|a| a.to_string()).collect::<Vec<_>>().join(" ");
+    let mut stdout = io::stdout();
+    
+    let result = match name {
+        "print" => write!(stdout, "{}", text),
+        "println" => writeln!(stdout, "{}", text),
+        _ => return None,
+    };
+    
+    Some(result.and_then(|_| stdout.flush())
+        .map(|_| Value::Void)
+        .map_err(|err| MessageId::OutputFailed.format(&[&err])))
+}
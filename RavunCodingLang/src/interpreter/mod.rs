@@ -0,0 +1,9 @@
+#[allow(clippy::module_inception)]
+pub mod interpreter;
+pub mod environment;
+pub mod error;
+pub mod value;
+
+pub use error::RuntimeError;
+pub use interpreter::Interpreter;
+pub use value::Value;
@@ -0,0 +1,104 @@
+use std::collections::HashMap;
+use crate::ir::function::Function;
+use crate::ir::instruction::BlockId;
+
+#[derive(Debug, Clone)]
+pub struct DominatorTree {
+    order: Vec<BlockId>,
+    idom: HashMap<BlockId, BlockId>,
+}
+
+impl DominatorTree {
+    pub fn compute(function: &Function) -> Self {
+        let order = function.reverse_postorder();
+        let index: HashMap<BlockId, usize> = order.iter().enumerate().map(|(i, b)| (*b, i)).collect();
+        let preds = function.predecessors();
+        
+        let mut idom: HashMap<BlockId, BlockId> = HashMap::new();
+        if let Some(&entry) = order.first() {
+            idom.insert(entry, entry);
+        }
+        
+        let mut changed = true;
+        while changed {
+            changed = false;
+            
+            for &block in order.iter().skip(1) {
+                let mut new_idom: Option<BlockId> = None;
+                
+                for pred in preds.get(&block).into_iter().flatten() {
+                    if !idom.contains_key(pred) {
+                        continue;
+                    }
+                    
+                    new_idom = Some(match new_idom {
+                        None => *pred,
+                        Some(current) => intersect(&idom, &index, *pred, current),
+                    });
+                }
+                
+                if let Some(new_idom) = new_idom
+                    && idom.get(&block) != Some(&new_idom) {
+                    idom.insert(block, new_idom);
+                    changed = true;
+                }
+            }
+        }
+        
+        DominatorTree { order, idom }
+    }
+    
+    pub fn reverse_postorder(&self) -> &[BlockId] {
+        &self.order
+    }
+    
+    pub fn is_reachable(&self, block: BlockId) -> bool {
+        self.idom.contains_key(&block)
+    }
+    
+    pub fn idom(&self, block: BlockId) -> Option<BlockId> {
+        self.idom.get(&block).copied().filter(|&d| d != block)
+    }
+    
+    pub fn dominates(&self, a: BlockId, b: BlockId) -> bool {
+        if !self.is_reachable(b) {
+            return false;
+        }
+        
+        let mut current = b;
+        loop {
+            if current == a {
+                return true;
+            }
+            match self.idom(current) {
+                Some(parent) => current = parent,
+                None => return false,
+            }
+        }
+    }
+    
+    pub fn strictly_dominates(&self, a: BlockId, b: BlockId) -> bool {
+        a != b && self.dominates(a, b)
+    }
+    
+    pub fn children(&self, block: BlockId) -> Vec<BlockId> {
+        self.order.iter().copied().filter(|&b| self.idom(b) == Some(block)).collect()
+    }
+}
+
+fn intersect(
+    idom: &HashMap<BlockId, BlockId>,
+    index: &HashMap<BlockId, usize>,
+    mut a: BlockId,
+    mut b: BlockId,
+) -> BlockId {
+    while a != b {
+        while index[&a] > index[&b] {
+            a = idom[&a];
+        }
+        while index[&b] > index[&a] {
+            b = idom[&b];
+        }
+    }
+    a
+}
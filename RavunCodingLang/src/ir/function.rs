@@ -0,0 +1,323 @@
+use std::collections::{HashMap, HashSet};
+use crate::ir::instruction::{BlockId, Constant, Instruction, InstructionKind, Operand, Terminator, ValueId};
+use crate::semantics::types::Type;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BasicBlock {
+    pub id: BlockId,
+    pub instructions: Vec<Instruction>,
+    pub terminator: Option<Terminator>,
+}
+
+impl BasicBlock {
+    pub fn new(id: BlockId) -> Self {
+        BasicBlock {
+            id,
+            instructions: Vec::new(),
+            terminator: None,
+        }
+    }
+    
+    pub fn successors(&self) -> Vec<BlockId> {
+        self.terminator.as_ref().map_or_else(Vec::new, |t| t.successors())
+    }
+    
+    pub fn phis(&self) -> impl Iterator<Item = &Instruction> {
+        self.instructions.iter().take_while(|i| i.is_phi())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Param {
+    pub id: ValueId,
+    pub ty: Type,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum InlineHint {
+    #[default]
+    None,
+    Always,
+    Never,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Function {
+    pub name: String,
+    pub params: Vec<Param>,
+    pub return_type: Type,
+    pub blocks: Vec<BasicBlock>,
+    pub inline_hint: InlineHint,
+    next_value: usize,
+    next_block: usize,
+}
+
+impl Function {
+    pub fn new(name: String, return_type: Type) -> Self {
+        Function {
+            name,
+            params: Vec::new(),
+            return_type,
+            blocks: Vec::new(),
+            inline_hint: InlineHint::None,
+            next_value: 0,
+            next_block: 0,
+        }
+    }
+    
+    pub fn new_value(&mut self) -> ValueId {
+        let id = ValueId(self.next_value);
+        self.next_value += 1;
+        id
+    }
+    
+    pub fn add_param(&mut self, ty: Type) -> ValueId {
+        let id = self.new_value();
+        self.params.push(Param { id, ty });
+        id
+    }
+    
+    pub fn add_block(&mut self) -> BlockId {
+        let id = BlockId(self.next_block);
+        self.next_block += 1;
+        self.blocks.push(BasicBlock::new(id));
+        id
+    }
+    
+    pub fn sync_counters(&mut self) {
+        let max_value = self.params.iter().map(|p| p.id.0)
+            .chain(self.blocks.iter().flat_map(|b| b.instructions.iter().filter_map(|i| i.result.map(|r| r.0))))
+            .max();
+        self.next_value = max_value.map_or(0, |v| v + 1);
+        self.next_block = self.blocks.iter().map(|b| b.id.0 + 1).max().unwrap_or(0);
+    }
+    
+    pub fn entry(&self) -> Option<BlockId> {
+        self.blocks.first().map(|b| b.id)
+    }
+    
+    pub fn block(&self, id: BlockId) -> Option<&BasicBlock> {
+        self.blocks.iter().find(|b| b.id == id)
+    }
+    
+    pub fn block_mut(&mut self, id: BlockId) -> Option<&mut BasicBlock> {
+        self.blocks.iter_mut().find(|b| b.id == id)
+    }
+    
+    pub fn value_types(&self) -> HashMap<ValueId, Type> {
+        let mut types = HashMap::new();
+        
+        for param in &self.params {
+            types.insert(param.id, param.ty.clone());
+        }
+        
+        for block in &self.blocks {
+            for instruction in &block.instructions {
+                if let Some(result) = instruction.result {
+                    types.insert(result, instruction.ty.clone());
+                }
+            }
+        }
+        
+        types
+    }
+    
+    pub fn operand_type(&self, operand: &Operand, types: &HashMap<ValueId, Type>) -> Type {
+        match operand {
+            Operand::Const(constant) => constant.type_of(),
+            Operand::Value(id) => types.get(id).cloned().unwrap_or(Type::Error),
+        }
+    }
+    
+    pub fn predecessors(&self) -> HashMap<BlockId, Vec<BlockId>> {
+        let mut preds: HashMap<BlockId, Vec<BlockId>> = HashMap::new();
+        
+        for block in &self.blocks {
+            preds.entry(block.id).or_default();
+        }
+        
+        for block in &self.blocks {
+            for successor in block.successors() {
+                let entry = preds.entry(successor).or_default();
+                if !entry.contains(&block.id) {
+                    entry.push(block.id);
+                }
+            }
+        }
+        
+        preds
+    }
+    
+    pub fn reverse_postorder(&self) -> Vec<BlockId> {
+        let mut order = Vec::new();
+        let mut visited = HashSet::new();
+        
+        if let Some(entry) = self.entry() {
+            let mut stack = vec![(entry, 0)];
+            visited.insert(entry);
+            
+            while let Some((block, index)) = stack.pop() {
+                let successors = self.block(block).map_or_else(Vec::new, |b| b.successors());
+                
+                if let Some(&next) = successors.iter().rev().nth(index) {
+                    stack.push((block, index + 1));
+                    if visited.insert(next) {
+                        stack.push((next, 0));
+                    }
+                } else {
+                    order.push(block);
+                }
+            }
+        }
+        
+        order.reverse();
+        order
+    }
+    
+    pub fn remove_unreachable_blocks(&mut self) -> bool {
+        let reachable: HashSet<BlockId> = self.reverse_postorder().into_iter().collect();
+        let before = self.blocks.len();
+        
+        self.blocks.retain(|b| reachable.contains(&b.id));
+        
+        for block in &mut self.blocks {
+            for instruction in &mut block.instructions {
+                if let InstructionKind::Phi(incoming) = &mut instruction.kind {
+                    incoming.retain(|(pred, _)| reachable.contains(pred));
+                }
+            }
+        }
+        
+        self.blocks.len() != before
+    }
+    
+    pub fn renumber(&mut self) {
+        let order = self.reverse_postorder();
+        let mut position: HashMap<BlockId, usize> = order.iter().enumerate().map(|(i, b)| (*b, i)).collect();
+        for block in &self.blocks {
+            let next = position.len();
+            position.entry(block.id).or_insert(next);
+        }
+        self.blocks.sort_by_key(|b| position[&b.id]);
+        
+        let block_map: HashMap<BlockId, BlockId> = position.iter().map(|(old, new)| (*old, BlockId(*new))).collect();
+        let mut value_map = HashMap::new();
+        
+        for param in &mut self.params {
+            let new = ValueId(value_map.len());
+            value_map.insert(param.id, new);
+            param.id = new;
+        }
+        
+        for block in &mut self.blocks {
+            for instruction in &mut block.instructions {
+                if let Some(result) = &mut instruction.result {
+                    let new = ValueId(value_map.len());
+                    value_map.insert(*result, new);
+                    *result = new;
+                }
+            }
+        }
+        
+        for block in &mut self.blocks {
+            block.id = block_map[&block.id];
+            
+            for instruction in &mut block.instructions {
+                if let InstructionKind::Phi(incoming) = &mut instruction.kind {
+                    for (pred, _) in incoming.iter_mut() {
+                        if let Some(new) = block_map.get(pred) {
+                            *pred = *new;
+                        }
+                    }
+                }
+                for operand in instruction.operands_mut() {
+                    if let Operand::Value(id) = operand
+                        && let Some(new) = value_map.get(id) {
+                        *id = *new;
+                    }
+                }
+            }
+            
+            if let Some(terminator) = &mut block.terminator {
+                for successor in terminator.successors_mut() {
+                    if let Some(new) = block_map.get(successor) {
+                        *successor = *new;
+                    }
+                }
+                for operand in terminator.operands_mut() {
+                    if let Operand::Value(id) = operand
+                        && let Some(new) = value_map.get(id) {
+                        *id = *new;
+                    }
+                }
+            }
+        }
+        
+        self.sync_counters();
+    }
+    
+    pub fn replace_uses(&mut self, replacements: &HashMap<ValueId, Operand>) {
+        if replacements.is_empty() {
+            return;
+        }
+        
+        let resolve = |operand: &mut Operand| {
+            let mut steps = 0;
+            while let Operand::Value(id) = operand {
+                match replacements.get(id) {
+                    Some(replacement) if steps <= replacements.len() => {
+                        *operand = replacement.clone();
+                        steps += 1;
+                    },
+                    _ => break,
+                }
+            }
+        };
+        
+        for block in &mut self.blocks {
+            for instruction in &mut block.instructions {
+                for operand in instruction.operands_mut() {
+                    resolve(operand);
+                }
+            }
+            
+            if let Some(terminator) = &mut block.terminator {
+                for operand in terminator.operands_mut() {
+                    resolve(operand);
+                }
+            }
+        }
+    }
+    
+    pub fn instruction_count(&self) -> usize {
+        self.blocks.iter().map(|b| b.instructions.len() + 1).sum()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Global {
+    pub name: String,
+    pub ty: Type,
+    pub is_mutable: bool,
+    pub initializer: Constant,
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Module {
+    pub globals: Vec<Global>,
+    pub functions: Vec<Function>,
+}
+
+impl Module {
+    pub fn new() -> Self {
+        Module::default()
+    }
+    
+    pub fn function(&self, name: &str) -> Option<&Function> {
+        self.functions.iter().find(|f| f.name == name)
+    }
+    
+    pub fn global(&self, name: &str) -> Option<&Global> {
+        self.globals.iter().find(|g| g.name == name)
+    }
+}
@@ -0,0 +1,311 @@
+use crate::semantics::types::Type;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ValueId(pub usize);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct BlockId(pub usize);
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Constant {
+    Int(i32),
+    Float(f64),
+    Bool(bool),
+    String(String),
+}
+
+impl Constant {
+    pub fn type_of(&self) -> Type {
+        match self {
+            Constant::Int(_) => Type::Int,
+            Constant::Float(_) => Type::Float,
+            Constant::Bool(_) => Type::Bool,
+            Constant::String(_) => Type::String,
+        }
+    }
+    
+    pub fn zero_of(ty: &Type) -> Option<Constant> {
+        match ty {
+            Type::Int => Some(Constant::Int(0)),
+            Type::Float => Some(Constant::Float(0.0)),
+            Type::Bool => Some(Constant::Bool(false)),
+            Type::String => Some(Constant::String(String::new())),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Operand {
+    Value(ValueId),
+    Const(Constant),
+}
+
+impl Operand {
+    pub fn as_value(&self) -> Option<ValueId> {
+        match self {
+            Operand::Value(id) => Some(*id),
+            Operand::Const(_) => None,
+        }
+    }
+    
+    pub fn as_const(&self) -> Option<&Constant> {
+        match self {
+            Operand::Const(c) => Some(c),
+            Operand::Value(_) => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BinaryOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Rem,
+    Pow,
+    And,
+    Or,
+    Xor,
+    Shl,
+    Shr,
+    Eq,
+    Ne,
+    Lt,
+    Gt,
+    Le,
+    Ge,
+}
+
+impl BinaryOp {
+    pub fn from_operator(operator: &str) -> Option<BinaryOp> {
+        match operator {
+            "+" => Some(BinaryOp::Add),
+            "-" => Some(BinaryOp::Sub),
+            "*" => Some(BinaryOp::Mul),
+            "/" => Some(BinaryOp::Div),
+            "%" => Some(BinaryOp::Rem),
+            "^" => Some(BinaryOp::Pow),
+            "&" => Some(BinaryOp::And),
+            "|" => Some(BinaryOp::Or),
+            "~" => Some(BinaryOp::Xor),
+            "<<" => Some(BinaryOp::Shl),
+            ">>" => Some(BinaryOp::Shr),
+            "==" => Some(BinaryOp::Eq),
+            "!=" => Some(BinaryOp::Ne),
+            "<" => Some(BinaryOp::Lt),
+            ">" => Some(BinaryOp::Gt),
+            "<=" => Some(BinaryOp::Le),
+            ">=" => Some(BinaryOp::Ge),
+            _ => None,
+        }
+    }
+    
+    pub fn mnemonic(&self) -> &'static str {
+        match self {
+            BinaryOp::Add => "add",
+            BinaryOp::Sub => "sub",
+            BinaryOp::Mul => "mul",
+            BinaryOp::Div => "div",
+            BinaryOp::Rem => "rem",
+            BinaryOp::Pow => "pow",
+            BinaryOp::And => "and",
+            BinaryOp::Or => "or",
+            BinaryOp::Xor => "xor",
+            BinaryOp::Shl => "shl",
+            BinaryOp::Shr => "shr",
+            BinaryOp::Eq => "eq",
+            BinaryOp::Ne => "ne",
+            BinaryOp::Lt => "lt",
+            BinaryOp::Gt => "gt",
+            BinaryOp::Le => "le",
+            BinaryOp::Ge => "ge",
+        }
+    }
+    
+    pub fn from_mnemonic(mnemonic: &str) -> Option<BinaryOp> {
+        match mnemonic {
+            "add" => Some(BinaryOp::Add),
+            "sub" => Some(BinaryOp::Sub),
+            "mul" => Some(BinaryOp::Mul),
+            "div" => Some(BinaryOp::Div),
+            "rem" => Some(BinaryOp::Rem),
+            "pow" => Some(BinaryOp::Pow),
+            "and" => Some(BinaryOp::And),
+            "or" => Some(BinaryOp::Or),
+            "xor" => Some(BinaryOp::Xor),
+            "shl" => Some(BinaryOp::Shl),
+            "shr" => Some(BinaryOp::Shr),
+            "eq" => Some(BinaryOp::Eq),
+            "ne" => Some(BinaryOp::Ne),
+            "lt" => Some(BinaryOp::Lt),
+            "gt" => Some(BinaryOp::Gt),
+            "le" => Some(BinaryOp::Le),
+            "ge" => Some(BinaryOp::Ge),
+            _ => None,
+        }
+    }
+    
+    pub fn is_comparison(&self) -> bool {
+        matches!(self, BinaryOp::Eq | BinaryOp::Ne | BinaryOp::Lt | BinaryOp::Gt | BinaryOp::Le | BinaryOp::Ge)
+    }
+    
+    pub fn is_bitwise(&self) -> bool {
+        matches!(self, BinaryOp::And | BinaryOp::Or | BinaryOp::Xor | BinaryOp::Shl | BinaryOp::Shr)
+    }
+    
+    pub fn is_commutative(&self) -> bool {
+        matches!(self, BinaryOp::Add | BinaryOp::Mul | BinaryOp::And | BinaryOp::Or | BinaryOp::Xor | BinaryOp::Eq | BinaryOp::Ne)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UnaryOp {
+    Neg,
+    Not,
+}
+
+impl UnaryOp {
+    pub fn mnemonic(&self) -> &'static str {
+        match self {
+            UnaryOp::Neg => "neg",
+            UnaryOp::Not => "not",
+        }
+    }
+    
+    pub fn from_mnemonic(mnemonic: &str) -> Option<UnaryOp> {
+        match mnemonic {
+            "neg" => Some(UnaryOp::Neg),
+            "not" => Some(UnaryOp::Not),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum InstructionKind {
+    Binary(BinaryOp, Operand, Operand),
+    Unary(UnaryOp, Operand),
+    IntToFloat(Operand),
+    Call(String, Vec<Operand>),
+    Phi(Vec<(BlockId, Operand)>),
+    LoadGlobal(String),
+    StoreGlobal(String, Operand),
+    Length(Operand),
+    Index(Operand, Operand),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Instruction {
+    pub result: Option<ValueId>,
+    pub ty: Type,
+    pub kind: InstructionKind,
+}
+
+impl Instruction {
+    pub fn new(result: Option<ValueId>, ty: Type, kind: InstructionKind) -> Self {
+        Instruction {
+            result,
+            ty,
+            kind,
+        }
+    }
+    
+    pub fn is_phi(&self) -> bool {
+        matches!(self.kind, InstructionKind::Phi(_))
+    }
+    
+    pub fn has_side_effects(&self) -> bool {
+        matches!(self.kind, InstructionKind::Call(_, _) | InstructionKind::StoreGlobal(_, _))
+    }
+    
+    pub fn may_trap(&self) -> bool {
+        match &self.kind {
+            InstructionKind::Binary(BinaryOp::Div | BinaryOp::Rem, _, divisor) => {
+                self.ty == Type::Int && !matches!(divisor, Operand::Const(Constant::Int(d)) if *d != 0)
+            },
+            InstructionKind::Index(_, _) => true,
+            _ => false,
+        }
+    }
+    
+    pub fn is_pure(&self) -> bool {
+        matches!(
+            self.kind,
+            InstructionKind::Binary(_, _, _)
+                | InstructionKind::Unary(_, _)
+                | InstructionKind::IntToFloat(_)
+                | InstructionKind::Length(_)
+                | InstructionKind::Index(_, _)
+        )
+    }
+    
+    pub fn operands(&self) -> Vec<&Operand> {
+        match &self.kind {
+            InstructionKind::Binary(_, lhs, rhs) | InstructionKind::Index(lhs, rhs) => vec![lhs, rhs],
+            InstructionKind::Unary(_, operand)
+            | InstructionKind::IntToFloat(operand)
+            | InstructionKind::StoreGlobal(_, operand)
+            | InstructionKind::Length(operand) => vec![operand],
+            InstructionKind::Call(_, args) => args.iter().collect(),
+            InstructionKind::Phi(incoming) => incoming.iter().map(|(_, op)| op).collect(),
+            InstructionKind::LoadGlobal(_) => Vec::new(),
+        }
+    }
+    
+    pub fn operands_mut(&mut self) -> Vec<&mut Operand> {
+        match &mut self.kind {
+            InstructionKind::Binary(_, lhs, rhs) | InstructionKind::Index(lhs, rhs) => vec![lhs, rhs],
+            InstructionKind::Unary(_, operand)
+            | InstructionKind::IntToFloat(operand)
+            | InstructionKind::StoreGlobal(_, operand)
+            | InstructionKind::Length(operand) => vec![operand],
+            InstructionKind::Call(_, args) => args.iter_mut().collect(),
+            InstructionKind::Phi(incoming) => incoming.iter_mut().map(|(_, op)| op).collect(),
+            InstructionKind::LoadGlobal(_) => Vec::new(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Terminator {
+    Return(Option<Operand>),
+    Jump(BlockId),
+    Branch(Operand, BlockId, BlockId),
+    Unreachable,
+}
+
+impl Terminator {
+    pub fn successors(&self) -> Vec<BlockId> {
+        match self {
+            Terminator::Jump(target) => vec![*target],
+            Terminator::Branch(_, then_block, else_block) => vec![*then_block, *else_block],
+            Terminator::Return(_) | Terminator::Unreachable => Vec::new(),
+        }
+    }
+    
+    pub fn successors_mut(&mut self) -> Vec<&mut BlockId> {
+        match self {
+            Terminator::Jump(target) => vec![target],
+            Terminator::Branch(_, then_block, else_block) => vec![then_block, else_block],
+            Terminator::Return(_) | Terminator::Unreachable => Vec::new(),
+        }
+    }
+    
+    pub fn operands(&self) -> Vec<&Operand> {
+        match self {
+            Terminator::Return(Some(value)) => vec![value],
+            Terminator::Branch(condition, _, _) => vec![condition],
+            _ => Vec::new(),
+        }
+    }
+    
+    pub fn operands_mut(&mut self) -> Vec<&mut Operand> {
+        match self {
+            Terminator::Return(Some(value)) => vec![value],
+            Terminator::Branch(condition, _, _) => vec![condition],
+            _ => Vec::new(),
+        }
+    }
+}
@@ -0,0 +1,80 @@
+use std::collections::{BTreeSet, HashMap};
+use crate::ir::dominators::DominatorTree;
+use crate::ir::function::Function;
+use crate::ir::instruction::BlockId;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Loop {
+    pub header: BlockId,
+    pub latches: Vec<BlockId>,
+    pub blocks: BTreeSet<BlockId>,
+}
+
+impl Loop {
+    pub fn contains(&self, block: BlockId) -> bool {
+        self.blocks.contains(&block)
+    }
+    
+    pub fn outside_predecessors(&self, preds: &HashMap<BlockId, Vec<BlockId>>) -> Vec<BlockId> {
+        preds.get(&self.header)
+            .map(|p| p.iter().copied().filter(|b| !self.contains(*b)).collect())
+            .unwrap_or_default()
+    }
+    
+    pub fn preheader(&self, function: &Function, preds: &HashMap<BlockId, Vec<BlockId>>) -> Option<BlockId> {
+        match self.outside_predecessors(preds).as_slice() {
+            [single] if function.block(*single)?.successors() == [self.header] => Some(*single),
+            _ => None,
+        }
+    }
+    
+    pub fn exit_edges(&self, function: &Function) -> Vec<(BlockId, BlockId)> {
+        self.blocks.iter()
+            .filter_map(|id| function.block(*id))
+            .flat_map(|block| block.successors().into_iter().map(move |s| (block.id, s)))
+            .filter(|(_, to)| !self.contains(*to))
+            .collect()
+    }
+}
+
+pub fn find_loops(function: &Function) -> Vec<Loop> {
+    let dominators = DominatorTree::compute(function);
+    let preds = function.predecessors();
+    let mut loops: Vec<Loop> = Vec::new();
+    
+    for &block in dominators.reverse_postorder() {
+        let Some(basic_block) = function.block(block) else {
+            continue;
+        };
+        
+        for header in basic_block.successors() {
+            if !dominators.dominates(header, block) {
+                continue;
+            }
+            
+            match loops.iter_mut().find(|l| l.header == header) {
+                Some(existing) => existing.latches.push(block),
+                None => loops.push(Loop { header, latches: vec![block], blocks: BTreeSet::new() }),
+            }
+        }
+    }
+    
+    for natural_loop in &mut loops {
+        natural_loop.blocks.insert(natural_loop.header);
+        let mut worklist = natural_loop.latches.clone();
+        
+        while let Some(block) = worklist.pop() {
+            if !natural_loop.blocks.insert(block) {
+                continue;
+            }
+            for pred in preds.get(&block).into_iter().flatten() {
+                if dominators.is_reachable(*pred) {
+                    worklist.push(*pred);
+                }
+            }
+        }
+    }
+    
+    loops.sort_by_key(|l| l.blocks.len());
+    loops
+}
@@ -0,0 +1,1021 @@
+use std::collections::{HashMap, HashSet};
+use crate::diagnostics::MessageId;
+use crate::ir::error::IrError;
+use crate::ir::function::{Function, Global, InlineHint, Module};
+use crate::ir::instruction::{
+    BinaryOp, BlockId, Constant, Instruction, InstructionKind, Operand, Terminator, UnaryOp, ValueId,
+};
+use crate::lexer::token::TokenType;
+use crate::parser::ast::{AstNode, AstNodeType};
+use crate::semantics::symbol_table::SymbolTable;
+use crate::semantics::types::Type;
+
+pub const BUILTINS: [&str; 2] = ["print", "println"];
+pub const INIT_FUNCTION: &str = "ravun.init";
+
+#[derive(Debug, Clone)]
+struct Signature {
+    params: Vec<Type>,
+    return_type: Type,
+}
+
+pub struct Lowering<'a> {
+    symbols: &'a SymbolTable,
+    signatures: HashMap<String, Signature>,
+    globals: HashMap<String, Type>,
+    has_initializer: bool,
+}
+
+pub fn lower_program(program: &AstNode, symbols: &SymbolTable) -> Result<Module, IrError> {
+    Lowering::new(symbols).lower(program)
+}
+
+impl<'a> Lowering<'a> {
+    pub fn new(symbols: &'a SymbolTable) -> Self {
+        Lowering {
+            symbols,
+            signatures: HashMap::new(),
+            globals: HashMap::new(),
+            has_initializer: false,
+        }
+    }
+    
+    fn resolved_signature(&self, name: &str) -> Option<Signature> {
+        match &self.symbols.resolve_function(name).ok()?.symbol_type {
+            Type::Function(params, return_type) if params.iter().chain([&**return_type]).all(is_resolved) => {
+                Some(Signature {
+                    params: params.clone(),
+                    return_type: (**return_type).clone(),
+                })
+            },
+            _ => None,
+        }
+    }
+    
+    fn resolved_type(&self, name: &str) -> Option<Type> {
+        self.symbols.resolve(name).ok()
+            .map(|symbol| symbol.symbol_type.clone())
+            .filter(is_resolved)
+    }
+    
+    pub fn lower(&mut self, program: &AstNode) -> Result<Module, IrError> {
+        let mut module = Module::new();
+        
+        for child in &program.children {
+            if child.node_type == AstNodeType::FuncDecl {
+                let name = child.value.clone().ok_or_else(||
+                    IrError::at(MessageId::MissingFunctionName.text().to_string(), child)
+                )?;
+                let signature = self.resolved_signature(&name).unwrap_or_else(|| signature_of(child));
+                self.signatures.insert(name, signature);
+            }
+        }
+        
+        let mut initializers = Vec::new();
+        for child in &program.children {
+            match child.node_type {
+                AstNodeType::VarDecl => {
+                    let (global, initializer) = self.lower_global(child)?;
+                    self.globals.insert(global.name.clone(), global.ty.clone());
+                    if let Some(expr) = initializer {
+                        initializers.push((global.name.clone(), global.ty.clone(), expr));
+                    }
+                    module.globals.push(global);
+                },
+                AstNodeType::FuncDecl | AstNodeType::StructDecl | AstNodeType::ImplDecl | AstNodeType::ModDecl => {},
+                _ => return Err(IrError::at(
+                    MessageId::UnsupportedTopLevel.format(&[&format!("{:?}", child.node_type)]),
+                    child,
+                )),
+            }
+        }
+        
+        if !initializers.is_empty() {
+            module.functions.push(self.lower_initializers(&initializers)?);
+            self.has_initializer = true;
+        }
+        
+        for child in &program.children {
+            if child.node_type == AstNodeType::FuncDecl {
+                module.functions.push(self.lower_function(child)?);
+            }
+        }
+        
+        Ok(module)
+    }
+    
+    // Sabit olmayan başlangıç değerleri, main'in başında çağrılan ayrı bir fonksiyonda kaynak sırasıyla hesaplanır.
+    fn lower_initializers(&self, initializers: &[(String, Type, &AstNode)]) -> Result<Function, IrError> {
+        let mut builder = FunctionBuilder::new(self, Function::new(INIT_FUNCTION.to_string(), Type::Void));
+        let entry = builder.new_block();
+        builder.seal_block(entry);
+        builder.current = Some(entry);
+        
+        for (name, ty, expr) in initializers {
+            let (value, value_type) = builder.lower_expression(expr)?;
+            let value = builder.coerce(value, &value_type, ty, expr)?;
+            builder.emit_instruction(Instruction::new(
+                None,
+                Type::Void,
+                InstructionKind::StoreGlobal(name.clone(), value),
+            ));
+        }
+        
+        builder.terminate(Terminator::Return(None));
+        Ok(builder.finish())
+    }
+    
+    fn lower_global<'n>(&self, node: &'n AstNode) -> Result<(Global, Option<&'n AstNode>), IrError> {
+        let name = node.value.clone().ok_or_else(||
+            IrError::at(MessageId::MissingVariableName.text().to_string(), node)
+        )?;
+        
+        let declared_type = self.resolved_type(&name).or_else(|| type_annotation(node));
+        let initializer = node.children.iter()
+            .find(|c| c.node_type != AstNodeType::TypeAnnotation);
+        
+        let (constant, deferred) = match initializer.map(|expr| (expr, constant_value(expr))) {
+            Some((_, Some(constant))) => (Some(constant), None),
+            Some((expr, None)) => (None, Some(expr)),
+            None => (None, None),
+        };
+        
+        let constant = match constant {
+            Some(constant) => constant,
+            None => declared_type.as_ref().and_then(Constant::zero_of).ok_or_else(|| IrError::at(
+                MessageId::GlobalNeedsInitializer.format(&[&name]),
+                node,
+            ))?,
+        };
+        
+        let constant = match (constant, &declared_type) {
+            (Constant::Int(i), Some(Type::Float)) => Constant::Float(i as f64),
+            (constant, _) => constant,
+        };
+        
+        // Çalışma zamanında başlatılan global'e başlatma fonksiyonu yazar; değişmezlik zaten analizde denetlenir.
+        let global = Global {
+            name,
+            ty: declared_type.unwrap_or_else(|| constant.type_of()),
+            is_mutable: node.metadata.as_deref() == Some("mutable") || deferred.is_some(),
+            initializer: constant,
+        };
+        
+        Ok((global, deferred))
+    }
+    
+    fn lower_function(&self, node: &AstNode) -> Result<Function, IrError> {
+        let name = node.value.clone().unwrap_or_default();
+        let signature = self.signatures.get(&name).cloned().unwrap_or_else(|| signature_of(node));
+        let return_type = signature.return_type.clone();
+        
+        let mut function = Function::new(name, return_type.clone());
+        let attributes = node.attributes();
+        if attributes.contains(&"noinline") {
+            function.inline_hint = InlineHint::Never;
+        } else if attributes.contains(&"inline") {
+            function.inline_hint = InlineHint::Always;
+        }
+        
+        let mut builder = FunctionBuilder::new(self, function);
+        let entry = builder.new_block();
+        builder.seal_block(entry);
+        builder.current = Some(entry);
+        
+        if self.has_initializer && node.value.as_deref() == Some("main") {
+            builder.emit_instruction(Instruction::new(
+                None,
+                Type::Void,
+                InstructionKind::Call(INIT_FUNCTION.to_string(), Vec::new()),
+            ));
+        }
+        
+        builder.enter_scope();
+        let params = node.children.iter().filter(|c| c.node_type == AstNodeType::ParamDecl);
+        for (param, param_type) in params.zip(signature.params) {
+            let id = builder.function.add_param(param_type.clone());
+            let var = builder.declare(param.value.clone().unwrap_or_default(), param_type);
+            builder.write_variable(var, entry, Operand::Value(id));
+        }
+        
+        if let Some(body) = node.children.iter().find(|c| c.node_type == AstNodeType::BlockStmt) {
+            builder.lower_statement(body)?;
+        }
+        builder.exit_scope();
+        
+        if builder.current.is_some() {
+            let value = Constant::zero_of(&return_type).map(Operand::Const);
+            builder.terminate(Terminator::Return(value));
+        }
+        
+        Ok(builder.finish())
+    }
+}
+
+fn signature_of(node: &AstNode) -> Signature {
+    let params = node.children.iter()
+        .filter(|c| c.node_type == AstNodeType::ParamDecl)
+        .map(|p| type_annotation(p).unwrap_or(Type::Any))
+        .collect();
+    
+    Signature {
+        params,
+        return_type: type_annotation(node).unwrap_or(Type::Void),
+    }
+}
+
+fn is_resolved(ty: &Type) -> bool {
+    !matches!(ty, Type::Unknown | Type::Error)
+}
+
+fn type_annotation(node: &AstNode) -> Option<Type> {
+    node.children.iter()
+        .find(|c| c.node_type == AstNodeType::TypeAnnotation)
+        .and_then(|t| t.value.as_deref())
+        .map(Type::from_name)
+}
+
+fn literal_value(node: &AstNode) -> Option<Constant> {
+    let lexeme = node.value.clone().unwrap_or_default();
+    
+    match node.token.as_ref().map(|t| &t.token_type) {
+        Some(TokenType::IntLiteral) => lexeme.parse::<i32>().ok().map(Constant::Int),
+        Some(TokenType::FloatLiteral) => lexeme.parse::<f64>().ok().map(Constant::Float),
+        Some(TokenType::BoolLiteral) => Some(Constant::Bool(lexeme == "true")),
+        Some(TokenType::StringLiteral) => Some(Constant::String(lexeme)),
+        _ => None,
+    }
+}
+
+fn constant_value(node: &AstNode) -> Option<Constant> {
+    match node.node_type {
+        AstNodeType::LiteralExpr => literal_value(node),
+        AstNodeType::GroupExpr => node.children.first().and_then(constant_value),
+        AstNodeType::UnaryExpr if node.value.as_deref() == Some("-") => {
+            match node.children.first().and_then(constant_value)? {
+                Constant::Int(i) => Some(Constant::Int(i.wrapping_neg())),
+                Constant::Float(x) => Some(Constant::Float(-x)),
+                _ => None,
+            }
+        },
+        _ => None,
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct VarId(usize);
+
+struct LoopTargets {
+    continue_block: BlockId,
+    break_block: BlockId,
+}
+
+struct FunctionBuilder<'a> {
+    lowering: &'a Lowering<'a>,
+    function: Function,
+    current: Option<BlockId>,
+    scopes: Vec<HashMap<String, VarId>>,
+    var_types: Vec<Type>,
+    current_def: HashMap<(VarId, BlockId), Operand>,
+    predecessors: HashMap<BlockId, Vec<BlockId>>,
+    sealed: HashSet<BlockId>,
+    incomplete_phis: HashMap<BlockId, Vec<(VarId, ValueId)>>,
+    loops: Vec<LoopTargets>,
+}
+
+impl<'a> FunctionBuilder<'a> {
+    fn new(lowering: &'a Lowering<'a>, function: Function) -> Self {
+        FunctionBuilder {
+            lowering,
+            function,
+            current: None,
+            scopes: Vec::new(),
+            var_types: Vec::new(),
+            current_def: HashMap::new(),
+            predecessors: HashMap::new(),
+            sealed: HashSet::new(),
+            incomplete_phis: HashMap::new(),
+            loops: Vec::new(),
+        }
+    }
+    
+    fn finish(mut self) -> Function {
+        self.function.remove_unreachable_blocks();
+        remove_trivial_phis(&mut self.function);
+        self.function.renumber();
+        self.function
+    }
+    
+    fn new_block(&mut self) -> BlockId {
+        let id = self.function.add_block();
+        self.predecessors.insert(id, Vec::new());
+        id
+    }
+    
+    fn enter_scope(&mut self) {
+        self.scopes.push(HashMap::new());
+    }
+    
+    fn exit_scope(&mut self) {
+        self.scopes.pop();
+    }
+    
+    fn declare(&mut self, name: String, ty: Type) -> VarId {
+        let var = VarId(self.var_types.len());
+        self.var_types.push(ty);
+        
+        if let Some(scope) = self.scopes.last_mut() {
+            scope.insert(name, var);
+        }
+        
+        var
+    }
+    
+    fn resolve(&self, name: &str) -> Option<VarId> {
+        self.scopes.iter().rev().find_map(|scope| scope.get(name).copied())
+    }
+    
+    fn emit(&mut self, ty: Type, kind: InstructionKind) -> Operand {
+        let result = self.function.new_value();
+        self.emit_instruction(Instruction::new(Some(result), ty, kind));
+        Operand::Value(result)
+    }
+    
+    fn emit_instruction(&mut self, instruction: Instruction) {
+        if let Some(block) = self.current.and_then(|id| self.function.block_mut(id)) {
+            block.instructions.push(instruction);
+        }
+    }
+    
+    fn terminate(&mut self, terminator: Terminator) {
+        let Some(id) = self.current.take() else {
+            return;
+        };
+        
+        for successor in terminator.successors() {
+            self.predecessors.entry(successor).or_default().push(id);
+        }
+        
+        if let Some(block) = self.function.block_mut(id) {
+            block.terminator = Some(terminator);
+        }
+    }
+    
+    fn write_variable(&mut self, var: VarId, block: BlockId, value: Operand) {
+        self.current_def.insert((var, block), value);
+    }
+    
+    fn read_variable(&mut self, var: VarId, block: BlockId) -> Operand {
+        match self.current_def.get(&(var, block)) {
+            Some(value) => value.clone(),
+            None => self.read_variable_recursive(var, block),
+        }
+    }
+    
+    fn read_variable_recursive(&mut self, var: VarId, block: BlockId) -> Operand {
+        let preds = self.predecessors.get(&block).cloned().unwrap_or_default();
+        
+        let value = if !self.sealed.contains(&block) {
+            let phi = self.new_phi(var, block);
+            self.incomplete_phis.entry(block).or_default().push((var, phi));
+            Operand::Value(phi)
+        } else if preds.is_empty() {
+            self.undefined(var)
+        } else if preds.len() == 1 {
+            self.read_variable(var, preds[0])
+        } else {
+            let phi = self.new_phi(var, block);
+            self.write_variable(var, block, Operand::Value(phi));
+            self.add_phi_operands(var, phi, block);
+            Operand::Value(phi)
+        };
+        
+        self.write_variable(var, block, value.clone());
+        value
+    }
+    
+    fn undefined(&self, var: VarId) -> Operand {
+        let ty = &self.var_types[var.0];
+        Operand::Const(Constant::zero_of(ty).unwrap_or(Constant::Int(0)))
+    }
+    
+    fn new_phi(&mut self, var: VarId, block: BlockId) -> ValueId {
+        let result = self.function.new_value();
+        let phi = Instruction::new(Some(result), self.var_types[var.0].clone(), InstructionKind::Phi(Vec::new()));
+        
+        if let Some(block) = self.function.block_mut(block) {
+            block.instructions.insert(0, phi);
+        }
+        
+        result
+    }
+    
+    fn add_phi_operands(&mut self, var: VarId, phi: ValueId, block: BlockId) {
+        let preds = self.predecessors.get(&block).cloned().unwrap_or_default();
+        
+        for pred in preds {
+            let value = self.read_variable(var, pred);
+            
+            let instruction = self.function.block_mut(block)
+                .and_then(|b| b.instructions.iter_mut().find(|i| i.result == Some(phi)));
+            
+            if let Some(Instruction { kind: InstructionKind::Phi(incoming), .. }) = instruction {
+                incoming.push((pred, value));
+            }
+        }
+    }
+    
+    fn seal_block(&mut self, block: BlockId) {
+        if let Some(phis) = self.incomplete_phis.remove(&block) {
+            for (var, phi) in phis {
+                self.add_phi_operands(var, phi, block);
+            }
+        }
+        
+        self.sealed.insert(block);
+    }
+    
+    fn lower_statement(&mut self, node: &AstNode) -> Result<(), IrError> {
+        if self.current.is_none() {
+            return Ok(());
+        }
+        
+        match node.node_type {
+            AstNodeType::VarDecl => self.lower_var_declaration(node),
+            AstNodeType::BlockStmt => {
+                self.enter_scope();
+                let result = node.children.iter().try_for_each(|stmt| self.lower_statement(stmt));
+                self.exit_scope();
+                result
+            },
+            AstNodeType::IfStmt => self.lower_if(node),
+            AstNodeType::WhileStmt => self.lower_while(node),
+            AstNodeType::ForStmt => self.lower_for(node),
+            AstNodeType::ReturnStmt => self.lower_return(node),
+            AstNodeType::BreakStmt | AstNodeType::ContinueStmt => {
+                let target = match (self.loops.last(), &node.node_type) {
+                    (Some(targets), AstNodeType::BreakStmt) => targets.break_block,
+                    (Some(targets), _) => targets.continue_block,
+                    (None, _) => return Err(IrError::at(
+                        MessageId::LoopControlOutsideLoop.text().to_string(),
+                        node,
+                    )),
+                };
+                self.terminate(Terminator::Jump(target));
+                Ok(())
+            },
+            AstNodeType::ExprStmt => match node.children.first() {
+                Some(expr) if expr.node_type == AstNodeType::CallExpr => self.lower_call(expr).map(|_| ()),
+                Some(expr) => self.lower_expression(expr).map(|_| ()),
+                None => Ok(()),
+            },
+            AstNodeType::FuncDecl | AstNodeType::StructDecl | AstNodeType::ImplDecl | AstNodeType::ModDecl => {
+                Err(IrError::at(MessageId::NestedDeclaration.text().to_string(), node))
+            },
+            _ => self.lower_expression(node).map(|_| ()),
+        }
+    }
+    
+    fn lower_var_declaration(&mut self, node: &AstNode) -> Result<(), IrError> {
+        let name = node.value.clone().ok_or_else(||
+            IrError::at(MessageId::MissingVariableName.text().to_string(), node)
+        )?;
+        
+        let declared_type = type_annotation(node);
+        let initializer = node.children.iter()
+            .find(|c| c.node_type != AstNodeType::TypeAnnotation);
+        
+        let (value, ty) = match (initializer, declared_type) {
+            (Some(expr), declared) => {
+                let (value, value_type) = self.lower_expression(expr)?;
+                match declared {
+                    Some(ty) => (self.coerce(value, &value_type, &ty, expr)?, ty),
+                    None => (value, value_type),
+                }
+            },
+            (None, Some(ty)) => match Constant::zero_of(&ty) {
+                Some(zero) => (Operand::Const(zero), ty),
+                None => return Err(IrError::at(
+                    MessageId::VariableNeedsInitializer.format(&[&name]),
+                    node,
+                )),
+            },
+            (None, None) => return Err(IrError::at(
+                MessageId::UnknownVariableType.format(&[&name]),
+                node,
+            )),
+        };
+        
+        let var = self.declare(name, ty);
+        if let Some(block) = self.current {
+            self.write_variable(var, block, value);
+        }
+        
+        Ok(())
+    }
+    
+    fn lower_condition(&mut self, node: &AstNode) -> Result<Operand, IrError> {
+        let (value, ty) = self.lower_expression(node)?;
+        
+        if ty != Type::Bool {
+            return Err(IrError::at(MessageId::ConditionNotBool.format(&[&ty]), node));
+        }
+        
+        Ok(value)
+    }
+    
+    fn lower_if(&mut self, node: &AstNode) -> Result<(), IrError> {
+        if node.children.len() < 2 {
+            return Err(IrError::at(MessageId::IncompleteIf.text().to_string(), node));
+        }
+        
+        let condition = self.lower_condition(&node.children[0])?;
+        
+        let then_block = self.new_block();
+        let else_block = self.new_block();
+        let merge_block = self.new_block();
+        let else_branch = node.children.get(2);
+        
+        let false_target = if else_branch.is_some() { else_block } else { merge_block };
+        self.terminate(Terminator::Branch(condition, then_block, false_target));
+        self.seal_block(then_block);
+        self.seal_block(else_block);
+        
+        self.current = Some(then_block);
+        self.lower_statement(&node.children[1])?;
+        self.terminate(Terminator::Jump(merge_block));
+        
+        if let Some(else_branch) = else_branch {
+            self.current = Some(else_block);
+            self.lower_statement(else_branch)?;
+            self.terminate(Terminator::Jump(merge_block));
+        }
+        
+        self.seal_block(merge_block);
+        
+        let reachable = self.predecessors.get(&merge_block).is_some_and(|preds| !preds.is_empty());
+        self.current = if reachable { Some(merge_block) } else { None };
+        
+        Ok(())
+    }
+    
+    fn lower_while(&mut self, node: &AstNode) -> Result<(), IrError> {
+        if node.children.len() < 2 {
+            return Err(IrError::at(MessageId::IncompleteWhile.text().to_string(), node));
+        }
+        
+        let header = self.new_block();
+        let body = self.new_block();
+        let exit = self.new_block();
+        
+        self.terminate(Terminator::Jump(header));
+        self.current = Some(header);
+        
+        let condition = self.lower_condition(&node.children[0])?;
+        self.terminate(Terminator::Branch(condition, body, exit));
+        self.seal_block(body);
+        
+        self.current = Some(body);
+        self.loops.push(LoopTargets { continue_block: header, break_block: exit });
+        let result = self.lower_statement(&node.children[1]);
+        self.loops.pop();
+        result?;
+        self.terminate(Terminator::Jump(header));
+        
+        self.seal_block(header);
+        self.seal_block(exit);
+        self.current = Some(exit);
+        
+        Ok(())
+    }
+    
+    fn lower_for(&mut self, node: &AstNode) -> Result<(), IrError> {
+        if node.children.len() < 3 {
+            return Err(IrError::at(MessageId::IncompleteFor.text().to_string(), node));
+        }
+        
+        let var_name = node.children[0].value.clone().ok_or_else(||
+            IrError::at(MessageId::MissingLoopVariable.text().to_string(), node)
+        )?;
+        
+        if node.children[1].node_type == AstNodeType::RangeExpr {
+            return self.lower_range_for(var_name, node);
+        }
+        
+        let (collection, collection_type) = self.lower_expression(&node.children[1])?;
+        let element_type = match &collection_type {
+            Type::String => Type::String,
+            Type::Array(element, _) => (**element).clone(),
+            other => return Err(IrError::at(
+                MessageId::NotIterable.format(&[&other]),
+                &node.children[1],
+            )),
+        };
+        
+        let length = self.emit(Type::Int, InstructionKind::Length(collection.clone()));
+        
+        self.enter_scope();
+        let index_var = self.declare(format!("{}.index", var_name), Type::Int);
+        if let Some(block) = self.current {
+            self.write_variable(index_var, block, Operand::Const(Constant::Int(0)));
+        }
+        
+        let header = self.new_block();
+        let body = self.new_block();
+        let step = self.new_block();
+        let exit = self.new_block();
+        
+        self.terminate(Terminator::Jump(header));
+        self.current = Some(header);
+        
+        let index = self.read_variable(index_var, header);
+        let condition = self.emit(Type::Bool, InstructionKind::Binary(BinaryOp::Lt, index.clone(), length));
+        self.terminate(Terminator::Branch(condition, body, exit));
+        self.seal_block(body);
+        
+        self.current = Some(body);
+        let element = self.emit(element_type.clone(), InstructionKind::Index(collection, index));
+        let loop_var = self.declare(var_name, element_type);
+        self.write_variable(loop_var, body, element);
+        
+        self.loops.push(LoopTargets { continue_block: step, break_block: exit });
+        let result = self.lower_statement(&node.children[2]);
+        self.loops.pop();
+        result?;
+        self.terminate(Terminator::Jump(step));
+        self.seal_block(step);
+        
+        let step_reachable = self.predecessors.get(&step).is_some_and(|preds| !preds.is_empty());
+        if step_reachable {
+            self.current = Some(step);
+            let index = self.read_variable(index_var, step);
+            let next = self.emit(Type::Int, InstructionKind::Binary(BinaryOp::Add, index, Operand::Const(Constant::Int(1))));
+            self.write_variable(index_var, step, next);
+            self.terminate(Terminator::Jump(header));
+        }
+        
+        self.seal_block(header);
+        self.seal_block(exit);
+        self.exit_scope();
+        self.current = Some(exit);
+        
+        Ok(())
+    }
+    
+    fn lower_range_for(&mut self, var_name: String, node: &AstNode) -> Result<(), IrError> {
+        let range = &node.children[1];
+        if range.children.len() < 2 {
+            return Err(IrError::at(MessageId::IncompleteRange.text().to_string(), range));
+        }
+        
+        let (start, start_type) = self.lower_expression(&range.children[0])?;
+        let start = self.coerce(start, &start_type, &Type::Int, &range.children[0])?;
+        let (end, end_type) = self.lower_expression(&range.children[1])?;
+        let end = self.coerce(end, &end_type, &Type::Int, &range.children[1])?;
+        
+        self.enter_scope();
+        let counter = self.declare(format!("{}.counter", var_name), Type::Int);
+        if let Some(block) = self.current {
+            self.write_variable(counter, block, start);
+        }
+        
+        let header = self.new_block();
+        let body = self.new_block();
+        let step = self.new_block();
+        let exit = self.new_block();
+        
+        self.terminate(Terminator::Jump(header));
+        self.current = Some(header);
+        
+        let value = self.read_variable(counter, header);
+        let condition = self.emit(Type::Bool, InstructionKind::Binary(BinaryOp::Lt, value.clone(), end));
+        self.terminate(Terminator::Branch(condition, body, exit));
+        self.seal_block(body);
+        
+        self.current = Some(body);
+        let loop_var = self.declare(var_name, Type::Int);
+        self.write_variable(loop_var, body, value);
+        
+        self.loops.push(LoopTargets { continue_block: step, break_block: exit });
+        let result = self.lower_statement(&node.children[2]);
+        self.loops.pop();
+        result?;
+        self.terminate(Terminator::Jump(step));
+        self.seal_block(step);
+        
+        let step_reachable = self.predecessors.get(&step).is_some_and(|preds| !preds.is_empty());
+        if step_reachable {
+            self.current = Some(step);
+            let value = self.read_variable(counter, step);
+            let next = self.emit(Type::Int, InstructionKind::Binary(BinaryOp::Add, value, Operand::Const(Constant::Int(1))));
+            self.write_variable(counter, step, next);
+            self.terminate(Terminator::Jump(header));
+        }
+        
+        self.seal_block(header);
+        self.seal_block(exit);
+        self.exit_scope();
+        self.current = Some(exit);
+        
+        Ok(())
+    }
+    
+    fn lower_return(&mut self, node: &AstNode) -> Result<(), IrError> {
+        let return_type = self.function.return_type.clone();
+        
+        let value = match node.children.first() {
+            Some(expr) => {
+                let (value, ty) = self.lower_expression(expr)?;
+                Some(self.coerce(value, &ty, &return_type, expr)?)
+            },
+            None => None,
+        };
+        
+        self.terminate(Terminator::Return(value));
+        Ok(())
+    }
+    
+    fn coerce(&mut self, value: Operand, from: &Type, to: &Type, node: &AstNode) -> Result<Operand, IrError> {
+        match (from, to) {
+            (Type::Int, Type::Float) => match value {
+                Operand::Const(Constant::Int(i)) => Ok(Operand::Const(Constant::Float(i as f64))),
+                value => Ok(self.emit(Type::Float, InstructionKind::IntToFloat(value))),
+            },
+            (from, to) if from == to || matches!(to, Type::Any) => Ok(value),
+            (from, to) => Err(IrError::at(
+                MessageId::AssignTypeMismatch.format(&[&to, &from]),
+                node,
+            )),
+        }
+    }
+    
+    fn lower_expression(&mut self, node: &AstNode) -> Result<(Operand, Type), IrError> {
+        match node.node_type {
+            AstNodeType::LiteralExpr => {
+                let constant = literal_value(node).ok_or_else(|| IrError::at(
+                    MessageId::InvalidLiteral.format(&[&node.value.as_deref().unwrap_or_default()]),
+                    node,
+                ))?;
+                let ty = constant.type_of();
+                Ok((Operand::Const(constant), ty))
+            },
+            AstNodeType::IdentifierExpr => self.lower_identifier(node),
+            AstNodeType::GroupExpr => match node.children.first() {
+                Some(expr) => self.lower_expression(expr),
+                None => Err(IrError::at(MessageId::EmptyGroup.text().to_string(), node)),
+            },
+            AstNodeType::UnaryExpr => self.lower_unary(node),
+            AstNodeType::BinaryExpr => self.lower_binary(node),
+            AstNodeType::CallExpr => match self.lower_call(node)? {
+                Some(result) => Ok(result),
+                None => Err(IrError::at(
+                    MessageId::FunctionReturnsNothing.format(&[&node.value.as_deref().unwrap_or_default()]),
+                    node,
+                )),
+            },
+            _ => Err(IrError::at(
+                MessageId::UnsupportedExpression.format(&[&format!("{:?}", node.node_type)]),
+                node,
+            )),
+        }
+    }
+    
+    fn lower_identifier(&mut self, node: &AstNode) -> Result<(Operand, Type), IrError> {
+        let name = node.value.as_deref().unwrap_or_default();
+        
+        if let Some(var) = self.resolve(name) {
+            let ty = self.var_types[var.0].clone();
+            let value = match self.current {
+                Some(block) => self.read_variable(var, block),
+                None => self.undefined(var),
+            };
+            return Ok((value, ty));
+        }
+        
+        if let Some(ty) = self.lowering.globals.get(name).cloned() {
+            let value = self.emit(ty.clone(), InstructionKind::LoadGlobal(name.to_string()));
+            return Ok((value, ty));
+        }
+        
+        Err(IrError::at(MessageId::Undefined.format(&[&name]), node))
+    }
+    
+    fn lower_unary(&mut self, node: &AstNode) -> Result<(Operand, Type), IrError> {
+        let (operand, ty) = match node.children.first() {
+            Some(expr) => self.lower_expression(expr)?,
+            None => return Err(IrError::at(MessageId::IncompleteUnary.text().to_string(), node)),
+        };
+        
+        let operator = node.value.as_deref().unwrap_or_default();
+        
+        let op = match (operator, &ty) {
+            ("-", Type::Int | Type::Float) => UnaryOp::Neg,
+            ("!", Type::Bool) | ("~", Type::Int) => UnaryOp::Not,
+            _ => return Err(IrError::at(
+                MessageId::InvalidUnaryOperand.format(&[&operator, &ty]),
+                node,
+            )),
+        };
+        
+        let value = self.emit(ty.clone(), InstructionKind::Unary(op, operand));
+        Ok((value, ty))
+    }
+    
+    fn lower_binary(&mut self, node: &AstNode) -> Result<(Operand, Type), IrError> {
+        if node.children.len() < 2 {
+            return Err(IrError::at(MessageId::IncompleteBinary.text().to_string(), node));
+        }
+        
+        let operator = node.value.as_deref().unwrap_or_default();
+        
+        match operator {
+            "=" | "+=" | "-=" | "*=" | "/=" | "&=" | "|=" | "~=" | "<<=" | ">>=" => return self.lower_assignment(node, operator),
+            "&&" | "||" => return self.lower_logical(node, operator),
+            _ => {}
+        }
+        
+        let left = self.lower_expression(&node.children[0])?;
+        let right = self.lower_expression(&node.children[1])?;
+        
+        self.binary_operation(operator, left, right, node)
+    }
+    
+    fn lower_logical(&mut self, node: &AstNode, operator: &str) -> Result<(Operand, Type), IrError> {
+        let left = self.lower_condition(&node.children[0])?;
+        
+        // Sonuç gizli bir değişkene yazılır; birleşme bloğundaki phi SSA kurulumuyla oluşur.
+        let result = self.declare(format!("{}.result", operator), Type::Bool);
+        if let Some(block) = self.current {
+            self.write_variable(result, block, left.clone());
+        }
+        
+        let right_block = self.new_block();
+        let merge_block = self.new_block();
+        
+        let terminator = match operator {
+            "&&" => Terminator::Branch(left, right_block, merge_block),
+            _ => Terminator::Branch(left, merge_block, right_block),
+        };
+        self.terminate(terminator);
+        self.seal_block(right_block);
+        
+        self.current = Some(right_block);
+        let right = self.lower_condition(&node.children[1])?;
+        if let Some(block) = self.current {
+            self.write_variable(result, block, right);
+        }
+        self.terminate(Terminator::Jump(merge_block));
+        self.seal_block(merge_block);
+        
+        self.current = Some(merge_block);
+        Ok((self.read_variable(result, merge_block), Type::Bool))
+    }
+    
+    fn binary_operation(
+        &mut self,
+        operator: &str,
+        (left, left_type): (Operand, Type),
+        (right, right_type): (Operand, Type),
+        node: &AstNode,
+    ) -> Result<(Operand, Type), IrError> {
+        let op = BinaryOp::from_operator(operator).ok_or_else(||
+            IrError::at(MessageId::UnknownOperator.format(&[&operator]), node)
+        )?;
+        
+        let operand_type = match (&left_type, &right_type) {
+            (Type::Int, Type::Int) => Type::Int,
+            (Type::Int | Type::Float, Type::Int | Type::Float) if !op.is_bitwise() => Type::Float,
+            (Type::String, Type::String) if op == BinaryOp::Add || op.is_comparison() => Type::String,
+            (Type::Bool, Type::Bool) if matches!(op, BinaryOp::Eq | BinaryOp::Ne) => Type::Bool,
+            _ => return Err(IrError::at(
+                MessageId::InvalidArithmeticOperands.format(&[&operator, &left_type, &right_type]),
+                node,
+            )),
+        };
+        
+        let left = self.coerce(left, &left_type, &operand_type, node)?;
+        let right = self.coerce(right, &right_type, &operand_type, node)?;
+        
+        let result_type = if op.is_comparison() { Type::Bool } else { operand_type };
+        let value = self.emit(result_type.clone(), InstructionKind::Binary(op, left, right));
+        
+        Ok((value, result_type))
+    }
+    
+    fn lower_assignment(&mut self, node: &AstNode, operator: &str) -> Result<(Operand, Type), IrError> {
+        let target = &node.children[0];
+        
+        if target.node_type != AstNodeType::IdentifierExpr {
+            return Err(IrError::at(
+                MessageId::NotAssignable.text().to_string(),
+                node,
+            ));
+        }
+        
+        let name = target.value.clone().unwrap_or_default();
+        let (current, target_type) = self.lower_identifier(target)?;
+        let right = self.lower_expression(&node.children[1])?;
+        
+        let (value, value_type) = match operator {
+            "=" => right,
+            _ => {
+                let op = &operator[..operator.len() - 1];
+                self.binary_operation(op, (current, target_type.clone()), right, node)?
+            }
+        };
+        
+        let value = self.coerce(value, &value_type, &target_type, node)?;
+        
+        match (self.resolve(&name), self.current) {
+            (Some(var), Some(block)) => self.write_variable(var, block, value.clone()),
+            (Some(_), None) => {},
+            (None, _) => self.emit_instruction(Instruction::new(
+                None,
+                Type::Void,
+                InstructionKind::StoreGlobal(name, value.clone()),
+            )),
+        }
+        
+        Ok((value, target_type))
+    }
+    
+    fn lower_call(&mut self, node: &AstNode) -> Result<Option<(Operand, Type)>, IrError> {
+        let name = node.value.clone().unwrap_or_default();
+        
+        let mut args = Vec::new();
+        for child in &node.children {
+            args.push((self.lower_expression(child)?, child));
+        }
+        
+        let signature = match self.lowering.signatures.get(&name) {
+            Some(signature) => signature.clone(),
+            None if BUILTINS.contains(&name.as_str()) => Signature {
+                params: args.iter().map(|((_, ty), _)| ty.clone()).collect(),
+                return_type: Type::Void,
+            },
+            None => return Err(IrError::at(MessageId::UndefinedFunction.format(&[&name]), node)),
+        };
+        
+        if signature.params.len() != args.len() {
+            return Err(IrError::at(
+                MessageId::ArgumentCountMismatch.format(&[&name, &signature.params.len(), &args.len()]),
+                node,
+            ));
+        }
+        
+        let mut operands = Vec::new();
+        for (((value, ty), arg), param_type) in args.into_iter().zip(&signature.params) {
+            operands.push(self.coerce(value, &ty, param_type, arg)?);
+        }
+        
+        if signature.return_type == Type::Void {
+            self.emit_instruction(Instruction::new(None, Type::Void, InstructionKind::Call(name, operands)));
+            Ok(None)
+        } else {
+            let value = self.emit(signature.return_type.clone(), InstructionKind::Call(name, operands));
+            Ok(Some((value, signature.return_type)))
+        }
+    }
+}
+
+fn remove_trivial_phis(function: &mut Function) {
+    loop {
+        let mut replacements = HashMap::new();
+        
+        for block in &function.blocks {
+            for instruction in block.phis() {
+                let (Some(result), InstructionKind::Phi(incoming)) = (instruction.result, &instruction.kind) else {
+                    continue;
+                };
+                
+                let mut unique: Option<&Operand> = None;
+                let mut trivial = true;
+                
+                for (_, value) in incoming {
+                    if value == &Operand::Value(result) || unique == Some(value) {
+                        continue;
+                    }
+                    if unique.is_some() {
+                        trivial = false;
+                        break;
+                    }
+                    unique = Some(value);
+                }
+                
+                if trivial && let Some(value) = unique {
+                    replacements.insert(result, value.clone());
+                }
+            }
+        }
+        
+        if replacements.is_empty() {
+            return;
+        }
+        
+        for block in &mut function.blocks {
+            block.instructions.retain(|i| i.result.is_none_or(|r| !replacements.contains_key(&r)));
+        }
+        
+        function.replace_uses(&replacements);
+    }
+}
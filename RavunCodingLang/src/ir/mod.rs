@@ -1 +1,16 @@
 //! Ir modülü
+pub mod instruction;
+pub mod function;
+pub mod lowering;
+pub mod printer;
+pub mod dominators;
+pub mod loops;
+pub mod verifier;
+pub mod parser;
+pub mod error;
+
+pub use error::IrError;
+pub use function::{BasicBlock, Function, Global, InlineHint, Module};
+pub use lowering::{lower_program, Lowering};
+pub use parser::parse_module;
+pub use verifier::{verify_module, VerifyError};
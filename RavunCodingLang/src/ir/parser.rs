@@ -0,0 +1,574 @@
+use crate::diagnostics::MessageId;
+use crate::ir::error::IrError;
+use crate::ir::function::{BasicBlock, Function, Global, InlineHint, Module, Param};
+use crate::ir::instruction::{
+    BinaryOp, BlockId, Constant, Instruction, InstructionKind, Operand, Terminator, UnaryOp, ValueId,
+};
+use crate::semantics::types::Type;
+
+#[derive(Debug, Clone, PartialEq)]
+enum IrTokenKind {
+    Value(usize),
+    Global(String),
+    Word(String),
+    Int(i32),
+    Float(f64),
+    Str(String),
+    Punct(char),
+    Arrow,
+    Eof,
+}
+
+#[derive(Debug, Clone)]
+struct IrToken {
+    kind: IrTokenKind,
+    line: usize,
+    column: usize,
+}
+
+struct IrLexer<'a> {
+    chars: std::iter::Peekable<std::str::Chars<'a>>,
+    line: usize,
+    column: usize,
+}
+
+impl<'a> IrLexer<'a> {
+    fn new(input: &'a str) -> Self {
+        IrLexer {
+            chars: input.chars().peekable(),
+            line: 1,
+            column: 1,
+        }
+    }
+    
+    fn advance(&mut self) -> Option<char> {
+        let c = self.chars.next()?;
+        if c == '\n' {
+            self.line += 1;
+            self.column = 1;
+        } else {
+            self.column += 1;
+        }
+        Some(c)
+    }
+    
+    fn take_while(&mut self, buffer: &mut String, predicate: impl Fn(char) -> bool) {
+        while let Some(&c) = self.chars.peek() {
+            if !predicate(c) {
+                break;
+            }
+            buffer.push(c);
+            self.advance();
+        }
+    }
+    
+    fn error(&self, message: String) -> IrError {
+        IrError::new(message, self.line, self.column)
+    }
+    
+    fn tokenize(mut self) -> Result<Vec<IrToken>, IrError> {
+        let mut tokens = Vec::new();
+        
+        loop {
+            while let Some(&c) = self.chars.peek() {
+                if c.is_whitespace() {
+                    self.advance();
+                } else if c == ';' {
+                    while let Some(c) = self.advance() {
+                        if c == '\n' {
+                            break;
+                        }
+                    }
+                } else {
+                    break;
+                }
+            }
+            
+            let (line, column) = (self.line, self.column);
+            let c = match self.chars.peek() {
+                Some(&c) => c,
+                None => {
+                    tokens.push(IrToken { kind: IrTokenKind::Eof, line, column });
+                    return Ok(tokens);
+                }
+            };
+            
+            let kind = match c {
+                '%' => {
+                    self.advance();
+                    let mut digits = String::new();
+                    self.take_while(&mut digits, |c| c.is_ascii_digit());
+                    let id = digits.parse::<usize>()
+                        .map_err(|_| self.error(MessageId::IrExpectedValueNumber.text().to_string()))?;
+                    IrTokenKind::Value(id)
+                },
+                '@' => {
+                    self.advance();
+                    let mut name = String::new();
+                    self.take_while(&mut name, |c| c.is_alphanumeric() || c == '_' || c == '.');
+                    if name.is_empty() {
+                        return Err(self.error(MessageId::IrExpectedName.text().to_string()));
+                    }
+                    IrTokenKind::Global(name)
+                },
+                '"' => {
+                    self.advance();
+                    IrTokenKind::Str(self.string()?)
+                },
+                '-' => {
+                    self.advance();
+                    match self.chars.peek() {
+                        Some('>') => {
+                            self.advance();
+                            IrTokenKind::Arrow
+                        },
+                        _ => self.number(true)?,
+                    }
+                },
+                c if c.is_ascii_digit() => self.number(false)?,
+                c if c.is_alphabetic() || c == '_' || c == '&' => {
+                    let mut word = String::new();
+                    self.take_while(&mut word, |c| c.is_alphanumeric() || c == '_' || c == '&');
+                    if self.chars.peek() == Some(&'[') {
+                        let mut lookahead = self.chars.clone();
+                        lookahead.next();
+                        let mut suffix = String::from("[");
+                        while let Some(&d) = lookahead.peek() {
+                            if d.is_ascii_digit() {
+                                suffix.push(d);
+                                lookahead.next();
+                            } else {
+                                break;
+                            }
+                        }
+                        if lookahead.peek() == Some(&']') {
+                            suffix.push(']');
+                            for _ in 0..suffix.chars().count() {
+                                self.advance();
+                            }
+                            word.push_str(&suffix);
+                        }
+                    }
+                    if self.chars.peek() == Some(&'?') {
+                        self.advance();
+                        word.push('?');
+                    }
+                    if word == "inf" || word == "NaN" {
+                        IrTokenKind::Float(word.parse::<f64>().unwrap_or(f64::NAN))
+                    } else {
+                        IrTokenKind::Word(word)
+                    }
+                },
+                '=' | ',' | ':' | '(' | ')' | '[' | ']' | '{' | '}' => {
+                    self.advance();
+                    IrTokenKind::Punct(c)
+                },
+                _ => return Err(self.error(MessageId::IrUnexpectedCharacter.format(&[&c]))),
+            };
+            
+            tokens.push(IrToken { kind, line, column });
+        }
+    }
+    
+    fn number(&mut self, negative: bool) -> Result<IrTokenKind, IrError> {
+        let mut text = String::new();
+        if negative {
+            text.push('-');
+        }
+        
+        self.take_while(&mut text, |c| c.is_alphanumeric() || c == '.' || c == '+' || c == '-');
+        
+        if text == "-inf" {
+            return Ok(IrTokenKind::Float(f64::NEG_INFINITY));
+        }
+        
+        if text.contains('.') || text.contains('e') {
+            text.parse::<f64>().map(IrTokenKind::Float)
+                .map_err(|_| self.error(MessageId::IrInvalidFloat.format(&[&text])))
+        } else {
+            text.parse::<i32>().map(IrTokenKind::Int)
+                .map_err(|_| self.error(MessageId::IrInvalidInteger.format(&[&text])))
+        }
+    }
+    
+    fn string(&mut self) -> Result<String, IrError> {
+        let mut string = String::new();
+        
+        while let Some(c) = self.advance() {
+            match c {
+                '"' => return Ok(string),
+                '\\' => match self.advance() {
+                    Some('n') => string.push('\n'),
+                    Some('t') => string.push('\t'),
+                    Some('r') => string.push('\r'),
+                    Some('\\') => string.push('\\'),
+                    Some('"') => string.push('"'),
+                    Some(other) => {
+                        string.push('\\');
+                        string.push(other);
+                    },
+                    None => break,
+                },
+                _ => string.push(c),
+            }
+        }
+        
+        Err(self.error(MessageId::UnterminatedString.text().to_string()))
+    }
+}
+
+pub struct IrParser {
+    tokens: Vec<IrToken>,
+    position: usize,
+}
+
+pub fn parse_module(input: &str) -> Result<Module, IrError> {
+    let tokens = IrLexer::new(input).tokenize()?;
+    let mut parser = IrParser { tokens, position: 0 };
+    parser.parse_module()
+}
+
+impl IrParser {
+    fn current(&self) -> &IrToken {
+        &self.tokens[self.position.min(self.tokens.len() - 1)]
+    }
+    
+    fn advance(&mut self) -> IrToken {
+        let token = self.current().clone();
+        if self.position < self.tokens.len() - 1 {
+            self.position += 1;
+        }
+        token
+    }
+    
+    fn error(&self, message: String) -> IrError {
+        let token = self.current();
+        IrError::new(message, token.line, token.column)
+    }
+    
+    fn check_word(&self, word: &str) -> bool {
+        matches!(&self.current().kind, IrTokenKind::Word(w) if w == word)
+    }
+    
+    fn check_punct(&self, c: char) -> bool {
+        self.current().kind == IrTokenKind::Punct(c)
+    }
+    
+    fn expect_punct(&mut self, c: char) -> Result<(), IrError> {
+        if self.check_punct(c) {
+            self.advance();
+            Ok(())
+        } else {
+            Err(self.error(MessageId::IrExpected.format(&[&c, &format!("{:?}", self.current().kind)])))
+        }
+    }
+    
+    fn expect_word(&mut self, word: &str) -> Result<(), IrError> {
+        if self.check_word(word) {
+            self.advance();
+            Ok(())
+        } else {
+            Err(self.error(MessageId::IrExpected.format(&[&word, &format!("{:?}", self.current().kind)])))
+        }
+    }
+    
+    fn expect_global(&mut self) -> Result<String, IrError> {
+        match self.advance().kind {
+            IrTokenKind::Global(name) => Ok(name),
+            other => Err(self.error(MessageId::IrExpectedGlobalName.format(&[&format!("{:?}", other)]))),
+        }
+    }
+    
+    fn expect_value(&mut self) -> Result<ValueId, IrError> {
+        match self.advance().kind {
+            IrTokenKind::Value(id) => Ok(ValueId(id)),
+            other => Err(self.error(MessageId::IrExpected.format(&[&"%n", &format!("{:?}", other)]))),
+        }
+    }
+    
+    fn parse_module(&mut self) -> Result<Module, IrError> {
+        let mut module = Module::new();
+        
+        loop {
+            match &self.current().kind {
+                IrTokenKind::Eof => return Ok(module),
+                IrTokenKind::Word(w) if w == "global" => module.globals.push(self.parse_global()?),
+                IrTokenKind::Word(w) if w == "fn" => module.functions.push(self.parse_function()?),
+                other => return Err(self.error(MessageId::IrExpectedItem.format(&[&format!("{:?}", other)]))),
+            }
+        }
+    }
+    
+    fn parse_global(&mut self) -> Result<Global, IrError> {
+        self.expect_word("global")?;
+        
+        let is_mutable = self.check_word("mut");
+        if is_mutable {
+            self.advance();
+        }
+        
+        let name = self.expect_global()?;
+        self.expect_punct(':')?;
+        let ty = self.parse_type()?;
+        self.expect_punct('=')?;
+        
+        let initializer = match self.parse_operand()? {
+            Operand::Const(constant) => constant,
+            Operand::Value(_) => return Err(self.error(MessageId::IrGlobalNotConstant.text().to_string())),
+        };
+        
+        Ok(Global { name, ty, is_mutable, initializer })
+    }
+    
+    fn parse_type(&mut self) -> Result<Type, IrError> {
+        let token = self.advance();
+        
+        let name = match token.kind {
+            IrTokenKind::Word(name) => name,
+            other => return Err(IrError::new(MessageId::IrExpectedType.format(&[&format!("{:?}", other)]), token.line, token.column)),
+        };
+        
+        match name.as_str() {
+            "any" => Ok(Type::Any),
+            "null" => Ok(Type::Null),
+            _ => match Type::from_name(&name) {
+                Type::Unknown => Err(IrError::new(MessageId::IrUnknownType.format(&[&name]), token.line, token.column)),
+                ty => Ok(ty),
+            },
+        }
+    }
+    
+    fn parse_block_id(&mut self) -> Result<BlockId, IrError> {
+        let token = self.advance();
+        
+        if let IrTokenKind::Word(word) = &token.kind
+            && let Some(number) = word.strip_prefix("bb")
+            && let Ok(id) = number.parse::<usize>() {
+            return Ok(BlockId(id));
+        }
+        
+        Err(IrError::new(MessageId::IrExpectedBlockLabel.format(&[&format!("{:?}", token.kind)]), token.line, token.column))
+    }
+    
+    fn is_block_label(&self) -> bool {
+        matches!(&self.current().kind, IrTokenKind::Word(w) if w.starts_with("bb") && w[2..].parse::<usize>().is_ok())
+    }
+    
+    fn parse_function(&mut self) -> Result<Function, IrError> {
+        self.expect_word("fn")?;
+        let name = self.expect_global()?;
+        
+        self.expect_punct('(')?;
+        let mut params = Vec::new();
+        if !self.check_punct(')') {
+            loop {
+                let id = self.expect_value()?;
+                self.expect_punct(':')?;
+                let ty = self.parse_type()?;
+                params.push((id, ty));
+                
+                if !self.check_punct(',') {
+                    break;
+                }
+                self.advance();
+            }
+        }
+        self.expect_punct(')')?;
+        
+        if self.current().kind != IrTokenKind::Arrow {
+            return Err(self.error(MessageId::ExpectedTokenLabel.format(&[&"'->'"])));
+        }
+        self.advance();
+        let return_type = self.parse_type()?;
+        
+        let mut function = Function::new(name, return_type);
+        
+        if self.check_word("inline") {
+            self.advance();
+            function.inline_hint = InlineHint::Always;
+        } else if self.check_word("noinline") {
+            self.advance();
+            function.inline_hint = InlineHint::Never;
+        }
+        for (id, ty) in params {
+            function.params.push(Param { id, ty });
+        }
+        
+        self.expect_punct('{')?;
+        
+        while !self.check_punct('}') {
+            let block = self.parse_block()?;
+            if function.block(block.id).is_some() {
+                return Err(self.error(MessageId::IrDuplicateBlock.format(&[&block.id])));
+            }
+            function.blocks.push(block);
+        }
+        
+        self.expect_punct('}')?;
+        function.sync_counters();
+        
+        Ok(function)
+    }
+    
+    fn parse_block(&mut self) -> Result<BasicBlock, IrError> {
+        let id = self.parse_block_id()?;
+        self.expect_punct(':')?;
+        
+        let mut block = BasicBlock::new(id);
+        
+        loop {
+            if self.check_punct('}') || self.is_block_label() {
+                return Ok(block);
+            }
+            
+            if let IrTokenKind::Word(word) = &self.current().kind {
+                match word.as_str() {
+                    "ret" | "jmp" | "br" | "unreachable" => {
+                        block.terminator = Some(self.parse_terminator()?);
+                        return Ok(block);
+                    },
+                    _ => {}
+                }
+            }
+            
+            block.instructions.push(self.parse_instruction()?);
+        }
+    }
+    
+    fn parse_terminator(&mut self) -> Result<Terminator, IrError> {
+        let token = self.advance();
+        
+        match token.kind {
+            IrTokenKind::Word(word) => match word.as_str() {
+                "ret" => {
+                    if self.is_operand_start() {
+                        Ok(Terminator::Return(Some(self.parse_operand()?)))
+                    } else {
+                        Ok(Terminator::Return(None))
+                    }
+                },
+                "jmp" => Ok(Terminator::Jump(self.parse_block_id()?)),
+                "br" => {
+                    let condition = self.parse_operand()?;
+                    self.expect_punct(',')?;
+                    let then_block = self.parse_block_id()?;
+                    self.expect_punct(',')?;
+                    let else_block = self.parse_block_id()?;
+                    Ok(Terminator::Branch(condition, then_block, else_block))
+                },
+                _ => Ok(Terminator::Unreachable),
+            },
+            other => Err(IrError::new(MessageId::IrExpectedTerminator.format(&[&format!("{:?}", other)]), token.line, token.column)),
+        }
+    }
+    
+    fn is_operand_start(&self) -> bool {
+        match &self.current().kind {
+            IrTokenKind::Value(_) | IrTokenKind::Int(_) | IrTokenKind::Float(_) | IrTokenKind::Str(_) => true,
+            IrTokenKind::Word(w) => w == "true" || w == "false",
+            _ => false,
+        }
+    }
+    
+    fn parse_operand(&mut self) -> Result<Operand, IrError> {
+        let token = self.advance();
+        
+        match token.kind {
+            IrTokenKind::Value(id) => Ok(Operand::Value(ValueId(id))),
+            IrTokenKind::Int(i) => Ok(Operand::Const(Constant::Int(i))),
+            IrTokenKind::Float(x) => Ok(Operand::Const(Constant::Float(x))),
+            IrTokenKind::Str(s) => Ok(Operand::Const(Constant::String(s))),
+            IrTokenKind::Word(w) if w == "true" => Ok(Operand::Const(Constant::Bool(true))),
+            IrTokenKind::Word(w) if w == "false" => Ok(Operand::Const(Constant::Bool(false))),
+            other => Err(IrError::new(MessageId::IrExpectedOperand.format(&[&format!("{:?}", other)]), token.line, token.column)),
+        }
+    }
+    
+    fn parse_operand_list(&mut self) -> Result<Vec<Operand>, IrError> {
+        let mut operands = Vec::new();
+        
+        self.expect_punct('(')?;
+        if !self.check_punct(')') {
+            loop {
+                operands.push(self.parse_operand()?);
+                if !self.check_punct(',') {
+                    break;
+                }
+                self.advance();
+            }
+        }
+        self.expect_punct(')')?;
+        
+        Ok(operands)
+    }
+    
+    fn parse_instruction(&mut self) -> Result<Instruction, IrError> {
+        let result = if let IrTokenKind::Value(id) = self.current().kind {
+            self.advance();
+            self.expect_punct('=')?;
+            Some(ValueId(id))
+        } else {
+            None
+        };
+        
+        let token = self.advance();
+        let opcode = match token.kind {
+            IrTokenKind::Word(word) => word,
+            other => return Err(IrError::new(MessageId::IrExpectedInstruction.format(&[&format!("{:?}", other)]), token.line, token.column)),
+        };
+        
+        if opcode == "store" {
+            let name = self.expect_global()?;
+            self.expect_punct(',')?;
+            let value = self.parse_operand()?;
+            return Ok(Instruction::new(result, Type::Void, InstructionKind::StoreGlobal(name, value)));
+        }
+        
+        let ty = self.parse_type()?;
+        
+        let kind = if let Some(op) = BinaryOp::from_mnemonic(&opcode) {
+            let lhs = self.parse_operand()?;
+            self.expect_punct(',')?;
+            let rhs = self.parse_operand()?;
+            InstructionKind::Binary(op, lhs, rhs)
+        } else if let Some(op) = UnaryOp::from_mnemonic(&opcode) {
+            InstructionKind::Unary(op, self.parse_operand()?)
+        } else {
+            match opcode.as_str() {
+                "itof" => InstructionKind::IntToFloat(self.parse_operand()?),
+                "call" => {
+                    let name = self.expect_global()?;
+                    InstructionKind::Call(name, self.parse_operand_list()?)
+                },
+                "phi" => {
+                    let mut incoming = Vec::new();
+                    while self.check_punct('[') {
+                        self.advance();
+                        let value = self.parse_operand()?;
+                        self.expect_punct(',')?;
+                        let block = self.parse_block_id()?;
+                        self.expect_punct(']')?;
+                        incoming.push((block, value));
+                        
+                        if !self.check_punct(',') {
+                            break;
+                        }
+                        self.advance();
+                    }
+                    InstructionKind::Phi(incoming)
+                },
+                "load" => InstructionKind::LoadGlobal(self.expect_global()?),
+                "len" => InstructionKind::Length(self.parse_operand()?),
+                "index" => {
+                    let collection = self.parse_operand()?;
+                    self.expect_punct(',')?;
+                    let index = self.parse_operand()?;
+                    InstructionKind::Index(collection, index)
+                },
+                _ => return Err(IrError::new(MessageId::IrUnknownInstruction.format(&[&opcode]), token.line, token.column)),
+            }
+        };
+        
+        Ok(Instruction::new(result, ty, kind))
+    }
+}
@@ -0,0 +1,171 @@
+use std::fmt;
+use crate::ir::function::{BasicBlock, Function, Global, InlineHint, Module};
+use crate::ir::instruction::{BlockId, Constant, Instruction, InstructionKind, Operand, Terminator, ValueId};
+
+impl fmt::Display for ValueId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "%{}", self.0)
+    }
+}
+
+impl fmt::Display for BlockId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "bb{}", self.0)
+    }
+}
+
+impl fmt::Display for Constant {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Constant::Int(i) => write!(f, "{}", i),
+            Constant::Float(x) => write!(f, "{:?}", x),
+            Constant::Bool(b) => write!(f, "{}", b),
+            Constant::String(s) => write!(f, "\"{}\"", escape_string(s)),
+        }
+    }
+}
+
+impl fmt::Display for Operand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Operand::Value(id) => write!(f, "{}", id),
+            Operand::Const(constant) => write!(f, "{}", constant),
+        }
+    }
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(result) = self.result {
+            write!(f, "{} = ", result)?;
+        }
+        
+        match &self.kind {
+            InstructionKind::Binary(op, lhs, rhs) => write!(f, "{} {} {}, {}", op.mnemonic(), self.ty, lhs, rhs),
+            InstructionKind::Unary(op, operand) => write!(f, "{} {} {}", op.mnemonic(), self.ty, operand),
+            InstructionKind::IntToFloat(operand) => write!(f, "itof {} {}", self.ty, operand),
+            InstructionKind::Call(name, args) => {
+                write!(f, "call {} @{}(", self.ty, name)?;
+                write_list(f, args)?;
+                write!(f, ")")
+            },
+            InstructionKind::Phi(incoming) => {
+                write!(f, "phi {}", self.ty)?;
+                for (i, (block, value)) in incoming.iter().enumerate() {
+                    let separator = if i == 0 { " " } else { ", " };
+                    write!(f, "{}[{}, {}]", separator, value, block)?;
+                }
+                Ok(())
+            },
+            InstructionKind::LoadGlobal(name) => write!(f, "load {} @{}", self.ty, name),
+            InstructionKind::StoreGlobal(name, value) => write!(f, "store @{}, {}", name, value),
+            InstructionKind::Length(operand) => write!(f, "len {} {}", self.ty, operand),
+            InstructionKind::Index(collection, index) => write!(f, "index {} {}, {}", self.ty, collection, index),
+        }
+    }
+}
+
+impl fmt::Display for Terminator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Terminator::Return(Some(value)) => write!(f, "ret {}", value),
+            Terminator::Return(None) => write!(f, "ret"),
+            Terminator::Jump(target) => write!(f, "jmp {}", target),
+            Terminator::Branch(condition, then_block, else_block) => {
+                write!(f, "br {}, {}, {}", condition, then_block, else_block)
+            },
+            Terminator::Unreachable => write!(f, "unreachable"),
+        }
+    }
+}
+
+impl fmt::Display for BasicBlock {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{}:", self.id)?;
+        
+        for instruction in &self.instructions {
+            writeln!(f, "    {}", instruction)?;
+        }
+        
+        match &self.terminator {
+            Some(terminator) => writeln!(f, "    {}", terminator),
+            None => Ok(()),
+        }
+    }
+}
+
+impl fmt::Display for Function {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "fn @{}(", self.name)?;
+        for (i, param) in self.params.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}: {}", param.id, param.ty)?;
+        }
+        write!(f, ") -> {}", self.return_type)?;
+        match self.inline_hint {
+            InlineHint::None => {},
+            InlineHint::Always => write!(f, " inline")?,
+            InlineHint::Never => write!(f, " noinline")?,
+        }
+        writeln!(f, " {{")?;
+        
+        for block in &self.blocks {
+            write!(f, "{}", block)?;
+        }
+        
+        writeln!(f, "}}")
+    }
+}
+
+impl fmt::Display for Global {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mutability = if self.is_mutable { "mut " } else { "" };
+        write!(f, "global {}@{}: {} = {}", mutability, self.name, self.ty, self.initializer)
+    }
+}
+
+impl fmt::Display for Module {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for global in &self.globals {
+            writeln!(f, "{}", global)?;
+        }
+        
+        for (i, function) in self.functions.iter().enumerate() {
+            if i > 0 || !self.globals.is_empty() {
+                writeln!(f)?;
+            }
+            write!(f, "{}", function)?;
+        }
+        
+        Ok(())
+    }
+}
+
+fn write_list(f: &mut fmt::Formatter<'_>, operands: &[Operand]) -> fmt::Result {
+    for (i, operand) in operands.iter().enumerate() {
+        if i > 0 {
+            write!(f, ", ")?;
+        }
+        write!(f, "{}", operand)?;
+    }
+    Ok(())
+}
+
+pub fn escape_string(s: &str) -> String {
+    let mut escaped = String::new();
+    
+    for c in s.chars() {
+        match c {
+            '\n' => escaped.push_str("\\n"),
+            '\t' => escaped.push_str("\\t"),
+            '\r' => escaped.push_str("\\r"),
+            '\\' => escaped.push_str("\\\\"),
+            '"' => escaped.push_str("\\\""),
+            _ => escaped.push(c),
+        }
+    }
+    
+    escaped
+}
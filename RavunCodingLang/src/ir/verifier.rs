@@ -0,0 +1,402 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use crate::diagnostics::MessageId;
+use crate::ir::dominators::DominatorTree;
+use crate::ir::function::{BasicBlock, Function, Module};
+use crate::ir::instruction::{
+    BinaryOp, BlockId, Instruction, InstructionKind, Operand, Terminator, UnaryOp, ValueId,
+};
+use crate::ir::lowering::BUILTINS;
+use crate::semantics::types::Type;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct VerifyError {
+    pub function: String,
+    pub block: Option<BlockId>,
+    pub message: String,
+}
+
+impl fmt::Display for VerifyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.block {
+            Some(block) => write!(f, "@{} {}: {}", self.function, block, self.message),
+            None => write!(f, "@{}: {}", self.function, self.message),
+        }
+    }
+}
+
+impl std::error::Error for VerifyError {}
+
+pub fn verify_module(module: &Module) -> Result<(), Vec<VerifyError>> {
+    let mut errors = Vec::new();
+    
+    let mut names = HashSet::new();
+    for function in &module.functions {
+        if !names.insert(function.name.as_str()) {
+            errors.push(VerifyError {
+                function: function.name.clone(),
+                block: None,
+                message: MessageId::VerifyDuplicateFunction.text().to_string(),
+            });
+        }
+        errors.extend(Verifier::new(module, function).verify());
+    }
+    
+    if errors.is_empty() { Ok(()) } else { Err(errors) }
+}
+
+pub fn verify_function(module: &Module, function: &Function) -> Result<(), Vec<VerifyError>> {
+    let errors = Verifier::new(module, function).verify();
+    if errors.is_empty() { Ok(()) } else { Err(errors) }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Definition {
+    Param,
+    Instruction(BlockId, usize),
+}
+
+struct Verifier<'a> {
+    module: &'a Module,
+    function: &'a Function,
+    errors: Vec<VerifyError>,
+    definitions: HashMap<ValueId, Definition>,
+    types: HashMap<ValueId, Type>,
+}
+
+impl<'a> Verifier<'a> {
+    fn new(module: &'a Module, function: &'a Function) -> Self {
+        Verifier {
+            module,
+            function,
+            errors: Vec::new(),
+            definitions: HashMap::new(),
+            types: function.value_types(),
+        }
+    }
+    
+    fn error(&mut self, block: Option<BlockId>, message: String) {
+        self.errors.push(VerifyError {
+            function: self.function.name.clone(),
+            block,
+            message,
+        });
+    }
+    
+    fn verify(mut self) -> Vec<VerifyError> {
+        if self.function.blocks.is_empty() {
+            self.error(None, MessageId::VerifyNoBlocks.text().to_string());
+            return self.errors;
+        }
+        
+        self.collect_definitions();
+        self.check_structure();
+        
+        if !self.errors.is_empty() {
+            return self.errors;
+        }
+        
+        let dominators = DominatorTree::compute(self.function);
+        let preds = self.function.predecessors();
+        
+        for block in &self.function.blocks {
+            self.check_phis(block, &preds);
+            
+            for (index, instruction) in block.instructions.iter().enumerate() {
+                self.check_instruction_uses(block.id, index, instruction, &dominators);
+                self.check_instruction_types(block.id, instruction);
+            }
+            
+            if let Some(terminator) = &block.terminator {
+                let position = block.instructions.len();
+                for operand in terminator.operands() {
+                    self.check_use(block.id, position, operand, &dominators);
+                }
+                self.check_terminator_types(block.id, terminator);
+            }
+        }
+        
+        self.errors
+    }
+    
+    fn collect_definitions(&mut self) {
+        let function = self.function;
+        
+        for param in &function.params {
+            if self.definitions.insert(param.id, Definition::Param).is_some() {
+                self.error(None, MessageId::VerifyDuplicateValue.format(&[&param.id]));
+            }
+        }
+        
+        for block in &function.blocks {
+            for (index, instruction) in block.instructions.iter().enumerate() {
+                if let Some(result) = instruction.result
+                    && self.definitions.insert(result, Definition::Instruction(block.id, index)).is_some() {
+                    self.error(Some(block.id), MessageId::VerifyDuplicateValue.format(&[&result]));
+                }
+            }
+        }
+    }
+    
+    fn check_structure(&mut self) {
+        let function = self.function;
+        let mut ids = HashSet::new();
+        
+        for block in &function.blocks {
+            if !ids.insert(block.id) {
+                self.error(Some(block.id), MessageId::VerifyDuplicateBlock.text().to_string());
+            }
+        }
+        
+        for block in &function.blocks {
+            match &block.terminator {
+                None => self.error(Some(block.id), MessageId::VerifyMissingTerminator.text().to_string()),
+                Some(terminator) => {
+                    for target in terminator.successors() {
+                        if !ids.contains(&target) {
+                            self.error(Some(block.id), MessageId::VerifyUndefinedTarget.format(&[&target]));
+                        }
+                    }
+                },
+            }
+            
+            let phi_count = block.phis().count();
+            if block.instructions.iter().skip(phi_count).any(|i| i.is_phi()) {
+                self.error(Some(block.id), MessageId::VerifyPhiNotAtStart.text().to_string());
+            }
+            
+            if Some(block.id) == function.entry() && phi_count > 0 {
+                self.error(Some(block.id), MessageId::VerifyPhiInEntry.text().to_string());
+            }
+            
+            for instruction in &block.instructions {
+                let produces_value = instruction.ty != Type::Void;
+                match (&instruction.kind, instruction.result) {
+                    (InstructionKind::StoreGlobal(_, _), Some(result)) => self.error(
+                        Some(block.id),
+                        MessageId::VerifyStoreWithResult.format(&[&result]),
+                    ),
+                    (InstructionKind::StoreGlobal(_, _), None) => {},
+                    (_, None) if produces_value => self.error(
+                        Some(block.id),
+                        MessageId::VerifyMissingResult.format(&[&instruction]),
+                    ),
+                    (_, Some(result)) if !produces_value => self.error(
+                        Some(block.id),
+                        MessageId::VerifyVoidResult.format(&[&result]),
+                    ),
+                    _ => {},
+                }
+            }
+        }
+    }
+    
+    fn check_phis(&mut self, block: &BasicBlock, preds: &HashMap<BlockId, Vec<BlockId>>) {
+        let expected: HashSet<BlockId> = preds.get(&block.id).into_iter().flatten().copied().collect();
+        
+        for phi in block.phis() {
+            let InstructionKind::Phi(incoming) = &phi.kind else {
+                continue;
+            };
+            
+            let mut seen = HashSet::new();
+            for (pred, _) in incoming {
+                if !seen.insert(*pred) {
+                    self.error(Some(block.id), MessageId::VerifyPhiDuplicateIncoming.format(&[&phi, &pred]));
+                } else if !expected.contains(pred) {
+                    self.error(Some(block.id), MessageId::VerifyPhiNotPredecessor.format(&[&phi, &pred]));
+                }
+            }
+            
+            for pred in &expected {
+                if !seen.contains(pred) {
+                    self.error(Some(block.id), MessageId::VerifyPhiMissingIncoming.format(&[&phi, &pred]));
+                }
+            }
+        }
+    }
+    
+    fn check_instruction_uses(
+        &mut self,
+        block: BlockId,
+        index: usize,
+        instruction: &Instruction,
+        dominators: &DominatorTree,
+    ) {
+        if let InstructionKind::Phi(incoming) = &instruction.kind {
+            for (pred, operand) in incoming {
+                let end = self.function.block(*pred).map_or(0, |b| b.instructions.len());
+                self.check_use(*pred, end, operand, dominators);
+            }
+            return;
+        }
+        
+        for operand in instruction.operands() {
+            self.check_use(block, index, operand, dominators);
+        }
+    }
+    
+    fn check_use(&mut self, block: BlockId, position: usize, operand: &Operand, dominators: &DominatorTree) {
+        let Operand::Value(value) = operand else {
+            return;
+        };
+        
+        let dominated = match self.definitions.get(value) {
+            None => {
+                self.error(Some(block), MessageId::VerifyUseBeforeDefinition.format(&[&value]));
+                return;
+            },
+            Some(_) if !dominators.is_reachable(block) => true,
+            Some(Definition::Param) => true,
+            Some(Definition::Instruction(def_block, def_index)) => {
+                if *def_block == block {
+                    *def_index < position
+                } else {
+                    dominators.strictly_dominates(*def_block, block)
+                }
+            },
+        };
+        
+        if !dominated {
+            self.error(Some(block), MessageId::VerifyNotDominated.format(&[&value]));
+        }
+    }
+    
+    fn type_of(&self, operand: &Operand) -> Type {
+        self.function.operand_type(operand, &self.types)
+    }
+    
+    fn expect_type(&mut self, block: BlockId, context: &dyn fmt::Display, found: &Type, expected: &Type) {
+        if found != expected && *expected != Type::Any && *found != Type::Error {
+            self.error(Some(block), MessageId::VerifyTypeMismatch.format(&[context, expected, found]));
+        }
+    }
+    
+    fn check_instruction_types(&mut self, block: BlockId, instruction: &Instruction) {
+        let ty = &instruction.ty;
+        
+        match &instruction.kind {
+            InstructionKind::Binary(op, lhs, rhs) => {
+                let (lhs_type, rhs_type) = (self.type_of(lhs), self.type_of(rhs));
+                self.expect_type(block, instruction, &rhs_type, &lhs_type);
+                
+                let valid = match op {
+                    BinaryOp::Eq | BinaryOp::Ne => {
+                        matches!(lhs_type, Type::Int | Type::Float | Type::Bool | Type::String)
+                    },
+                    BinaryOp::Lt | BinaryOp::Gt | BinaryOp::Le | BinaryOp::Ge => {
+                        matches!(lhs_type, Type::Int | Type::Float | Type::String)
+                    },
+                    BinaryOp::Add => matches!(lhs_type, Type::Int | Type::Float | Type::String),
+                    op if op.is_bitwise() => lhs_type == Type::Int,
+                    _ => matches!(lhs_type, Type::Int | Type::Float),
+                };
+                
+                if !valid {
+                    self.error(Some(block), MessageId::VerifyInvalidOperandType.format(&[&op.mnemonic(), &lhs_type]));
+                }
+                
+                let result_type = if op.is_comparison() { Type::Bool } else { lhs_type };
+                self.expect_type(block, instruction, ty, &result_type);
+            },
+            InstructionKind::Unary(op, operand) => {
+                let operand_type = self.type_of(operand);
+                let valid = match op {
+                    UnaryOp::Neg => matches!(operand_type, Type::Int | Type::Float),
+                    UnaryOp::Not => matches!(operand_type, Type::Bool | Type::Int),
+                };
+                
+                if !valid {
+                    self.error(Some(block), MessageId::VerifyInvalidOperandType.format(&[&op.mnemonic(), &operand_type]));
+                }
+                self.expect_type(block, instruction, ty, &operand_type);
+            },
+            InstructionKind::IntToFloat(operand) => {
+                let operand_type = self.type_of(operand);
+                self.expect_type(block, instruction, &operand_type, &Type::Int);
+                self.expect_type(block, instruction, ty, &Type::Float);
+            },
+            InstructionKind::Call(name, args) => {
+                let Some(callee) = self.module.function(name) else {
+                    if BUILTINS.contains(&name.as_str()) {
+                        self.expect_type(block, instruction, ty, &Type::Void);
+                    } else {
+                        self.error(Some(block), MessageId::VerifyUndefinedFunction.format(&[&name]));
+                    }
+                    return;
+                };
+                
+                if callee.params.len() != args.len() {
+                    self.error(Some(block), MessageId::VerifyArgumentCount.format(&[
+                        &instruction, &name, &callee.params.len(), &args.len(),
+                    ]));
+                    return;
+                }
+                
+                for (arg, param) in args.iter().zip(&callee.params) {
+                    let arg_type = self.type_of(arg);
+                    self.expect_type(block, instruction, &arg_type, &param.ty);
+                }
+                self.expect_type(block, instruction, ty, &callee.return_type);
+            },
+            InstructionKind::Phi(incoming) => {
+                for (_, operand) in incoming {
+                    let operand_type = self.type_of(operand);
+                    self.expect_type(block, instruction, &operand_type, ty);
+                }
+            },
+            InstructionKind::LoadGlobal(name) => match self.module.global(name) {
+                Some(global) => self.expect_type(block, instruction, ty, &global.ty),
+                None => self.error(Some(block), MessageId::VerifyUndefinedGlobal.format(&[&name])),
+            },
+            InstructionKind::StoreGlobal(name, value) => match self.module.global(name) {
+                Some(global) => {
+                    if !global.is_mutable {
+                        self.error(Some(block), MessageId::VerifyImmutableGlobal.format(&[&name]));
+                    }
+                    let value_type = self.type_of(value);
+                    self.expect_type(block, instruction, &value_type, &global.ty);
+                },
+                None => self.error(Some(block), MessageId::VerifyUndefinedGlobal.format(&[&name])),
+            },
+            InstructionKind::Length(operand) => {
+                let operand_type = self.type_of(operand);
+                if !matches!(operand_type, Type::String | Type::Array(_, _)) {
+                    self.error(Some(block), MessageId::VerifyInvalidOperandType.format(&[&"len", &operand_type]));
+                }
+                self.expect_type(block, instruction, ty, &Type::Int);
+            },
+            InstructionKind::Index(collection, index) => {
+                let index_type = self.type_of(index);
+                self.expect_type(block, instruction, &index_type, &Type::Int);
+                
+                match self.type_of(collection) {
+                    Type::String => self.expect_type(block, instruction, ty, &Type::String),
+                    Type::Array(element, _) => self.expect_type(block, instruction, ty, &element),
+                    other => self.error(Some(block), MessageId::VerifyInvalidOperandType.format(&[&"index", &other])),
+                }
+            },
+        }
+    }
+    
+    fn check_terminator_types(&mut self, block: BlockId, terminator: &Terminator) {
+        let return_type = self.function.return_type.clone();
+        
+        match terminator {
+            Terminator::Return(Some(value)) => {
+                let value_type = self.type_of(value);
+                self.expect_type(block, terminator, &value_type, &return_type);
+            },
+            Terminator::Return(None) => {
+                if return_type != Type::Void {
+                    self.error(Some(block), MessageId::VerifyMissingReturnValue.format(&[&return_type]));
+                }
+            },
+            Terminator::Branch(condition, _, _) => {
+                let condition_type = self.type_of(condition);
+                self.expect_type(block, terminator, &condition_type, &Type::Bool);
+            },
+            Terminator::Jump(_) | Terminator::Unreachable => {},
+        }
+    }
+}
@@ -1,29 +1,45 @@
 use std::iter::Peekable;
 use std::str::Chars;
+use crate::diagnostics::{Diagnostic, ErrorCode, MessageId};
+use crate::lexer::span::Span;
 use crate::lexer::token::{Token, TokenType};
 
 pub struct Lexer<'a> {
+    source: &'a str,
     input: Peekable<Chars<'a>>,
     line: usize,
     column: usize,
     position: usize,
+    file_id: usize,
+    errors: Vec<Diagnostic>,
 }
 
 impl<'a> Lexer<'a> {
     pub fn new(input: &'a str) -> Self {
+        Self::with_file_id(input, 0)
+    }
+    
+    pub fn with_file_id(input: &'a str, file_id: usize) -> Self {
         Lexer {
+            source: input,
             input: input.chars().peekable(),
             line: 1,
             column: 1,
             position: 0,
+            file_id,
+            errors: Vec::new(),
         }
     }
     
+    pub fn errors(&self) -> &[Diagnostic] {
+        &self.errors
+    }
+    
     fn advance(&mut self) -> Option<char> {
         let c = self.input.next();
         
         if let Some(ch) = c {
-            self.position += 1;
+            self.position += ch.len_utf8();
             self.column += 1;
             
             if ch == '\n' {
@@ -63,6 +79,8 @@ impl<'a> Lexer<'a> {
             "else" => TokenType::Else,
             "for" => TokenType::For,
             "while" => TokenType::While,
+            "break" => TokenType::Break,
+            "continue" => TokenType::Continue,
             "in" => TokenType::In,
             "struct" => TokenType::Struct,
             "impl" => TokenType::Impl,
@@ -86,18 +104,17 @@ impl<'a> Lexer<'a> {
         number.push(first_digit);
         
         while let Some(&c) = self.peek() {
-            if c.is_digit(10) {
+            if c.is_ascii_digit() {
                 number.push(c);
                 self.advance();
-            } else if c == '.' && !is_float {
+            } else if c == '.' && !is_float && self.input.clone().nth(1) != Some('.') {
                 is_float = true;
                 number.push(c);
                 self.advance();
                 
-                if let Some(&next) = self.peek() {
-                    if !next.is_digit(10) {
-                        return Token::new(TokenType::Invalid, number, self.line, start_pos);
-                    }
+                if let Some(&next) = self.peek()
+                    && !next.is_ascii_digit() {
+                    return Token::new(TokenType::Invalid, number, self.line, start_pos);
                 }
             } else {
                 break;
@@ -174,6 +191,9 @@ impl<'a> Lexer<'a> {
                     
                     prev_char = c;
                 }
+            } else if next == '=' {
+                self.advance();
+                return Token::new(TokenType::DivAssign, "/=".to_string(), self.line, start_pos);
             } else {
                 return Token::new(TokenType::Slash, "/".to_string(), self.line, start_pos);
             }
@@ -187,11 +207,42 @@ impl<'a> Lexer<'a> {
     pub fn next_token(&mut self) -> Token {
         self.skip_whitespace();
         
+        let start = self.position;
+        let mut token = self.scan_token();
+        token.span = Span::new(self.file_id, start, self.position);
+        
+        if token.token_type == TokenType::Invalid {
+            let diagnostic = self.invalid_token(token.span);
+            self.errors.push(diagnostic);
+        }
+        
+        token
+    }
+    
+    fn invalid_token(&self, span: Span) -> Diagnostic {
+        let text = span.text(self.source).unwrap_or_default();
+        
+        if text.starts_with('"') {
+            Diagnostic::error(MessageId::UnterminatedString.text())
+                .with_code(ErrorCode::UnterminatedString.to_string())
+                .with_label(Span::new(self.file_id, span.start, span.start + 1), MessageId::StringStartsHere.text())
+        } else if text.starts_with(|c: char| c.is_ascii_digit()) {
+            Diagnostic::error(MessageId::InvalidNumber.format(&[&text]))
+                .with_code(ErrorCode::InvalidNumber.to_string())
+                .with_label(span, MessageId::ExpectedFractionDigit.text())
+        } else {
+            Diagnostic::error(MessageId::InvalidCharacter.format(&[&text]))
+                .with_code(ErrorCode::InvalidCharacter.to_string())
+                .with_label(span, MessageId::UnrecognizedCharacter.text())
+        }
+    }
+    
+    fn scan_token(&mut self) -> Token {
         if let Some(c) = self.advance() {
             match c {
                 c if c.is_alphabetic() || c == '_' => self.identifier(c),
                 
-                c if c.is_digit(10) => self.number(c),
+                c if c.is_ascii_digit() => self.number(c),
                 
                 '"' => self.string(),
                 
@@ -262,36 +313,85 @@ impl<'a> Lexer<'a> {
                             self.advance();
                             Token::new(TokenType::NotEqual, "!=".to_string(), self.line, start_pos)
                         } else {
-                            Token::new(TokenType::Invalid, "!".to_string(), self.line, start_pos)
+                            Token::new(TokenType::Not, "!".to_string(), self.line, start_pos)
                         }
                     } else {
-                        Token::new(TokenType::Invalid, "!".to_string(), self.line, start_pos)
+                        Token::new(TokenType::Not, "!".to_string(), self.line, start_pos)
+                    }
+                },
+                '&' => {
+                    let start_pos = self.column - 1;
+                    match self.peek() {
+                        Some(&'&') => {
+                            self.advance();
+                            Token::new(TokenType::And, "&&".to_string(), self.line, start_pos)
+                        },
+                        Some(&'=') => {
+                            self.advance();
+                            Token::new(TokenType::BitAndAssign, "&=".to_string(), self.line, start_pos)
+                        },
+                        _ => Token::new(TokenType::BitAnd, "&".to_string(), self.line, start_pos),
+                    }
+                },
+                '|' => {
+                    let start_pos = self.column - 1;
+                    match self.peek() {
+                        Some(&'|') => {
+                            self.advance();
+                            Token::new(TokenType::Or, "||".to_string(), self.line, start_pos)
+                        },
+                        Some(&'=') => {
+                            self.advance();
+                            Token::new(TokenType::BitOrAssign, "|=".to_string(), self.line, start_pos)
+                        },
+                        _ => Token::new(TokenType::BitOr, "|".to_string(), self.line, start_pos),
+                    }
+                },
+                '~' => {
+                    let start_pos = self.column - 1;
+                    if let Some(&'=') = self.peek() {
+                        self.advance();
+                        Token::new(TokenType::XorAssign, "~=".to_string(), self.line, start_pos)
+                    } else {
+                        Token::new(TokenType::Tilde, "~".to_string(), self.line, start_pos)
                     }
                 },
                 '>' => {
                     let start_pos = self.column - 1;
-                    if let Some(&next) = self.peek() {
-                        if next == '=' {
+                    match self.peek() {
+                        Some(&'=') => {
                             self.advance();
                             Token::new(TokenType::GreaterEq, ">=".to_string(), self.line, start_pos)
-                        } else {
-                            Token::new(TokenType::Greater, ">".to_string(), self.line, start_pos)
-                        }
-                    } else {
-                        Token::new(TokenType::Greater, ">".to_string(), self.line, start_pos)
+                        },
+                        Some(&'>') => {
+                            self.advance();
+                            if let Some(&'=') = self.peek() {
+                                self.advance();
+                                Token::new(TokenType::ShrAssign, ">>=".to_string(), self.line, start_pos)
+                            } else {
+                                Token::new(TokenType::ShiftRight, ">>".to_string(), self.line, start_pos)
+                            }
+                        },
+                        _ => Token::new(TokenType::Greater, ">".to_string(), self.line, start_pos),
                     }
                 },
                 '<' => {
                     let start_pos = self.column - 1;
-                    if let Some(&next) = self.peek() {
-                        if next == '=' {
+                    match self.peek() {
+                        Some(&'=') => {
                             self.advance();
                             Token::new(TokenType::LessEq, "<=".to_string(), self.line, start_pos)
-                        } else {
-                            Token::new(TokenType::Less, "<".to_string(), self.line, start_pos)
-                        }
-                    } else {
-                        Token::new(TokenType::Less, "<".to_string(), self.line, start_pos)
+                        },
+                        Some(&'<') => {
+                            self.advance();
+                            if let Some(&'=') = self.peek() {
+                                self.advance();
+                                Token::new(TokenType::ShlAssign, "<<=".to_string(), self.line, start_pos)
+                            } else {
+                                Token::new(TokenType::ShiftLeft, "<<".to_string(), self.line, start_pos)
+                            }
+                        },
+                        _ => Token::new(TokenType::Less, "<".to_string(), self.line, start_pos),
                     }
                 },
                 
@@ -304,6 +404,7 @@ impl<'a> Lexer<'a> {
                 ';' => Token::new(TokenType::Semicolon, ";".to_string(), self.line, self.column - 1),
                 ':' => Token::new(TokenType::Colon, ":".to_string(), self.line, self.column - 1),
                 ',' => Token::new(TokenType::Comma, ",".to_string(), self.line, self.column - 1),
+                '#' => Token::new(TokenType::Hash, "#".to_string(), self.line, self.column - 1),
                 '.' => {
                     let start_pos = self.column - 1;
                     if let Some(&next) = self.peek() {
@@ -346,7 +447,7 @@ impl<'a> Lexer<'a> {
                 break;
             }
             
-            if token.token_type != TokenType::Comment && token.token_type != TokenType::Whitespace {
+            if !matches!(token.token_type, TokenType::Comment | TokenType::Whitespace | TokenType::Invalid) {
                 tokens.push(token);
             }
         }
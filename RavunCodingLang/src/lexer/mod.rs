@@ -1,2 +1,4 @@
+#[allow(clippy::module_inception)]
 pub mod lexer;
+pub mod span;
 pub mod token;
@@ -0,0 +1,40 @@
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Span {
+    pub file_id: usize,
+    pub start: usize,
+    pub end: usize,
+}
+
+impl Span {
+    pub fn new(file_id: usize, start: usize, end: usize) -> Self {
+        Span { file_id, start, end }
+    }
+    
+    pub fn to(self, other: Span) -> Span {
+        Span {
+            file_id: self.file_id,
+            start: self.start.min(other.start),
+            end: self.end.max(other.end),
+        }
+    }
+    
+    pub fn len(&self) -> usize {
+        self.end - self.start
+    }
+    
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+    
+    pub fn text<'a>(&self, source: &'a str) -> Option<&'a str> {
+        source.get(self.start..self.end)
+    }
+}
+
+impl fmt::Display for Span {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}..{}", self.start, self.end)
+    }
+}
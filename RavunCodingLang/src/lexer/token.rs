@@ -1,3 +1,7 @@
+use std::fmt;
+use crate::diagnostics::MessageId;
+use crate::lexer::span::Span;
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum TokenType {
     Let,
@@ -9,6 +13,8 @@ pub enum TokenType {
     Else,
     For,
     While,
+    Break,
+    Continue,
     In,
     Struct,
     Impl,
@@ -32,6 +38,12 @@ pub enum TokenType {
     Percent,
     Caret,
     
+    BitAnd,
+    BitOr,
+    Tilde,
+    ShiftLeft,
+    ShiftRight,
+    
     Equal,
     NotEqual,
     Greater,
@@ -39,11 +51,20 @@ pub enum TokenType {
     GreaterEq,
     LessEq,
     
+    And,
+    Or,
+    Not,
+    
     Assign,
     PlusAssign,
     MinusAssign,
     MulAssign,
     DivAssign,
+    BitAndAssign,
+    BitOrAssign,
+    XorAssign,
+    ShlAssign,
+    ShrAssign,
     
     LeftParen,
     RightParen,
@@ -57,6 +78,7 @@ pub enum TokenType {
     Dot,
     DoubleDot,
     Arrow,
+    Hash,
     
     Comment,
     Whitespace,
@@ -65,12 +87,94 @@ pub enum TokenType {
     Invalid,
 }
 
+impl fmt::Display for TokenType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let symbol = match self {
+            TokenType::Let => "let",
+            TokenType::Mut => "mut",
+            TokenType::Const => "const",
+            TokenType::Fn => "fn",
+            TokenType::Return => "return",
+            TokenType::If => "if",
+            TokenType::Else => "else",
+            TokenType::For => "for",
+            TokenType::While => "while",
+            TokenType::Break => "break",
+            TokenType::Continue => "continue",
+            TokenType::In => "in",
+            TokenType::Struct => "struct",
+            TokenType::Impl => "impl",
+            TokenType::Mod => "mod",
+            TokenType::Pub => "pub",
+            TokenType::Async => "async",
+            TokenType::Parallel => "parallel",
+            TokenType::Match => "match",
+            TokenType::Plus => "+",
+            TokenType::Minus => "-",
+            TokenType::Asterisk => "*",
+            TokenType::Slash => "/",
+            TokenType::Percent => "%",
+            TokenType::Caret => "^",
+            TokenType::BitAnd => "&",
+            TokenType::BitOr => "|",
+            TokenType::Tilde => "~",
+            TokenType::ShiftLeft => "<<",
+            TokenType::ShiftRight => ">>",
+            TokenType::Equal => "==",
+            TokenType::NotEqual => "!=",
+            TokenType::Greater => ">",
+            TokenType::Less => "<",
+            TokenType::GreaterEq => ">=",
+            TokenType::LessEq => "<=",
+            TokenType::And => "&&",
+            TokenType::Or => "||",
+            TokenType::Not => "!",
+            TokenType::Assign => "=",
+            TokenType::PlusAssign => "+=",
+            TokenType::MinusAssign => "-=",
+            TokenType::MulAssign => "*=",
+            TokenType::DivAssign => "/=",
+            TokenType::BitAndAssign => "&=",
+            TokenType::BitOrAssign => "|=",
+            TokenType::XorAssign => "~=",
+            TokenType::ShlAssign => "<<=",
+            TokenType::ShrAssign => ">>=",
+            TokenType::LeftParen => "(",
+            TokenType::RightParen => ")",
+            TokenType::LeftBrace => "{",
+            TokenType::RightBrace => "}",
+            TokenType::LeftBracket => "[",
+            TokenType::RightBracket => "]",
+            TokenType::Semicolon => ";",
+            TokenType::Colon => ":",
+            TokenType::Comma => ",",
+            TokenType::Dot => ".",
+            TokenType::DoubleDot => "..",
+            TokenType::Arrow => "->",
+            TokenType::Hash => "#",
+            TokenType::Identifier => return write!(f, "{}", MessageId::TokenIdentifier.text()),
+            TokenType::IntLiteral => return write!(f, "{}", MessageId::TokenIntLiteral.text()),
+            TokenType::FloatLiteral => return write!(f, "{}", MessageId::TokenFloatLiteral.text()),
+            TokenType::StringLiteral => return write!(f, "{}", MessageId::TokenStringLiteral.text()),
+            TokenType::CharLiteral => return write!(f, "{}", MessageId::TokenCharLiteral.text()),
+            TokenType::BoolLiteral => return write!(f, "{}", MessageId::TokenBoolLiteral.text()),
+            TokenType::Comment => return write!(f, "{}", MessageId::TokenComment.text()),
+            TokenType::Whitespace => return write!(f, "{}", MessageId::TokenWhitespace.text()),
+            TokenType::EOF => return write!(f, "{}", MessageId::TokenEof.text()),
+            TokenType::Invalid => return write!(f, "{}", MessageId::TokenInvalid.text()),
+        };
+        
+        write!(f, "'{}'", symbol)
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct Token {
     pub token_type: TokenType,
     pub lexeme: String,
     pub line: usize,
     pub column: usize,
+    pub span: Span,
 }
 
 impl Token {
@@ -80,6 +184,7 @@ impl Token {
             lexeme,
             line,
             column,
+            span: Span::default(),
         }
     }
 }
@@ -0,0 +1,12 @@
+pub mod lexer;
+pub mod parser;
+pub mod semantics;
+pub mod interpreter;
+pub mod ir;
+pub mod optimizer;
+pub mod codegen;
+pub mod diagnostics;
+pub mod bytecode;
+pub mod repl;
+pub mod lsp;
+pub mod utils;
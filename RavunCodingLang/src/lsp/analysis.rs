@@ -0,0 +1,291 @@
+use crate::diagnostics::SourceFile;
+use crate::lexer::lexer::Lexer;
+use crate::lexer::span::Span;
+use crate::parser::error::ParseError;
+use crate::parser::parser::Parser;
+use crate::semantics::analyzer::{SemanticAnalyzer, SymbolReference};
+use crate::semantics::symbol_table::{Symbol, SymbolKind};
+use crate::semantics::types::Type;
+
+const KEYWORDS: [&str; 19] = [
+    "let", "mut", "const", "fn", "return", "if", "else", "for", "while", "break", "continue", "in",
+    "struct", "impl", "mod", "pub", "match", "true", "false",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Range {
+    pub line: usize,
+    pub start: usize,
+    pub end: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Diagnostic {
+    pub range: Range,
+    pub severity: Severity,
+    pub code: Option<String>,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CompletionKind {
+    Keyword,
+    Function,
+    Variable,
+    Struct,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Completion {
+    pub label: String,
+    pub kind: CompletionKind,
+    pub detail: String,
+}
+
+pub struct Analysis {
+    lines: Vec<Vec<char>>,
+    parsed: bool,
+    pub diagnostics: Vec<Diagnostic>,
+    references: Vec<SymbolReference>,
+    definitions: Vec<Symbol>,
+    item_lines: Vec<usize>,
+}
+
+impl Analysis {
+    pub fn new(source: &str) -> Analysis {
+        let mut analysis = Analysis {
+            lines: source.split('\n').map(|line| line.chars().collect()).collect(),
+            parsed: false,
+            diagnostics: Vec::new(),
+            references: Vec::new(),
+            definitions: Vec::new(),
+            item_lines: Vec::new(),
+        };
+        
+        let file = SourceFile::new(0, "", source);
+        let mut lexer = Lexer::new(source);
+        let tokens = lexer.tokenize();
+        let mut errors = lexer.errors().to_vec();
+        let (ast, parser_errors) = Parser::new(tokens).parse_recovering();
+        errors.extend(parser_errors.iter().map(ParseError::to_diagnostic));
+        
+        for error in &errors {
+            let range = match error.primary_span() {
+                Some(span) => analysis.span_range(&file, span),
+                None => Range { line: 0, start: 0, end: 0 },
+            };
+            analysis.diagnostics.push(Diagnostic { range, severity: Severity::Error, code: error.code.clone(), message: error.message.clone() });
+        }
+        analysis.parsed = errors.is_empty();
+        analysis.item_lines = ast.children.iter()
+            .filter_map(|item| item.token.as_ref().map(|token| token.line))
+            .collect();
+        
+        let mut analyzer = SemanticAnalyzer::new();
+        if let Err(err) = analyzer.load_std_library() {
+            analysis.diagnostics.push(Diagnostic {
+                range: Range { line: 0, start: 0, end: 0 },
+                severity: Severity::Error,
+                code: err.code().map(|code| code.to_string()),
+                message: err.message,
+            });
+            return analysis;
+        }
+        
+        for error in analyzer.analyze(&ast) {
+            if !analysis.parsed && error.is_warning() {
+                continue;
+            }
+            
+            let range = match error.span {
+                Some(span) => analysis.span_range(&file, span),
+                None => analysis.word_range(error.line, error.column),
+            };
+            let severity = if error.is_warning() { Severity::Warning } else { Severity::Error };
+            analysis.diagnostics.push(Diagnostic { range, severity, code: error.code().map(|code| code.to_string()), message: error.message });
+        }
+        
+        if analysis.parsed {
+            analysis.references = analyzer.references.clone();
+            analysis.definitions = analyzer.get_symbol_table().all_definitions().to_vec();
+        }
+        
+        analysis
+    }
+    
+    pub fn is_parsed(&self) -> bool {
+        self.parsed
+    }
+    
+    pub fn inherit_symbols(&mut self, previous: &Analysis) {
+        self.references = previous.references.clone();
+        self.definitions = previous.definitions.clone();
+        self.item_lines = previous.item_lines.clone();
+    }
+    
+    pub fn hover(&self, line: usize, column: usize) -> Option<String> {
+        let symbol = self.symbol_at(line, column)?;
+        
+        let signature = match (&symbol.kind, &symbol.parameters, &symbol.symbol_type) {
+            (SymbolKind::Function, Some(parameters), Type::Function(_, return_type)) => {
+                let parameters: Vec<String> = parameters.iter()
+                    .map(|p| format!("{}: {}", p.name, p.symbol_type))
+                    .collect();
+                format!("fn {}({}) -> {}", symbol.name, parameters.join(", "), return_type)
+            },
+            (SymbolKind::Variable, _, _) if symbol.is_mutable => format!("let mut {}: {}", symbol.name, symbol.symbol_type),
+            (SymbolKind::Variable, _, _) => format!("let {}: {}", symbol.name, symbol.symbol_type),
+            (SymbolKind::Type, _, _) => format!("struct {}", symbol.name),
+            _ => format!("{}: {}", symbol.name, symbol.symbol_type),
+        };
+        
+        Some(format!("```ravun\n{}\n```\n{}", signature, symbol.kind))
+    }
+    
+    pub fn definition(&self, line: usize, column: usize) -> Option<Range> {
+        let symbol = self.symbol_at(line, column)?;
+        
+        if symbol.line == 0 {
+            return None;
+        }
+        
+        Some(self.name_range(symbol))
+    }
+    
+    pub fn completions(&self, line: usize) -> Vec<Completion> {
+        let mut completions: Vec<Completion> = KEYWORDS.iter()
+            .map(|keyword| Completion {
+                label: keyword.to_string(),
+                kind: CompletionKind::Keyword,
+                detail: "anahtar kelime".to_string(),
+            })
+            .collect();
+        
+        for symbol in &self.definitions {
+            let visible = symbol.scope_level == 0
+                || (symbol.line <= line + 1 && self.item_at(symbol.line) == self.item_at(line + 1));
+            if !visible {
+                continue;
+            }
+            
+            let kind = match symbol.kind {
+                SymbolKind::Function => CompletionKind::Function,
+                SymbolKind::Type => CompletionKind::Struct,
+                _ => CompletionKind::Variable,
+            };
+            let completion = Completion {
+                label: symbol.name.clone(),
+                kind,
+                detail: symbol.symbol_type.to_string(),
+            };
+            
+            match completions.iter_mut().find(|c| c.label == completion.label) {
+                Some(existing) => *existing = completion,
+                None => completions.push(completion),
+            }
+        }
+        
+        completions
+    }
+    
+    fn item_at(&self, line: usize) -> Option<usize> {
+        self.item_lines.iter().rposition(|&start| start <= line)
+    }
+    
+    fn symbol_at(&self, line: usize, column: usize) -> Option<&Symbol> {
+        let word = self.word_at(line, column)?;
+        
+        let reference = self.references.iter().find(|r| {
+            r.line == line + 1 && r.column == word.start + 1 && r.symbol.name == self.text(&word)
+        });
+        if let Some(reference) = reference {
+            return Some(&reference.symbol);
+        }
+        
+        self.definitions.iter().find(|symbol| symbol.line > 0 && self.name_range(symbol) == word)
+    }
+    
+    fn text(&self, range: &Range) -> String {
+        self.lines.get(range.line).map_or(String::new(), |line| line[range.start..range.end].iter().collect())
+    }
+    
+    fn word_at(&self, line: usize, column: usize) -> Option<Range> {
+        let chars = self.lines.get(line)?;
+        
+        let mut start = column.min(chars.len());
+        while start > 0 && is_identifier_char(chars[start - 1]) {
+            start -= 1;
+        }
+        
+        let mut end = column.min(chars.len());
+        while end < chars.len() && is_identifier_char(chars[end]) {
+            end += 1;
+        }
+        
+        if start == end {
+            None
+        } else {
+            Some(Range { line, start, end })
+        }
+    }
+    
+    fn word_range(&self, line: usize, column: usize) -> Range {
+        let line = line.saturating_sub(1);
+        let start = column.saturating_sub(1);
+        
+        let end = match self.lines.get(line) {
+            Some(chars) if start < chars.len() && is_identifier_char(chars[start]) => {
+                start + chars[start..].iter().take_while(|c| is_identifier_char(**c)).count()
+            },
+            Some(chars) if start < chars.len() => start + 1,
+            _ => start,
+        };
+        
+        Range { line, start, end }
+    }
+    
+    fn span_range(&self, file: &SourceFile, span: Span) -> Range {
+        let (line, start) = file.location(span.start);
+        let (end_line, end) = file.location(span.end);
+        
+        let line = line - 1;
+        let end = if end_line == line + 1 {
+            end - 1
+        } else {
+            self.lines.get(line).map_or(start, |chars| chars.len())
+        };
+        
+        Range { line, start: start - 1, end }
+    }
+    
+    fn name_range(&self, symbol: &Symbol) -> Range {
+        let line = symbol.line.saturating_sub(1);
+        let name: Vec<char> = symbol.name.chars().collect();
+        
+        if let Some(chars) = self.lines.get(line) {
+            let mut start = symbol.column.saturating_sub(1);
+            
+            while start + name.len() <= chars.len() {
+                let before = start == 0 || !is_identifier_char(chars[start - 1]);
+                let after = chars.get(start + name.len()).is_none_or(|c| !is_identifier_char(*c));
+                
+                if before && after && chars[start..start + name.len()] == name[..] {
+                    return Range { line, start, end: start + name.len() };
+                }
+                start += 1;
+            }
+        }
+        
+        self.word_range(symbol.line, symbol.column)
+    }
+}
+
+fn is_identifier_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
@@ -0,0 +1,7 @@
+//! LSP modülü
+pub mod analysis;
+pub mod server;
+
+pub use crate::utils::Json;
+pub use analysis::Analysis;
+pub use server::{run, Server};
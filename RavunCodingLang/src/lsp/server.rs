@@ -0,0 +1,343 @@
+use std::collections::HashMap;
+use std::io::{self, BufRead, Write};
+
+use crate::diagnostics::MessageId;
+use crate::lsp::analysis::{Analysis, CompletionKind, Range, Severity};
+use crate::utils::Json;
+
+const PARSE_ERROR: i64 = -32700;
+const INVALID_REQUEST: i64 = -32600;
+const METHOD_NOT_FOUND: i64 = -32601;
+const INVALID_PARAMS: i64 = -32602;
+const SERVER_NOT_INITIALIZED: i64 = -32002;
+
+struct Document {
+    lines: Vec<Vec<char>>,
+    analysis: Analysis,
+}
+
+impl Document {
+    fn new(text: &str, previous: Option<&Document>) -> Document {
+        let mut analysis = Analysis::new(text);
+        
+        if let Some(previous) = previous
+            && !analysis.is_parsed() {
+            analysis.inherit_symbols(&previous.analysis);
+        }
+        
+        Document {
+            lines: text.split('\n').map(|line| line.chars().collect()).collect(),
+            analysis,
+        }
+    }
+    
+    fn utf16_column(&self, line: usize, column: usize) -> usize {
+        self.lines.get(line).map_or(column, |chars| {
+            chars.iter().take(column).map(|c| c.len_utf16()).sum()
+        })
+    }
+    
+    fn char_column(&self, line: usize, character: usize) -> usize {
+        let Some(chars) = self.lines.get(line) else {
+            return character;
+        };
+        
+        let mut units = 0;
+        for (index, c) in chars.iter().enumerate() {
+            if units >= character {
+                return index;
+            }
+            units += c.len_utf16();
+        }
+        chars.len()
+    }
+    
+    fn range(&self, range: Range) -> Json {
+        Json::object(vec![
+            ("start", position(range.line, self.utf16_column(range.line, range.start))),
+            ("end", position(range.line, self.utf16_column(range.line, range.end))),
+        ])
+    }
+}
+
+#[derive(Default)]
+pub struct Server {
+    documents: HashMap<String, Document>,
+    initialized: bool,
+    shutdown: bool,
+    exit_code: Option<i32>,
+}
+
+impl Server {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    pub fn exit_code(&self) -> Option<i32> {
+        self.exit_code
+    }
+    
+    pub fn handle(&mut self, message: &Json) -> Vec<Json> {
+        let Some(method) = message.get("method").and_then(Json::as_str) else {
+            return Vec::new();
+        };
+        let params = message.get("params").unwrap_or(&Json::Null);
+        
+        let Some(id) = message.get("id").cloned() else {
+            return self.notification(method, params);
+        };
+        
+        let result = if self.shutdown {
+            Err((INVALID_REQUEST, MessageId::LspShutDown.text().to_string()))
+        } else if !self.initialized && method != "initialize" {
+            Err((SERVER_NOT_INITIALIZED, MessageId::LspNotInitialized.text().to_string()))
+        } else {
+            self.request(method, params)
+        };
+        
+        let response = match result {
+            Ok(result) => Json::object(vec![
+                ("jsonrpc", Json::string("2.0")),
+                ("id", id),
+                ("result", result),
+            ]),
+            Err((code, message)) => error_response(id, code, &message),
+        };
+        
+        vec![response]
+    }
+    
+    fn request(&mut self, method: &str, params: &Json) -> Result<Json, (i64, String)> {
+        match method {
+            "initialize" => {
+                self.initialized = true;
+                Ok(capabilities())
+            },
+            "shutdown" => {
+                self.shutdown = true;
+                Ok(Json::Null)
+            },
+            "textDocument/hover" => {
+                let (document, line, column) = self.position_params(params)?;
+                Ok(match document.analysis.hover(line, column) {
+                    Some(contents) => Json::object(vec![
+                        ("contents", Json::object(vec![
+                            ("kind", Json::string("markdown")),
+                            ("value", Json::string(contents)),
+                        ])),
+                    ]),
+                    None => Json::Null,
+                })
+            },
+            "textDocument/definition" => {
+                let (document, line, column) = self.position_params(params)?;
+                let uri = params.path(&["textDocument", "uri"]).cloned().unwrap_or(Json::Null);
+                Ok(match document.analysis.definition(line, column) {
+                    Some(range) => Json::object(vec![("uri", uri), ("range", document.range(range))]),
+                    None => Json::Null,
+                })
+            },
+            "textDocument/completion" => {
+                let (document, line, _) = self.position_params(params)?;
+                let items = document.analysis.completions(line).into_iter()
+                    .map(|completion| Json::object(vec![
+                        ("label", Json::string(completion.label)),
+                        ("kind", Json::Number(completion_kind(completion.kind))),
+                        ("detail", Json::string(completion.detail)),
+                    ]))
+                    .collect();
+                Ok(Json::Array(items))
+            },
+            _ => Err((METHOD_NOT_FOUND, MessageId::LspUnsupportedMethod.format(&[&method]))),
+        }
+    }
+    
+    fn notification(&mut self, method: &str, params: &Json) -> Vec<Json> {
+        if method == "exit" {
+            self.exit_code = Some(if self.shutdown { 0 } else { 1 });
+            return Vec::new();
+        }
+        
+        if !self.initialized {
+            return Vec::new();
+        }
+        
+        let Some(uri) = params.path(&["textDocument", "uri"]).and_then(Json::as_str) else {
+            return Vec::new();
+        };
+        
+        match method {
+            "textDocument/didOpen" => {
+                let Some(text) = params.path(&["textDocument", "text"]).and_then(Json::as_str) else {
+                    return Vec::new();
+                };
+                self.update(uri, text)
+            },
+            "textDocument/didChange" => {
+                let text = params.get("contentChanges")
+                    .and_then(Json::as_array)
+                    .and_then(|changes| changes.last())
+                    .and_then(|change| change.get("text"))
+                    .and_then(Json::as_str);
+                match text {
+                    Some(text) => self.update(uri, text),
+                    None => Vec::new(),
+                }
+            },
+            "textDocument/didClose" => {
+                self.documents.remove(uri);
+                vec![publish_diagnostics(uri, Vec::new())]
+            },
+            _ => Vec::new(),
+        }
+    }
+    
+    fn update(&mut self, uri: &str, text: &str) -> Vec<Json> {
+        let document = Document::new(text, self.documents.get(uri));
+        
+        let diagnostics = document.analysis.diagnostics.iter()
+            .map(|diagnostic| Json::object(vec![
+                ("range", document.range(diagnostic.range)),
+                ("severity", Json::Number(match diagnostic.severity {
+                    Severity::Error => 1.0,
+                    Severity::Warning => 2.0,
+                })),
+                ("code", diagnostic.code.clone().map_or(Json::Null, Json::String)),
+                ("source", Json::string("ravun")),
+                ("message", Json::string(diagnostic.message.clone())),
+            ]))
+            .collect();
+        
+        self.documents.insert(uri.to_string(), document);
+        vec![publish_diagnostics(uri, diagnostics)]
+    }
+    
+    fn position_params(&self, params: &Json) -> Result<(&Document, usize, usize), (i64, String)> {
+        let uri = params.path(&["textDocument", "uri"]).and_then(Json::as_str);
+        let line = params.path(&["position", "line"]).and_then(Json::as_u64);
+        let character = params.path(&["position", "character"]).and_then(Json::as_u64);
+        
+        let (Some(uri), Some(line), Some(character)) = (uri, line, character) else {
+            return Err((INVALID_PARAMS, MessageId::LspExpectedPosition.text().to_string()));
+        };
+        let document = self.documents.get(uri)
+            .ok_or_else(|| (INVALID_PARAMS, MessageId::LspDocumentNotOpen.format(&[&uri])))?;
+        
+        let line = line as usize;
+        Ok((document, line, document.char_column(line, character as usize)))
+    }
+}
+
+pub fn run(reader: &mut impl BufRead, writer: &mut impl Write) -> io::Result<i32> {
+    let mut server = Server::new();
+    
+    while let Some(content) = read_message(reader)? {
+        let responses = match Json::parse(&content) {
+            Ok(message) => server.handle(&message),
+            Err(err) => vec![error_response(Json::Null, PARSE_ERROR, &err)],
+        };
+        
+        for response in responses {
+            write_message(writer, &response)?;
+        }
+        
+        if let Some(code) = server.exit_code() {
+            return Ok(code);
+        }
+    }
+    
+    Ok(if server.shutdown { 0 } else { 1 })
+}
+
+pub fn read_message(reader: &mut impl BufRead) -> io::Result<Option<String>> {
+    let mut content_length = None;
+    
+    loop {
+        let mut header = String::new();
+        if reader.read_line(&mut header)? == 0 {
+            return Ok(None);
+        }
+        
+        let header = header.trim_end();
+        if header.is_empty() {
+            if content_length.is_some() {
+                break;
+            }
+            continue;
+        }
+        
+        if let Some((name, value)) = header.split_once(':')
+            && name.eq_ignore_ascii_case("Content-Length") {
+            content_length = value.trim().parse::<usize>().ok();
+        }
+    }
+    
+    let mut content = vec![0; content_length.unwrap_or(0)];
+    reader.read_exact(&mut content)?;
+    
+    String::from_utf8(content)
+        .map(Some)
+        .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+}
+
+pub fn write_message(writer: &mut impl Write, message: &Json) -> io::Result<()> {
+    let content = message.to_string();
+    write!(writer, "Content-Length: {}\r\n\r\n{}", content.len(), content)?;
+    writer.flush()
+}
+
+fn capabilities() -> Json {
+    Json::object(vec![
+        ("capabilities", Json::object(vec![
+            ("textDocumentSync", Json::object(vec![
+                ("openClose", Json::Bool(true)),
+                ("change", Json::Number(1.0)),
+            ])),
+            ("hoverProvider", Json::Bool(true)),
+            ("definitionProvider", Json::Bool(true)),
+            ("completionProvider", Json::object(vec![])),
+        ])),
+        ("serverInfo", Json::object(vec![
+            ("name", Json::string("ravun-lsp")),
+            ("version", Json::string(env!("CARGO_PKG_VERSION"))),
+        ])),
+    ])
+}
+
+fn completion_kind(kind: CompletionKind) -> f64 {
+    match kind {
+        CompletionKind::Function => 3.0,
+        CompletionKind::Variable => 6.0,
+        CompletionKind::Keyword => 14.0,
+        CompletionKind::Struct => 22.0,
+    }
+}
+
+fn position(line: usize, character: usize) -> Json {
+    Json::object(vec![
+        ("line", Json::Number(line as f64)),
+        ("character", Json::Number(character as f64)),
+    ])
+}
+
+fn publish_diagnostics(uri: &str, diagnostics: Vec<Json>) -> Json {
+    Json::object(vec![
+        ("jsonrpc", Json::string("2.0")),
+        ("method", Json::string("textDocument/publishDiagnostics")),
+        ("params", Json::object(vec![
+            ("uri", Json::string(uri)),
+            ("diagnostics", Json::Array(diagnostics)),
+        ])),
+    ])
+}
+
+fn error_response(id: Json, code: i64, message: &str) -> Json {
+    Json::object(vec![
+        ("jsonrpc", Json::string("2.0")),
+        ("id", id),
+        ("error", Json::object(vec![
+            ("code", Json::Number(code as f64)),
+            ("message", Json::string(message)),
+        ])),
+    ])
+}
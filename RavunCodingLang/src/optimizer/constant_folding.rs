@@ -0,0 +1,204 @@
+use std::collections::HashMap;
+use crate::interpreter::interpreter::int_pow;
+use crate::ir::function::{Function, Module};
+use crate::ir::instruction::{
+    BinaryOp, Constant, Instruction, InstructionKind, Operand, Terminator, UnaryOp, ValueId,
+};
+use crate::optimizer::pass_manager::Pass;
+use crate::semantics::types::Type;
+
+pub struct ConstantFolding;
+
+impl Pass for ConstantFolding {
+    fn name(&self) -> &'static str {
+        "const-fold"
+    }
+    
+    fn run(&mut self, module: &mut Module) -> bool {
+        let mut changed = false;
+        
+        for function in &mut module.functions {
+            while fold_function(function) {
+                changed = true;
+            }
+        }
+        
+        changed
+    }
+}
+
+fn fold_function(function: &mut Function) -> bool {
+    let mut replacements: HashMap<ValueId, Operand> = HashMap::new();
+    
+    for block in &function.blocks {
+        for instruction in &block.instructions {
+            if let Some(result) = instruction.result
+                && let Some(value) = fold_instruction(instruction, result) {
+                replacements.insert(result, value);
+            }
+        }
+    }
+    
+    let mut changed = !replacements.is_empty();
+    
+    if changed {
+        for block in &mut function.blocks {
+            block.instructions.retain(|i| i.result.is_none_or(|r| !replacements.contains_key(&r)));
+        }
+        function.replace_uses(&replacements);
+    }
+    
+    changed |= fold_branches(function);
+    changed
+}
+
+fn fold_instruction(instruction: &Instruction, result: ValueId) -> Option<Operand> {
+    match &instruction.kind {
+        InstructionKind::Phi(incoming) => {
+            let mut values = incoming.iter()
+                .map(|(_, value)| value)
+                .filter(|value| **value != Operand::Value(result));
+            let first = values.next()?;
+            values.all(|value| value == first).then(|| first.clone())
+        },
+        InstructionKind::Binary(op, Operand::Const(lhs), Operand::Const(rhs)) => {
+            fold_binary(*op, lhs, rhs).map(Operand::Const)
+        },
+        InstructionKind::Binary(op, lhs, rhs) if instruction.ty == Type::Int => simplify_binary(*op, lhs, rhs),
+        InstructionKind::Unary(op, Operand::Const(operand)) => fold_unary(*op, operand).map(Operand::Const),
+        InstructionKind::IntToFloat(Operand::Const(Constant::Int(i))) => {
+            Some(Operand::Const(Constant::Float(*i as f64)))
+        },
+        InstructionKind::Length(Operand::Const(Constant::String(s))) => {
+            i32::try_from(s.chars().count()).ok().map(|n| Operand::Const(Constant::Int(n)))
+        },
+        InstructionKind::Index(Operand::Const(Constant::String(s)), Operand::Const(Constant::Int(i))) => {
+            let c = usize::try_from(*i).ok().and_then(|i| s.chars().nth(i))?;
+            Some(Operand::Const(Constant::String(c.to_string())))
+        },
+        _ => None,
+    }
+}
+
+pub fn fold_binary(op: BinaryOp, lhs: &Constant, rhs: &Constant) -> Option<Constant> {
+    match (lhs, rhs) {
+        (Constant::Int(a), Constant::Int(b)) => {
+            let (a, b) = (*a, *b);
+            Some(match op {
+                BinaryOp::Add => Constant::Int(a.wrapping_add(b)),
+                BinaryOp::Sub => Constant::Int(a.wrapping_sub(b)),
+                BinaryOp::Mul => Constant::Int(a.wrapping_mul(b)),
+                BinaryOp::Div if b != 0 => Constant::Int(a.wrapping_div(b)),
+                BinaryOp::Rem if b != 0 => Constant::Int(a.wrapping_rem(b)),
+                BinaryOp::Div | BinaryOp::Rem => return None,
+                BinaryOp::Pow => Constant::Int(int_pow(a, b)),
+                BinaryOp::And => Constant::Int(a & b),
+                BinaryOp::Or => Constant::Int(a | b),
+                BinaryOp::Xor => Constant::Int(a ^ b),
+                BinaryOp::Shl => Constant::Int(a.wrapping_shl(b as u32)),
+                BinaryOp::Shr => Constant::Int(a.wrapping_shr(b as u32)),
+                _ => Constant::Bool(compare(op, a.cmp(&b))),
+            })
+        },
+        (Constant::Float(a), Constant::Float(b)) => {
+            let (a, b) = (*a, *b);
+            Some(match op {
+                BinaryOp::Add => Constant::Float(a + b),
+                BinaryOp::Sub => Constant::Float(a - b),
+                BinaryOp::Mul => Constant::Float(a * b),
+                BinaryOp::Div => Constant::Float(a / b),
+                BinaryOp::Rem => Constant::Float(a % b),
+                BinaryOp::Pow => Constant::Float(a.powf(b)),
+                op if op.is_bitwise() => return None,
+                BinaryOp::Eq => Constant::Bool(a == b),
+                BinaryOp::Ne => Constant::Bool(a != b),
+                _ => Constant::Bool(a.partial_cmp(&b).is_some_and(|ordering| compare(op, ordering))),
+            })
+        },
+        (Constant::String(a), Constant::String(b)) => match op {
+            BinaryOp::Add => Some(Constant::String(format!("{}{}", a, b))),
+            op if op.is_comparison() => Some(Constant::Bool(compare(op, a.cmp(b)))),
+            _ => None,
+        },
+        (Constant::Bool(a), Constant::Bool(b)) => match op {
+            BinaryOp::Eq => Some(Constant::Bool(a == b)),
+            BinaryOp::Ne => Some(Constant::Bool(a != b)),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn compare(op: BinaryOp, ordering: std::cmp::Ordering) -> bool {
+    match op {
+        BinaryOp::Eq => ordering.is_eq(),
+        BinaryOp::Ne => ordering.is_ne(),
+        BinaryOp::Lt => ordering.is_lt(),
+        BinaryOp::Gt => ordering.is_gt(),
+        BinaryOp::Le => ordering.is_le(),
+        _ => ordering.is_ge(),
+    }
+}
+
+pub fn fold_unary(op: UnaryOp, operand: &Constant) -> Option<Constant> {
+    match (op, operand) {
+        (UnaryOp::Neg, Constant::Int(i)) => Some(Constant::Int(i.wrapping_neg())),
+        (UnaryOp::Neg, Constant::Float(x)) => Some(Constant::Float(-x)),
+        (UnaryOp::Not, Constant::Bool(b)) => Some(Constant::Bool(!b)),
+        (UnaryOp::Not, Constant::Int(i)) => Some(Constant::Int(!i)),
+        _ => None,
+    }
+}
+
+fn simplify_binary(op: BinaryOp, lhs: &Operand, rhs: &Operand) -> Option<Operand> {
+    let zero = Operand::Const(Constant::Int(0));
+    let one = Operand::Const(Constant::Int(1));
+    
+    match op {
+        BinaryOp::Add if *rhs == zero => Some(lhs.clone()),
+        BinaryOp::Add if *lhs == zero => Some(rhs.clone()),
+        BinaryOp::Sub if *rhs == zero => Some(lhs.clone()),
+        BinaryOp::Mul if *rhs == one => Some(lhs.clone()),
+        BinaryOp::Mul if *lhs == one => Some(rhs.clone()),
+        BinaryOp::Mul if *lhs == zero || *rhs == zero => Some(zero),
+        BinaryOp::Div if *rhs == one => Some(lhs.clone()),
+        BinaryOp::Or | BinaryOp::Xor | BinaryOp::Shl | BinaryOp::Shr if *rhs == zero => Some(lhs.clone()),
+        BinaryOp::Or | BinaryOp::Xor if *lhs == zero => Some(rhs.clone()),
+        BinaryOp::And if *lhs == zero || *rhs == zero => Some(zero),
+        BinaryOp::Sub if lhs.as_value().is_some() && lhs == rhs => Some(zero),
+        _ => None,
+    }
+}
+
+fn fold_branches(function: &mut Function) -> bool {
+    let mut removed_edges = Vec::new();
+    
+    for block in &mut function.blocks {
+        let Some(Terminator::Branch(Operand::Const(Constant::Bool(condition)), then_block, else_block)) = &block.terminator else {
+            continue;
+        };
+        
+        let (taken, dropped) = if *condition { (*then_block, *else_block) } else { (*else_block, *then_block) };
+        if taken != dropped {
+            removed_edges.push((block.id, dropped));
+        }
+        block.terminator = Some(Terminator::Jump(taken));
+    }
+    
+    if removed_edges.is_empty() {
+        return false;
+    }
+    
+    for (from, to) in removed_edges {
+        if let Some(block) = function.block_mut(to) {
+            for instruction in &mut block.instructions {
+                if let InstructionKind::Phi(incoming) = &mut instruction.kind {
+                    incoming.retain(|(pred, _)| *pred != from);
+                }
+            }
+        }
+    }
+    
+    function.remove_unreachable_blocks();
+    true
+}
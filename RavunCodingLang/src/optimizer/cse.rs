@@ -0,0 +1,105 @@
+use std::collections::HashMap;
+use crate::ir::dominators::DominatorTree;
+use crate::ir::function::{Function, Module};
+use crate::ir::instruction::{BlockId, Instruction, InstructionKind, Operand, ValueId};
+use crate::optimizer::pass_manager::Pass;
+
+pub struct CommonSubexpressionElimination;
+
+impl Pass for CommonSubexpressionElimination {
+    fn name(&self) -> &'static str {
+        "cse"
+    }
+    
+    fn run(&mut self, module: &mut Module) -> bool {
+        let mut changed = false;
+        
+        for function in &mut module.functions {
+            changed |= eliminate_common_subexpressions(function);
+        }
+        
+        changed
+    }
+}
+
+fn eliminate_common_subexpressions(function: &mut Function) -> bool {
+    let dominators = DominatorTree::compute(function);
+    let Some(entry) = function.entry() else {
+        return false;
+    };
+    
+    let mut replacements: HashMap<ValueId, Operand> = HashMap::new();
+    let mut available: Vec<HashMap<String, ValueId>> = Vec::new();
+    visit(function, &dominators, entry, &mut available, &mut replacements);
+    
+    if replacements.is_empty() {
+        return false;
+    }
+    
+    for block in &mut function.blocks {
+        block.instructions.retain(|i| i.result.is_none_or(|r| !replacements.contains_key(&r)));
+    }
+    function.replace_uses(&replacements);
+    
+    true
+}
+
+fn visit(
+    function: &Function,
+    dominators: &DominatorTree,
+    block: BlockId,
+    available: &mut Vec<HashMap<String, ValueId>>,
+    replacements: &mut HashMap<ValueId, Operand>,
+) {
+    available.push(HashMap::new());
+    
+    if let Some(basic_block) = function.block(block) {
+        for instruction in &basic_block.instructions {
+            let (Some(result), true) = (instruction.result, instruction.is_pure()) else {
+                continue;
+            };
+            
+            let key = expression_key(instruction, replacements);
+            let existing = available.iter().rev().find_map(|scope| scope.get(&key).copied());
+            
+            match existing {
+                Some(existing) => {
+                    replacements.insert(result, Operand::Value(existing));
+                },
+                None => {
+                    if let Some(scope) = available.last_mut() {
+                        scope.insert(key, result);
+                    }
+                },
+            }
+        }
+    }
+    
+    for child in dominators.children(block) {
+        visit(function, dominators, child, available, replacements);
+    }
+    
+    available.pop();
+}
+
+fn expression_key(instruction: &Instruction, replacements: &HashMap<ValueId, Operand>) -> String {
+    let mut normalized = instruction.clone();
+    normalized.result = None;
+    
+    for operand in normalized.operands_mut() {
+        while let Operand::Value(id) = operand {
+            match replacements.get(id) {
+                Some(replacement) => *operand = replacement.clone(),
+                None => break,
+            }
+        }
+    }
+    
+    if let InstructionKind::Binary(op, lhs, rhs) = &mut normalized.kind
+        && op.is_commutative()
+        && lhs.to_string() > rhs.to_string() {
+        std::mem::swap(lhs, rhs);
+    }
+    
+    normalized.to_string()
+}
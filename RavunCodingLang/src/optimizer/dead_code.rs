@@ -0,0 +1,65 @@
+use std::collections::{HashMap, HashSet};
+use crate::ir::function::{Function, Module};
+use crate::ir::instruction::ValueId;
+use crate::optimizer::pass_manager::Pass;
+
+pub struct DeadCodeElimination;
+
+impl Pass for DeadCodeElimination {
+    fn name(&self) -> &'static str {
+        "dce"
+    }
+    
+    fn run(&mut self, module: &mut Module) -> bool {
+        let mut changed = false;
+        
+        for function in &mut module.functions {
+            changed |= function.remove_unreachable_blocks();
+            changed |= eliminate_dead_instructions(function);
+        }
+        
+        changed
+    }
+}
+
+fn eliminate_dead_instructions(function: &mut Function) -> bool {
+    let mut live: HashSet<ValueId> = HashSet::new();
+    let mut worklist: Vec<ValueId> = Vec::new();
+    
+    for block in &function.blocks {
+        for instruction in &block.instructions {
+            if instruction.result.is_none() || instruction.has_side_effects() || instruction.may_trap() {
+                worklist.extend(instruction.operands().iter().filter_map(|o| o.as_value()));
+                if let Some(result) = instruction.result {
+                    live.insert(result);
+                }
+            }
+        }
+        
+        if let Some(terminator) = &block.terminator {
+            worklist.extend(terminator.operands().iter().filter_map(|o| o.as_value()));
+        }
+    }
+    
+    let definitions: HashMap<ValueId, Vec<ValueId>> = function.blocks.iter()
+        .flat_map(|b| b.instructions.iter())
+        .filter_map(|i| i.result.map(|r| (r, i.operands().iter().filter_map(|o| o.as_value()).collect())))
+        .collect();
+    
+    while let Some(value) = worklist.pop() {
+        if live.insert(value)
+            && let Some(operands) = definitions.get(&value) {
+            worklist.extend(operands.iter().copied());
+        }
+    }
+    
+    let mut changed = false;
+    
+    for block in &mut function.blocks {
+        let before = block.instructions.len();
+        block.instructions.retain(|i| i.result.is_none_or(|r| live.contains(&r)));
+        changed |= block.instructions.len() != before;
+    }
+    
+    changed
+}
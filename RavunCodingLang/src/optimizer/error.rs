@@ -0,0 +1,35 @@
+use std::fmt;
+use crate::diagnostics::MessageId;
+use crate::ir::verifier::VerifyError;
+
+#[derive(Debug, Clone)]
+pub struct OptimizerError {
+    pub pass: Option<String>,
+    pub errors: Vec<VerifyError>,
+}
+
+impl OptimizerError {
+    pub fn new(pass: Option<String>, errors: Vec<VerifyError>) -> Self {
+        OptimizerError {
+            pass,
+            errors,
+        }
+    }
+}
+
+impl fmt::Display for OptimizerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.pass {
+            Some(pass) => write!(f, "{}", MessageId::VerifyFailedAfterPass.format(&[pass]))?,
+            None => write!(f, "{}", MessageId::VerifyFailedBeforePasses.text())?,
+        }
+        
+        for error in &self.errors {
+            write!(f, "\n  {}", error)?;
+        }
+        
+        Ok(())
+    }
+}
+
+impl std::error::Error for OptimizerError {}
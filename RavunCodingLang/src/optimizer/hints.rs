@@ -0,0 +1,18 @@
+use crate::semantics::analyzer::SemanticAnalyzer;
+
+#[derive(Debug, Clone, Default)]
+pub struct OptimizationHints {
+    pub small_functions: Vec<String>,
+}
+
+impl OptimizationHints {
+    pub fn new() -> Self {
+        OptimizationHints::default()
+    }
+    
+    pub fn from_analyzer(analyzer: &SemanticAnalyzer) -> Self {
+        OptimizationHints {
+            small_functions: analyzer.small_functions.clone(),
+        }
+    }
+}
@@ -0,0 +1,298 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+use crate::diagnostics::MessageId;
+use crate::ir::function::{Function, InlineHint, Module};
+use crate::ir::instruction::{BlockId, Instruction, InstructionKind, Operand, Terminator, ValueId};
+use crate::optimizer::pass_manager::{Pass, Remark};
+
+pub const INLINE_THRESHOLD: usize = 25;
+const MAX_INLINES_PER_FUNCTION: usize = 32;
+
+enum Decision {
+    Inline(String),
+    Skip(String),
+}
+
+pub struct Inliner {
+    small_functions: HashSet<String>,
+    threshold: usize,
+    remarks: Vec<Remark>,
+}
+
+impl Inliner {
+    pub fn new(small_functions: &[String]) -> Self {
+        Inliner {
+            small_functions: small_functions.iter().cloned().collect(),
+            threshold: INLINE_THRESHOLD,
+            remarks: Vec::new(),
+        }
+    }
+    
+    pub fn with_threshold(mut self, threshold: usize) -> Self {
+        self.threshold = threshold;
+        self
+    }
+    
+    fn remark(&mut self, function: &str, message: String) {
+        self.remarks.push(Remark {
+            pass: self.name(),
+            function: function.to_string(),
+            message,
+        });
+    }
+    
+    fn decide(&self, callee: &Function, args: &[Operand], recursive: &HashSet<String>) -> Decision {
+        if callee.inline_hint == InlineHint::Never {
+            return Decision::Skip(MessageId::InlineMarkedNoinline.text().to_string());
+        }
+        
+        if recursive.contains(&callee.name) {
+            return Decision::Skip(MessageId::InlineRecursive.text().to_string());
+        }
+        
+        if callee.inline_hint == InlineHint::Always {
+            return Decision::Inline(MessageId::InlineMarkedInline.text().to_string());
+        }
+        
+        if !self.small_functions.contains(&callee.name) {
+            return Decision::Skip(MessageId::InlineNotSmall.text().to_string());
+        }
+        
+        let cost = inline_cost(callee, args);
+        if cost <= self.threshold {
+            Decision::Inline(MessageId::InlineWithinThreshold.format(&[&cost, &self.threshold]))
+        } else {
+            Decision::Skip(MessageId::InlineOverThreshold.format(&[&cost, &self.threshold]))
+        }
+    }
+}
+
+impl Pass for Inliner {
+    fn name(&self) -> &'static str {
+        "inline"
+    }
+    
+    fn run(&mut self, module: &mut Module) -> bool {
+        let recursive = recursive_functions(module);
+        let mut changed = false;
+        
+        for index in 0..module.functions.len() {
+            let caller_name = module.functions[index].name.clone();
+            let mut worklist: VecDeque<BlockId> = module.functions[index].blocks.iter().map(|b| b.id).collect();
+            let mut inlined = 0;
+            
+            while let Some(block_id) = worklist.pop_front() {
+                let calls: Vec<(usize, String, Vec<Operand>)> = match module.functions[index].block(block_id) {
+                    Some(block) => block.instructions.iter().enumerate()
+                        .filter_map(|(i, instruction)| match &instruction.kind {
+                            InstructionKind::Call(name, args) => Some((i, name.clone(), args.clone())),
+                            _ => None,
+                        })
+                        .collect(),
+                    None => continue,
+                };
+                
+                for (position, callee_name, args) in calls {
+                    let Some(callee) = module.function(&callee_name).cloned() else {
+                        continue;
+                    };
+                    
+                    let reason = match self.decide(&callee, &args, &recursive) {
+                        Decision::Skip(reason) => {
+                            self.remark(&caller_name, MessageId::RemarkNotInlined.format(&[&callee_name, &reason]));
+                            continue;
+                        },
+                        Decision::Inline(_) if inlined >= MAX_INLINES_PER_FUNCTION => {
+                            self.remark(&caller_name, MessageId::RemarkNotInlined.format(&[&callee_name, &MessageId::InlineBudgetExceeded.text()]));
+                            continue;
+                        },
+                        Decision::Inline(reason) => reason,
+                    };
+                    
+                    let new_blocks = inline_call(&mut module.functions[index], block_id, position, &callee);
+                    self.remark(&caller_name, MessageId::RemarkInlined.format(&[&callee_name, &reason]));
+                    
+                    inlined += 1;
+                    changed = true;
+                    for block in new_blocks.into_iter().rev() {
+                        worklist.push_front(block);
+                    }
+                    break;
+                }
+            }
+            
+            if inlined > 0 {
+                module.functions[index].remove_unreachable_blocks();
+            }
+        }
+        
+        changed
+    }
+    
+    fn take_remarks(&mut self) -> Vec<Remark> {
+        std::mem::take(&mut self.remarks)
+    }
+}
+
+pub fn inline_cost(callee: &Function, args: &[Operand]) -> usize {
+    let constant_args = args.iter().filter(|a| a.as_const().is_some()).count();
+    callee.instruction_count().saturating_sub(constant_args)
+}
+
+fn recursive_functions(module: &Module) -> HashSet<String> {
+    let calls: HashMap<&str, HashSet<&str>> = module.functions.iter()
+        .map(|function| {
+            let callees = function.blocks.iter()
+                .flat_map(|b| b.instructions.iter())
+                .filter_map(|i| match &i.kind {
+                    InstructionKind::Call(name, _) => Some(name.as_str()),
+                    _ => None,
+                })
+                .collect();
+            (function.name.as_str(), callees)
+        })
+        .collect();
+    
+    let mut recursive = HashSet::new();
+    
+    for function in &module.functions {
+        let start = function.name.as_str();
+        let mut stack: Vec<&str> = calls.get(start).into_iter().flatten().copied().collect();
+        let mut visited = HashSet::new();
+        
+        while let Some(name) = stack.pop() {
+            if name == start {
+                recursive.insert(start.to_string());
+                break;
+            }
+            if visited.insert(name) {
+                stack.extend(calls.get(name).into_iter().flatten().copied());
+            }
+        }
+    }
+    
+    recursive
+}
+
+fn inline_call(caller: &mut Function, block_id: BlockId, position: usize, callee: &Function) -> Vec<BlockId> {
+    let Some(block) = caller.block_mut(block_id) else {
+        return Vec::new();
+    };
+    
+    let rest = block.instructions.split_off(position + 1);
+    let Some(call) = block.instructions.pop() else {
+        return Vec::new();
+    };
+    let terminator = block.terminator.take();
+    
+    let InstructionKind::Call(_, args) = call.kind else {
+        return Vec::new();
+    };
+    
+    let continuation = caller.add_block();
+    for successor in terminator.as_ref().map_or_else(Vec::new, |t| t.successors()) {
+        if let Some(block) = caller.block_mut(successor) {
+            for instruction in &mut block.instructions {
+                if let InstructionKind::Phi(incoming) = &mut instruction.kind {
+                    for (pred, _) in incoming.iter_mut() {
+                        if *pred == block_id {
+                            *pred = continuation;
+                        }
+                    }
+                }
+            }
+        }
+    }
+    
+    if let Some(block) = caller.block_mut(continuation) {
+        block.instructions = rest;
+        block.terminator = terminator;
+    }
+    
+    let mut values: HashMap<ValueId, Operand> = callee.params.iter()
+        .map(|p| p.id)
+        .zip(args)
+        .collect();
+    
+    for instruction in callee.blocks.iter().flat_map(|b| b.instructions.iter()) {
+        if let Some(result) = instruction.result {
+            values.insert(result, Operand::Value(caller.new_value()));
+        }
+    }
+    
+    let blocks: HashMap<BlockId, BlockId> = callee.blocks.iter()
+        .map(|b| (b.id, caller.add_block()))
+        .collect();
+    
+    let map_operand = |operand: &mut Operand| {
+        if let Operand::Value(id) = operand
+            && let Some(value) = values.get(id) {
+            *operand = value.clone();
+        }
+    };
+    
+    let mut returns = Vec::new();
+    
+    for callee_block in &callee.blocks {
+        let new_id = blocks[&callee_block.id];
+        let mut instructions = Vec::new();
+        
+        for instruction in &callee_block.instructions {
+            let mut instruction = instruction.clone();
+            instruction.result = instruction.result.and_then(|r| values.get(&r).and_then(|v| v.as_value()));
+            
+            if let InstructionKind::Phi(incoming) = &mut instruction.kind {
+                for (pred, _) in incoming.iter_mut() {
+                    *pred = blocks[pred];
+                }
+            }
+            for operand in instruction.operands_mut() {
+                map_operand(operand);
+            }
+            
+            instructions.push(instruction);
+        }
+        
+        let terminator = match callee_block.terminator.clone() {
+            Some(Terminator::Return(value)) => {
+                let value = value.map(|mut v| {
+                    map_operand(&mut v);
+                    v
+                });
+                returns.push((new_id, value));
+                Terminator::Jump(continuation)
+            },
+            Some(mut terminator) => {
+                for successor in terminator.successors_mut() {
+                    *successor = blocks[successor];
+                }
+                for operand in terminator.operands_mut() {
+                    map_operand(operand);
+                }
+                terminator
+            },
+            None => Terminator::Unreachable,
+        };
+        
+        if let Some(block) = caller.block_mut(new_id) {
+            block.instructions = instructions;
+            block.terminator = Some(terminator);
+        }
+    }
+    
+    if let Some(result) = call.result {
+        let incoming = returns.into_iter()
+            .filter_map(|(block, value)| value.map(|v| (block, v)))
+            .collect();
+        if let Some(block) = caller.block_mut(continuation) {
+            block.instructions.insert(0, Instruction::new(Some(result), call.ty, InstructionKind::Phi(incoming)));
+        }
+    }
+    
+    if let (Some(block), Some(entry)) = (caller.block_mut(block_id), callee.entry()) {
+        block.terminator = Some(Terminator::Jump(blocks[&entry]));
+    }
+    
+    let mut new_blocks: Vec<BlockId> = callee.blocks.iter().map(|b| blocks[&b.id]).collect();
+    new_blocks.push(continuation);
+    new_blocks
+}
@@ -0,0 +1,151 @@
+use std::collections::HashSet;
+use crate::diagnostics::MessageId;
+use crate::ir::function::{Function, Module};
+use crate::ir::instruction::{BlockId, InstructionKind, Terminator, ValueId};
+use crate::ir::loops::{find_loops, Loop};
+use crate::optimizer::pass_manager::{Pass, Remark};
+
+pub struct LoopInvariantCodeMotion {
+    remarks: Vec<Remark>,
+}
+
+impl LoopInvariantCodeMotion {
+    pub fn new() -> Self {
+        LoopInvariantCodeMotion { remarks: Vec::new() }
+    }
+}
+
+impl Default for LoopInvariantCodeMotion {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Pass for LoopInvariantCodeMotion {
+    fn name(&self) -> &'static str {
+        "licm"
+    }
+    
+    fn run(&mut self, module: &mut Module) -> bool {
+        let mut changed = false;
+        
+        for function in &mut module.functions {
+            let mut loops = find_loops(function);
+            
+            for index in 0..loops.len() {
+                let Some(preheader) = ensure_preheader(function, &mut loops, index) else {
+                    continue;
+                };
+                
+                let hoisted = hoist_invariants(function, &loops[index], preheader);
+                if hoisted > 0 {
+                    self.remarks.push(Remark {
+                        pass: "licm",
+                        function: function.name.clone(),
+                        message: MessageId::RemarkHoisted.format(&[&loops[index].header, &hoisted, &preheader]),
+                    });
+                    changed = true;
+                }
+            }
+        }
+        
+        changed
+    }
+    
+    fn take_remarks(&mut self) -> Vec<Remark> {
+        std::mem::take(&mut self.remarks)
+    }
+}
+
+fn ensure_preheader(function: &mut Function, loops: &mut [Loop], index: usize) -> Option<BlockId> {
+    let preds = function.predecessors();
+    let header = loops[index].header;
+    
+    if let Some(preheader) = loops[index].preheader(function, &preds) {
+        return Some(preheader);
+    }
+    
+    let [outside] = loops[index].outside_predecessors(&preds)[..] else {
+        return None;
+    };
+    
+    let preheader = function.add_block();
+    if let Some(block) = function.block_mut(preheader) {
+        block.terminator = Some(Terminator::Jump(header));
+    }
+    
+    if let Some(terminator) = function.block_mut(outside).and_then(|b| b.terminator.as_mut()) {
+        for successor in terminator.successors_mut() {
+            if *successor == header {
+                *successor = preheader;
+            }
+        }
+    }
+    
+    if let Some(block) = function.block_mut(header) {
+        for instruction in &mut block.instructions {
+            if let InstructionKind::Phi(incoming) = &mut instruction.kind {
+                for (pred, _) in incoming.iter_mut() {
+                    if *pred == outside {
+                        *pred = preheader;
+                    }
+                }
+            }
+        }
+    }
+    
+    for other in loops.iter_mut() {
+        if other.contains(outside) {
+            other.blocks.insert(preheader);
+        }
+    }
+    
+    Some(preheader)
+}
+
+fn hoist_invariants(function: &mut Function, natural_loop: &Loop, preheader: BlockId) -> usize {
+    let mut defined_in_loop: HashSet<ValueId> = function.blocks.iter()
+        .filter(|b| natural_loop.contains(b.id))
+        .flat_map(|b| b.instructions.iter())
+        .filter_map(|i| i.result)
+        .collect();
+    
+    let mut hoisted = Vec::new();
+    
+    loop {
+        let before = hoisted.len();
+        
+        for block in function.blocks.iter_mut().filter(|b| natural_loop.contains(b.id)) {
+            let mut kept = Vec::with_capacity(block.instructions.len());
+            
+            for instruction in std::mem::take(&mut block.instructions) {
+                let invariant = !instruction.is_phi()
+                    && instruction.is_pure()
+                    && !instruction.may_trap()
+                    && instruction.operands().iter()
+                        .filter_map(|o| o.as_value())
+                        .all(|v| !defined_in_loop.contains(&v));
+                
+                match instruction.result {
+                    Some(result) if invariant => {
+                        defined_in_loop.remove(&result);
+                        hoisted.push(instruction);
+                    },
+                    _ => kept.push(instruction),
+                }
+            }
+            
+            block.instructions = kept;
+        }
+        
+        if hoisted.len() == before {
+            break;
+        }
+    }
+    
+    let count = hoisted.len();
+    if let Some(block) = function.block_mut(preheader) {
+        block.instructions.extend(hoisted);
+    }
+    count
+}
@@ -0,0 +1,431 @@
+use std::collections::{HashMap, HashSet};
+use crate::diagnostics::MessageId;
+use crate::ir::function::{BasicBlock, Function, Module};
+use crate::ir::instruction::{BinaryOp, BlockId, Constant, InstructionKind, Operand, Terminator, ValueId};
+use crate::ir::loops::{find_loops, Loop};
+use crate::optimizer::constant_folding::fold_binary;
+use crate::optimizer::pass_manager::{Pass, Remark};
+use crate::semantics::analyzer::LoopInfo;
+
+pub const DEFAULT_UNROLL_FACTOR: usize = 2;
+const UNROLL_SIZE_LIMIT: usize = 128;
+const MAX_TRIP_COUNT: usize = 1 << 16;
+const MAX_UNROLLS_PER_FUNCTION: usize = 16;
+
+struct Induction {
+    preheader: BlockId,
+    latch: BlockId,
+    body: BlockId,
+    exit: BlockId,
+    start: i32,
+    step: i32,
+    trip_count: usize,
+}
+
+impl Induction {
+    fn loop_info(&self, header: BlockId) -> LoopInfo {
+        let end = i32::try_from(self.trip_count).ok()
+            .and_then(|count| count.checked_mul(self.step))
+            .and_then(|span| span.checked_add(self.start));
+        
+        LoopInfo {
+            description: MessageId::LoopHeadedBy.format(&[&header]),
+            start_value: Some(self.start),
+            end_value: end,
+            step_value: Some(self.step),
+        }
+    }
+}
+
+pub struct LoopUnroll {
+    factor: usize,
+    remarks: Vec<Remark>,
+}
+
+impl LoopUnroll {
+    pub fn new() -> Self {
+        LoopUnroll {
+            factor: DEFAULT_UNROLL_FACTOR,
+            remarks: Vec::new(),
+        }
+    }
+    
+    pub fn with_factor(mut self, factor: usize) -> Self {
+        self.factor = factor.max(1);
+        self
+    }
+    
+    fn remark(&mut self, function: &Function, message: String) {
+        self.remarks.push(Remark {
+            pass: "loop-unroll",
+            function: function.name.clone(),
+            message,
+        });
+    }
+    
+    fn unroll_function(&mut self, function: &mut Function) -> bool {
+        let mut visited: HashSet<BlockId> = HashSet::new();
+        let mut unrolled = 0;
+        
+        while unrolled < MAX_UNROLLS_PER_FUNCTION {
+            let Some(natural_loop) = find_loops(function).into_iter().find(|l| !visited.contains(&l.header)) else {
+                break;
+            };
+            visited.insert(natural_loop.header);
+            
+            let induction = match analyze_induction(function, &natural_loop) {
+                Ok(induction) => induction,
+                Err(reason) => {
+                    self.remark(function, MessageId::RemarkNotUnrolled.format(&[&natural_loop.header, &reason.text()]));
+                    continue;
+                },
+            };
+            
+            let size = loop_size(function, &natural_loop);
+            let trip_count = induction.trip_count;
+            
+            if induction.loop_info(natural_loop.header).is_small_constant_range() && trip_count * size <= UNROLL_SIZE_LIMIT {
+                fully_unroll(function, &natural_loop, &induction);
+                self.remark(function, MessageId::RemarkFullyUnrolled.format(&[&natural_loop.header, &trip_count]));
+                unrolled += 1;
+            } else if self.factor > 1 && trip_count >= self.factor && trip_count % self.factor == 0 && self.factor * size <= UNROLL_SIZE_LIMIT {
+                partially_unroll(function, &natural_loop, &induction, self.factor);
+                self.remark(function, MessageId::RemarkPartiallyUnrolled.format(&[&natural_loop.header, &self.factor, &trip_count]));
+                unrolled += 1;
+            } else {
+                let reason = MessageId::UnrollTooLarge.format(&[&trip_count, &size]);
+                self.remark(function, MessageId::RemarkNotUnrolled.format(&[&natural_loop.header, &reason]));
+            }
+        }
+        
+        unrolled > 0
+    }
+}
+
+impl Default for LoopUnroll {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Pass for LoopUnroll {
+    fn name(&self) -> &'static str {
+        "loop-unroll"
+    }
+    
+    fn run(&mut self, module: &mut Module) -> bool {
+        let mut changed = false;
+        
+        for function in &mut module.functions {
+            if self.unroll_function(function) {
+                function.remove_unreachable_blocks();
+                changed = true;
+            }
+        }
+        
+        changed
+    }
+    
+    fn take_remarks(&mut self) -> Vec<Remark> {
+        std::mem::take(&mut self.remarks)
+    }
+}
+
+fn loop_size(function: &Function, natural_loop: &Loop) -> usize {
+    function.blocks.iter()
+        .filter(|b| natural_loop.contains(b.id))
+        .map(|b| b.instructions.len())
+        .sum()
+}
+
+fn analyze_induction(function: &Function, natural_loop: &Loop) -> Result<Induction, MessageId> {
+    let preds = function.predecessors();
+    let header = natural_loop.header;
+    
+    let [latch] = natural_loop.latches[..] else {
+        return Err(MessageId::UnrollMultipleLatches);
+    };
+    let preheader = natural_loop.preheader(function, &preds).ok_or(MessageId::UnrollNoPreheader)?;
+    if preds.get(&header).map_or(0, |p| p.len()) != 2 {
+        return Err(MessageId::UnrollHeaderPredecessors);
+    }
+    
+    let [(from, exit)] = natural_loop.exit_edges(function)[..] else {
+        return Err(MessageId::UnrollMultipleExits);
+    };
+    if from != header || preds.get(&exit).map_or(0, |p| p.len()) != 1 {
+        return Err(MessageId::UnrollExitsOutsideHeader);
+    }
+    
+    let header_block = function.block(header).ok_or(MessageId::UnrollNoHeaderBlock)?;
+    let Some(Terminator::Branch(Operand::Value(condition), then_block, else_block)) = header_block.terminator else {
+        return Err(MessageId::UnrollNoConditionalBranch);
+    };
+    let (body, continue_when) = if then_block == exit { (else_block, false) } else { (then_block, true) };
+    
+    let (op, counter, bound) = header_block.instructions.iter()
+        .find(|i| i.result == Some(condition))
+        .and_then(|i| match &i.kind {
+            InstructionKind::Binary(op, Operand::Value(v), Operand::Const(Constant::Int(b))) if op.is_comparison() => Some((*op, *v, *b)),
+            InstructionKind::Binary(op, Operand::Const(Constant::Int(b)), Operand::Value(v)) if op.is_comparison() => Some((swap_comparison(*op), *v, *b)),
+            _ => None,
+        })
+        .ok_or(MessageId::UnrollNoConstantBound)?;
+    
+    let incoming = header_block.phis()
+        .find(|i| i.result == Some(counter))
+        .and_then(|i| match &i.kind {
+            InstructionKind::Phi(incoming) => Some(incoming),
+            _ => None,
+        })
+        .ok_or(MessageId::UnrollNoCounter)?;
+    
+    let incoming_value = |pred: BlockId| incoming.iter().find(|(p, _)| *p == pred).map(|(_, v)| v);
+    let Some(Operand::Const(Constant::Int(start))) = incoming_value(preheader) else {
+        return Err(MessageId::UnrollNonConstantStart);
+    };
+    let Some(Operand::Value(next)) = incoming_value(latch) else {
+        return Err(MessageId::UnrollCounterNotUpdated);
+    };
+    
+    let step = function.blocks.iter()
+        .filter(|b| natural_loop.contains(b.id))
+        .flat_map(|b| b.instructions.iter())
+        .find(|i| i.result == Some(*next))
+        .and_then(|i| match &i.kind {
+            InstructionKind::Binary(BinaryOp::Add, Operand::Value(v), Operand::Const(Constant::Int(s)))
+            | InstructionKind::Binary(BinaryOp::Add, Operand::Const(Constant::Int(s)), Operand::Value(v)) if *v == counter => Some(*s),
+            InstructionKind::Binary(BinaryOp::Sub, Operand::Value(v), Operand::Const(Constant::Int(s))) if *v == counter => s.checked_neg(),
+            _ => None,
+        })
+        .filter(|s| *s != 0)
+        .ok_or(MessageId::UnrollNonConstantStep)?;
+    
+    let mut value = *start;
+    let mut trip_count = 0;
+    
+    while fold_binary(op, &Constant::Int(value), &Constant::Int(bound)) == Some(Constant::Bool(continue_when)) {
+        trip_count += 1;
+        if trip_count > MAX_TRIP_COUNT {
+            return Err(MessageId::UnrollTooManyIterations);
+        }
+        value = value.checked_add(step).ok_or(MessageId::UnrollCounterOverflow)?;
+    }
+    
+    Ok(Induction {
+        preheader,
+        latch,
+        body,
+        exit,
+        start: *start,
+        step,
+        trip_count,
+    })
+}
+
+fn swap_comparison(op: BinaryOp) -> BinaryOp {
+    match op {
+        BinaryOp::Lt => BinaryOp::Gt,
+        BinaryOp::Gt => BinaryOp::Lt,
+        BinaryOp::Le => BinaryOp::Ge,
+        BinaryOp::Ge => BinaryOp::Le,
+        op => op,
+    }
+}
+
+fn fully_unroll(function: &mut Function, natural_loop: &Loop, induction: &Induction) {
+    let snapshot = loop_snapshot(function, natural_loop);
+    let header = natural_loop.header;
+    let headers: Vec<BlockId> = (0..=induction.trip_count).map(|_| function.add_block()).collect();
+    
+    let mut phi_values = header_phi_values(&snapshot, header, induction.preheader, &HashMap::new());
+    for iteration in 0..induction.trip_count {
+        let (values, _) = clone_iteration(function, &snapshot, natural_loop, induction, headers[iteration], &phi_values, headers[iteration + 1], None);
+        phi_values = header_phi_values(&snapshot, header, induction.latch, &values);
+    }
+    
+    let last = headers[induction.trip_count];
+    let (values, _) = clone_iteration(function, &snapshot, natural_loop, induction, last, &phi_values, last, Some(induction.exit));
+    
+    redirect_edge(function, induction.preheader, header, headers[0]);
+    retarget_phis(function, induction.exit, header, last);
+    function.blocks.retain(|b| !natural_loop.contains(b.id));
+    
+    let replacements: HashMap<ValueId, Operand> = snapshot.iter()
+        .filter(|b| b.id == header)
+        .flat_map(|b| b.instructions.iter())
+        .filter_map(|i| i.result)
+        .filter_map(|r| values.get(&r).map(|v| (r, v.clone())))
+        .collect();
+    function.replace_uses(&replacements);
+}
+
+fn partially_unroll(function: &mut Function, natural_loop: &Loop, induction: &Induction, factor: usize) {
+    let snapshot = loop_snapshot(function, natural_loop);
+    let header = natural_loop.header;
+    let copies: Vec<BlockId> = (1..factor).map(|_| function.add_block()).collect();
+    
+    let mut phi_values = header_phi_values(&snapshot, header, induction.latch, &HashMap::new());
+    let mut last_latch = induction.latch;
+    
+    for (index, copy) in copies.iter().enumerate() {
+        let next = copies.get(index + 1).copied().unwrap_or(header);
+        let (values, blocks) = clone_iteration(function, &snapshot, natural_loop, induction, *copy, &phi_values, next, None);
+        phi_values = header_phi_values(&snapshot, header, induction.latch, &values);
+        last_latch = blocks[&induction.latch];
+    }
+    
+    redirect_edge(function, induction.latch, header, copies[0]);
+    
+    if let Some(block) = function.block_mut(header) {
+        for instruction in &mut block.instructions {
+            let Some(result) = instruction.result else {
+                continue;
+            };
+            if let InstructionKind::Phi(incoming) = &mut instruction.kind {
+                for (pred, value) in incoming.iter_mut() {
+                    if *pred == induction.latch
+                        && let Some(new_value) = phi_values.get(&result) {
+                        *pred = last_latch;
+                        *value = new_value.clone();
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn loop_snapshot(function: &Function, natural_loop: &Loop) -> Vec<BasicBlock> {
+    function.blocks.iter()
+        .filter(|b| natural_loop.contains(b.id))
+        .cloned()
+        .collect()
+}
+
+fn header_phi_values(
+    snapshot: &[BasicBlock],
+    header: BlockId,
+    from: BlockId,
+    values: &HashMap<ValueId, Operand>,
+) -> HashMap<ValueId, Operand> {
+    snapshot.iter()
+        .filter(|b| b.id == header)
+        .flat_map(|b| b.phis())
+        .filter_map(|phi| {
+            let InstructionKind::Phi(incoming) = &phi.kind else {
+                return None;
+            };
+            let (_, value) = incoming.iter().find(|(pred, _)| *pred == from)?;
+            Some((phi.result?, remap(value, values)))
+        })
+        .collect()
+}
+
+fn remap(operand: &Operand, values: &HashMap<ValueId, Operand>) -> Operand {
+    match operand {
+        Operand::Value(id) => values.get(id).cloned().unwrap_or_else(|| operand.clone()),
+        constant => constant.clone(),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn clone_iteration(
+    function: &mut Function,
+    snapshot: &[BasicBlock],
+    natural_loop: &Loop,
+    induction: &Induction,
+    header_copy: BlockId,
+    phi_values: &HashMap<ValueId, Operand>,
+    back_edge_target: BlockId,
+    exit: Option<BlockId>,
+) -> (HashMap<ValueId, Operand>, HashMap<BlockId, BlockId>) {
+    let header = natural_loop.header;
+    let cloned: Vec<&BasicBlock> = snapshot.iter()
+        .filter(|b| b.id == header || exit.is_none())
+        .collect();
+    
+    let mut values = phi_values.clone();
+    let mut blocks = HashMap::from([(header, header_copy)]);
+    
+    for block in &cloned {
+        if block.id != header {
+            blocks.insert(block.id, function.add_block());
+        }
+        for instruction in &block.instructions {
+            if let Some(result) = instruction.result
+                && !(block.id == header && instruction.is_phi()) {
+                values.insert(result, Operand::Value(function.new_value()));
+            }
+        }
+    }
+    
+    for block in cloned {
+        let mut instructions = Vec::new();
+        
+        for instruction in &block.instructions {
+            if block.id == header && instruction.is_phi() {
+                continue;
+            }
+            
+            let mut instruction = instruction.clone();
+            instruction.result = instruction.result.and_then(|r| values.get(&r).and_then(|v| v.as_value()));
+            if let InstructionKind::Phi(incoming) = &mut instruction.kind {
+                for (pred, _) in incoming.iter_mut() {
+                    *pred = blocks[pred];
+                }
+            }
+            for operand in instruction.operands_mut() {
+                *operand = remap(operand, &values);
+            }
+            instructions.push(instruction);
+        }
+        
+        let terminator = if block.id == header {
+            Terminator::Jump(match exit {
+                Some(exit) => exit,
+                None if induction.body == header => back_edge_target,
+                None => blocks[&induction.body],
+            })
+        } else {
+            let mut terminator = block.terminator.clone().unwrap_or(Terminator::Unreachable);
+            for successor in terminator.successors_mut() {
+                *successor = if *successor == header { back_edge_target } else { blocks[successor] };
+            }
+            for operand in terminator.operands_mut() {
+                *operand = remap(operand, &values);
+            }
+            terminator
+        };
+        
+        if let Some(copy) = function.block_mut(blocks[&block.id]) {
+            copy.instructions = instructions;
+            copy.terminator = Some(terminator);
+        }
+    }
+    
+    (values, blocks)
+}
+
+fn redirect_edge(function: &mut Function, from: BlockId, old_target: BlockId, new_target: BlockId) {
+    if let Some(terminator) = function.block_mut(from).and_then(|b| b.terminator.as_mut()) {
+        for successor in terminator.successors_mut() {
+            if *successor == old_target {
+                *successor = new_target;
+            }
+        }
+    }
+}
+
+fn retarget_phis(function: &mut Function, block: BlockId, old_pred: BlockId, new_pred: BlockId) {
+    if let Some(block) = function.block_mut(block) {
+        for instruction in &mut block.instructions {
+            if let InstructionKind::Phi(incoming) = &mut instruction.kind {
+                for (pred, _) in incoming.iter_mut() {
+                    if *pred == old_pred {
+                        *pred = new_pred;
+                    }
+                }
+            }
+        }
+    }
+}
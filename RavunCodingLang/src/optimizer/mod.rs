@@ -1 +1,15 @@
 //! Optimizer modülü
+pub mod pass_manager;
+pub mod hints;
+pub mod constant_folding;
+pub mod dead_code;
+pub mod cse;
+pub mod simplify_cfg;
+pub mod inline;
+pub mod licm;
+pub mod loop_unroll;
+pub mod error;
+
+pub use error::OptimizerError;
+pub use hints::OptimizationHints;
+pub use pass_manager::{OptLevel, Pass, PassManager, Remark};
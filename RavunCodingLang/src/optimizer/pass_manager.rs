@@ -0,0 +1,165 @@
+use std::fmt;
+use crate::diagnostics::MessageId;
+use crate::ir::function::Module;
+use crate::ir::verifier::verify_module;
+use crate::optimizer::constant_folding::ConstantFolding;
+use crate::optimizer::cse::CommonSubexpressionElimination;
+use crate::optimizer::dead_code::DeadCodeElimination;
+use crate::optimizer::error::OptimizerError;
+use crate::optimizer::hints::OptimizationHints;
+use crate::optimizer::inline::Inliner;
+use crate::optimizer::licm::LoopInvariantCodeMotion;
+use crate::optimizer::loop_unroll::LoopUnroll;
+use crate::optimizer::simplify_cfg::SimplifyCfg;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub enum OptLevel {
+    #[default]
+    O0,
+    O1,
+    O2,
+}
+
+impl OptLevel {
+    pub fn parse(level: &str) -> Option<OptLevel> {
+        match level.trim_start_matches("-O") {
+            "0" => Some(OptLevel::O0),
+            "1" => Some(OptLevel::O1),
+            "2" => Some(OptLevel::O2),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for OptLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OptLevel::O0 => write!(f, "-O0"),
+            OptLevel::O1 => write!(f, "-O1"),
+            OptLevel::O2 => write!(f, "-O2"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Remark {
+    pub pass: &'static str,
+    pub function: String,
+    pub message: String,
+}
+
+impl fmt::Display for Remark {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}] @{}: {}", self.pass, self.function, self.message)
+    }
+}
+
+pub trait Pass {
+    fn name(&self) -> &'static str;
+    
+    fn run(&mut self, module: &mut Module) -> bool;
+    
+    fn take_remarks(&mut self) -> Vec<Remark> {
+        Vec::new()
+    }
+}
+
+pub struct PassManager {
+    passes: Vec<Box<dyn Pass>>,
+    verify: bool,
+    dump_after_each_pass: bool,
+    remarks: Vec<Remark>,
+}
+
+impl Default for PassManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PassManager {
+    pub fn new() -> Self {
+        PassManager {
+            passes: Vec::new(),
+            verify: cfg!(debug_assertions),
+            dump_after_each_pass: false,
+            remarks: Vec::new(),
+        }
+    }
+    
+    pub fn for_level(level: OptLevel, hints: &OptimizationHints) -> Self {
+        let mut manager = PassManager::new();
+        
+        match level {
+            OptLevel::O0 => {},
+            OptLevel::O1 => {
+                manager.add_pass(Box::new(ConstantFolding));
+                manager.add_pass(Box::new(DeadCodeElimination));
+                manager.add_pass(Box::new(SimplifyCfg));
+            },
+            OptLevel::O2 => {
+                manager.add_pass(Box::new(Inliner::new(&hints.small_functions)));
+                manager.add_pass(Box::new(ConstantFolding));
+                manager.add_pass(Box::new(SimplifyCfg));
+                manager.add_pass(Box::new(LoopInvariantCodeMotion::new()));
+                manager.add_pass(Box::new(LoopUnroll::new()));
+                manager.add_pass(Box::new(ConstantFolding));
+                manager.add_pass(Box::new(SimplifyCfg));
+                manager.add_pass(Box::new(CommonSubexpressionElimination));
+                manager.add_pass(Box::new(ConstantFolding));
+                manager.add_pass(Box::new(DeadCodeElimination));
+                manager.add_pass(Box::new(SimplifyCfg));
+            },
+        }
+        
+        manager
+    }
+    
+    pub fn add_pass(&mut self, pass: Box<dyn Pass>) {
+        self.passes.push(pass);
+    }
+    
+    pub fn set_verify(&mut self, verify: bool) {
+        self.verify = verify;
+    }
+    
+    pub fn set_dump_after_each_pass(&mut self, dump: bool) {
+        self.dump_after_each_pass = dump;
+    }
+    
+    pub fn remarks(&self) -> &[Remark] {
+        &self.remarks
+    }
+    
+    pub fn pass_names(&self) -> Vec<&'static str> {
+        self.passes.iter().map(|p| p.name()).collect()
+    }
+    
+    pub fn run(&mut self, module: &mut Module) -> Result<bool, OptimizerError> {
+        if self.verify {
+            verify_module(module).map_err(|errors| OptimizerError::new(None, errors))?;
+        }
+        
+        let mut changed = false;
+        
+        for pass in &mut self.passes {
+            let pass_changed = pass.run(module);
+            changed |= pass_changed;
+            self.remarks.extend(pass.take_remarks());
+            
+            if self.verify {
+                verify_module(module).map_err(|errors| {
+                    OptimizerError::new(Some(pass.name().to_string()), errors)
+                })?;
+            }
+            
+            if self.dump_after_each_pass {
+                let status = if pass_changed { MessageId::PassChanged } else { MessageId::PassUnchanged };
+                eprintln!("{}", MessageId::PassDumpHeader.format(&[&pass.name(), &status.text()]));
+                eprint!("{}", module);
+            }
+        }
+        
+        Ok(changed)
+    }
+}
@@ -0,0 +1,83 @@
+use std::collections::HashMap;
+use crate::ir::function::{Function, Module};
+use crate::ir::instruction::{InstructionKind, Operand, Terminator, ValueId};
+use crate::optimizer::pass_manager::Pass;
+
+pub struct SimplifyCfg;
+
+impl Pass for SimplifyCfg {
+    fn name(&self) -> &'static str {
+        "simplify-cfg"
+    }
+    
+    fn run(&mut self, module: &mut Module) -> bool {
+        let mut changed = false;
+        
+        for function in &mut module.functions {
+            changed |= function.remove_unreachable_blocks();
+            while merge_one_block(function) {
+                changed = true;
+            }
+        }
+        
+        changed
+    }
+}
+
+fn merge_one_block(function: &mut Function) -> bool {
+    let preds = function.predecessors();
+    let entry = function.entry();
+    
+    let candidate = function.blocks.iter().find_map(|block| match block.terminator {
+        Some(Terminator::Jump(target))
+            if target != block.id
+                && Some(target) != entry
+                && preds.get(&target).is_some_and(|p| p.len() == 1) => Some((block.id, target)),
+        _ => None,
+    });
+    
+    let Some((pred, target)) = candidate else {
+        return false;
+    };
+    
+    let Some(position) = function.blocks.iter().position(|b| b.id == target) else {
+        return false;
+    };
+    let merged = function.blocks.remove(position);
+    
+    let mut replacements: HashMap<ValueId, Operand> = HashMap::new();
+    let mut instructions = Vec::new();
+    
+    for instruction in merged.instructions {
+        match (&instruction.kind, instruction.result) {
+            (InstructionKind::Phi(incoming), Some(result)) => {
+                if let Some((_, value)) = incoming.first() {
+                    replacements.insert(result, value.clone());
+                }
+            },
+            _ => instructions.push(instruction),
+        }
+    }
+    
+    for successor in merged.terminator.as_ref().map_or_else(Vec::new, |t| t.successors()) {
+        if let Some(block) = function.block_mut(successor) {
+            for instruction in &mut block.instructions {
+                if let InstructionKind::Phi(incoming) = &mut instruction.kind {
+                    for (from, _) in incoming.iter_mut() {
+                        if *from == target {
+                            *from = pred;
+                        }
+                    }
+                }
+            }
+        }
+    }
+    
+    if let Some(block) = function.block_mut(pred) {
+        block.instructions.extend(instructions);
+        block.terminator = merged.terminator;
+    }
+    
+    function.replace_uses(&replacements);
+    true
+}
@@ -1,4 +1,5 @@
 use std::fmt;
+use crate::lexer::span::Span;
 use crate::lexer::token::{Token, TokenType};
 
 #[derive(Debug, Clone, PartialEq)]
@@ -13,6 +14,7 @@ pub enum AstNodeType {
     CallExpr,
     IndexExpr,
     MemberExpr,
+    RangeExpr,
     
     ExprStmt,
     BlockStmt,
@@ -20,6 +22,8 @@ pub enum AstNodeType {
     WhileStmt,
     ForStmt,
     ReturnStmt,
+    BreakStmt,
+    ContinueStmt,
     
     VarDecl,
     FuncDecl,
@@ -29,6 +33,8 @@ pub enum AstNodeType {
     ParamDecl,
     
     TypeAnnotation,
+    
+    Error,
 }
 
 #[derive(Clone)]
@@ -40,6 +46,7 @@ pub struct AstNode {
     pub metadata: Option<String>,
     pub line: usize,
     pub column: usize,
+    pub span: Span,
     pub kind: Option<AstNodeKind>,
 }
 
@@ -57,10 +64,10 @@ impl Eq for AstNode {}
 
 impl AstNode {
     pub fn new(node_type: AstNodeType, token: Option<Token>) -> Self {
-        let (line, column) = if let Some(ref tok) = token {
-            (tok.line, tok.column)
+        let (line, column, span) = if let Some(ref tok) = token {
+            (tok.line, tok.column, tok.span)
         } else {
-            (0, 0)
+            (0, 0, Span::default())
         };
         
         AstNode {
@@ -71,11 +78,16 @@ impl AstNode {
             metadata: None,
             line,
             column,
+            span,
             kind: None,
         }
     }
     
     pub fn add_child(&mut self, child: AstNode) {
+        if self.token.is_none() && self.line == 0 {
+            self.line = child.line;
+            self.column = child.column;
+        }
         self.children.push(child);
     }
     
@@ -87,6 +99,17 @@ impl AstNode {
         self.metadata = Some(metadata);
     }
     
+    pub fn attributes(&self) -> Vec<&str> {
+        match (&self.node_type, &self.metadata) {
+            (AstNodeType::FuncDecl, Some(metadata)) => metadata.split(',').filter(|a| !a.is_empty()).collect(),
+            _ => Vec::new(),
+        }
+    }
+    
+    pub fn is_error(&self) -> bool {
+        self.node_type == AstNodeType::Error
+    }
+    
     pub fn line(&self) -> usize {
         self.line
     }
@@ -95,6 +118,14 @@ impl AstNode {
         self.column
     }
     
+    pub fn span(&self) -> Span {
+        self.span
+    }
+    
+    pub fn set_span(&mut self, span: Span) {
+        self.span = span;
+    }
+    
     pub fn kind(&self) -> &Option<AstNodeKind> {
         &self.kind
     }
@@ -131,22 +162,6 @@ impl fmt::Debug for AstNode {
     }
 }
 
-impl PartialEq for Box<AstNode> {
-    fn eq(&self, other: &Self) -> bool {
-        **self == **other
-    }
-}
-
-impl Eq for Box<AstNode> {}
-
-impl PartialEq for (String, Box<AstNode>) {
-    fn eq(&self, other: &Self) -> bool {
-        self.0 == other.0 && self.1 == other.1
-    }
-}
-
-impl Eq for (String, Box<AstNode>) {}
-
 #[derive(Debug, Clone, PartialEq)]
 pub enum BinaryOperator {
     Add,
@@ -155,17 +170,29 @@ pub enum BinaryOperator {
     Divide,
     Modulo,
     Power,
+    BitAnd,
+    BitOr,
+    BitXor,
+    ShiftLeft,
+    ShiftRight,
     Equal,
     NotEqual,
     Greater,
     Less,
     GreaterEq,
     LessEq,
+    And,
+    Or,
     Assign,
     PlusAssign,
     MinusAssign,
     MulAssign,
     DivAssign,
+    BitAndAssign,
+    BitOrAssign,
+    BitXorAssign,
+    ShiftLeftAssign,
+    ShiftRightAssign,
 }
 
 impl From<TokenType> for BinaryOperator {
@@ -177,17 +204,29 @@ impl From<TokenType> for BinaryOperator {
             TokenType::Slash => BinaryOperator::Divide,
             TokenType::Percent => BinaryOperator::Modulo,
             TokenType::Caret => BinaryOperator::Power,
+            TokenType::BitAnd => BinaryOperator::BitAnd,
+            TokenType::BitOr => BinaryOperator::BitOr,
+            TokenType::Tilde => BinaryOperator::BitXor,
+            TokenType::ShiftLeft => BinaryOperator::ShiftLeft,
+            TokenType::ShiftRight => BinaryOperator::ShiftRight,
             TokenType::Equal => BinaryOperator::Equal,
             TokenType::NotEqual => BinaryOperator::NotEqual,
             TokenType::Greater => BinaryOperator::Greater,
             TokenType::Less => BinaryOperator::Less,
             TokenType::GreaterEq => BinaryOperator::GreaterEq,
             TokenType::LessEq => BinaryOperator::LessEq,
+            TokenType::And => BinaryOperator::And,
+            TokenType::Or => BinaryOperator::Or,
             TokenType::Assign => BinaryOperator::Assign,
             TokenType::PlusAssign => BinaryOperator::PlusAssign,
             TokenType::MinusAssign => BinaryOperator::MinusAssign,
             TokenType::MulAssign => BinaryOperator::MulAssign,
             TokenType::DivAssign => BinaryOperator::DivAssign,
+            TokenType::BitAndAssign => BinaryOperator::BitAndAssign,
+            TokenType::BitOrAssign => BinaryOperator::BitOrAssign,
+            TokenType::XorAssign => BinaryOperator::BitXorAssign,
+            TokenType::ShlAssign => BinaryOperator::ShiftLeftAssign,
+            TokenType::ShrAssign => BinaryOperator::ShiftRightAssign,
             _ => panic!("Invalid binary operator token type: {:?}", token_type),
         }
     }
@@ -196,6 +235,8 @@ impl From<TokenType> for BinaryOperator {
 #[derive(Debug, Clone, PartialEq)]
 pub enum UnaryOperator {
     Negate,
+    Not,
+    BitNot,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -0,0 +1,200 @@
+use std::fmt;
+use crate::diagnostics::{Diagnostic, ErrorCode, MessageId};
+use crate::lexer::span::Span;
+use crate::lexer::token::TokenType;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseContext {
+    VarDeclaration,
+    FunctionDeclaration,
+    FunctionParameters,
+    Attributes,
+    StructDeclaration,
+    StructFields,
+    ImplBlock,
+    ModuleBlock,
+    IfStatement,
+    WhileStatement,
+    ForStatement,
+    ReturnStatement,
+    Block,
+    CallArguments,
+    GroupExpression,
+}
+
+impl fmt::Display for ParseContext {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let message = match self {
+            ParseContext::VarDeclaration => MessageId::ContextVarDeclaration,
+            ParseContext::FunctionDeclaration => MessageId::ContextFunctionDeclaration,
+            ParseContext::FunctionParameters => MessageId::ContextFunctionParameters,
+            ParseContext::Attributes => MessageId::ContextAttributes,
+            ParseContext::StructDeclaration => MessageId::ContextStructDeclaration,
+            ParseContext::StructFields => MessageId::ContextStructFields,
+            ParseContext::ImplBlock => MessageId::ContextImplBlock,
+            ParseContext::ModuleBlock => MessageId::ContextModuleBlock,
+            ParseContext::IfStatement => MessageId::ContextIfStatement,
+            ParseContext::WhileStatement => MessageId::ContextWhileStatement,
+            ParseContext::ForStatement => MessageId::ContextForStatement,
+            ParseContext::ReturnStatement => MessageId::ContextReturnStatement,
+            ParseContext::Block => MessageId::ContextBlock,
+            ParseContext::CallArguments => MessageId::ContextCallArguments,
+            ParseContext::GroupExpression => MessageId::ContextGroupExpression,
+        };
+        
+        write!(f, "{}", message.text())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseError {
+    UnexpectedToken {
+        span: Span,
+        found: TokenType,
+        expected: Vec<TokenType>,
+        context: Option<ParseContext>,
+    },
+    UnexpectedEof {
+        span: Span,
+        expected: Vec<TokenType>,
+        context: Option<ParseContext>,
+    },
+    ExpectedExpression {
+        span: Span,
+        found: TokenType,
+        expected: Vec<TokenType>,
+        context: Option<ParseContext>,
+    },
+    MisplacedAttribute {
+        span: Span,
+        found: TokenType,
+        attributes: Span,
+    },
+    InvalidAssignmentTarget {
+        span: Span,
+        operator: Span,
+        context: Option<ParseContext>,
+    },
+}
+
+impl ParseError {
+    pub fn span(&self) -> Span {
+        match self {
+            ParseError::UnexpectedToken { span, .. } |
+            ParseError::UnexpectedEof { span, .. } |
+            ParseError::ExpectedExpression { span, .. } |
+            ParseError::MisplacedAttribute { span, .. } |
+            ParseError::InvalidAssignmentTarget { span, .. } => *span,
+        }
+    }
+    
+    pub fn found(&self) -> Option<&TokenType> {
+        match self {
+            ParseError::UnexpectedToken { found, .. } |
+            ParseError::ExpectedExpression { found, .. } |
+            ParseError::MisplacedAttribute { found, .. } => Some(found),
+            ParseError::UnexpectedEof { .. } => Some(&TokenType::EOF),
+            ParseError::InvalidAssignmentTarget { .. } => None,
+        }
+    }
+    
+    pub fn expected(&self) -> &[TokenType] {
+        match self {
+            ParseError::UnexpectedToken { expected, .. } |
+            ParseError::UnexpectedEof { expected, .. } |
+            ParseError::ExpectedExpression { expected, .. } => expected,
+            ParseError::MisplacedAttribute { .. } |
+            ParseError::InvalidAssignmentTarget { .. } => &[],
+        }
+    }
+    
+    pub fn context(&self) -> Option<ParseContext> {
+        match self {
+            ParseError::UnexpectedToken { context, .. } |
+            ParseError::UnexpectedEof { context, .. } |
+            ParseError::ExpectedExpression { context, .. } |
+            ParseError::InvalidAssignmentTarget { context, .. } => *context,
+            ParseError::MisplacedAttribute { .. } => None,
+        }
+    }
+    
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            ParseError::UnexpectedToken { .. } => ErrorCode::ExpectedToken,
+            ParseError::UnexpectedEof { .. } => ErrorCode::UnexpectedEof,
+            ParseError::ExpectedExpression { .. } => ErrorCode::ExpectedExpression,
+            ParseError::MisplacedAttribute { .. } => ErrorCode::MisplacedAttribute,
+            ParseError::InvalidAssignmentTarget { .. } => ErrorCode::InvalidAssignmentTarget,
+        }
+    }
+    
+    pub fn message(&self) -> String {
+        match self {
+            ParseError::UnexpectedToken { found, expected, .. } => {
+                MessageId::ExpectedToken.format(&[&describe_expected(expected, false), found])
+            },
+            ParseError::UnexpectedEof { expected, .. } if expected.is_empty() => {
+                MessageId::UnexpectedEof.text().to_string()
+            },
+            ParseError::UnexpectedEof { expected, .. } => {
+                MessageId::ExpectedTokenAtEof.format(&[&describe_expected(expected, false)])
+            },
+            ParseError::ExpectedExpression { found, .. } => MessageId::UnexpectedToken.format(&[found]),
+            ParseError::MisplacedAttribute { .. } => MessageId::AttributeOnNonFunction.text().to_string(),
+            ParseError::InvalidAssignmentTarget { .. } => MessageId::InvalidAssignmentTarget.text().to_string(),
+        }
+    }
+    
+    pub fn to_diagnostic(&self) -> Diagnostic {
+        let diagnostic = Diagnostic::error(self.message()).with_code(self.code().to_string());
+        
+        let diagnostic = match self {
+            ParseError::UnexpectedToken { span, expected, .. } |
+            ParseError::UnexpectedEof { span, expected, .. } if !expected.is_empty() => {
+                diagnostic.with_label(*span, MessageId::ExpectedTokenLabel.format(&[&describe_expected(expected, false)]))
+            },
+            ParseError::UnexpectedToken { span, .. } |
+            ParseError::UnexpectedEof { span, .. } => diagnostic.with_label(*span, ""),
+            ParseError::ExpectedExpression { span, expected, .. } => {
+                diagnostic.with_label(*span, MessageId::ExpectedTokenLabel.format(&[&describe_expected(expected, true)]))
+            },
+            ParseError::MisplacedAttribute { span, attributes, .. } => diagnostic
+                .with_label(*span, MessageId::ExpectedFunction.text())
+                .with_secondary_label(*attributes, MessageId::AttributesHere.text()),
+            ParseError::InvalidAssignmentTarget { span, operator, .. } => diagnostic
+                .with_label(*span, MessageId::CannotAssignToExpression.text())
+                .with_secondary_label(*operator, ""),
+        };
+        
+        match self.context() {
+            Some(context) => diagnostic.with_note(MessageId::ParsingContext.format(&[&context])),
+            None => diagnostic,
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message())
+    }
+}
+
+impl From<ParseError> for Diagnostic {
+    fn from(error: ParseError) -> Self {
+        error.to_diagnostic()
+    }
+}
+
+fn describe_expected(expected: &[TokenType], expression: bool) -> String {
+    let mut items: Vec<String> = Vec::new();
+    if expression {
+        items.push(MessageId::TokenExpression.text().to_string());
+    }
+    items.extend(expected.iter().map(|token_type| token_type.to_string()));
+    
+    match items.split_last() {
+        Some((last, rest)) if !rest.is_empty() => format!("{} {} {}", rest.join(", "), MessageId::Or.text(), last),
+        Some((last, _)) => last.clone(),
+        None => String::new(),
+    }
+}
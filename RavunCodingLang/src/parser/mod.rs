@@ -1,2 +1,5 @@
+#[allow(clippy::module_inception, clippy::result_large_err)]
 pub mod parser;
 pub mod ast;
+pub mod error;
+pub mod precedence;
@@ -1,12 +1,18 @@
 use std::iter::Peekable;
 use std::vec::IntoIter;
+use crate::lexer::span::Span;
 use crate::lexer::token::{Token, TokenType};
-use crate::parser::ast::{AstNode, AstNodeType, BinaryOperator, UnaryOperator};
+use crate::parser::ast::{AstNode, AstNodeType};
+use crate::parser::error::{ParseContext, ParseError};
+use crate::parser::precedence::{infix_operator, prefix_operator, INFIX_OPERATORS};
 
 pub struct Parser {
     tokens: Peekable<IntoIter<Token>>,
     current_token: Option<Token>,
-    errors: Vec<String>,
+    previous_span: Span,
+    expected: Vec<TokenType>,
+    contexts: Vec<ParseContext>,
+    errors: Vec<ParseError>,
 }
 
 impl Parser {
@@ -14,6 +20,9 @@ impl Parser {
         let mut parser = Parser {
             tokens: tokens.into_iter().peekable(),
             current_token: None,
+            previous_span: Span::default(),
+            expected: Vec::new(),
+            contexts: Vec::new(),
             errors: Vec::new(),
         };
         
@@ -23,79 +32,243 @@ impl Parser {
     }
     
     fn advance(&mut self) {
+        if let Some(ref token) = self.current_token {
+            self.previous_span = token.span;
+        }
         self.current_token = self.tokens.next();
+        self.expected.clear();
     }
     
-    fn check(&self, expected_type: TokenType) -> bool {
-        if let Some(ref token) = self.current_token {
-            token.token_type == expected_type
-        } else {
-            false
+    fn current_span(&self) -> Span {
+        self.current_token.as_ref().map_or(self.previous_span, |token| token.span)
+    }
+    
+    fn span_from(&self, start: Span) -> Span {
+        start.to(self.previous_span)
+    }
+    
+    fn peek_is(&self, token_type: &TokenType) -> bool {
+        self.current_token.as_ref().is_some_and(|token| &token.token_type == token_type)
+    }
+    
+    fn at_end(&self) -> bool {
+        self.current_token.as_ref().is_none_or(|token| token.token_type == TokenType::EOF)
+    }
+    
+    fn check(&mut self, expected_type: TokenType) -> bool {
+        if self.peek_is(&expected_type) {
+            return true;
         }
+        
+        self.expect_one_of(&[expected_type]);
+        false
     }
     
-    fn consume(&mut self, expected_type: TokenType) -> Result<Token, String> {
-        if let Some(ref token) = self.current_token {
-            if token.token_type == expected_type {
-                let token_clone = token.clone();
-                self.advance();
-                return Ok(token_clone);
+    fn expect_one_of(&mut self, token_types: &[TokenType]) {
+        for token_type in token_types {
+            if !self.expected.contains(token_type) {
+                self.expected.push(token_type.clone());
+            }
+        }
+    }
+    
+    fn in_context<T>(&mut self, context: ParseContext, parse: impl FnOnce(&mut Self) -> Result<T, ParseError>) -> Result<T, ParseError> {
+        self.contexts.push(context);
+        let result = parse(self);
+        self.contexts.pop();
+        result
+    }
+    
+    fn context(&self) -> Option<ParseContext> {
+        self.contexts.last().copied()
+    }
+    
+    fn consume(&mut self, expected_type: TokenType) -> Result<Token, ParseError> {
+        if let Some(ref token) = self.current_token
+            && token.token_type == expected_type {
+            let token_clone = token.clone();
+            self.advance();
+            return Ok(token_clone);
+        }
+        
+        self.expect_one_of(&[expected_type]);
+        Err(self.unexpected())
+    }
+    
+    fn unexpected(&self) -> ParseError {
+        match self.current_token {
+            Some(ref token) if token.token_type != TokenType::EOF => ParseError::UnexpectedToken {
+                span: token.span,
+                found: token.token_type.clone(),
+                expected: self.expected.clone(),
+                context: self.context(),
+            },
+            _ => self.unexpected_eof(),
+        }
+    }
+    
+    fn unexpected_eof(&self) -> ParseError {
+        ParseError::UnexpectedEof {
+            span: self.end_of_input(),
+            expected: self.expected.clone(),
+            context: self.context(),
+        }
+    }
+    
+    fn end_of_input(&self) -> Span {
+        Span { start: self.previous_span.end, ..self.previous_span }
+    }
+    
+    fn error(&mut self, error: ParseError) {
+        self.errors.push(error);
+    }
+    
+    fn error_node(&self, start: Span) -> AstNode {
+        let mut node = AstNode::new(AstNodeType::Error, None);
+        node.set_span(self.span_from(start));
+        node
+    }
+    
+    fn skip_until(&mut self, boundaries: &[TokenType]) {
+        let mut depth = 0usize;
+        
+        while let Some(ref token) = self.current_token {
+            match token.token_type {
+                TokenType::EOF => return,
+                TokenType::Semicolon | TokenType::RightBrace if depth == 0 => return,
+                ref token_type if depth == 0 && boundaries.contains(token_type) => return,
+                TokenType::LeftParen | TokenType::LeftBrace | TokenType::LeftBracket => depth += 1,
+                TokenType::RightParen | TokenType::RightBrace | TokenType::RightBracket => depth = depth.saturating_sub(1),
+                _ => {}
             }
             
-            Err(format!(
-                "Beklenen token tipi: {:?}, bulunan: {:?} (satır: {}, sütun: {})",
-                expected_type, token.token_type, token.line, token.column
-            ))
-        } else {
-            Err(format!("Beklenen token tipi: {:?}, dosya sonu bulundu", expected_type))
+            self.advance();
         }
     }
     
-    fn peek(&mut self) -> Option<&Token> {
-        self.tokens.peek()
+    fn recover_statement(&mut self, error: ParseError, start: Span) -> Result<AstNode, ParseError> {
+        if let ParseError::UnexpectedEof { .. } = error {
+            return Err(error);
+        }
+        self.error(error);
+        
+        if self.current_span() == start {
+            self.advance();
+        }
+        
+        self.skip_until(&[
+            TokenType::Let, TokenType::Fn, TokenType::If, TokenType::While,
+            TokenType::For, TokenType::Return, TokenType::Break, TokenType::Continue,
+            TokenType::Struct, TokenType::Impl, TokenType::Mod, TokenType::Hash,
+        ]);
+        if self.peek_is(&TokenType::Semicolon) {
+            self.advance();
+        }
+        
+        Ok(self.error_node(start))
     }
     
-    fn error(&mut self, message: String) {
-        self.errors.push(message);
+    fn recover_list_item(&mut self, error: ParseError, start: Span, closing: TokenType) -> Result<AstNode, ParseError> {
+        if let ParseError::UnexpectedEof { .. } = error {
+            return Err(error);
+        }
+        self.error(error);
+        self.skip_until(&[TokenType::Comma, closing]);
+        
+        Ok(self.error_node(start))
     }
     
-    pub fn parse(&mut self) -> Result<AstNode, Vec<String>> {
-        let program = self.parse_program();
+    fn missing_separator(&mut self, closing: TokenType) -> bool {
+        let at_boundary = self.current_token.as_ref().is_none_or(|token| matches!(
+            token.token_type,
+            TokenType::EOF | TokenType::Semicolon | TokenType::RightBrace
+        ));
+        if at_boundary || self.peek_is(&closing) {
+            return false;
+        }
         
-        if !self.errors.is_empty() {
-            Err(self.errors.clone())
+        self.expect_one_of(&[closing]);
+        let error = self.unexpected();
+        self.error(error);
+        true
+    }
+    
+    fn close_list(&mut self, closing: TokenType, recovered: bool) -> Result<(), ParseError> {
+        if self.peek_is(&closing) {
+            self.advance();
+            return Ok(());
+        }
+        if recovered && !self.at_end() {
+            return Ok(());
+        }
+        
+        let error = match self.consume(closing.clone()) {
+            Ok(_) => return Ok(()),
+            Err(error @ ParseError::UnexpectedEof { .. }) => return Err(error),
+            Err(error) => error,
+        };
+        self.error(error);
+        self.skip_until(std::slice::from_ref(&closing));
+        if self.peek_is(&closing) {
+            self.advance();
+        }
+        
+        Ok(())
+    }
+    
+    pub fn parse(&mut self) -> Result<AstNode, Vec<ParseError>> {
+        let (program, errors) = self.parse_recovering();
+        
+        if !errors.is_empty() {
+            Err(errors)
         } else {
             Ok(program)
         }
     }
     
+    pub fn parse_recovering(&mut self) -> (AstNode, Vec<ParseError>) {
+        let program = self.parse_program();
+        
+        (program, std::mem::take(&mut self.errors))
+    }
+    
     fn parse_program(&mut self) -> AstNode {
         let mut program = AstNode::new(AstNodeType::Program, None);
+        let start = self.current_span();
         
-        while let Some(_) = self.current_token {
+        while !self.at_end() {
+            let declaration_start = self.current_span();
+            
             match self.parse_declaration() {
                 Ok(declaration) => program.add_child(declaration),
                 Err(err) => {
                     self.error(err);
                     self.synchronize();
+                    program.add_child(self.error_node(declaration_start));
                 }
             }
         }
         
+        program.set_span(self.span_from(start));
         program
     }
     
     fn synchronize(&mut self) {
         while let Some(ref token) = self.current_token {
+            if token.token_type == TokenType::EOF {
+                return;
+            }
+            
             if token.token_type == TokenType::Semicolon {
                 self.advance();
                 return;
             }
             
             match token.token_type {
-                TokenType::Let | TokenType::Fn | TokenType::For | 
+                TokenType::Let | TokenType::Fn | TokenType::For |
                 TokenType::If | TokenType::While | TokenType::Return |
-                TokenType::Struct | TokenType::Impl | TokenType::Mod => {
+                TokenType::Break | TokenType::Continue | TokenType::Struct |
+                TokenType::Impl | TokenType::Mod | TokenType::Hash => {
                     return;
                 }
                 _ => {}
@@ -105,21 +278,22 @@ impl Parser {
         }
     }
     
-    fn parse_declaration(&mut self) -> Result<AstNode, String> {
+    fn parse_declaration(&mut self) -> Result<AstNode, ParseError> {
         match self.current_token {
             Some(ref token) => match token.token_type {
-                TokenType::Let => self.parse_var_declaration(),
-                TokenType::Fn => self.parse_function_declaration(),
-                TokenType::Struct => self.parse_struct_declaration(),
-                TokenType::Impl => self.parse_impl_declaration(),
-                TokenType::Mod => self.parse_module_declaration(),
+                TokenType::Let => self.in_context(ParseContext::VarDeclaration, Self::parse_var_declaration),
+                TokenType::Fn => self.in_context(ParseContext::FunctionDeclaration, Self::parse_function_declaration),
+                TokenType::Hash => self.in_context(ParseContext::Attributes, Self::parse_attributed_declaration),
+                TokenType::Struct => self.in_context(ParseContext::StructDeclaration, Self::parse_struct_declaration),
+                TokenType::Impl => self.in_context(ParseContext::ImplBlock, Self::parse_impl_declaration),
+                TokenType::Mod => self.in_context(ParseContext::ModuleBlock, Self::parse_module_declaration),
                 _ => self.parse_statement(),
             },
-            None => Err("Beklenmeyen dosya sonu".to_string()),
+            None => Err(self.unexpected_eof()),
         }
     }
     
-    fn parse_var_declaration(&mut self) -> Result<AstNode, String> {
+    fn parse_var_declaration(&mut self) -> Result<AstNode, ParseError> {
         let let_token = self.consume(TokenType::Let)?;
         
         let is_mutable = if self.check(TokenType::Mut) {
@@ -139,11 +313,18 @@ impl Parser {
         };
         
         self.consume(TokenType::Assign)?;
-        let initializer = self.parse_expression()?;
-        
-        self.consume(TokenType::Semicolon)?;
+        let initializer_start = self.current_span();
+        let initializer = match self.parse_expression() {
+            Ok(initializer) => {
+                self.consume(TokenType::Semicolon)?;
+                initializer
+            },
+            Err(err) => self.recover_statement(err, initializer_start)?,
+        };
         
+        let span = self.span_from(let_token.span);
         let mut var_decl = AstNode::new(AstNodeType::VarDecl, Some(let_token));
+        var_decl.set_span(span);
         var_decl.set_value(identifier.lexeme);
         
         if is_mutable {
@@ -159,14 +340,18 @@ impl Parser {
         Ok(var_decl)
     }
     
-    fn parse_function_declaration(&mut self) -> Result<AstNode, String> {
+    fn parse_function_declaration(&mut self) -> Result<AstNode, ParseError> {
         let fn_token = self.consume(TokenType::Fn)?;
         
         let identifier = self.consume(TokenType::Identifier)?;
         
-        self.consume(TokenType::LeftParen)?;
-        let parameters = self.parse_parameters()?;
-        self.consume(TokenType::RightParen)?;
+        let parameters = self.in_context(ParseContext::FunctionParameters, |parser| {
+            parser.consume(TokenType::LeftParen)?;
+            let parameters = parser.parse_parameters()?;
+            let recovered = parameters.last().is_some_and(AstNode::is_error);
+            parser.close_list(TokenType::RightParen, recovered)?;
+            Ok(parameters)
+        })?;
         
         let return_type = if self.check(TokenType::Arrow) {
             self.advance();
@@ -175,9 +360,20 @@ impl Parser {
             None
         };
         
-        let body = self.parse_block_statement()?;
+        // Gövde okunamasa bile başlık korunur; böylece fonksiyon adı tanımsız sayılmaz.
+        let body_start = self.current_span();
+        let body = match self.parse_block_statement() {
+            Ok(body) => body,
+            Err(err) => {
+                self.error(err);
+                self.synchronize();
+                self.error_node(body_start)
+            }
+        };
         
+        let span = self.span_from(fn_token.span);
         let mut func_decl = AstNode::new(AstNodeType::FuncDecl, Some(fn_token));
+        func_decl.set_span(span);
         func_decl.set_value(identifier.lexeme);
         
         for param in parameters {
@@ -193,43 +389,89 @@ impl Parser {
         Ok(func_decl)
     }
     
-    fn parse_parameters(&mut self) -> Result<Vec<AstNode>, String> {
+    fn parse_attributed_declaration(&mut self) -> Result<AstNode, ParseError> {
+        let mut attributes = Vec::new();
+        let start = self.current_span();
+        
+        while self.check(TokenType::Hash) {
+            self.advance();
+            self.consume(TokenType::LeftBracket)?;
+            let name = self.consume(TokenType::Identifier)?;
+            self.consume(TokenType::RightBracket)?;
+            
+            attributes.push(name.lexeme);
+        }
+        
+        match self.current_token {
+            Some(ref token) if token.token_type == TokenType::Fn => {},
+            Some(ref token) => {
+                return Err(ParseError::MisplacedAttribute {
+                    span: token.span,
+                    found: token.token_type.clone(),
+                    attributes: self.span_from(start),
+                });
+            },
+            None => return Err(self.unexpected_eof()),
+        }
+        
+        let mut func_decl = self.in_context(ParseContext::FunctionDeclaration, Self::parse_function_declaration)?;
+        func_decl.set_metadata(attributes.join(","));
+        func_decl.set_span(self.span_from(start));
+        
+        Ok(func_decl)
+    }
+    
+    fn parse_parameters(&mut self) -> Result<Vec<AstNode>, ParseError> {
         let mut parameters = Vec::new();
         
         if !self.check(TokenType::RightParen) {
             loop {
-                let param_name = self.consume(TokenType::Identifier)?;
-                
-                self.consume(TokenType::Colon)?;
-                let param_type = self.parse_type_annotation()?;
-                
-                let mut param = AstNode::new(AstNodeType::ParamDecl, Some(param_name.clone()));
-                param.set_value(param_name.lexeme);
-                param.add_child(param_type);
+                let start = self.current_span();
+                let param = self.parse_parameter()
+                    .or_else(|err| self.recover_list_item(err, start, TokenType::RightParen))?;
                 
                 parameters.push(param);
                 
-                if !self.check(TokenType::Comma) {
+                if self.check(TokenType::Comma) {
+                    self.advance();
+                } else if !self.missing_separator(TokenType::RightParen) {
                     break;
                 }
-                
-                self.advance();
             }
         }
         
         Ok(parameters)
     }
     
-    fn parse_struct_declaration(&mut self) -> Result<AstNode, String> {
+    fn parse_parameter(&mut self) -> Result<AstNode, ParseError> {
+        let param_name = self.consume(TokenType::Identifier)?;
+        
+        self.consume(TokenType::Colon)?;
+        let param_type = self.parse_type_annotation()?;
+        
+        let mut param = AstNode::new(AstNodeType::ParamDecl, Some(param_name.clone()));
+        param.set_value(param_name.lexeme);
+        param.set_span(param_name.span.to(param_type.span));
+        param.add_child(param_type);
+        
+        Ok(param)
+    }
+    
+    fn parse_struct_declaration(&mut self) -> Result<AstNode, ParseError> {
         let struct_token = self.consume(TokenType::Struct)?;
         
         let identifier = self.consume(TokenType::Identifier)?;
         
-        self.consume(TokenType::LeftBrace)?;
-        let fields = self.parse_struct_fields()?;
-        self.consume(TokenType::RightBrace)?;
+        let fields = self.in_context(ParseContext::StructFields, |parser| {
+            parser.consume(TokenType::LeftBrace)?;
+            let fields = parser.parse_struct_fields()?;
+            parser.consume(TokenType::RightBrace)?;
+            Ok(fields)
+        })?;
         
+        let span = self.span_from(struct_token.span);
         let mut struct_decl = AstNode::new(AstNodeType::StructDecl, Some(struct_token));
+        struct_decl.set_span(span);
         struct_decl.set_value(identifier.lexeme);
         
         for field in fields {
@@ -239,7 +481,7 @@ impl Parser {
         Ok(struct_decl)
     }
     
-    fn parse_struct_fields(&mut self) -> Result<Vec<AstNode>, String> {
+    fn parse_struct_fields(&mut self) -> Result<Vec<AstNode>, ParseError> {
         let mut fields = Vec::new();
         
         if !self.check(TokenType::RightBrace) {
@@ -253,6 +495,7 @@ impl Parser {
                 
                 let mut field = AstNode::new(AstNodeType::VarDecl, Some(field_name.clone()));
                 field.set_value(field_name.lexeme);
+                field.set_span(self.span_from(field_name.span));
                 field.add_child(field_type);
                 
                 fields.push(field);
@@ -266,7 +509,7 @@ impl Parser {
         Ok(fields)
     }
     
-    fn parse_impl_declaration(&mut self) -> Result<AstNode, String> {
+    fn parse_impl_declaration(&mut self) -> Result<AstNode, ParseError> {
         let impl_token = self.consume(TokenType::Impl)?;
         
         let identifier = self.consume(TokenType::Identifier)?;
@@ -275,7 +518,9 @@ impl Parser {
         let methods = self.parse_impl_methods()?;
         self.consume(TokenType::RightBrace)?;
         
+        let span = self.span_from(impl_token.span);
         let mut impl_decl = AstNode::new(AstNodeType::ImplDecl, Some(impl_token));
+        impl_decl.set_span(span);
         impl_decl.set_value(identifier.lexeme);
         
         for method in methods {
@@ -285,18 +530,18 @@ impl Parser {
         Ok(impl_decl)
     }
     
-    fn parse_impl_methods(&mut self) -> Result<Vec<AstNode>, String> {
+    fn parse_impl_methods(&mut self) -> Result<Vec<AstNode>, ParseError> {
         let mut methods = Vec::new();
         
         while !self.check(TokenType::RightBrace) {
-            let method = self.parse_function_declaration()?;
+            let method = self.in_context(ParseContext::FunctionDeclaration, Self::parse_function_declaration)?;
             methods.push(method);
         }
         
         Ok(methods)
     }
     
-    fn parse_module_declaration(&mut self) -> Result<AstNode, String> {
+    fn parse_module_declaration(&mut self) -> Result<AstNode, ParseError> {
         let mod_token = self.consume(TokenType::Mod)?;
         
         let identifier = self.consume(TokenType::Identifier)?;
@@ -305,7 +550,9 @@ impl Parser {
         let declarations = self.parse_module_declarations()?;
         self.consume(TokenType::RightBrace)?;
         
+        let span = self.span_from(mod_token.span);
         let mut mod_decl = AstNode::new(AstNodeType::ModDecl, Some(mod_token));
+        mod_decl.set_span(span);
         mod_decl.set_value(identifier.lexeme);
         
         for decl in declarations {
@@ -315,7 +562,7 @@ impl Parser {
         Ok(mod_decl)
     }
     
-    fn parse_module_declarations(&mut self) -> Result<Vec<AstNode>, String> {
+    fn parse_module_declarations(&mut self) -> Result<Vec<AstNode>, ParseError> {
         let mut declarations = Vec::new();
         
         while !self.check(TokenType::RightBrace) {
@@ -326,7 +573,7 @@ impl Parser {
         Ok(declarations)
     }
     
-    fn parse_type_annotation(&mut self) -> Result<AstNode, String> {
+    fn parse_type_annotation(&mut self) -> Result<AstNode, ParseError> {
         let type_name = self.consume(TokenType::Identifier)?;
         
         let mut type_node = AstNode::new(AstNodeType::TypeAnnotation, Some(type_name.clone()));
@@ -335,21 +582,23 @@ impl Parser {
         Ok(type_node)
     }
     
-    fn parse_statement(&mut self) -> Result<AstNode, String> {
+    fn parse_statement(&mut self) -> Result<AstNode, ParseError> {
         match self.current_token {
             Some(ref token) => match token.token_type {
-                TokenType::If => self.parse_if_statement(),
-                TokenType::While => self.parse_while_statement(),
-                TokenType::For => self.parse_for_statement(),
-                TokenType::Return => self.parse_return_statement(),
+                TokenType::If => self.in_context(ParseContext::IfStatement, Self::parse_if_statement),
+                TokenType::While => self.in_context(ParseContext::WhileStatement, Self::parse_while_statement),
+                TokenType::For => self.in_context(ParseContext::ForStatement, Self::parse_for_statement),
+                TokenType::Return => self.in_context(ParseContext::ReturnStatement, Self::parse_return_statement),
+                TokenType::Break => self.parse_loop_control_statement(TokenType::Break, AstNodeType::BreakStmt),
+                TokenType::Continue => self.parse_loop_control_statement(TokenType::Continue, AstNodeType::ContinueStmt),
                 TokenType::LeftBrace => self.parse_block_statement(),
                 _ => self.parse_expression_statement(),
             },
-            None => Err("Beklenmeyen dosya sonu".to_string()),
+            None => Err(self.unexpected_eof()),
         }
     }
     
-    fn parse_if_statement(&mut self) -> Result<AstNode, String> {
+    fn parse_if_statement(&mut self) -> Result<AstNode, ParseError> {
         let if_token = self.consume(TokenType::If)?;
         
         let condition = self.parse_expression()?;
@@ -368,7 +617,9 @@ impl Parser {
             None
         };
         
+        let span = self.span_from(if_token.span);
         let mut if_stmt = AstNode::new(AstNodeType::IfStmt, Some(if_token));
+        if_stmt.set_span(span);
         
         if_stmt.add_child(condition);
         if_stmt.add_child(then_branch);
@@ -380,14 +631,16 @@ impl Parser {
         Ok(if_stmt)
     }
     
-    fn parse_while_statement(&mut self) -> Result<AstNode, String> {
+    fn parse_while_statement(&mut self) -> Result<AstNode, ParseError> {
         let while_token = self.consume(TokenType::While)?;
         
         let condition = self.parse_expression()?;
         
         let body = self.parse_block_statement()?;
         
+        let span = self.span_from(while_token.span);
         let mut while_stmt = AstNode::new(AstNodeType::WhileStmt, Some(while_token));
+        while_stmt.set_span(span);
         
         while_stmt.add_child(condition);
         while_stmt.add_child(body);
@@ -395,18 +648,31 @@ impl Parser {
         Ok(while_stmt)
     }
     
-    fn parse_for_statement(&mut self) -> Result<AstNode, String> {
+    fn parse_for_statement(&mut self) -> Result<AstNode, ParseError> {
         let for_token = self.consume(TokenType::For)?;
         
         let variable = self.consume(TokenType::Identifier)?;
         
         self.consume(TokenType::In)?;
         
-        let range = self.parse_expression()?;
+        let mut range = self.parse_expression()?;
+        
+        if self.check(TokenType::DoubleDot) {
+            let dots = self.consume(TokenType::DoubleDot)?;
+            let end = self.parse_expression()?;
+            
+            let mut range_expr = AstNode::new(AstNodeType::RangeExpr, Some(dots));
+            range_expr.set_span(range.span.to(end.span));
+            range_expr.add_child(range);
+            range_expr.add_child(end);
+            range = range_expr;
+        }
         
         let body = self.parse_block_statement()?;
         
+        let span = self.span_from(for_token.span);
         let mut for_stmt = AstNode::new(AstNodeType::ForStmt, Some(for_token));
+        for_stmt.set_span(span);
         
         let mut var_node = AstNode::new(AstNodeType::IdentifierExpr, Some(variable.clone()));
         var_node.set_value(variable.lexeme);
@@ -418,7 +684,7 @@ impl Parser {
         Ok(for_stmt)
     }
     
-    fn parse_return_statement(&mut self) -> Result<AstNode, String> {
+    fn parse_return_statement(&mut self) -> Result<AstNode, ParseError> {
         let return_token = self.consume(TokenType::Return)?;
         
         let value = if !self.check(TokenType::Semicolon) {
@@ -429,7 +695,9 @@ impl Parser {
         
         self.consume(TokenType::Semicolon)?;
         
+        let span = self.span_from(return_token.span);
         let mut return_stmt = AstNode::new(AstNodeType::ReturnStmt, Some(return_token));
+        return_stmt.set_span(span);
         
         if let Some(expr) = value {
             return_stmt.add_child(expr);
@@ -438,19 +706,37 @@ impl Parser {
         Ok(return_stmt)
     }
     
-    fn parse_block_statement(&mut self) -> Result<AstNode, String> {
-        let brace_token = self.consume(TokenType::LeftBrace)?;
+    fn parse_loop_control_statement(&mut self, keyword: TokenType, node_type: AstNodeType) -> Result<AstNode, ParseError> {
+        let keyword = self.consume(keyword)?;
+        self.consume(TokenType::Semicolon)?;
         
-        let mut statements = Vec::new();
+        let span = self.span_from(keyword.span);
+        let mut statement = AstNode::new(node_type, Some(keyword));
+        statement.set_span(span);
         
-        while !self.check(TokenType::RightBrace) && self.current_token.is_some() {
-            let statement = self.parse_declaration()?;
-            statements.push(statement);
-        }
+        Ok(statement)
+    }
+    
+    fn parse_block_statement(&mut self) -> Result<AstNode, ParseError> {
+        let brace_token = self.consume(TokenType::LeftBrace)?;
         
-        self.consume(TokenType::RightBrace)?;
+        let statements = self.in_context(ParseContext::Block, |parser| {
+            let mut statements = Vec::new();
+            
+            while !parser.check(TokenType::RightBrace) && !parser.at_end() {
+                let start = parser.current_span();
+                let statement = parser.parse_declaration()
+                    .or_else(|err| parser.recover_statement(err, start))?;
+                statements.push(statement);
+            }
+            
+            parser.consume(TokenType::RightBrace)?;
+            Ok(statements)
+        })?;
         
+        let span = self.span_from(brace_token.span);
         let mut block = AstNode::new(AstNodeType::BlockStmt, Some(brace_token));
+        block.set_span(span);
         
         for stmt in statements {
             block.add_child(stmt);
@@ -459,239 +745,92 @@ impl Parser {
         Ok(block)
     }
     
-    fn parse_expression_statement(&mut self) -> Result<AstNode, String> {
+    fn parse_expression_statement(&mut self) -> Result<AstNode, ParseError> {
         let expression = self.parse_expression()?;
         
         self.consume(TokenType::Semicolon)?;
         
         let mut expr_stmt = AstNode::new(AstNodeType::ExprStmt, None);
+        expr_stmt.set_span(self.span_from(expression.span));
         expr_stmt.add_child(expression);
         
         Ok(expr_stmt)
     }
     
-    fn parse_expression(&mut self) -> Result<AstNode, String> {
-        self.parse_assignment()
+    fn parse_expression(&mut self) -> Result<AstNode, ParseError> {
+        self.parse_expression_bp(0)
     }
     
-    fn parse_assignment(&mut self) -> Result<AstNode, String> {
-        let expr = self.parse_equality()?;
+    fn parse_expression_bp(&mut self, min_bp: u8) -> Result<AstNode, ParseError> {
+        let mut expr = self.parse_prefix()?;
         
-        if let Some(ref token) = self.current_token {
-            match token.token_type {
-                TokenType::Assign | TokenType::PlusAssign | 
-                TokenType::MinusAssign | TokenType::MulAssign | 
-                TokenType::DivAssign => {
-                    let operator = token.clone();
-                    self.advance();
-                    
-                    let value = self.parse_assignment()?;
-                    
-                    match expr.node_type {
-                        AstNodeType::IdentifierExpr | AstNodeType::MemberExpr | AstNodeType::IndexExpr => {
-                            let mut assign_expr = AstNode::new(AstNodeType::BinaryExpr, Some(operator.clone()));
-                            assign_expr.set_value(match operator.token_type {
-                                TokenType::Assign => "=",
-                                TokenType::PlusAssign => "+=",
-                                TokenType::MinusAssign => "-=",
-                                TokenType::MulAssign => "*=",
-                                TokenType::DivAssign => "/=",
-                                _ => "?=",
-                            }.to_string());
-                            
-                            assign_expr.add_child(expr);
-                            assign_expr.add_child(value);
-                            
-                            return Ok(assign_expr);
-                        },
-                        _ => {
-                            return Err(format!("Geçersiz atama hedefi, satır: {}, sütun: {}", 
-                                              operator.line, operator.column));
-                        }
-                    }
-                }
-                _ => {}
-            }
-        }
-        
-        Ok(expr)
-    }
-    
-    fn parse_equality(&mut self) -> Result<AstNode, String> {
-        let mut expr = self.parse_comparison()?;
-        
-        while let Some(ref token) = self.current_token {
-            match token.token_type {
-                TokenType::Equal | TokenType::NotEqual => {
-                    let operator = token.clone();
-                    self.advance();
-                    
-                    let right = self.parse_comparison()?;
-                    
-                    let mut binary_expr = AstNode::new(AstNodeType::BinaryExpr, Some(operator.clone()));
-                    binary_expr.set_value(match operator.token_type {
-                        TokenType::Equal => "==",
-                        TokenType::NotEqual => "!=",
-                        _ => "?",
-                    }.to_string());
-                    
-                    binary_expr.add_child(expr);
-                    binary_expr.add_child(right);
-                    
-                    expr = binary_expr;
-                }
+        loop {
+            let operator = match self.current_token {
+                Some(ref token) => infix_operator(&token.token_type),
+                None => None,
+            };
+            let operator = match operator {
+                Some(operator) if operator.binding_power().0 >= min_bp => operator,
                 _ => break,
+            };
+            
+            let operator_token = self.consume(operator.token_type.clone())?;
+            let right = self.parse_expression_bp(operator.binding_power().1)?;
+            
+            if operator.is_assignment() && !matches!(
+                expr.node_type,
+                AstNodeType::IdentifierExpr | AstNodeType::MemberExpr | AstNodeType::IndexExpr
+            ) {
+                return Err(ParseError::InvalidAssignmentTarget {
+                    span: expr.span,
+                    operator: operator_token.span,
+                    context: self.context(),
+                });
             }
+            
+            let mut binary_expr = AstNode::new(AstNodeType::BinaryExpr, Some(operator_token));
+            binary_expr.set_value(operator.symbol.to_string());
+            binary_expr.set_span(expr.span.to(right.span));
+            binary_expr.add_child(expr);
+            binary_expr.add_child(right);
+            
+            expr = binary_expr;
         }
         
-        Ok(expr)
-    }
-    
-    fn parse_comparison(&mut self) -> Result<AstNode, String> {
-        let mut expr = self.parse_term()?;
-        
-        while let Some(ref token) = self.current_token {
-            match token.token_type {
-                TokenType::Greater | TokenType::GreaterEq | 
-                TokenType::Less | TokenType::LessEq => {
-                    let operator = token.clone();
-                    self.advance();
-                    
-                    let right = self.parse_term()?;
-                    
-                    let mut binary_expr = AstNode::new(AstNodeType::BinaryExpr, Some(operator.clone()));
-                    binary_expr.set_value(match operator.token_type {
-                        TokenType::Greater => ">",
-                        TokenType::GreaterEq => ">=",
-                        TokenType::Less => "<",
-                        TokenType::LessEq => "<=",
-                        _ => "?",
-                    }.to_string());
-                    
-                    binary_expr.add_child(expr);
-                    binary_expr.add_child(right);
-                    
-                    expr = binary_expr;
-                }
-                _ => break,
-            }
-        }
+        let accepted: Vec<TokenType> = INFIX_OPERATORS.iter()
+            .filter(|operator| operator.binding_power().0 >= min_bp)
+            .map(|operator| operator.token_type.clone())
+            .collect();
+        self.expect_one_of(&accepted);
         
         Ok(expr)
     }
     
-    fn parse_term(&mut self) -> Result<AstNode, String> {
-        let mut expr = self.parse_factor()?;
-        
-        while let Some(ref token) = self.current_token {
-            match token.token_type {
-                TokenType::Plus | TokenType::Minus => {
-                    let operator = token.clone();
-                    self.advance();
-                    
-                    let right = self.parse_factor()?;
-                    
-                    let mut binary_expr = AstNode::new(AstNodeType::BinaryExpr, Some(operator.clone()));
-                    binary_expr.set_value(match operator.token_type {
-                        TokenType::Plus => "+",
-                        TokenType::Minus => "-",
-                        _ => "?",
-                    }.to_string());
-                    
-                    binary_expr.add_child(expr);
-                    binary_expr.add_child(right);
-                    
-                    expr = binary_expr;
-                }
-                _ => break,
-            }
-        }
-        
-        Ok(expr)
-    }
-    
-    fn parse_factor(&mut self) -> Result<AstNode, String> {
-        let mut expr = self.parse_unary()?;
-        
-        while let Some(ref token) = self.current_token {
-            match token.token_type {
-                TokenType::Asterisk | TokenType::Slash | TokenType::Percent => {
-                    let operator = token.clone();
-                    self.advance();
-                    
-                    let right = self.parse_unary()?;
-                    
-                    let mut binary_expr = AstNode::new(AstNodeType::BinaryExpr, Some(operator.clone()));
-                    binary_expr.set_value(match operator.token_type {
-                        TokenType::Asterisk => "*",
-                        TokenType::Slash => "/",
-                        TokenType::Percent => "%",
-                        _ => "?",
-                    }.to_string());
-                    
-                    binary_expr.add_child(expr);
-                    binary_expr.add_child(right);
-                    
-                    expr = binary_expr;
-                }
-                _ => break,
-            }
-        }
+    fn parse_prefix(&mut self) -> Result<AstNode, ParseError> {
+        let operator = match self.current_token {
+            Some(ref token) => prefix_operator(&token.token_type),
+            None => None,
+        };
         
-        Ok(expr)
-    }
-    
-    fn parse_power(&mut self) -> Result<AstNode, String> {
-        let mut expr = self.parse_primary()?;
+        let Some(operator) = operator else {
+            return self.parse_primary();
+        };
         
-        while let Some(ref token) = self.current_token {
-            if token.token_type == TokenType::Caret {
-                let operator = token.clone();
-                self.advance();
-                
-                let right = self.parse_primary()?;
-                
-                let mut binary_expr = AstNode::new(AstNodeType::BinaryExpr, Some(operator.clone()));
-                binary_expr.set_value("^".to_string());
-                
-                binary_expr.add_child(expr);
-                binary_expr.add_child(right);
-                
-                expr = binary_expr;
-            } else {
-                break;
-            }
-        }
+        let operator_token = self.consume(operator.token_type.clone())?;
+        let right = self.parse_expression_bp(operator.binding_power().1)?;
         
-        Ok(expr)
-    }
-    
-    fn parse_unary(&mut self) -> Result<AstNode, String> {
-        if let Some(ref token) = self.current_token {
-            match token.token_type {
-                TokenType::Minus => {
-                    let operator = token.clone();
-                    self.advance();
-                    
-                    let right = self.parse_unary()?;
-                    
-                    let mut unary_expr = AstNode::new(AstNodeType::UnaryExpr, Some(operator.clone()));
-                    unary_expr.set_value("-".to_string());
-                    unary_expr.add_child(right);
-                    
-                    return Ok(unary_expr);
-                }
-                _ => {}
-            }
-        }
+        let mut unary_expr = AstNode::new(AstNodeType::UnaryExpr, Some(operator_token.clone()));
+        unary_expr.set_value(operator.symbol.to_string());
+        unary_expr.set_span(operator_token.span.to(right.span));
+        unary_expr.add_child(right);
         
-        self.parse_power()
+        Ok(unary_expr)
     }
     
-    fn parse_primary(&mut self) -> Result<AstNode, String> {
+    fn parse_primary(&mut self) -> Result<AstNode, ParseError> {
         if let Some(ref token) = self.current_token {
             match token.token_type {
-                TokenType::IntLiteral | TokenType::FloatLiteral | 
+                TokenType::IntLiteral | TokenType::FloatLiteral |
                 TokenType::StringLiteral | TokenType::BoolLiteral => {
                     let literal_token = token.clone();
                     self.advance();
@@ -717,47 +856,68 @@ impl Parser {
                 }
                 
                 TokenType::LeftParen => {
+                    let paren = token.clone();
                     self.advance();
                     
-                    let expr = self.parse_expression()?;
-                    
-                    self.consume(TokenType::RightParen)?;
+                    let expr = self.in_context(ParseContext::GroupExpression, |parser| {
+                        let start = parser.current_span();
+                        let expr = parser.parse_expression()
+                            .or_else(|err| parser.recover_list_item(err, start, TokenType::RightParen))?;
+                        parser.close_list(TokenType::RightParen, expr.is_error())?;
+                        Ok(expr)
+                    })?;
                     
                     let mut group_expr = AstNode::new(AstNodeType::GroupExpr, None);
+                    group_expr.line = paren.line;
+                    group_expr.column = paren.column;
+                    group_expr.set_span(self.span_from(paren.span));
                     group_expr.add_child(expr);
                     
                     Ok(group_expr)
                 }
                 
-                _ => Err(format!("Beklenmeyen token: {:?}, satır: {}, sütun: {}", 
-                                token.token_type, token.line, token.column))
+                TokenType::EOF => Err(self.unexpected_eof()),
+                
+                _ => Err(ParseError::ExpectedExpression {
+                    span: token.span,
+                    found: token.token_type.clone(),
+                    expected: self.expected.clone(),
+                    context: self.context(),
+                })
             }
         } else {
-            Err("Beklenmeyen dosya sonu".to_string())
+            Err(self.unexpected_eof())
         }
     }
     
-    fn parse_call_expr(&mut self, identifier: Token) -> Result<AstNode, String> {
-        self.consume(TokenType::LeftParen)?;
-        
-        let mut arguments = Vec::new();
-        
-        if !self.check(TokenType::RightParen) {
-            loop {
-                let arg = self.parse_expression()?;
-                arguments.push(arg);
-                
-                if !self.check(TokenType::Comma) {
-                    break;
+    fn parse_call_expr(&mut self, identifier: Token) -> Result<AstNode, ParseError> {
+        let arguments = self.in_context(ParseContext::CallArguments, |parser| {
+            parser.consume(TokenType::LeftParen)?;
+            
+            let mut arguments = Vec::new();
+            
+            if !parser.check(TokenType::RightParen) {
+                loop {
+                    let start = parser.current_span();
+                    let arg = parser.parse_expression()
+                        .or_else(|err| parser.recover_list_item(err, start, TokenType::RightParen))?;
+                    arguments.push(arg);
+                    
+                    if parser.check(TokenType::Comma) {
+                        parser.advance();
+                    } else if !parser.missing_separator(TokenType::RightParen) {
+                        break;
+                    }
                 }
-                
-                self.advance();
             }
-        }
-        
-        self.consume(TokenType::RightParen)?;
+            
+            let recovered = arguments.last().is_some_and(AstNode::is_error);
+            parser.close_list(TokenType::RightParen, recovered)?;
+            Ok(arguments)
+        })?;
         
         let mut call_expr = AstNode::new(AstNodeType::CallExpr, Some(identifier.clone()));
+        call_expr.set_span(self.span_from(identifier.span));
         call_expr.set_value(identifier.lexeme);
         
         for arg in arguments {
@@ -0,0 +1,95 @@
+use crate::lexer::token::TokenType;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Associativity {
+    Left,
+    Right,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Operator {
+    pub token_type: TokenType,
+    pub symbol: &'static str,
+    pub precedence: u8,
+    pub associativity: Associativity,
+}
+
+pub const ASSIGNMENT: u8 = 1;
+pub const OR: u8 = 2;
+pub const AND: u8 = 3;
+pub const EQUALITY: u8 = 4;
+pub const COMPARISON: u8 = 5;
+pub const BIT_OR: u8 = 6;
+pub const BIT_XOR: u8 = 7;
+pub const BIT_AND: u8 = 8;
+pub const SHIFT: u8 = 9;
+pub const TERM: u8 = 10;
+pub const FACTOR: u8 = 11;
+pub const PREFIX: u8 = 12;
+pub const POWER: u8 = 13;
+
+const fn operator(token_type: TokenType, symbol: &'static str, precedence: u8, associativity: Associativity) -> Operator {
+    Operator { token_type, symbol, precedence, associativity }
+}
+
+pub const INFIX_OPERATORS: &[Operator] = &[
+    operator(TokenType::Caret, "^", POWER, Associativity::Right),
+    operator(TokenType::Asterisk, "*", FACTOR, Associativity::Left),
+    operator(TokenType::Slash, "/", FACTOR, Associativity::Left),
+    operator(TokenType::Percent, "%", FACTOR, Associativity::Left),
+    operator(TokenType::Plus, "+", TERM, Associativity::Left),
+    operator(TokenType::Minus, "-", TERM, Associativity::Left),
+    operator(TokenType::ShiftLeft, "<<", SHIFT, Associativity::Left),
+    operator(TokenType::ShiftRight, ">>", SHIFT, Associativity::Left),
+    operator(TokenType::BitAnd, "&", BIT_AND, Associativity::Left),
+    // `^` üs alma olduğundan XOR, Lua'daki gibi ikili `~` ile yazılır; tekli `~` bit tümleyenidir.
+    operator(TokenType::Tilde, "~", BIT_XOR, Associativity::Left),
+    operator(TokenType::BitOr, "|", BIT_OR, Associativity::Left),
+    operator(TokenType::Greater, ">", COMPARISON, Associativity::Left),
+    operator(TokenType::GreaterEq, ">=", COMPARISON, Associativity::Left),
+    operator(TokenType::Less, "<", COMPARISON, Associativity::Left),
+    operator(TokenType::LessEq, "<=", COMPARISON, Associativity::Left),
+    operator(TokenType::Equal, "==", EQUALITY, Associativity::Left),
+    operator(TokenType::NotEqual, "!=", EQUALITY, Associativity::Left),
+    operator(TokenType::And, "&&", AND, Associativity::Left),
+    operator(TokenType::Or, "||", OR, Associativity::Left),
+    operator(TokenType::Assign, "=", ASSIGNMENT, Associativity::Right),
+    operator(TokenType::PlusAssign, "+=", ASSIGNMENT, Associativity::Right),
+    operator(TokenType::MinusAssign, "-=", ASSIGNMENT, Associativity::Right),
+    operator(TokenType::MulAssign, "*=", ASSIGNMENT, Associativity::Right),
+    operator(TokenType::DivAssign, "/=", ASSIGNMENT, Associativity::Right),
+    operator(TokenType::BitAndAssign, "&=", ASSIGNMENT, Associativity::Right),
+    operator(TokenType::BitOrAssign, "|=", ASSIGNMENT, Associativity::Right),
+    operator(TokenType::XorAssign, "~=", ASSIGNMENT, Associativity::Right),
+    operator(TokenType::ShlAssign, "<<=", ASSIGNMENT, Associativity::Right),
+    operator(TokenType::ShrAssign, ">>=", ASSIGNMENT, Associativity::Right),
+];
+
+pub const PREFIX_OPERATORS: &[Operator] = &[
+    operator(TokenType::Minus, "-", PREFIX, Associativity::Right),
+    operator(TokenType::Not, "!", PREFIX, Associativity::Right),
+    operator(TokenType::Tilde, "~", PREFIX, Associativity::Right),
+];
+
+impl Operator {
+    pub fn binding_power(&self) -> (u8, u8) {
+        let power = self.precedence * 2;
+        
+        match self.associativity {
+            Associativity::Left => (power, power + 1),
+            Associativity::Right => (power + 1, power),
+        }
+    }
+    
+    pub fn is_assignment(&self) -> bool {
+        self.precedence == ASSIGNMENT
+    }
+}
+
+pub fn infix_operator(token_type: &TokenType) -> Option<&'static Operator> {
+    INFIX_OPERATORS.iter().find(|operator| &operator.token_type == token_type)
+}
+
+pub fn prefix_operator(token_type: &TokenType) -> Option<&'static Operator> {
+    PREFIX_OPERATORS.iter().find(|operator| &operator.token_type == token_type)
+}
@@ -0,0 +1,4 @@
+//! REPL modülü
+pub mod session;
+
+pub use session::{is_complete, Evaluation, Session};
@@ -0,0 +1,162 @@
+use crate::diagnostics::{Diagnostic, MessageId, Renderer, SourceFile};
+use crate::interpreter::interpreter::Flow;
+use crate::interpreter::{Interpreter, RuntimeError, Value};
+use crate::lexer::lexer::Lexer;
+use crate::lexer::token::TokenType;
+use crate::parser::ast::{AstNode, AstNodeType};
+use crate::parser::error::ParseError;
+use crate::parser::parser::Parser;
+use crate::semantics::analyzer::SemanticAnalyzer;
+use crate::semantics::error::SemanticError;
+use crate::semantics::types::Type;
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Evaluation {
+    pub value: Option<(Value, Type)>,
+    pub warnings: Vec<String>,
+}
+
+pub struct Session {
+    analyzer: SemanticAnalyzer,
+    interpreter: Interpreter,
+}
+
+impl Session {
+    pub fn new() -> Result<Self, String> {
+        let mut analyzer = SemanticAnalyzer::new();
+        analyzer.load_std_library()
+            .map_err(|err| MessageId::StdLibFailed.format(&[&err]))?;
+        
+        Ok(Session {
+            analyzer,
+            interpreter: Interpreter::new(),
+        })
+    }
+    
+    pub fn eval(&mut self, input: &str) -> Result<Evaluation, String> {
+        let (program, file) = parse_input(input)?;
+        let mut analyzer = self.analyzer.clone();
+        let interpreter = self.interpreter.clone();
+        
+        // Bir deyim başarısız olursa önceki deyimlerin etkileri de analizörle birlikte geri alınır.
+        match self.eval_program(&program, &file, &mut analyzer) {
+            Ok(evaluation) => {
+                self.analyzer = analyzer;
+                Ok(evaluation)
+            },
+            Err(err) => {
+                self.interpreter = interpreter;
+                Err(err)
+            },
+        }
+    }
+    
+    fn eval_program(&mut self, program: &AstNode, file: &SourceFile, analyzer: &mut SemanticAnalyzer) -> Result<Evaluation, String> {
+        let mut evaluation = Evaluation::default();
+        
+        for node in &program.children {
+            let (node_type, diagnostics) = analyzer.analyze_statement(node);
+            
+            let (warnings, errors): (Vec<SemanticError>, Vec<SemanticError>) = diagnostics.into_iter().partition(|e| e.is_warning());
+            if !errors.is_empty() {
+                return Err(render(&errors, file));
+            }
+            
+            evaluation.warnings.extend(warnings.iter().map(|w| render(std::slice::from_ref(w), file)));
+            evaluation.value = self.execute(node)?.map(|value| (value, node_type));
+        }
+        
+        Ok(evaluation)
+    }
+    
+    pub fn type_of(&self, input: &str) -> Result<Type, String> {
+        let (expression, file) = parse_expression(input)?;
+        let mut analyzer = self.analyzer.clone();
+        let (expression_type, diagnostics) = analyzer.analyze_statement(&expression);
+        
+        let errors: Vec<SemanticError> = diagnostics.into_iter().filter(|e| !e.is_warning()).collect();
+        if !errors.is_empty() {
+            return Err(render(&errors, &file));
+        }
+        
+        Ok(expression_type)
+    }
+    
+    pub fn ast(&self, input: &str) -> Result<AstNode, String> {
+        parse_expression(input).map(|(expression, _)| expression)
+    }
+    
+    pub fn symbols(&self) -> String {
+        self.analyzer.dump_symbol_table()
+    }
+    
+    fn execute(&mut self, node: &AstNode) -> Result<Option<Value>, String> {
+        let runtime_error = |err: RuntimeError| MessageId::RuntimeError.format(&[&err]);
+        
+        if node.node_type == AstNodeType::ExprStmt {
+            let value = match node.children.first() {
+                Some(expression) => self.interpreter.evaluate(expression).map_err(runtime_error)?,
+                None => Value::Void,
+            };
+            return Ok(if value == Value::Void { None } else { Some(value) });
+        }
+        
+        match self.interpreter.execute(node).map_err(runtime_error)? {
+            Flow::Normal => Ok(None),
+            _ => Err(MessageId::ControlFlowOutsideFunction.text().to_string()),
+        }
+    }
+}
+
+pub fn is_complete(input: &str) -> bool {
+    let mut depth = 0i32;
+    
+    for token in Lexer::new(input).tokenize() {
+        match token.token_type {
+            TokenType::LeftBrace | TokenType::LeftParen | TokenType::LeftBracket => depth += 1,
+            TokenType::RightBrace | TokenType::RightParen | TokenType::RightBracket => depth -= 1,
+            _ => {},
+        }
+    }
+    
+    depth <= 0
+}
+
+fn parse_input(input: &str) -> Result<(AstNode, SourceFile), String> {
+    let input = input.trim_end();
+    let source = if input.ends_with(';') || input.ends_with('}') {
+        input.to_string()
+    } else {
+        format!("{};", input)
+    };
+    
+    let file = SourceFile::new(0, "<repl>", source);
+    let mut lexer = Lexer::new(&file.source);
+    let tokens = lexer.tokenize();
+    let mut errors = lexer.errors().to_vec();
+    
+    match Parser::new(tokens).parse() {
+        Ok(program) if errors.is_empty() => Ok((program, file)),
+        result => {
+            errors.extend(result.err().unwrap_or_default().iter().map(ParseError::to_diagnostic));
+            Err(Renderer::default().render_all(&errors, &file).trim_end().to_string())
+        },
+    }
+}
+
+fn parse_expression(input: &str) -> Result<(AstNode, SourceFile), String> {
+    let input = input.trim().trim_end_matches(';');
+    let (mut program, file) = parse_input(input)?;
+    
+    match program.children.pop() {
+        Some(mut statement) if program.children.is_empty()
+            && statement.node_type == AstNodeType::ExprStmt
+            && statement.children.len() == 1 => Ok((statement.children.remove(0), file)),
+        _ => Err(MessageId::ReplNotAnExpression.format(&[&input])),
+    }
+}
+
+fn render(errors: &[SemanticError], file: &SourceFile) -> String {
+    let diagnostics: Vec<Diagnostic> = errors.iter().map(|error| error.to_diagnostic(file)).collect();
+    Renderer::default().render_all(&diagnostics, file).trim_end().to_string()
+}
@@ -1,9 +1,14 @@
+use crate::diagnostics::{Diagnostic, MessageId, Renderer, SourceFile};
+use crate::lexer::token::TokenType;
 use crate::parser::ast::{AstNode, AstNodeType};
 use crate::semantics::error::{SemanticError, SemanticErrorType};
-use crate::semantics::symbol_table::{Symbol, SymbolTable, SymbolKind, ScopeType};
+use crate::semantics::symbol_table::{Symbol, SymbolTable, SymbolKind, ScopeType, StructDefinition, StructField};
 use crate::semantics::types::Type;
 use std::path::Path;
 
+const SMALL_FUNCTION_STATEMENTS: usize = 3;
+const FUNCTION_ATTRIBUTES: [&str; 2] = ["inline", "noinline"];
+
 #[derive(Debug, Clone)]
 pub struct LoopInfo {
     pub description: String,
@@ -15,14 +20,22 @@ pub struct LoopInfo {
 impl LoopInfo {
     pub fn is_small_constant_range(&self) -> bool {
         if let (Some(start), Some(end), Some(step)) = (self.start_value, self.end_value, self.step_value) {
-            let range_size = (end - start) / step;
-            range_size >= 0 && range_size <= 4
+            let range_size = end.checked_sub(start).and_then(|size| size.checked_div(step));
+            range_size.is_some_and(|size| (0..=4).contains(&size))
         } else {
             false
         }
     }
 }
 
+#[derive(Debug, Clone)]
+pub struct SymbolReference {
+    pub line: usize,
+    pub column: usize,
+    pub symbol: Symbol,
+}
+
+#[derive(Clone)]
 pub struct SemanticAnalyzer {
     symbol_table: SymbolTable,
     current_function_return_type: Option<Type>,
@@ -32,6 +45,13 @@ pub struct SemanticAnalyzer {
     pub loop_infos: Vec<LoopInfo>,
     pub small_functions: Vec<String>,
     pub warnings: Vec<SemanticError>,
+    pub references: Vec<SymbolReference>,
+}
+
+impl Default for SemanticAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl SemanticAnalyzer {
@@ -45,6 +65,7 @@ impl SemanticAnalyzer {
             loop_infos: Vec::new(),
             small_functions: Vec::new(),
             warnings: Vec::new(),
+            references: Vec::new(),
         }
     }
     
@@ -60,7 +81,7 @@ impl SemanticAnalyzer {
     }
     
     fn check_unused_variables(&mut self) {
-        let unused_symbols = self.symbol_table.get_unused_symbols();
+        let unused_symbols: Vec<Symbol> = self.symbol_table.get_unused_symbols().into_iter().cloned().collect();
         
         for symbol in unused_symbols {
             if symbol.kind == SymbolKind::Function && symbol.name == "main" {
@@ -68,9 +89,8 @@ impl SemanticAnalyzer {
             }
             
             self.add_warning(SemanticError::with_position(
-                SemanticErrorType::Other,
-                format!("'{}' {} tanımlandı fakat hiç kullanılmadı", 
-                       symbol.name, symbol.kind),
+                SemanticErrorType::UnusedSymbol,
+                MessageId::UnusedSymbol.format(&[&symbol.name, &symbol.kind]),
                 symbol.line,
                 symbol.column,
             ));
@@ -78,12 +98,32 @@ impl SemanticAnalyzer {
     }
     
     fn check_uninitialized_variables(&mut self) {
-        let uninitialized_symbols = self.symbol_table.get_uninitialized_symbols();
+        let uninitialized_symbols: Vec<Symbol> = self.symbol_table.get_uninitialized_symbols().into_iter().cloned().collect();
         
         for symbol in uninitialized_symbols {
             self.add_error(SemanticError::with_position(
-                SemanticErrorType::Other,
-                format!("'{}' değişkeni kullanılmadan önce başlatılmamış", symbol.name),
+                SemanticErrorType::UninitializedVariable,
+                MessageId::UninitializedVariable.format(&[&symbol.name]),
+                symbol.line,
+                symbol.column,
+            ));
+        }
+    }
+    
+    fn exit_scope(&mut self) {
+        let Some(scope) = self.symbol_table.exit_scope() else {
+            return;
+        };
+        
+        let mut unused: Vec<&Symbol> = scope.get_symbols_by_kind(SymbolKind::Variable).into_iter()
+            .filter(|symbol| !symbol.is_used && !symbol.name.starts_with('_'))
+            .collect();
+        unused.sort_by_key(|symbol| (symbol.line, symbol.column));
+        
+        for symbol in unused {
+            self.add_warning(SemanticError::with_position(
+                SemanticErrorType::UnusedVariable,
+                MessageId::UnusedVariable.format(&[&symbol.name]),
                 symbol.line,
                 symbol.column,
             ));
@@ -94,7 +134,18 @@ impl SemanticAnalyzer {
         self.errors.push(error);
     }
     
-    fn add_warning(&mut self, warning: SemanticError) {
+    fn add_reference(&mut self, node: &AstNode, symbol: &Symbol) {
+        if let Some(token) = &node.token {
+            self.references.push(SymbolReference {
+                line: token.line,
+                column: token.column,
+                symbol: symbol.clone(),
+            });
+        }
+    }
+    
+    fn add_warning(&mut self, mut warning: SemanticError) {
+        warning.is_warning = true;
         self.errors.push(warning);
     }
     
@@ -121,17 +172,11 @@ impl SemanticAnalyzer {
             AstNodeType::ImplDecl => self.visit_impl_declaration(node),
             AstNodeType::MemberExpr => self.visit_member_expr(node),
             AstNodeType::IndexExpr => self.visit_index_expr(node),
+            AstNodeType::RangeExpr => self.visit_range_expr(node),
             AstNodeType::BreakStmt => self.visit_break_stmt(node),
             AstNodeType::ContinueStmt => self.visit_continue_stmt(node),
             AstNodeType::ModDecl => self.visit_module_declaration(node),
-            _ => {
-                self.add_error(SemanticError::new(
-                    SemanticErrorType::Other,
-                    format!("Desteklenmeyen AST düğüm tipi: {:?}", node.node_type),
-                    node.token.clone(),
-                ));
-                Type::Error
-            }
+            AstNodeType::Error => Type::Error,
         }
     }
     
@@ -144,8 +189,8 @@ impl SemanticAnalyzer {
             Ok(symbol) => {
                 if symbol.kind != SymbolKind::Function {
                     self.add_error(SemanticError::new(
-                        SemanticErrorType::Other,
-                        "'main' bir fonksiyon olmalıdır".to_string(),
+                        SemanticErrorType::MainNotFunction,
+                        MessageId::MainNotFunction.text().to_string(),
                         None,
                     ));
                 }
@@ -154,10 +199,12 @@ impl SemanticAnalyzer {
                     self.add_error(err);
                 }
             },
+            // Okunamayan bir bildirim main olabilir; eksik main hatası bu durumda yanıltıcı olur.
+            Err(_) if node.children.iter().any(AstNode::is_error) => {},
             Err(_) => {
                 self.add_error(SemanticError::new(
-                    SemanticErrorType::Other,
-                    "'main' fonksiyonu tanımlanmamış".to_string(),
+                    SemanticErrorType::MissingMain,
+                    MessageId::MissingMain.text().to_string(),
                     None,
                 ));
             }
@@ -168,7 +215,7 @@ impl SemanticAnalyzer {
     
     fn visit_var_declaration(&mut self, node: &AstNode) -> Type {
         let var_name = node.value.as_ref().expect("Değişken adı bulunamadı");
-        let is_mutable = node.metadata.as_ref().map_or(false, |m| m == "mutable");
+        let is_mutable = node.metadata.as_ref().is_some_and(|m| m == "mutable");
         
         let mut var_type = Type::Unknown;
         let mut init_value_type = Type::Unknown;
@@ -190,35 +237,36 @@ impl SemanticAnalyzer {
             var_type = init_value_type.clone();
         }
         
-        if var_type == Type::Unknown {
+        if var_type == Type::Unknown && init_value_type == Type::Error {
+            var_type = Type::Error;
+        } else if var_type == Type::Unknown {
             self.add_error(SemanticError::new(
-                SemanticErrorType::Other,
-                format!("'{}' değişkeninin tipi belirtilmemiş ve çıkarsanamadı", var_name),
+                SemanticErrorType::UninferableType,
+                MessageId::UninferableType.format(&[&var_name]),
                 node.token.clone(),
             ));
             var_type = Type::Error;
         }
         
-        if is_initialized && init_value_type != Type::Error && var_type != Type::Error {
-            if let Err(err) = var_type.can_assign_from(&init_value_type) {
-                self.add_error(SemanticError::with_position(
-                    SemanticErrorType::TypeMismatch,
-                    format!("'{}' değişkeni için tip uyuşmazlığı: {}", var_name, err.message),
-                    node.token.as_ref().map_or(0, |t| t.line),
-                    node.token.as_ref().map_or(0, |t| t.column),
-                ));
-            }
+        if is_initialized && init_value_type != Type::Error && var_type != Type::Error
+            && let Err(err) = var_type.can_assign_from(&init_value_type) {
+            self.add_error(SemanticError::with_position(
+                SemanticErrorType::TypeMismatch,
+                MessageId::VariableTypeMismatch.format(&[&var_name, &err.message]),
+                node.token.as_ref().map_or(0, |t| t.line),
+                node.token.as_ref().map_or(0, |t| t.column),
+            ));
         }
         
         let line = node.token.as_ref().map_or(0, |t| t.line);
         let column = node.token.as_ref().map_or(0, |t| t.column);
         
         if let Err(err) = self.symbol_table.define_variable(
-            var_name.clone(), 
-            var_type.clone(), 
-            is_mutable, 
+            var_name.clone(),
+            var_type.clone(),
+            is_mutable,
             is_initialized,
-            line, 
+            line,
             column
         ) {
             self.add_error(err);
@@ -249,8 +297,8 @@ impl SemanticAnalyzer {
                     
                     if param_type == Type::Unknown {
                         self.add_error(SemanticError::new(
-                            SemanticErrorType::Other,
-                            format!("'{}' parametresinin tipi belirtilmemiş", param_name),
+                            SemanticErrorType::MissingParameterType,
+                            MessageId::MissingParameterType.format(&[&param_name]),
                             child.token.clone(),
                         ));
                         param_type = Type::Error;
@@ -277,10 +325,11 @@ impl SemanticAnalyzer {
                 AstNodeType::BlockStmt => {
                     body_node = Some(child);
                 },
+                AstNodeType::Error => {},
                 _ => {
                     self.add_error(SemanticError::new(
-                        SemanticErrorType::Other,
-                        format!("Beklenmeyen düğüm tipi: {:?}", child.node_type),
+                        SemanticErrorType::Internal,
+                        MessageId::UnexpectedNode.format(&[&format!("{:?}", child.node_type)]),
                         child.token.clone(),
                     ));
                 }
@@ -321,9 +370,15 @@ impl SemanticAnalyzer {
             
             self.current_function_return_type = prev_return_type;
             
-            self.symbol_table.exit_scope();
+            self.exit_scope();
+            
+            if is_small_function(body) && !self.small_functions.contains(func_name) {
+                self.small_functions.push(func_name.clone());
+            }
         }
         
+        self.process_attributes(node);
+        
         Type::Function(
             param_symbols.iter().map(|p| p.symbol_type.clone()).collect(),
             Box::new(return_type)
@@ -344,8 +399,8 @@ impl SemanticAnalyzer {
         
         if param_type == Type::Unknown {
             self.add_error(SemanticError::new(
-                SemanticErrorType::Other,
-                format!("'{}' parametresinin tipi belirtilmemiş", param_name),
+                SemanticErrorType::MissingParameterType,
+                MessageId::MissingParameterType.format(&[&param_name]),
                 node.token.clone(),
             ));
             param_type = Type::Error;
@@ -361,10 +416,14 @@ impl SemanticAnalyzer {
         if let Type::Struct(struct_name) = &result_type {
             match self.symbol_table.resolve_type(struct_name) {
                 Ok(_) => {},
+                Err(err) if err.error_type == SemanticErrorType::NotAType => {
+                    self.add_error(located(err, node));
+                    return Type::Error;
+                },
                 Err(_) => {
                     self.add_error(SemanticError::new(
-                        SemanticErrorType::Other,
-                        format!("'{}' tipi tanımlanmamış", struct_name),
+                        SemanticErrorType::UndefinedType,
+                        MessageId::UndefinedType.format(&[&struct_name]),
                         node.token.clone(),
                     ));
                     return Type::Error;
@@ -384,7 +443,7 @@ impl SemanticAnalyzer {
             last_type = self.visit_node(child);
         }
         
-        self.symbol_table.exit_scope();
+        self.exit_scope();
         
         last_type
     }
@@ -392,8 +451,8 @@ impl SemanticAnalyzer {
     fn visit_if_stmt(&mut self, node: &AstNode) -> Type {
         if node.children.len() < 2 {
             self.add_error(SemanticError::new(
-                SemanticErrorType::Other,
-                "If ifadesi eksik".to_string(),
+                SemanticErrorType::Internal,
+                MessageId::IncompleteIf.text().to_string(),
                 node.token.clone(),
             ));
             return Type::Error;
@@ -403,20 +462,20 @@ impl SemanticAnalyzer {
         
         if condition_type != Type::Bool && condition_type != Type::Error {
             self.add_error(SemanticError::new(
-                SemanticErrorType::TypeMismatch,
-                format!("If koşulu boolean tipinde olmalı, bulunan: {}", condition_type),
+                SemanticErrorType::NonBoolCondition,
+                MessageId::IfConditionNotBool.format(&[&condition_type]),
                 node.token.clone(),
             ));
         }
         
         self.symbol_table.enter_scope(ScopeType::If);
         self.visit_node(&node.children[1]);
-        self.symbol_table.exit_scope();
+        self.exit_scope();
         
         if node.children.len() > 2 {
             self.symbol_table.enter_scope(ScopeType::If);
             self.visit_node(&node.children[2]);
-            self.symbol_table.exit_scope();
+            self.exit_scope();
         }
         
         Type::Void
@@ -425,8 +484,8 @@ impl SemanticAnalyzer {
     fn visit_while_stmt(&mut self, node: &AstNode) -> Type {
         if node.children.len() < 2 {
             self.add_error(SemanticError::new(
-                SemanticErrorType::Other,
-                "While ifadesi eksik".to_string(),
+                SemanticErrorType::Internal,
+                MessageId::IncompleteWhile.text().to_string(),
                 node.token.clone(),
             ));
             return Type::Error;
@@ -436,8 +495,8 @@ impl SemanticAnalyzer {
         
         if condition_type != Type::Bool && condition_type != Type::Error {
             self.add_error(SemanticError::new(
-                SemanticErrorType::TypeMismatch,
-                format!("While koşulu boolean tipinde olmalı, bulunan: {}", condition_type),
+                SemanticErrorType::NonBoolCondition,
+                MessageId::WhileConditionNotBool.format(&[&condition_type]),
                 node.token.clone(),
             ));
         }
@@ -447,7 +506,7 @@ impl SemanticAnalyzer {
         
         self.symbol_table.enter_scope(ScopeType::Loop);
         self.visit_node(&node.children[1]);
-        self.symbol_table.exit_scope();
+        self.exit_scope();
         
         self.in_loop = prev_in_loop;
         
@@ -457,8 +516,8 @@ impl SemanticAnalyzer {
     fn visit_for_stmt(&mut self, node: &AstNode) -> Type {
         if node.children.len() < 3 {
             self.add_error(SemanticError::new(
-                SemanticErrorType::Other,
-                "For ifadesi eksik".to_string(),
+                SemanticErrorType::Internal,
+                MessageId::IncompleteFor.text().to_string(),
                 node.token.clone(),
             ));
             return Type::Error;
@@ -469,16 +528,22 @@ impl SemanticAnalyzer {
         let iterator_var = &node.children[0];
         let var_name = iterator_var.value.as_ref().expect("Döngü değişkeni adı bulunamadı");
         
-        let range_type = self.visit_node(&node.children[1]);
+        let is_range = node.children[1].node_type == AstNodeType::RangeExpr;
+        let range_type = if is_range {
+            self.visit_for_range(var_name, &node.children[1])
+        } else {
+            self.visit_node(&node.children[1])
+        };
         
         let element_type = match &range_type {
+            Type::Int if is_range => Type::Int,
             Type::Array(elem_type, _) => *elem_type.clone(),
             Type::String => Type::String,
             _ => {
                 if range_type != Type::Error {
                     self.add_error(SemanticError::new(
-                        SemanticErrorType::TypeMismatch,
-                        format!("For döngüsünün '{}'  tipi üzerinde döngülenemez", range_type),
+                        SemanticErrorType::NotIterable,
+                        MessageId::NotIterable.format(&[&range_type]),
                         node.children[1].token.clone(),
                     ));
                 }
@@ -507,11 +572,61 @@ impl SemanticAnalyzer {
         
         self.in_loop = prev_in_loop;
         
-        self.symbol_table.exit_scope();
+        self.exit_scope();
         
         Type::Void
     }
     
+    fn visit_for_range(&mut self, var_name: &str, node: &AstNode) -> Type {
+        if node.children.len() < 2 {
+            self.add_error(SemanticError::new(
+                SemanticErrorType::Internal,
+                MessageId::IncompleteRange.text().to_string(),
+                node.token.clone(),
+            ));
+            return Type::Error;
+        }
+        
+        for bound in &node.children[..2] {
+            let bound_type = self.visit_node(bound);
+            if bound_type != Type::Int && bound_type != Type::Error {
+                self.add_error(SemanticError::new(
+                    SemanticErrorType::InvalidRangeBound,
+                    MessageId::RangeBoundNotInt.format(&[&bound_type]),
+                    bound.token.clone(),
+                ));
+                return Type::Error;
+            }
+        }
+        
+        let start_value = constant_int(&node.children[0]);
+        let end_value = constant_int(&node.children[1]);
+        let bound_text = |value: Option<i32>| value.map_or("?".to_string(), |v| v.to_string());
+        
+        self.loop_infos.push(LoopInfo {
+            description: MessageId::LoopDescription.format(&[
+                &var_name,
+                &bound_text(start_value),
+                &bound_text(end_value),
+                &node.line,
+            ]),
+            start_value,
+            end_value,
+            step_value: Some(1),
+        });
+        
+        Type::Int
+    }
+    
+    fn visit_range_expr(&mut self, node: &AstNode) -> Type {
+        self.add_error(SemanticError::new(
+            SemanticErrorType::RangeOutsideFor,
+            MessageId::RangeOutsideFor.text().to_string(),
+            node.token.clone(),
+        ));
+        Type::Error
+    }
+    
     fn visit_return_stmt(&mut self, node: &AstNode) -> Type {
         let mut return_value_type = Type::Void;
         
@@ -520,19 +635,18 @@ impl SemanticAnalyzer {
         }
         
         if let Some(expected_type) = &self.current_function_return_type {
-            if expected_type != &Type::Error && return_value_type != Type::Error {
-                if let Err(err) = expected_type.can_assign_from(&return_value_type) {
-                    self.add_error(SemanticError::new(
-                        SemanticErrorType::InvalidReturn,
-                        format!("Dönüş tipi uyuşmazlığı: {}", err.message),
-                        node.token.clone(),
-                    ));
-                }
+            if expected_type != &Type::Error && return_value_type != Type::Error
+                && let Err(err) = expected_type.can_assign_from(&return_value_type) {
+                self.add_error(SemanticError::new(
+                    SemanticErrorType::InvalidReturn,
+                    MessageId::ReturnTypeMismatch.format(&[&err.message]),
+                    node.token.clone(),
+                ));
             }
         } else {
             self.add_error(SemanticError::new(
-                SemanticErrorType::InvalidReturn,
-                "Return ifadesi yalnızca fonksiyon içinde kullanılabilir".to_string(),
+                SemanticErrorType::ReturnOutsideFunction,
+                MessageId::ReturnOutsideFunction.text().to_string(),
                 node.token.clone(),
             ));
         }
@@ -551,8 +665,8 @@ impl SemanticAnalyzer {
     fn visit_binary_expr(&mut self, node: &AstNode) -> Type {
         if node.children.len() < 2 {
             self.add_error(SemanticError::new(
-                SemanticErrorType::Other,
-                "Eksik ikili ifade".to_string(),
+                SemanticErrorType::Internal,
+                MessageId::IncompleteBinary.text().to_string(),
                 node.token.clone(),
             ));
             return Type::Error;
@@ -568,7 +682,7 @@ impl SemanticAnalyzer {
         let operator = node.value.as_ref().expect("Operatör bulunamadı");
         
         match operator.as_str() {
-            "+" | "-" | "*" | "/" | "%" => {
+            "+" | "-" | "*" | "/" | "%" | "^" | "&" | "|" | "~" | "<<" | ">>" => {
                 match left_type.check_arithmetic_compatible(&right_type, operator) {
                     Ok(result_type) => result_type,
                     Err(err) => {
@@ -589,9 +703,8 @@ impl SemanticAnalyzer {
             "&&" | "||" => {
                 if left_type != Type::Bool || right_type != Type::Bool {
                     self.add_error(SemanticError::new(
-                        SemanticErrorType::TypeMismatch,
-                        format!("Mantıksal operatör '{}' için boolean değerler gerekli, bulunan: {} ve {}", 
-                                operator, left_type, right_type),
+                        SemanticErrorType::InvalidOperands,
+                        MessageId::LogicalOperandsNotBool.format(&[&operator, &left_type, &right_type]),
                         node.token.clone(),
                     ));
                     Type::Error
@@ -599,16 +712,16 @@ impl SemanticAnalyzer {
                     Type::Bool
                 }
             },
-            "=" | "+=" | "-=" | "*=" | "/=" => {
+            "=" | "+=" | "-=" | "*=" | "/=" | "&=" | "|=" | "~=" | "<<=" | ">>=" => {
                 if let AstNodeType::IdentifierExpr = node.children[0].node_type {
                     let var_name = node.children[0].value.as_ref().expect("Değişken adı bulunamadı");
                     
-                    match self.symbol_table.resolve(var_name) {
+                    match self.symbol_table.resolve(var_name).cloned() {
                         Ok(symbol) => {
                             if !symbol.is_mutable {
                                 self.add_error(SemanticError::new(
-                                    SemanticErrorType::Other,
-                                    format!("'{}' değiştirilemez (mut değil)", var_name),
+                                    SemanticErrorType::ImmutableAssignment,
+                                    MessageId::ImmutableAssignment.format(&[&var_name]),
                                     node.token.clone(),
                                 ));
                             }
@@ -617,23 +730,17 @@ impl SemanticAnalyzer {
                                 if let Err(err) = symbol.symbol_type.can_assign_from(&right_type) {
                                     self.add_error(SemanticError::new(
                                         SemanticErrorType::TypeMismatch,
-                                        format!("Tip uyuşmazlığı: {}", err.message),
+                                        MessageId::TypeMismatch.format(&[&err.message]),
                                         node.token.clone(),
                                     ));
                                 }
                             } else {
-                                let op = match operator.as_str() {
-                                    "+=" => "+",
-                                    "-=" => "-",
-                                    "*=" => "*",
-                                    "/=" => "/",
-                                    _ => "?",
-                                };
+                                let op = &operator[..operator.len() - 1];
                                 
                                 if let Err(err) = symbol.symbol_type.check_arithmetic_compatible(&right_type, op) {
                                     self.add_error(SemanticError::new(
                                         SemanticErrorType::TypeMismatch,
-                                        format!("Bileşik atama için tip uyuşmazlığı: {}", err.message),
+                                        MessageId::CompoundAssignTypeMismatch.format(&[&err.message]),
                                         node.token.clone(),
                                     ));
                                 }
@@ -656,8 +763,8 @@ impl SemanticAnalyzer {
                     Type::Error
                 } else {
                     self.add_error(SemanticError::new(
-                        SemanticErrorType::Other,
-                        "Sol taraf atama için geçerli bir hedef değil".to_string(),
+                        SemanticErrorType::InvalidAssignmentTarget,
+                        MessageId::NotAssignable.text().to_string(),
                         node.token.clone(),
                     ));
                     Type::Error
@@ -665,8 +772,8 @@ impl SemanticAnalyzer {
             },
             _ => {
                 self.add_error(SemanticError::new(
-                    SemanticErrorType::Other,
-                    format!("Bilinmeyen operatör: {}", operator),
+                    SemanticErrorType::Internal,
+                    MessageId::UnknownOperator.format(&[&operator]),
                     node.token.clone(),
                 ));
                 Type::Error
@@ -677,8 +784,8 @@ impl SemanticAnalyzer {
     fn visit_unary_expr(&mut self, node: &AstNode) -> Type {
         if node.children.is_empty() {
             self.add_error(SemanticError::new(
-                SemanticErrorType::Other,
-                "Eksik tekli ifade".to_string(),
+                SemanticErrorType::Internal,
+                MessageId::IncompleteUnary.text().to_string(),
                 node.token.clone(),
             ));
             return Type::Error;
@@ -697,8 +804,8 @@ impl SemanticAnalyzer {
                     expr_type
                 } else {
                     self.add_error(SemanticError::new(
-                        SemanticErrorType::TypeMismatch,
-                        format!("Operatör '{}' tip {} için geçerli değil", operator, expr_type),
+                        SemanticErrorType::InvalidOperands,
+                        MessageId::InvalidUnaryOperand.format(&[&operator, &expr_type]),
                         node.token.clone(),
                     ));
                     Type::Error
@@ -709,8 +816,20 @@ impl SemanticAnalyzer {
                     Type::Bool
                 } else {
                     self.add_error(SemanticError::new(
-                        SemanticErrorType::TypeMismatch,
-                        format!("Operatör '{}' tip {} için geçerli değil, bool bekleniyor", operator, expr_type),
+                        SemanticErrorType::InvalidOperands,
+                        MessageId::InvalidUnaryOperandExpectedBool.format(&[&operator, &expr_type]),
+                        node.token.clone(),
+                    ));
+                    Type::Error
+                }
+            },
+            "~" => {
+                if expr_type == Type::Int {
+                    Type::Int
+                } else {
+                    self.add_error(SemanticError::new(
+                        SemanticErrorType::InvalidOperands,
+                        MessageId::InvalidUnaryOperandExpectedInt.format(&[&operator, &expr_type]),
                         node.token.clone(),
                     ));
                     Type::Error
@@ -718,8 +837,8 @@ impl SemanticAnalyzer {
             },
             _ => {
                 self.add_error(SemanticError::new(
-                    SemanticErrorType::Other,
-                    format!("Bilinmeyen tekli operatör: {}", operator),
+                    SemanticErrorType::Internal,
+                    MessageId::UnknownUnaryOperator.format(&[&operator]),
                     node.token.clone(),
                 ));
                 Type::Error
@@ -736,8 +855,8 @@ impl SemanticAnalyzer {
                 crate::lexer::token::TokenType::BoolLiteral => Type::Bool,
                 _ => {
                     self.add_error(SemanticError::new(
-                        SemanticErrorType::Other,
-                        format!("Desteklenmeyen literal tipi: {:?}", token.token_type),
+                        SemanticErrorType::Internal,
+                        MessageId::UnsupportedLiteral.format(&[&format!("{:?}", token.token_type)]),
                         Some(token.clone()),
                     ));
                     Type::Error
@@ -756,8 +875,8 @@ impl SemanticAnalyzer {
                 Type::String
             } else {
                 self.add_error(SemanticError::new(
-                    SemanticErrorType::Other,
-                    format!("Bilinmeyen literal: {}", value),
+                    SemanticErrorType::Internal,
+                    MessageId::UnknownLiteral.format(&[&value]),
                     node.token.clone(),
                 ));
                 Type::Error
@@ -768,16 +887,17 @@ impl SemanticAnalyzer {
     fn visit_identifier(&mut self, node: &AstNode) -> Type {
         let name = node.value.as_ref().expect("Tanımlayıcı adı bulunamadı");
         
-        match self.symbol_table.resolve(name) {
+        match self.symbol_table.resolve(name).cloned() {
             Ok(symbol) => {
                 if let Err(err) = self.symbol_table.mark_used(name) {
                     self.add_error(err);
                 }
+                self.add_reference(node, &symbol);
                 
                 if symbol.kind == SymbolKind::Variable && !symbol.is_initialized {
                     self.add_error(SemanticError::new(
-                        SemanticErrorType::Other,
-                        format!("'{}' değişkeni kullanımdan önce başlatılmamış", name),
+                        SemanticErrorType::UninitializedVariable,
+                        MessageId::UninitializedVariable.format(&[&name]),
                         node.token.clone(),
                     ));
                 }
@@ -785,7 +905,7 @@ impl SemanticAnalyzer {
                 symbol.symbol_type.clone()
             },
             Err(err) => {
-                self.add_error(err);
+                self.add_error(located(err, node));
                 Type::Error
             }
         }
@@ -794,11 +914,12 @@ impl SemanticAnalyzer {
     fn visit_call_expr(&mut self, node: &AstNode) -> Type {
         let func_name = node.value.as_ref().expect("Fonksiyon adı bulunamadı");
         
-        match self.symbol_table.resolve(func_name) {
+        match self.symbol_table.resolve(func_name).cloned() {
             Ok(symbol) => {
                 if let Err(err) = self.symbol_table.mark_used(func_name) {
                     self.add_error(err);
                 }
+                self.add_reference(node, &symbol);
                 
                 if let Type::Function(param_types, return_type) = &symbol.symbol_type {
                     let mut arg_types = Vec::new();
@@ -809,38 +930,39 @@ impl SemanticAnalyzer {
                     
                     if arg_types.len() != param_types.len() {
                         self.add_error(SemanticError::new(
-                            SemanticErrorType::Other,
-                            format!("Fonksiyon '{}' {} argüman alır, {} verilmiş", 
-                                   func_name, param_types.len(), arg_types.len()),
+                            SemanticErrorType::ArgumentCountMismatch,
+                            MessageId::ArgumentCountMismatch.format(&[&func_name, &param_types.len(), &arg_types.len()]),
                             node.token.clone(),
                         ));
                         return (**return_type).clone();
                     }
                     
                     for (i, (arg_type, param_type)) in arg_types.iter().zip(param_types.iter()).enumerate() {
-                        if arg_type != &Type::Error && param_type != &Type::Error {
-                            if let Err(err) = param_type.can_assign_from(arg_type) {
-                                self.add_error(SemanticError::new(
-                                    SemanticErrorType::TypeMismatch,
-                                    format!("Argüman {} için tip uyuşmazlığı: {}", i+1, err.message),
-                                    node.children[i].token.clone(),
-                                ));
-                            }
+                        if arg_type != &Type::Error && param_type != &Type::Error
+                            && let Err(err) = param_type.can_assign_from(arg_type) {
+                            self.add_error(SemanticError::new(
+                                SemanticErrorType::ArgumentTypeMismatch,
+                                MessageId::ArgumentTypeMismatch.format(&[&(i + 1), &err.message]),
+                                node.children[i].token.clone(),
+                            ));
                         }
                     }
                     
                     (**return_type).clone()
                 } else {
                     self.add_error(SemanticError::new(
-                        SemanticErrorType::Other,
-                        format!("'{}' bir fonksiyon değil", func_name),
+                        SemanticErrorType::NotAFunction,
+                        MessageId::NotAFunction.format(&[&func_name]),
                         node.token.clone(),
                     ));
                     Type::Error
                 }
             },
-            Err(err) => {
-                self.add_error(err);
+            Err(mut err) => {
+                err.error_type = SemanticErrorType::UndefinedFunction;
+                err.help = self.symbol_table.suggest(func_name, Some(SymbolKind::Function))
+                    .map(|candidate| MessageId::DidYouMean.format(&[&candidate]));
+                self.add_error(located(err, node));
                 Type::Error
             }
         }
@@ -849,8 +971,8 @@ impl SemanticAnalyzer {
     fn visit_group_expr(&mut self, node: &AstNode) -> Type {
         if node.children.is_empty() {
             self.add_error(SemanticError::new(
-                SemanticErrorType::Other,
-                "Boş grup ifadesi".to_string(),
+                SemanticErrorType::Internal,
+                MessageId::EmptyGroup.text().to_string(),
                 node.token.clone(),
             ));
             return Type::Error;
@@ -884,13 +1006,17 @@ impl SemanticAnalyzer {
         
         self.symbol_table.enter_scope(ScopeType::Struct);
         
+        let mut fields = Vec::new();
         for field in &node.children {
             if field.node_type == AstNodeType::VarDecl {
-                self.visit_node(field);
+                let field_type = self.visit_node(field);
+                if let Some(field_name) = &field.value {
+                    fields.push(StructField { name: field_name.clone(), type_info: field_type });
+                }
             } else {
                 self.add_error(SemanticError::new(
-                    SemanticErrorType::Other,
-                    format!("Struct içinde beklenmeyen düğüm tipi: {:?}", field.node_type),
+                    SemanticErrorType::Internal,
+                    MessageId::UnexpectedStructNode.format(&[&format!("{:?}", field.node_type)]),
                     field.token.clone(),
                 ));
             }
@@ -898,6 +1024,8 @@ impl SemanticAnalyzer {
         
         self.symbol_table.exit_scope();
         
+        self.symbol_table.define_struct(StructDefinition { name: struct_name.clone(), fields });
+        
         struct_type
     }
     
@@ -913,8 +1041,8 @@ impl SemanticAnalyzer {
                         self.visit_node(method);
                     } else {
                         self.add_error(SemanticError::new(
-                            SemanticErrorType::Other,
-                            format!("Impl içinde beklenmeyen düğüm tipi: {:?}", method.node_type),
+                            SemanticErrorType::Internal,
+                            MessageId::UnexpectedImplNode.format(&[&format!("{:?}", method.node_type)]),
                             method.token.clone(),
                         ));
                     }
@@ -924,10 +1052,10 @@ impl SemanticAnalyzer {
                 
                 Type::Void
             },
-            Err(err) => {
+            Err(_) => {
                 self.add_error(SemanticError::new(
-                    SemanticErrorType::Other,
-                    format!("'{}' struct'ı tanımlı değil, impl yapılamaz", struct_name),
+                    SemanticErrorType::UndefinedType,
+                    MessageId::ImplForUndefinedStruct.format(&[&struct_name]),
                     node.token.clone(),
                 ));
                 Type::Error
@@ -936,10 +1064,10 @@ impl SemanticAnalyzer {
     }
     
     fn visit_member_expr(&mut self, node: &AstNode) -> Type {
-        if node.children.len() < 1 {
+        if node.children.is_empty() {
             self.add_error(SemanticError::new(
-                SemanticErrorType::Other,
-                "Üye erişimi ifadesi eksik".to_string(),
+                SemanticErrorType::Internal,
+                MessageId::IncompleteMemberAccess.text().to_string(),
                 node.token.clone(),
             ));
             return Type::Error;
@@ -949,12 +1077,30 @@ impl SemanticAnalyzer {
         
         let member_name = node.value.as_ref().expect("Üye adı bulunamadı");
         
-        if let Type::Struct(struct_name) = struct_expr_type {
-            Type::Int
+        if let Type::Struct(struct_name) = &struct_expr_type {
+            if self.symbol_table.get_struct_def(struct_name).is_none() {
+                return Type::Int;
+            }
+            
+            match self.symbol_table.get_field_type(struct_name, member_name) {
+                Some(field_type) => field_type,
+                None => {
+                    let mut error = SemanticError::new(
+                        SemanticErrorType::UnknownField,
+                        MessageId::UnknownField.format(&[struct_name, member_name]),
+                        node.token.clone(),
+                    );
+                    if let Some(candidate) = self.symbol_table.suggest_field(struct_name, member_name) {
+                        error = error.with_help(MessageId::DidYouMean.format(&[&candidate]));
+                    }
+                    self.add_error(error);
+                    Type::Error
+                }
+            }
         } else {
             self.add_error(SemanticError::new(
-                SemanticErrorType::TypeMismatch,
-                format!("'.' operatörü struct tipi beklerken '{}' tipi bulundu", struct_expr_type),
+                SemanticErrorType::InvalidMemberAccess,
+                MessageId::MemberAccessOnNonStruct.format(&[&struct_expr_type]),
                 node.token.clone(),
             ));
             Type::Error
@@ -964,8 +1110,8 @@ impl SemanticAnalyzer {
     fn visit_index_expr(&mut self, node: &AstNode) -> Type {
         if node.children.len() < 2 {
             self.add_error(SemanticError::new(
-                SemanticErrorType::Other,
-                "Dizin erişimi ifadesi eksik".to_string(),
+                SemanticErrorType::Internal,
+                MessageId::IncompleteIndex.text().to_string(),
                 node.token.clone(),
             ));
             return Type::Error;
@@ -977,8 +1123,8 @@ impl SemanticAnalyzer {
         
         if index_expr_type != Type::Int && index_expr_type != Type::Error {
             self.add_error(SemanticError::new(
-                SemanticErrorType::TypeMismatch,
-                format!("Dizin ifadesi integer tipinde olmalı, bulunan: {}", index_expr_type),
+                SemanticErrorType::InvalidIndex,
+                MessageId::IndexNotInt.format(&[&index_expr_type]),
                 node.children[1].token.clone(),
             ));
         }
@@ -989,8 +1135,8 @@ impl SemanticAnalyzer {
             Type::String
         } else if array_expr_type != Type::Error {
             self.add_error(SemanticError::new(
-                SemanticErrorType::TypeMismatch,
-                format!("'[]' operatörü dizi veya string tipi beklerken '{}' tipi bulundu", array_expr_type),
+                SemanticErrorType::NotIndexable,
+                MessageId::NotIndexable.format(&[&array_expr_type]),
                 node.token.clone(),
             ));
             Type::Error
@@ -1002,8 +1148,8 @@ impl SemanticAnalyzer {
     fn visit_break_stmt(&mut self, node: &AstNode) -> Type {
         if !self.in_loop {
             self.add_error(SemanticError::new(
-                SemanticErrorType::Other,
-                "Break ifadesi sadece döngü içinde kullanılabilir".to_string(),
+                SemanticErrorType::BreakOutsideLoop,
+                MessageId::BreakOutsideLoop.text().to_string(),
                 node.token.clone(),
             ));
         }
@@ -1014,8 +1160,8 @@ impl SemanticAnalyzer {
     fn visit_continue_stmt(&mut self, node: &AstNode) -> Type {
         if !self.in_loop {
             self.add_error(SemanticError::new(
-                SemanticErrorType::Other,
-                "Continue ifadesi sadece döngü içinde kullanılabilir".to_string(),
+                SemanticErrorType::ContinueOutsideLoop,
+                MessageId::ContinueOutsideLoop.text().to_string(),
                 node.token.clone(),
             ));
         }
@@ -1064,7 +1210,7 @@ impl SemanticAnalyzer {
     pub fn get_symbol_table(&self) -> &SymbolTable {
         &self.symbol_table
     }
-
+    
     pub fn reset(&mut self) {
         self.symbol_table = SymbolTable::new();
         self.current_function_return_type = None;
@@ -1112,21 +1258,28 @@ impl SemanticAnalyzer {
         Ok(())
     }
     
-    pub fn import_module(&mut self, module_name: &str, module_path: &Path) -> Result<(), SemanticError> {
+    pub fn import_module(&mut self, _module_name: &str, _module_path: &Path) -> Result<(), SemanticError> {
         self.symbol_table.enter_scope(ScopeType::Module);
         self.symbol_table.exit_scope();
         
         Ok(())
     }
     
+    pub fn analyze_statement(&mut self, node: &AstNode) -> (Type, Vec<SemanticError>) {
+        self.errors.clear();
+        let node_type = self.visit_node(node);
+        
+        (node_type, self.errors.clone())
+    }
+    
     pub fn analyze_with_reports(&mut self, ast: &AstNode) -> (bool, Vec<SemanticError>) {
         let errors = self.analyze(ast);
         let success = errors.iter().all(|e| e.is_warning());
         
         (success, errors)
     }
-
-    pub fn evaluate_constant_expressions(&mut self, ast: &AstNode) -> Result<(), Vec<SemanticError>> {
+    
+    pub fn evaluate_constant_expressions(&mut self, _ast: &AstNode) -> Result<(), Vec<SemanticError>> {
         if self.errors.is_empty() {
             Ok(())
         } else {
@@ -1295,34 +1448,20 @@ impl SemanticAnalyzer {
         Ok(())
     }
     
-    pub fn post_analysis_optimization(&mut self, ast: &mut AstNode) {
+    pub fn post_analysis_optimization(&mut self, _ast: &mut AstNode) {
     }
     
-    pub fn format_diagnostics(&self) -> String {
-        let mut result = String::new();
-        
-        for (index, error) in self.errors.iter().enumerate() {
-            let error_type = if error.is_warning() { "UYARI" } else { "HATA" };
-            
-            result.push_str(&format!(
-                "[{}] {} (satır {}, sütun {}): {}\n",
-                index + 1,
-                error_type,
-                error.line,
-                error.column,
-                error.message
-            ));
-        }
-        
-        result
+    pub fn format_diagnostics(&self, file: &SourceFile, renderer: &Renderer) -> String {
+        let diagnostics: Vec<Diagnostic> = self.errors.iter().map(|error| error.to_diagnostic(file)).collect();
+        renderer.render_all(&diagnostics, file)
     }
     
     pub fn dump_symbol_table(&self) -> String {
-        format!("{:?}", self.symbol_table)
+        self.symbol_table.display_scopes()
     }
     
-    pub fn process_imports(&mut self, ast: &AstNode, module_paths: &[&Path]) -> Result<(), Vec<SemanticError>> {
-        let mut import_errors = Vec::new();
+    pub fn process_imports(&mut self, _ast: &AstNode, _module_paths: &[&Path]) -> Result<(), Vec<SemanticError>> {
+        let import_errors = Vec::new();
         
         if import_errors.is_empty() {
             Ok(())
@@ -1330,14 +1469,14 @@ impl SemanticAnalyzer {
             Err(import_errors)
         }
     }
-
+    
     pub fn check_type_compatibility(&mut self, expr: &AstNode, expected_type: &Type, context: &str) -> Type {
         let expr_type = self.visit_node(expr);
         
         if !self.is_compatible(&expr_type, expected_type) {
             self.errors.push(SemanticError::new(
                 SemanticErrorType::TypeMismatch,
-                format!("{} için beklenmeyen tip: {} (beklenen: {})", context, expr_type, expected_type),
+                MessageId::UnexpectedType.format(&[&context, &expr_type, &expected_type]),
                 expr.token.clone(),
             ));
         }
@@ -1384,7 +1523,7 @@ impl SemanticAnalyzer {
     
     pub fn check_accessibility(&mut self, _symbol: &Symbol, _usage_scope: &str, _node: &AstNode) {
     }
-
+    
     pub fn resolve_generic_type(&mut self, generic_type: &Type, concrete_types: &[Type]) -> Type {
         match generic_type {
             Type::TypeParameter(name, _) => {
@@ -1397,7 +1536,7 @@ impl SemanticAnalyzer {
             },
             Type::Array(elem_type, size) => {
                 let resolved_elem = self.resolve_generic_type(elem_type, concrete_types);
-                Type::Array(Box::new(resolved_elem), size.clone())
+                Type::Array(Box::new(resolved_elem), *size)
             },
             Type::Optional(inner_type) => {
                 let resolved_inner = self.resolve_generic_type(inner_type, concrete_types);
@@ -1414,23 +1553,20 @@ impl SemanticAnalyzer {
         }
     }
     
-    pub fn check_recursive_types(&mut self, type_name: &str, visited: &mut Vec<String>) -> bool {
+    pub fn check_recursive_types(&self, type_name: &str, visited: &mut Vec<String>) -> bool {
         if visited.contains(&type_name.to_string()) {
             return true;
         }
         
         visited.push(type_name.to_string());
         
-        if let Some(symbol) = self.symbol_table.lookup(type_name) {
-            if let Type::Struct(struct_name) = &symbol.type_info() {
-                if let Some(struct_def) = self.symbol_table.get_struct_def(struct_name) {
-                    for field in &struct_def.fields {
-                        if let Type::Struct(field_type_name) = &field.type_info {
-                            if self.check_recursive_types(field_type_name, visited) {
-                                return true;
-                            }
-                        }
-                    }
+        if let Some(symbol) = self.symbol_table.lookup(type_name)
+            && let Type::Struct(struct_name) = &symbol.type_info()
+            && let Some(struct_def) = self.symbol_table.get_struct_def(struct_name) {
+            for field in &struct_def.fields {
+                if let Type::Struct(field_type_name) = &field.type_info
+                    && self.check_recursive_types(field_type_name, visited) {
+                    return true;
                 }
             }
         }
@@ -1456,34 +1592,34 @@ impl SemanticAnalyzer {
         
         for (name, line, column) in unused_symbols {
             self.errors.push(SemanticError::with_position(
-                SemanticErrorType::Other,
-                format!("Kullanılmayan değişken: {}", name),
+                SemanticErrorType::UnusedVariable,
+                MessageId::UnusedVariable.format(&[&name]),
                 line,
                 column,
             ));
         }
     }
-
+    
     pub fn generate_optimization_hints(&self) -> Vec<String> {
         let mut hints = Vec::new();
         
         for expr in &self.constant_expressions {
-            hints.push(format!("Sabit ifade: {} - Derleme zamanında hesaplanabilir", expr));
+            hints.push(MessageId::HintConstantExpression.format(&[&expr]));
         }
         
         for loop_info in &self.loop_infos {
             if loop_info.is_small_constant_range() {
-                hints.push(format!("Döngü açılabilir (loop unrolling): {}", loop_info.description));
+                hints.push(MessageId::HintUnrollableLoop.format(&[&loop_info.description]));
             }
         }
         
         for func in &self.small_functions {
-            hints.push(format!("İnline edilebilir fonksiyon: {}", func));
+            hints.push(MessageId::HintInlinableFunction.format(&[&func]));
         }
         
         hints
     }
-
+    
     pub fn process_modules(&mut self, node: &AstNode) {
         match &node.node_type {
             AstNodeType::ModDecl => {
@@ -1491,8 +1627,8 @@ impl SemanticAnalyzer {
                 
                 if !self.module_exists(module_name) {
                     self.add_error(SemanticError::new(
-                        SemanticErrorType::Other,
-                        format!("Module not found: {}", module_name),
+                        SemanticErrorType::ModuleNotFound,
+                        MessageId::ModuleNotFound.format(&[&module_name]),
                         node.token.clone(),
                     ));
                 }
@@ -1505,45 +1641,47 @@ impl SemanticAnalyzer {
         }
     }
     
-    pub fn process_operator_overloading(&mut self, node: &AstNode) {
+    pub fn process_operator_overloading(&mut self, _node: &AstNode) {
     }
     
     pub fn process_attributes(&mut self, node: &AstNode) {
+        let attributes = node.attributes();
+        
+        for attribute in &attributes {
+            if !FUNCTION_ATTRIBUTES.contains(attribute) {
+                self.add_error(SemanticError::new(
+                    SemanticErrorType::UnknownAttribute,
+                    MessageId::UnknownAttribute.format(&[&attribute]),
+                    node.token.clone(),
+                ));
+            }
+        }
+        
+        if attributes.contains(&"inline") && attributes.contains(&"noinline") {
+            self.add_error(SemanticError::new(
+                SemanticErrorType::ConflictingAttributes,
+                MessageId::ConflictingInlineAttributes.text().to_string(),
+                node.token.clone(),
+            ));
+        }
     }
     
-    pub fn analyze_concurrency(&mut self, node: &AstNode) {
+    pub fn analyze_concurrency(&mut self, _node: &AstNode) {
     }
-
-    pub fn analyze_generics(&mut self, node: &AstNode) {
+    
+    pub fn analyze_generics(&mut self, _node: &AstNode) {
     }
     
-    pub fn analyze_pattern_matching(&mut self, node: &AstNode) -> Type {
+    pub fn analyze_pattern_matching(&mut self, _node: &AstNode) -> Type {
         Type::Void
     }
-
-    pub fn infer_type(&mut self, node: &AstNode, context_type: Option<&Type>) -> Type {
-        self.visit_node(node)
-    }
     
-    pub fn visit_expression_with_context(&mut self, node: &AstNode, context_type: Option<&Type>) -> Type {
+    pub fn infer_type(&mut self, node: &AstNode, _context_type: Option<&Type>) -> Type {
         self.visit_node(node)
     }
     
-    fn validate_generic_constraint(&mut self, type_arg: &str, type_params: &[String], node: &AstNode) {
-    }
-
-    fn get_literal_type(&self, value: &str) -> Type {
-        if value.parse::<i32>().is_ok() {
-            Type::Int
-        } else if value.parse::<f64>().is_ok() {
-            Type::Float
-        } else if value == "true" || value == "false" {
-            Type::Bool
-        } else if value.starts_with('"') && value.ends_with('"') {
-            Type::String
-        } else {
-            Type::Error
-        }
+    pub fn visit_expression_with_context(&mut self, node: &AstNode, _context_type: Option<&Type>) -> Type {
+        self.visit_node(node)
     }
     
     pub fn visit_expression(&mut self, node: &AstNode) -> Type {
@@ -1558,41 +1696,18 @@ impl SemanticAnalyzer {
             AstNodeType::IndexExpr => self.visit_index_expr(node),
             _ => {
                 self.add_error(SemanticError::new(
-                    SemanticErrorType::Other,
-                    format!("Beklenmeyen ifade tipi: {:?}", node.node_type),
+                    SemanticErrorType::Internal,
+                    MessageId::UnexpectedExpression.format(&[&format!("{:?}", node.node_type)]),
                     node.token.clone(),
                 ));
                 Type::Error
             }
         }
     }
-
-    fn visit(&mut self, node: &AstNode) -> Type {
-        self.visit_node(node)
-    }
-    
-    fn module_exists(&self, module_path: &str) -> bool {
-        true
-    }
     
-    fn symbol_exists(&self, module_path: &str, symbol_name: &str) -> bool {
+    fn module_exists(&self, _module_path: &str) -> bool {
         true
     }
-    
-    fn import_symbol(&mut self, module_path: &str, symbol_name: &str) {
-    }
-
-    fn node_kind(&self, node: &AstNode) -> AstNodeType {
-        node.node_type.clone()
-    }
-    
-    fn node_line(&self, node: &AstNode) -> usize {
-        node.token.as_ref().map_or(0, |t| t.line)
-    }
-    
-    fn node_column(&self, node: &AstNode) -> usize {
-        node.token.as_ref().map_or(0, |t| t.column)
-    }
 }
 
 impl std::fmt::Debug for SemanticAnalyzer {
@@ -1604,4 +1719,45 @@ impl std::fmt::Debug for SemanticAnalyzer {
             .field("in_loop", &self.in_loop)
             .finish()
     }
+}
+
+fn located(mut error: SemanticError, node: &AstNode) -> SemanticError {
+    if error.line == 0
+        && let Some(token) = &node.token {
+        error.line = token.line;
+        error.column = token.column;
+        error.span = Some(token.span).filter(|span| !span.is_empty());
+    }
+    
+    error
+}
+
+fn is_small_function(body: &AstNode) -> bool {
+    fn count_statements(node: &AstNode) -> Option<usize> {
+        match node.node_type {
+            AstNodeType::WhileStmt | AstNodeType::ForStmt => None,
+            AstNodeType::BlockStmt => node.children.iter().map(count_statements).sum(),
+            AstNodeType::IfStmt => {
+                let branches: Option<usize> = node.children.iter().skip(1).map(count_statements).sum();
+                branches.map(|count| count + 1)
+            },
+            _ => Some(1),
+        }
+    }
+    
+    count_statements(body).is_some_and(|count| count <= SMALL_FUNCTION_STATEMENTS)
+}
+
+fn constant_int(node: &AstNode) -> Option<i32> {
+    match node.node_type {
+        AstNodeType::LiteralExpr => match node.token.as_ref().map(|t| &t.token_type) {
+            Some(TokenType::IntLiteral) => node.value.as_ref()?.parse().ok(),
+            _ => None,
+        },
+        AstNodeType::GroupExpr => node.children.first().and_then(constant_int),
+        AstNodeType::UnaryExpr if node.value.as_deref() == Some("-") => {
+            node.children.first().and_then(constant_int).map(i32::wrapping_neg)
+        },
+        _ => None,
+    }
 }
\ No newline at end of file
@@ -1,14 +1,89 @@
 use std::fmt;
+use crate::diagnostics::{Diagnostic, ErrorCode, MessageId, Severity, SourceFile};
+use crate::lexer::span::Span;
 use crate::lexer::token::Token;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum SemanticErrorType {
     UndefinedVariable,
     UndefinedFunction,
-    TypeMismatch,
+    UndefinedType,
     Redefinition,
+    ImmutableAssignment,
+    NotAFunction,
+    NotAType,
+    UninitializedVariable,
+    ModuleNotFound,
+    UnknownField,
+    TypeMismatch,
     InvalidReturn,
-    Other,
+    InvalidOperands,
+    NonBoolCondition,
+    NotIterable,
+    InvalidRangeBound,
+    ArgumentCountMismatch,
+    ArgumentTypeMismatch,
+    UninferableType,
+    MissingParameterType,
+    InvalidMemberAccess,
+    InvalidIndex,
+    NotIndexable,
+    InvalidAssignmentTarget,
+    MissingMain,
+    MainNotFunction,
+    ReturnOutsideFunction,
+    BreakOutsideLoop,
+    ContinueOutsideLoop,
+    RangeOutsideFor,
+    UnknownAttribute,
+    ConflictingAttributes,
+    UnusedSymbol,
+    UnusedVariable,
+    Internal,
+}
+
+impl SemanticErrorType {
+    pub fn code(self) -> Option<ErrorCode> {
+        let code = match self {
+            SemanticErrorType::UndefinedVariable => ErrorCode::UndefinedVariable,
+            SemanticErrorType::UndefinedFunction => ErrorCode::UndefinedFunction,
+            SemanticErrorType::UndefinedType => ErrorCode::UndefinedType,
+            SemanticErrorType::Redefinition => ErrorCode::Redefinition,
+            SemanticErrorType::ImmutableAssignment => ErrorCode::ImmutableAssignment,
+            SemanticErrorType::NotAFunction => ErrorCode::NotAFunction,
+            SemanticErrorType::NotAType => ErrorCode::NotAType,
+            SemanticErrorType::TypeMismatch => ErrorCode::TypeMismatch,
+            SemanticErrorType::InvalidReturn => ErrorCode::InvalidReturn,
+            SemanticErrorType::InvalidOperands => ErrorCode::InvalidOperands,
+            SemanticErrorType::NonBoolCondition => ErrorCode::NonBoolCondition,
+            SemanticErrorType::NotIterable => ErrorCode::NotIterable,
+            SemanticErrorType::InvalidRangeBound => ErrorCode::InvalidRangeBound,
+            SemanticErrorType::ArgumentCountMismatch => ErrorCode::ArgumentCountMismatch,
+            SemanticErrorType::ArgumentTypeMismatch => ErrorCode::ArgumentTypeMismatch,
+            SemanticErrorType::InvalidAssignmentTarget => ErrorCode::InvalidAssignmentTarget,
+            SemanticErrorType::MissingMain => ErrorCode::MissingMain,
+            SemanticErrorType::MainNotFunction => ErrorCode::MainNotFunction,
+            SemanticErrorType::ReturnOutsideFunction => ErrorCode::ReturnOutsideFunction,
+            SemanticErrorType::BreakOutsideLoop => ErrorCode::BreakOutsideLoop,
+            SemanticErrorType::ContinueOutsideLoop => ErrorCode::ContinueOutsideLoop,
+            SemanticErrorType::UnknownAttribute => ErrorCode::UnknownAttribute,
+            SemanticErrorType::ConflictingAttributes => ErrorCode::ConflictingAttributes,
+            SemanticErrorType::UnusedSymbol => ErrorCode::UnusedSymbol,
+            SemanticErrorType::UnusedVariable => ErrorCode::UnusedVariable,
+            // Bu türler yalnızca savunma amaçlı üretilir; hiçbir program bunları tetikleyemez.
+            SemanticErrorType::UninitializedVariable
+            | SemanticErrorType::ModuleNotFound
+            | SemanticErrorType::UnknownField
+            | SemanticErrorType::UninferableType
+            | SemanticErrorType::MissingParameterType
+            | SemanticErrorType::InvalidMemberAccess
+            | SemanticErrorType::InvalidIndex
+            | SemanticErrorType::NotIndexable
+            | SemanticErrorType::RangeOutsideFor
+            | SemanticErrorType::Internal => return None,
+        };
+        Some(code)
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -19,23 +94,24 @@ pub struct SemanticError {
     pub line: usize,
     pub column: usize,
     pub is_warning: bool,
+    pub span: Option<Span>,
+    pub related: Vec<(usize, usize, String)>,
+    pub notes: Vec<String>,
+    pub help: Option<String>,
 }
 
 impl SemanticError {
     pub fn new(error_type: SemanticErrorType, message: String, token: Option<Token>) -> Self {
-        let (line, column) = if let Some(ref tok) = token {
-            (tok.line, tok.column)
+        let (line, column, span) = if let Some(ref tok) = token {
+            (tok.line, tok.column, Some(tok.span).filter(|span| !span.is_empty()))
         } else {
-            (0, 0)
+            (0, 0, None)
         };
         
         SemanticError {
-            error_type,
-            message,
+            span,
             token,
-            line,
-            column,
-            is_warning: false,
+            ..Self::with_position(error_type, message, line, column)
         }
     }
     
@@ -47,62 +123,84 @@ impl SemanticError {
             line,
             column,
             is_warning: false,
-        }
-    }
-    
-    pub fn with_warning(message: String, line: usize, column: usize, is_warning: bool) -> Self {
-        SemanticError {
-            error_type: SemanticErrorType::Other,
-            message,
-            token: None,
-            line,
-            column,
-            is_warning,
-        }
-    }
-
-    pub fn new_simple(message: String, line: usize, column: usize, is_warning: bool) -> Self {
-        SemanticError {
-            error_type: SemanticErrorType::Other,
-            message,
-            token: None,
-            line,
-            column,
-            is_warning,
+            span: None,
+            related: Vec::new(),
+            notes: Vec::new(),
+            help: None,
         }
     }
     
     pub fn position_info(&self) -> String {
         if let Some(ref token) = self.token {
-            format!("satır {}, sütun {}", token.line, token.column)
+            MessageId::Position.format(&[&token.line, &token.column])
         } else {
-            format!("satır {}, sütun {}", self.line, self.column)
+            MessageId::Position.format(&[&self.line, &self.column])
         }
     }
     
+    pub fn code(&self) -> Option<ErrorCode> {
+        self.error_type.code()
+    }
+    
     pub fn is_warning(&self) -> bool {
         self.is_warning
     }
-
-    pub fn new_with_location(error_type: SemanticErrorType, message: String, line: usize, column: usize, is_warning: bool) -> Self {
-        SemanticError {
-            error_type,
-            message,
-            line,
-            column,
-            token: None,
-            is_warning
+    
+    pub fn with_span(mut self, span: Span) -> Self {
+        self.span = Some(span);
+        self
+    }
+    
+    pub fn with_related(mut self, line: usize, column: usize, message: impl Into<String>) -> Self {
+        self.related.push((line, column, message.into()));
+        self
+    }
+    
+    pub fn with_note(mut self, note: impl Into<String>) -> Self {
+        self.notes.push(note.into());
+        self
+    }
+    
+    pub fn with_help(mut self, help: impl Into<String>) -> Self {
+        self.help = Some(help.into());
+        self
+    }
+    
+    pub fn span_in(&self, file: &SourceFile) -> Option<Span> {
+        match self.span {
+            Some(span) => Some(span),
+            None if self.line > 0 => Some(file.word_span(self.line, self.column)),
+            None => None,
         }
     }
-
-    pub fn new_basic(message: String, line: usize, column: usize, is_warning: bool) -> Self {
+    
+    pub fn to_diagnostic(&self, file: &SourceFile) -> Diagnostic {
+        let severity = if self.is_warning { Severity::Warning } else { Severity::Error };
+        let mut diagnostic = Diagnostic::new(severity, self.message.clone());
+        
+        if let Some(code) = self.code() {
+            diagnostic = diagnostic.with_code(code.to_string());
+        }
+        if let Some(span) = self.span_in(file) {
+            diagnostic = diagnostic.with_label(span, "");
+        }
+        for (line, column, message) in self.related.iter().filter(|(line, _, _)| *line > 0) {
+            diagnostic = diagnostic.with_secondary_label(file.word_span(*line, *column), message.clone());
+        }
+        for note in &self.notes {
+            diagnostic = diagnostic.with_note(note.clone());
+        }
+        if let Some(help) = &self.help {
+            diagnostic = diagnostic.with_help(help.clone());
+        }
+        
+        diagnostic
+    }
+    
+    pub fn new_with_location(error_type: SemanticErrorType, message: String, line: usize, column: usize, is_warning: bool) -> Self {
         SemanticError {
-            error_type: SemanticErrorType::Other,
-            message,
-            token: None,
-            line,
-            column,
             is_warning,
+            ..Self::with_position(error_type, message, line, column)
         }
     }
 }
@@ -1,5 +1,8 @@
+#[allow(clippy::result_large_err)]
 pub mod analyzer;
+#[allow(clippy::result_large_err)]
 pub mod symbol_table;
+#[allow(clippy::result_large_err)]
 pub mod types;
 pub mod error;
 
@@ -1,7 +1,9 @@
 use std::collections::HashMap;
 use std::fmt;
+use crate::diagnostics::MessageId;
 use crate::semantics::error::{SemanticError, SemanticErrorType};
 use crate::semantics::types::Type;
+use crate::utils::best_match;
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum SymbolKind {
@@ -16,12 +18,12 @@ pub enum SymbolKind {
 impl fmt::Display for SymbolKind {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            SymbolKind::Variable => write!(f, "değişken"),
-            SymbolKind::Function => write!(f, "fonksiyon"),
-            SymbolKind::Parameter => write!(f, "parametre"),
-            SymbolKind::Type => write!(f, "tür"),
-            SymbolKind::Module => write!(f, "modül"),
-            SymbolKind::TypeParameter => write!(f, "tip parametresi"),
+            SymbolKind::Variable => write!(f, "{}", MessageId::KindVariable.text()),
+            SymbolKind::Function => write!(f, "{}", MessageId::KindFunction.text()),
+            SymbolKind::Parameter => write!(f, "{}", MessageId::KindParameter.text()),
+            SymbolKind::Type => write!(f, "{}", MessageId::KindType.text()),
+            SymbolKind::Module => write!(f, "{}", MessageId::KindModule.text()),
+            SymbolKind::TypeParameter => write!(f, "{}", MessageId::KindTypeParameter.text()),
         }
     }
 }
@@ -42,12 +44,12 @@ pub struct Symbol {
 
 impl Symbol {
     pub fn new(
-        name: String, 
-        symbol_type: Type, 
+        name: String,
+        symbol_type: Type,
         kind: SymbolKind,
-        is_mutable: bool, 
+        is_mutable: bool,
         scope_level: usize,
-        line: usize, 
+        line: usize,
         column: usize
     ) -> Self {
         Symbol {
@@ -65,11 +67,11 @@ impl Symbol {
     }
     
     pub fn new_variable(
-        name: String, 
-        symbol_type: Type, 
-        is_mutable: bool, 
+        name: String,
+        symbol_type: Type,
+        is_mutable: bool,
         scope_level: usize,
-        line: usize, 
+        line: usize,
         column: usize,
         is_initialized: bool
     ) -> Self {
@@ -79,11 +81,11 @@ impl Symbol {
     }
     
     pub fn new_function(
-        name: String, 
-        return_type: Type, 
+        name: String,
+        return_type: Type,
         parameters: Vec<Symbol>,
         scope_level: usize,
-        line: usize, 
+        line: usize,
         column: usize
     ) -> Self {
         let param_types = parameters.iter().map(|p| p.symbol_type.clone()).collect();
@@ -94,7 +96,7 @@ impl Symbol {
         symbol.is_initialized = true;
         symbol
     }
-
+    
     pub fn type_info(&self) -> &Type {
         &self.symbol_type
     }
@@ -126,18 +128,18 @@ impl Symbol {
 
 impl fmt::Display for Symbol {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{} {} (tip: {}, kapsam: {})", self.kind, self.name, self.symbol_type, self.scope_level)?;
+        write!(f, "{}", MessageId::SymbolSummary.format(&[&self.kind, &self.name, &self.symbol_type, &self.scope_level]))?;
         
         if self.is_mutable {
-            write!(f, ", değiştirilebilir")?;
+            write!(f, ", {}", MessageId::SymbolMutable.text())?;
         }
         
         if !self.is_initialized {
-            write!(f, ", başlatılmamış")?;
+            write!(f, ", {}", MessageId::SymbolUninitialized.text())?;
         }
         
         if !self.is_used {
-            write!(f, ", kullanılmıyor")?;
+            write!(f, ", {}", MessageId::SymbolUnused.text())?;
         }
         
         Ok(())
@@ -151,7 +153,7 @@ pub struct Scope {
     pub scope_type: ScopeType,
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum ScopeType {
     Global,
     Function,
@@ -166,14 +168,14 @@ pub enum ScopeType {
 impl fmt::Display for ScopeType {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            ScopeType::Global => write!(f, "global"),
-            ScopeType::Function => write!(f, "fonksiyon"),
-            ScopeType::Block => write!(f, "blok"),
-            ScopeType::Loop => write!(f, "döngü"),
-            ScopeType::If => write!(f, "if"),
-            ScopeType::Struct => write!(f, "struct"),
-            ScopeType::Impl => write!(f, "impl"),
-            ScopeType::Module => write!(f, "modül"),
+            ScopeType::Global => write!(f, "{}", MessageId::ScopeGlobal.text()),
+            ScopeType::Function => write!(f, "{}", MessageId::ScopeFunction.text()),
+            ScopeType::Block => write!(f, "{}", MessageId::ScopeBlock.text()),
+            ScopeType::Loop => write!(f, "{}", MessageId::ScopeLoop.text()),
+            ScopeType::If => write!(f, "{}", MessageId::ScopeIf.text()),
+            ScopeType::Struct => write!(f, "{}", MessageId::ScopeStruct.text()),
+            ScopeType::Impl => write!(f, "{}", MessageId::ScopeImpl.text()),
+            ScopeType::Module => write!(f, "{}", MessageId::ScopeModule.text()),
         }
     }
 }
@@ -194,11 +196,10 @@ impl Scope {
             let existing = self.symbols.get(&name).unwrap();
             return Err(SemanticError::with_position(
                 SemanticErrorType::Redefinition,
-                format!("'{}' daha önce {} olarak tanımlanmış (satır: {}, sütun: {})", 
-                       name, existing.kind, existing.line, existing.column),
+                MessageId::Redefinition.format(&[&name, &existing.kind, &existing.line, &existing.column]),
                 symbol.line,
                 symbol.column,
-            ));
+            ).with_related(existing.line, existing.column, MessageId::FirstDefinedHere.text()));
         }
         
         self.symbols.insert(name, symbol);
@@ -212,7 +213,7 @@ impl Scope {
     pub fn display_symbols(&self) -> String {
         let mut result = format!("Kapsam {} ({}):\n", self.level, self.scope_type);
         
-        for (_, symbol) in &self.symbols {
+        for symbol in self.symbols.values() {
             result.push_str(&format!("  {}\n", symbol));
         }
         
@@ -240,6 +241,13 @@ pub struct SymbolTable {
     generic_instantiations: HashMap<String, (String, Vec<Type>)>,
     struct_definitions: HashMap<String, StructDefinition>,
     enum_definitions: HashMap<String, EnumDefinition>,
+    definitions: Vec<Symbol>,
+}
+
+impl Default for SymbolTable {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl SymbolTable {
@@ -255,6 +263,7 @@ impl SymbolTable {
             generic_instantiations: HashMap::new(),
             struct_definitions: HashMap::new(),
             enum_definitions: HashMap::new(),
+            definitions: Vec::new(),
         };
         table.enter_scope(ScopeType::Global);
         table
@@ -273,13 +282,12 @@ impl SymbolTable {
                     }
                 }
             }
-        } else if scope_type == ScopeType::Struct {
-            if let Some(struct_scope) = self.scopes.last() {
-                for (name, symbol) in &struct_scope.symbols {
-                    if symbol.kind == SymbolKind::Type {
-                        self.current_struct = Some(name.clone());
-                        break;
-                    }
+        } else if scope_type == ScopeType::Struct
+            && let Some(struct_scope) = self.scopes.last() {
+            for (name, symbol) in &struct_scope.symbols {
+                if symbol.kind == SymbolKind::Type {
+                    self.current_struct = Some(name.clone());
+                    break;
                 }
             }
         }
@@ -297,7 +305,6 @@ impl SymbolTable {
             
             Some(self.scopes.pop().unwrap())
         } else {
-            println!("Uyarı: Evrensel kapsamdan çıkma girişimi");
             None
         }
     }
@@ -324,11 +331,13 @@ impl SymbolTable {
     
     pub fn define_symbol(&mut self, symbol: Symbol) -> Result<(), SemanticError> {
         if let Some(scope) = self.scopes.last_mut() {
-            scope.define(symbol)
+            scope.define(symbol.clone())?;
+            self.definitions.push(symbol);
+            Ok(())
         } else {
             Err(SemanticError::new(
-                SemanticErrorType::Other,
-                "Kapsam bulunamadı".to_string(),
+                SemanticErrorType::Internal,
+                MessageId::ScopeNotFound.text().to_string(),
                 None,
             ))
         }
@@ -348,12 +357,12 @@ impl SymbolTable {
     }
     
     pub fn define_variable(
-        &mut self, 
-        name: String, 
-        var_type: Type, 
+        &mut self,
+        name: String,
+        var_type: Type,
         is_mutable: bool,
         is_initialized: bool,
-        line: usize, 
+        line: usize,
         column: usize
     ) -> Result<(), SemanticError> {
         let level = self.current_level();
@@ -362,11 +371,11 @@ impl SymbolTable {
     }
     
     pub fn define_function(
-        &mut self, 
-        name: String, 
-        return_type: Type, 
+        &mut self,
+        name: String,
+        return_type: Type,
         parameters: Vec<Symbol>,
-        line: usize, 
+        line: usize,
         column: usize
     ) -> Result<(), SemanticError> {
         let level = self.current_level();
@@ -381,14 +390,10 @@ impl SymbolTable {
             }
         }
         
-        Err(SemanticError::new(
-            SemanticErrorType::UndefinedVariable,
-            format!("'{}' tanımlı değil", name),
-            None,
-        ))
+        Err(self.undefined(name))
     }
     
-    pub fn lookup(&self, name: &str, max_level: Option<usize>) -> Result<&Symbol, SemanticError> {
+    pub fn lookup_at_level(&self, name: &str, max_level: Option<usize>) -> Result<&Symbol, SemanticError> {
         let max = max_level.unwrap_or(self.scopes.len());
         
         for scope in self.scopes.iter().rev() {
@@ -401,23 +406,45 @@ impl SymbolTable {
             }
         }
         
-        Err(SemanticError::new(
+        Err(self.undefined(name))
+    }
+    
+    fn undefined(&self, name: &str) -> SemanticError {
+        let error = SemanticError::new(
             SemanticErrorType::UndefinedVariable,
-            format!("'{}' tanımlı değil", name),
+            MessageId::Undefined.format(&[&name]),
             None,
-        ))
+        );
+        
+        match self.suggest(name, None) {
+            Some(candidate) => error.with_help(MessageId::DidYouMean.format(&[&candidate])),
+            None => error,
+        }
+    }
+    
+    pub fn suggest(&self, name: &str, kind: Option<SymbolKind>) -> Option<&str> {
+        let candidates = self.scopes.iter().rev()
+            .flat_map(|scope| scope.symbols.values())
+            .filter(|symbol| kind.as_ref().is_none_or(|kind| &symbol.kind == kind))
+            .map(|symbol| symbol.name.as_str());
+        
+        best_match(name, candidates)
+    }
+    
+    pub fn suggest_field(&self, struct_name: &str, field_name: &str) -> Option<&str> {
+        let struct_def = self.struct_definitions.get(struct_name)?;
+        best_match(field_name, struct_def.fields.iter().map(|field| field.name.as_str()))
     }
     
     pub fn resolve_local(&self, name: &str) -> Result<&Symbol, SemanticError> {
-        if let Some(scope) = self.scopes.last() {
-            if let Some(symbol) = scope.resolve(name) {
-                return Ok(symbol);
-            }
+        if let Some(scope) = self.scopes.last()
+            && let Some(symbol) = scope.resolve(name) {
+            return Ok(symbol);
         }
         
         Err(SemanticError::new(
             SemanticErrorType::UndefinedVariable,
-            format!("'{}' mevcut kapsamda tanımlı değil", name),
+            MessageId::UndefinedInCurrentScope.format(&[&name]),
             None,
         ))
     }
@@ -432,7 +459,7 @@ impl SymbolTable {
         
         Err(SemanticError::new(
             SemanticErrorType::UndefinedVariable,
-            format!("'{}' tanımlı değil, kullanılamaz", name),
+            MessageId::UndefinedCannotUse.format(&[&name]),
             None,
         ))
     }
@@ -447,7 +474,7 @@ impl SymbolTable {
         
         Err(SemanticError::new(
             SemanticErrorType::UndefinedVariable,
-            format!("'{}' tanımlı değil, değer atanamaz", name),
+            MessageId::UndefinedCannotAssign.format(&[&name]),
             None,
         ))
     }
@@ -457,8 +484,8 @@ impl SymbolTable {
         
         if !symbol.is_mutable {
             return Err(SemanticError::new(
-                SemanticErrorType::Other,
-                format!("'{}' değiştirilemez (mut değil)", name),
+                SemanticErrorType::ImmutableAssignment,
+                MessageId::ImmutableAssignment.format(&[&name]),
                 None,
             ));
         }
@@ -472,8 +499,8 @@ impl SymbolTable {
         match symbol.kind {
             SymbolKind::Function => Ok(symbol),
             _ => Err(SemanticError::new(
-                SemanticErrorType::UndefinedFunction,
-                format!("'{}' bir fonksiyon değil, {} türünde", name, symbol.kind),
+                SemanticErrorType::NotAFunction,
+                MessageId::NotAFunctionKind.format(&[&name, &symbol.kind]),
                 None,
             )),
         }
@@ -485,24 +512,33 @@ impl SymbolTable {
         match symbol.kind {
             SymbolKind::Type => Ok(symbol),
             _ => Err(SemanticError::new(
-                SemanticErrorType::Other,
-                format!("'{}' bir tür değil, {} türünde", name, symbol.kind),
+                SemanticErrorType::NotAType,
+                MessageId::NotATypeKind.format(&[&name, &symbol.kind]),
                 None,
             )),
         }
     }
     
+    pub fn all_definitions(&self) -> &[Symbol] {
+        &self.definitions
+    }
+    
     pub fn display_scopes(&self) -> String {
         let mut result = String::new();
         
         for (i, scope) in self.scopes.iter().enumerate() {
-            result.push_str(&format!("\n=== Kapsam {} ({}) ===\n", i, scope.scope_type));
+            result.push_str(&format!("\n{}\n", MessageId::ScopeHeader.format(&[&i, &scope.scope_type])));
             
-            for (name, symbol) in &scope.symbols {
+            let mut symbols: Vec<_> = scope.symbols.iter().collect();
+            symbols.sort_by(|a, b| a.0.cmp(b.0));
+            
+            for (name, symbol) in symbols {
                 result.push_str(&format!("  {} : {}\n", name, symbol.symbol_type));
-                if symbol.kind == SymbolKind::Function && symbol.parameters.is_some() {
-                    result.push_str("    Parametreler:\n");
-                    for param in symbol.parameters.as_ref().unwrap() {
+                if symbol.kind == SymbolKind::Function
+                    && let Some(parameters) = &symbol.parameters
+                {
+                    result.push_str(&format!("    {}\n", MessageId::ScopeParameters.text()));
+                    for param in parameters {
                         result.push_str(&format!("      {} : {}\n", param.name, param.symbol_type));
                     }
                 }
@@ -562,9 +598,9 @@ impl SymbolTable {
         }
     }
     
-    pub fn register_instantiated_generic(&mut self, 
-                                        instantiated_name: &str, 
-                                        base_type: &str, 
+    pub fn register_instantiated_generic(&mut self,
+                                        instantiated_name: &str,
+                                        base_type: &str,
                                         concrete_types: Vec<Type>,
                                         line: usize,
                                         column: usize) -> Result<(), SemanticError> {
@@ -588,8 +624,8 @@ impl SymbolTable {
         Ok(())
     }
     
-    pub fn define_generic(&mut self, 
-                         name: &str, 
+    pub fn define_generic(&mut self,
+                         name: &str,
                          type_params: Vec<String>,
                          line: usize,
                          column: usize) -> Result<(), SemanticError> {
@@ -616,8 +652,8 @@ impl SymbolTable {
         Ok(())
     }
     
-    pub fn define_type_parameter(&mut self, 
-                                name: &str, 
+    pub fn define_type_parameter(&mut self,
+                                name: &str,
                                 param_type: Type,
                                 line: usize,
                                 column: usize) -> Result<(), SemanticError> {
@@ -627,7 +663,7 @@ impl SymbolTable {
             SymbolKind::TypeParameter,
             false,
             self.current_level(),
-            line, 
+            line,
             column,
         );
         
@@ -656,7 +692,7 @@ impl SymbolTable {
             Vec::new()
         }
     }
-
+    
     pub fn type_exists(&self, type_name: &str) -> bool {
         for scope in self.scopes.iter().rev() {
             for (name, symbol) in &scope.symbols {
@@ -677,19 +713,19 @@ impl SymbolTable {
         false
     }
     
-    pub fn mark_as_exported(&mut self, name: &str) -> Result<(), SemanticError> {
+    pub fn mark_as_exported(&mut self, _name: &str) -> Result<(), SemanticError> {
         Ok(())
     }
     
-    pub fn mark_as_deprecated(&mut self, name: &str, message: String) -> Result<(), SemanticError> {
+    pub fn mark_as_deprecated(&mut self, _name: &str, _message: String) -> Result<(), SemanticError> {
         Ok(())
     }
     
-    pub fn define_extern(&mut self, 
-                        name: &str, 
-                        func_type: Type, 
+    pub fn define_extern(&mut self,
+                        name: &str,
+                        func_type: Type,
                         external_name: Option<String>,
-                        line: usize, 
+                        line: usize,
                         column: usize) -> Result<(), SemanticError> {
         let mut symbol = Symbol::new(
             name.to_string(),
@@ -703,16 +739,26 @@ impl SymbolTable {
         
         symbol.is_initialized = true;
         
-        if let Some(ext_name) = external_name {
+        if let Some(_ext_name) = external_name {
         }
         
         self.define_symbol(symbol)
     }
-
+    
     pub fn get_struct_def(&self, struct_name: &str) -> Option<&StructDefinition> {
         self.struct_definitions.get(struct_name)
     }
     
+    pub fn define_struct(&mut self, definition: StructDefinition) {
+        self.struct_definitions.insert(definition.name.clone(), definition);
+    }
+    
+    pub fn struct_definitions(&self) -> Vec<&StructDefinition> {
+        let mut definitions: Vec<&StructDefinition> = self.struct_definitions.values().collect();
+        definitions.sort_by(|a, b| a.name.cmp(&b.name));
+        definitions
+    }
+    
     pub fn all_symbols(&self) -> Vec<&Symbol> {
         let mut all_symbols = Vec::new();
         
@@ -1,4 +1,5 @@
 use std::fmt;
+use crate::diagnostics::MessageId;
 use crate::parser::ast::{AstNode, AstNodeType};
 use crate::semantics::error::{SemanticError, SemanticErrorType};
 
@@ -22,7 +23,7 @@ pub enum Type {
     Error,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum GenericConstraint {
     Trait(String),
     Type(Type),
@@ -52,8 +53,7 @@ impl Type {
                     return Type::Array(Box::new(base_type), size);
                 }
                 
-                if name.starts_with('&') {
-                    let inner_type_name = &name[1..];
+                if let Some(inner_type_name) = name.strip_prefix('&') {
                     let inner_type = Type::from_name(inner_type_name);
                     return Type::Ref(Box::new(inner_type));
                 }
@@ -64,7 +64,7 @@ impl Type {
                     return Type::Optional(Box::new(inner_type));
                 }
                 
-                if name.chars().next().map_or(false, |c| c.is_uppercase()) {
+                if name.chars().next().is_some_and(|c| c.is_uppercase()) {
                     Type::Struct(name.to_string())
                 } else {
                     Type::Unknown
@@ -95,7 +95,7 @@ impl Type {
                     return false;
                 }
                 
-                ret1.is_compatible_with(ret2) && 
+                ret1.is_compatible_with(ret2) &&
                 params1.iter().zip(params2.iter()).all(|(p1, p2)| p1.is_compatible_with(p2))
             },
             
@@ -111,7 +111,7 @@ impl Type {
         } else {
             Err(SemanticError::new(
                 SemanticErrorType::TypeMismatch,
-                format!("Tip uyuşmazlığı: '{}' tipine '{}' tipi atanamaz", self, other),
+                MessageId::AssignTypeMismatch.format(&[self, other]),
                 None,
             ))
         }
@@ -123,7 +123,7 @@ impl Type {
         } else {
             Err(SemanticError::new(
                 SemanticErrorType::InvalidReturn,
-                format!("Geçersiz dönüş tipi: Beklenen '{}', bulunan '{}'", expected, self),
+                MessageId::InvalidReturnType.format(&[expected, self]),
                 None,
             ))
         }
@@ -152,6 +152,17 @@ impl Type {
     }
     
     pub fn check_arithmetic_compatible(&self, other: &Type, operator: &str) -> Result<Type, SemanticError> {
+        if matches!(operator, "&" | "|" | "~" | "<<" | ">>") {
+            return match (self, other) {
+                (Type::Int | Type::Any, Type::Int | Type::Any) => Ok(Type::Int),
+                _ => Err(SemanticError::new(
+                    SemanticErrorType::InvalidOperands,
+                    MessageId::InvalidIntegerOperands.format(&[&operator, self, other]),
+                    None,
+                )),
+            };
+        }
+        
         match (self, other) {
             (Type::Int, Type::Int) => Ok(Type::Int),
             
@@ -164,20 +175,19 @@ impl Type {
             (Type::Any, _) => Ok(other.clone()),
             (_, Type::Any) => Ok(self.clone()),
             
-            (Type::TypeParameter(_, _), other) if 
-                [Type::Int, Type::Float, Type::String].contains(other) && 
-                (operator == "+" || operator == "-" || operator == "*" || operator == "/") => 
+            (Type::TypeParameter(_, _), other) if
+                [Type::Int, Type::Float, Type::String].contains(other) &&
+                (operator == "+" || operator == "-" || operator == "*" || operator == "/") =>
                     Ok(other.clone()),
-                    
-            (self_type, Type::TypeParameter(_, _)) if 
-                [Type::Int, Type::Float, Type::String].contains(self_type) && 
-                (operator == "+" || operator == "-" || operator == "*" || operator == "/") => 
+            
+            (self_type, Type::TypeParameter(_, _)) if
+                [Type::Int, Type::Float, Type::String].contains(self_type) &&
+                (operator == "+" || operator == "-" || operator == "*" || operator == "/") =>
                     Ok(self_type.clone()),
             
             _ => Err(SemanticError::new(
-                SemanticErrorType::TypeMismatch,
-                format!("'{}' operatörü '{}' ve '{}' tipleri için geçerli değil", 
-                        operator, self, other),
+                SemanticErrorType::InvalidOperands,
+                MessageId::InvalidArithmeticOperands.format(&[&operator, self, other]),
                 None,
             )),
         }
@@ -195,18 +205,18 @@ impl Type {
         }
         else if operator == "<" || operator == ">" || operator == "<=" || operator == ">=" {
             match (self, other) {
-                (Type::Int, Type::Int) | 
+                (Type::Int, Type::Int) |
                 (Type::Float, Type::Float) |
                 (Type::Int, Type::Float) |
                 (Type::Float, Type::Int) |
                 (Type::String, Type::String) => return Ok(Type::Bool),
                 
-                (Type::TypeParameter(_, _), other) if 
-                    [Type::Int, Type::Float, Type::String].contains(other) => 
+                (Type::TypeParameter(_, _), other) if
+                    [Type::Int, Type::Float, Type::String].contains(other) =>
                         return Ok(Type::Bool),
-                    
-                (self_type, Type::TypeParameter(_, _)) if 
-                    [Type::Int, Type::Float, Type::String].contains(self_type) => 
+                
+                (self_type, Type::TypeParameter(_, _)) if
+                    [Type::Int, Type::Float, Type::String].contains(self_type) =>
                         return Ok(Type::Bool),
                 
                 _ => {}
@@ -214,9 +224,8 @@ impl Type {
         }
         
         Err(SemanticError::new(
-            SemanticErrorType::TypeMismatch,
-            format!("'{}' karşılaştırma operatörü '{}' ve '{}' tipleri için geçerli değil", 
-                    operator, self, other),
+            SemanticErrorType::InvalidOperands,
+            MessageId::InvalidComparisonOperands.format(&[&operator, self, other]),
             None,
         ))
     }
@@ -224,16 +233,16 @@ impl Type {
     pub fn from_type_annotation(node: &AstNode) -> Result<Type, SemanticError> {
         if node.node_type != AstNodeType::TypeAnnotation {
             return Err(SemanticError::new(
-                SemanticErrorType::Other,
-                format!("Tip tanımlaması bekleniyordu, {:?} bulundu", node.node_type),
+                SemanticErrorType::Internal,
+                MessageId::ExpectedTypeAnnotation.format(&[&format!("{:?}", node.node_type)]),
                 node.token.clone(),
             ));
         }
         
-        let type_name = node.value.as_ref().ok_or_else(|| 
+        let type_name = node.value.as_ref().ok_or_else(||
             SemanticError::new(
-                SemanticErrorType::Other,
-                "Tip adı bulunamadı".to_string(),
+                SemanticErrorType::Internal,
+                MessageId::MissingTypeName.text().to_string(),
                 node.token.clone(),
             )
         )?;
@@ -269,8 +278,8 @@ impl fmt::Display for Type {
             Type::Any => write!(f, "any"),
             Type::Null => write!(f, "null"),
             Type::TypeParameter(name, _) => write!(f, "{}", name),
-            Type::Unknown => write!(f, "bilinmeyen"),
-            Type::Error => write!(f, "hata"),
+            Type::Unknown => write!(f, "{}", MessageId::TypeUnknown.text()),
+            Type::Error => write!(f, "{}", MessageId::SeverityError.text()),
         }
     }
 }
@@ -278,19 +287,19 @@ impl fmt::Display for Type {
 pub struct TypeConverter;
 
 impl TypeConverter {
-    pub fn string_literal_to_type(literal: &str) -> Type {
+    pub fn string_literal_to_type(_literal: &str) -> Type {
         Type::String
     }
     
-    pub fn int_literal_to_type(literal: &str) -> Type {
+    pub fn int_literal_to_type(_literal: &str) -> Type {
         Type::Int
     }
     
-    pub fn float_literal_to_type(literal: &str) -> Type {
+    pub fn float_literal_to_type(_literal: &str) -> Type {
         Type::Float
     }
     
-    pub fn bool_literal_to_type(literal: &str) -> Type {
+    pub fn bool_literal_to_type(_literal: &str) -> Type {
         Type::Bool
     }
 }
\ No newline at end of file
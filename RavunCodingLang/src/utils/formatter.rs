@@ -0,0 +1,248 @@
+use crate::diagnostics::MessageId;
+use crate::lexer::lexer::Lexer;
+use crate::lexer::token::{Token, TokenType};
+
+const INDENT: &str = "    ";
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Brace {
+    Block,
+    Struct,
+}
+
+pub fn format_source(source: &str) -> Result<String, String> {
+    let mut lexer = Lexer::new(source);
+    let mut formatter = Formatter::new();
+    
+    loop {
+        let token = lexer.next_token();
+        
+        match token.token_type {
+            TokenType::EOF => break,
+            TokenType::Whitespace => continue,
+            TokenType::Invalid => return Err(MessageId::FormatInvalidToken.format(&[
+                &token.lexeme, &token.line, &token.column,
+            ])),
+            _ => formatter.token(&token),
+        }
+    }
+    
+    Ok(formatter.finish())
+}
+
+struct Formatter {
+    out: String,
+    indent: usize,
+    line_start: bool,
+    needs_newline: bool,
+    no_space: bool,
+    braces: Vec<Brace>,
+    struct_header: bool,
+    attribute_depth: usize,
+    previous: Option<TokenType>,
+    previous_line: usize,
+}
+
+impl Formatter {
+    fn new() -> Self {
+        Formatter {
+            out: String::new(),
+            indent: 0,
+            line_start: true,
+            needs_newline: false,
+            no_space: true,
+            braces: Vec::new(),
+            struct_header: false,
+            attribute_depth: 0,
+            previous: None,
+            previous_line: 0,
+        }
+    }
+    
+    fn finish(mut self) -> String {
+        self.newline();
+        
+        while self.out.ends_with("\n\n") {
+            self.out.pop();
+        }
+        
+        if self.out.trim().is_empty() {
+            String::new()
+        } else {
+            self.out
+        }
+    }
+    
+    fn newline(&mut self) {
+        while self.out.ends_with(' ') {
+            self.out.pop();
+        }
+        
+        if !self.line_start {
+            self.out.push('\n');
+        }
+        
+        self.line_start = true;
+        self.needs_newline = false;
+    }
+    
+    fn write(&mut self, text: &str, space_before: bool) {
+        if self.line_start {
+            for _ in 0..self.indent {
+                self.out.push_str(INDENT);
+            }
+        } else if space_before && !self.no_space {
+            self.out.push(' ');
+        }
+        
+        self.out.push_str(text);
+        self.line_start = false;
+        self.no_space = false;
+    }
+    
+    fn token(&mut self, token: &Token) {
+        let kind = &token.token_type;
+        let (start_line, end_line) = token_lines(token);
+        
+        if self.needs_newline {
+            let trailing_comment = *kind == TokenType::Comment && start_line == self.previous_line;
+            let continues_line = matches!(kind, TokenType::Else | TokenType::Semicolon | TokenType::Comma | TokenType::RightParen)
+                && self.previous == Some(TokenType::RightBrace);
+            let closes_empty_block = *kind == TokenType::RightBrace && self.previous == Some(TokenType::LeftBrace);
+            
+            if !(trailing_comment || continues_line || closes_empty_block) {
+                self.newline();
+                
+                if self.previous.is_some() && start_line > self.previous_line + 1 && *kind != TokenType::RightBrace {
+                    self.out.push('\n');
+                }
+            } else if continues_line {
+                self.needs_newline = false;
+            }
+        }
+        
+        match kind {
+            TokenType::Comment => {
+                let text = token.lexeme.trim_end();
+                self.write(text, true);
+                if text.starts_with("//") || start_line != self.previous_line {
+                    self.needs_newline = true;
+                }
+            },
+            TokenType::LeftBrace => {
+                let brace = if self.struct_header { Brace::Struct } else { Brace::Block };
+                self.struct_header = false;
+                self.write("{", true);
+                self.braces.push(brace);
+                self.indent += 1;
+                self.needs_newline = true;
+            },
+            TokenType::RightBrace => {
+                self.braces.pop();
+                self.indent = self.indent.saturating_sub(1);
+                
+                if self.previous == Some(TokenType::LeftBrace) {
+                    self.no_space = true;
+                    self.needs_newline = false;
+                    self.write("}", false);
+                } else {
+                    self.newline();
+                    self.write("}", false);
+                }
+                self.needs_newline = true;
+            },
+            TokenType::Semicolon => {
+                self.write(";", false);
+                self.needs_newline = true;
+            },
+            TokenType::Comma => {
+                self.write(",", false);
+                if self.braces.last() == Some(&Brace::Struct) {
+                    self.needs_newline = true;
+                }
+            },
+            TokenType::Colon => self.write(":", false),
+            TokenType::Dot | TokenType::DoubleDot => {
+                self.write(&token.lexeme, false);
+                self.no_space = true;
+            },
+            TokenType::Hash => {
+                self.write("#", true);
+                self.no_space = true;
+            },
+            TokenType::LeftParen | TokenType::LeftBracket => {
+                let attached = matches!(self.previous,
+                    Some(TokenType::Identifier) | Some(TokenType::RightParen) |
+                    Some(TokenType::RightBracket) | Some(TokenType::Hash));
+                
+                if *kind == TokenType::LeftBracket && self.previous == Some(TokenType::Hash) {
+                    self.attribute_depth += 1;
+                }
+                
+                self.write(&token.lexeme, !attached);
+                self.no_space = true;
+            },
+            TokenType::RightParen => self.write(")", false),
+            TokenType::RightBracket => {
+                self.write("]", false);
+                if self.attribute_depth > 0 {
+                    self.attribute_depth -= 1;
+                    self.needs_newline = true;
+                }
+            },
+            TokenType::Minus | TokenType::Tilde if self.previous.as_ref().is_none_or(is_prefix_position) => {
+                self.write(&token.lexeme, true);
+                self.no_space = true;
+            },
+            TokenType::Not => {
+                self.write("!", true);
+                self.no_space = true;
+            },
+            TokenType::Struct => {
+                self.struct_header = true;
+                self.write("struct", true);
+            },
+            TokenType::StringLiteral => self.write(&format!("\"{}\"", escape(&token.lexeme)), true),
+            _ => self.write(&token.lexeme, true),
+        }
+        
+        self.previous_line = end_line;
+        self.previous = Some(kind.clone());
+    }
+}
+
+fn token_lines(token: &Token) -> (usize, usize) {
+    match token.token_type {
+        TokenType::Comment => {
+            let newlines = token.lexeme.matches('\n').count();
+            let start = token.line.saturating_sub(newlines);
+            let end = start + token.lexeme.trim_end().matches('\n').count();
+            (start, end)
+        },
+        _ => (token.line, token.line),
+    }
+}
+
+fn is_prefix_position(token_type: &TokenType) -> bool {
+    !matches!(token_type,
+        TokenType::Identifier | TokenType::IntLiteral | TokenType::FloatLiteral |
+        TokenType::StringLiteral | TokenType::CharLiteral | TokenType::BoolLiteral |
+        TokenType::RightParen | TokenType::RightBracket | TokenType::RightBrace)
+}
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::new();
+    
+    for c in text.chars() {
+        match c {
+            '\n' => escaped.push_str("\\n"),
+            '\t' => escaped.push_str("\\t"),
+            '\r' => escaped.push_str("\\r"),
+            '\\' => escaped.push_str("\\\\"),
+            '"' => escaped.push_str("\\\""),
+            _ => escaped.push(c),
+        }
+    }
+    
+    escaped
+}
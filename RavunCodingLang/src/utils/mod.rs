@@ -1 +1,8 @@
 //! Utils modülü
+pub mod formatter;
+pub mod json;
+pub mod suggest;
+
+pub use formatter::format_source;
+pub use json::Json;
+pub use suggest::{best_match, edit_distance};
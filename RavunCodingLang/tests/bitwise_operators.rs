@@ -0,0 +1,92 @@
+mod common;
+
+use common::{analyze, lower, parse, tree};
+use ravun::bytecode::{compile_program, Vm};
+use ravun::codegen::llvm::emit_llvm;
+use ravun::codegen::x86_64::emit_asm;
+use ravun::interpreter::{Interpreter, Value};
+use ravun::lexer::lexer::Lexer;
+use ravun::lexer::token::TokenType;
+use ravun::optimizer::constant_folding::ConstantFolding;
+use ravun::optimizer::PassManager;
+
+// İfade yorumlayıcıda ve VM'de parametrelerle, sabit katlamada ise değişmezlerle hesaplanır.
+fn evaluate(expression: &str, a: i32, b: i32) -> i32 {
+    let source = format!("fn f(a: int, b: int) -> int {{\n    return {};\n}}\n\nfn main() -> int {{\n    return f({}, {});\n}}\n", expression, a, b);
+    let (ast, _, errors) = analyze(&source);
+    assert!(errors.is_empty(), "semantik hatalar: {:?}", errors);
+    
+    let interpreted = Interpreter::new().run(&ast).expect("çalışma zamanı hatası");
+    let program = compile_program(&ast).expect("bytecode üretim hatası");
+    assert_eq!(Vm::new(&program).run().expect("çalışma zamanı hatası"), interpreted, "{}", expression);
+    
+    let constant = expression.replace('a', &format!("({})", a)).replace('b', &format!("({})", b));
+    let mut module = lower(&format!("fn main() -> int {{\n    return {};\n}}\n", constant));
+    let mut manager = PassManager::new();
+    manager.set_verify(true);
+    manager.add_pass(Box::new(ConstantFolding));
+    manager.run(&mut module).expect("optimizasyon hatası");
+    let folded = module.function("main").expect("main fonksiyonu bekleniyor").to_string();
+    
+    let Value::Int(value) = interpreted else {
+        panic!("{} tamsayı vermedi: {:?}", expression, interpreted);
+    };
+    assert!(folded.contains(&format!("ret {}\n", value)), "{} katlanınca {} vermeli:\n{}", constant, value, folded);
+    value
+}
+
+#[test]
+fn tilde_is_complement_as_prefix_and_xor_as_infix() {
+    let types: Vec<TokenType> = Lexer::new("~~a ~ ~b ~= ~c").tokenize()
+        .into_iter().map(|t| t.token_type).filter(|t| *t != TokenType::Identifier).collect();
+    assert_eq!(types, vec![
+        TokenType::Tilde, TokenType::Tilde, TokenType::Tilde, TokenType::Tilde, TokenType::XorAssign,
+        TokenType::Tilde, TokenType::EOF,
+    ]);
+    
+    let program = parse("fn main() -> int {\n    ~a ~ ~b;\n    a ~ ~b ~ c;\n    ~~a ~ b & ~c;\n    ~(a ~ b);\n}\n");
+    let body = program.children[0].children.last().expect("gövde bekleniyor");
+    let shapes: Vec<String> = body.children.iter().map(|statement| tree(&statement.children[0])).collect();
+    assert_eq!(shapes, ["(~ (~ a) (~ b))", "(~ (~ a (~ b)) c)", "(~ (~ (~ a)) (& b (~ c)))", "(~ (~ a b))"]);
+    
+    assert_eq!(evaluate("~a ~ ~b", 5, 3), 6);
+    assert_eq!(evaluate("a ~ ~b", 5, 3), -7);
+    assert_eq!(evaluate("~~a ~ b & ~b", 5, 3), 5);
+    assert_eq!(evaluate("~(a ~ b)", 5, 3), -7);
+}
+
+#[test]
+fn shift_amounts_are_taken_modulo_32() {
+    assert_eq!(evaluate("a << b", 1, 32), 1);
+    assert_eq!(evaluate("a << b", 1, 33), 2);
+    assert_eq!(evaluate("a << b", 3, 31), i32::MIN);
+    assert_eq!(evaluate("a << b", 1, -1), i32::MIN);
+    assert_eq!(evaluate("a >> b", -8, 33), -4);
+    assert_eq!(evaluate("a >> b", -1, 40), -1);
+    assert_eq!(evaluate("a >> b", 256, -28), 16);
+    assert_eq!(evaluate("a >> b", -2147483647, 31), -1);
+    
+    let (ast, _, errors) = analyze("fn main() -> int {\n    let mut x = 1;\n    x <<= 35;\n    x >>= -31;\n    return x;\n}\n");
+    assert!(errors.is_empty(), "semantik hatalar: {:?}", errors);
+    assert_eq!(Interpreter::new().run(&ast).expect("çalışma zamanı hatası"), Value::Int(4));
+    assert_eq!(Vm::new(&compile_program(&ast).expect("bytecode üretim hatası")).run().expect("çalışma zamanı hatası"), Value::Int(4));
+    
+    let module = lower("fn f(a: int, b: int) -> int {\n    return (a << 33) >> b;\n}\n\nfn main() -> int {\n    return f(1, 2);\n}\n");
+    let asm = emit_asm(&module).expect("assembly üretilemedi");
+    assert!(asm.contains("sall $1, %eax"), "sabit kaydırma miktarı maskelenmeli:\n{}", asm);
+    assert!(asm.contains("sarl %cl, %eax"), "{}", asm);
+    let llvm = emit_llvm(&module, &[]).expect("LLVM IR üretimi");
+    assert_eq!(llvm.matches(", 31\n").count(), 2, "her kaydırma miktarı maskelenmeli:\n{}", llvm);
+}
+
+#[test]
+fn bitwise_operators_require_int_operands() {
+    let (_, _, errors) = analyze("fn main() -> int {\n    let a = 1.5 & 2;\n    let b = true | false;\n    let c = ~1.0;\n    let mut d = 1.0;\n    d <<= 1;\n    let e = 1 >> true;\n    return 0;\n}\n");
+    let messages: Vec<&str> = errors.iter().map(|e| e.message.as_str()).collect();
+    assert_eq!(messages.len(), 5, "beş hata bekleniyordu: {:?}", messages);
+    assert!(messages[0].contains("'&'") && messages[0].contains("float"), "{}", messages[0]);
+    assert!(messages[1].contains("'|'") && messages[1].contains("bool"), "{}", messages[1]);
+    assert!(messages[2].contains("'~'") && messages[2].contains("float"), "{}", messages[2]);
+    assert!(messages[3].contains("'<<'") && messages[3].contains("float"), "{}", messages[3]);
+    assert!(messages[4].contains("'>>'") && messages[4].contains("bool"), "{}", messages[4]);
+}
@@ -0,0 +1,206 @@
+use ravun::bytecode::vm::MAX_CALL_DEPTH;
+use ravun::bytecode::{compile_program, Instruction, Program, Vm};
+use ravun::interpreter::{Interpreter, Value};
+use ravun::ir::{lower_program, verify_module};
+use ravun::lexer::lexer::Lexer;
+use ravun::optimizer::constant_folding::ConstantFolding;
+use ravun::optimizer::PassManager;
+use ravun::parser::parser::Parser;
+use ravun::semantics::analyzer::SemanticAnalyzer;
+
+const PROGRAM: &str = "let mut calls = 0;
+let mut total: float = 0.0;
+
+fn fib(n: int) -> int {
+    calls += 1;
+    if n < 2 {
+        return n;
+    }
+    return fib(n - 1) + fib(n - 2);
+}
+
+fn main() -> int {
+    let mut s = 0;
+    for i in 0..10 {
+        s += fib(i);
+    }
+    let mut k = 0;
+    while k < 3 {
+        total += 1.5;
+        k += 1;
+    }
+    for c in \"ab\" {
+        if c != \"\" {
+            s += 1;
+        }
+    }
+    return s;
+}
+";
+
+fn compile(source: &str) -> Program {
+    let tokens = Lexer::new(source).tokenize();
+    let ast = Parser::new(tokens).parse().expect("parse hatası");
+    
+    let mut analyzer = SemanticAnalyzer::new();
+    analyzer.load_std_library().expect("standart kütüphane yüklenemedi");
+    let errors: Vec<_> = analyzer.analyze(&ast).into_iter().filter(|e| !e.is_warning()).collect();
+    assert!(errors.is_empty(), "semantik hatalar: {:?}", errors);
+    
+    compile_program(&ast).expect("bytecode üretim hatası")
+}
+
+#[test]
+fn runs_the_same_result_as_the_interpreter() {
+    let program = compile(PROGRAM);
+    let mut vm = Vm::new(&program);
+    assert_eq!(vm.run().expect("çalışma zamanı hatası"), Value::Int(90));
+    assert_eq!(vm.global("calls"), Some(&Value::Int(276)));
+    assert_eq!(vm.global("total"), Some(&Value::Float(4.5)));
+    
+    let ast = Parser::new(Lexer::new(PROGRAM).tokenize()).parse().expect("parse hatası");
+    assert_eq!(Interpreter::new().run(&ast).expect("çalışma zamanı hatası"), Value::Int(90));
+}
+
+#[test]
+fn round_trips_through_the_rvc_format() {
+    let program = compile(PROGRAM);
+    let bytes = program.to_bytes().expect("serileştirme hatası");
+    assert_eq!(&bytes[..4], b"RVC\0");
+    
+    let loaded = Program::from_bytes(&bytes).expect("bytecode yüklenemedi");
+    assert_eq!(loaded, program);
+    assert_eq!(Vm::new(&loaded).run().expect("çalışma zamanı hatası"), Value::Int(90));
+    
+    let fib = &loaded.functions[loaded.function_index("fib").unwrap() as usize];
+    assert!(fib.code.contains(&Instruction::Call(loaded.function_index("fib").unwrap(), 1)));
+}
+
+#[test]
+fn rejects_truncated_and_corrupted_files() {
+    let bytes = compile(PROGRAM).to_bytes().expect("serileştirme hatası");
+    
+    assert!(Program::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+    assert!(Program::from_bytes(b"RVX\0\x01\x00").is_err());
+    
+    let mut corrupted = bytes.clone();
+    let last = corrupted.len() - 1;
+    corrupted[last - 3] = 0xff;
+    assert!(Program::from_bytes(&corrupted).is_err());
+    
+    for locals in [u32::MAX, 0] {
+        let mut program = compile(PROGRAM);
+        let fib = program.function_index("fib").unwrap() as usize;
+        program.functions[fib].locals = locals;
+        let err = Program::from_bytes(&program.to_bytes().expect("serileştirme hatası"))
+            .expect_err("geçersiz yerel sayısı reddedilmeli");
+        assert!(err.message.contains("'fib'"), "{}", err.message);
+    }
+}
+
+#[test]
+fn reports_runtime_errors_with_source_positions() {
+    let program = compile("fn main() -> int {
+    let zero = 0;
+    return 10 / zero;
+}
+");
+    let err = Vm::new(&program).run().expect_err("sıfıra bölme hatası bekleniyordu");
+    assert_eq!(err.message, "Sıfıra bölme");
+    assert_eq!(err.line, 3);
+}
+
+#[test]
+fn deep_recursion_is_not_limited_by_the_interpreter_depth() {
+    let program = compile("fn depth(n: int) -> int {
+    if n == 0 {
+        return 0;
+    }
+    return depth(n - 1) + 1;
+}
+
+fn main() -> int {
+    return depth(600);
+}
+");
+    assert_eq!(Vm::new(&program).run().expect("çalışma zamanı hatası"), Value::Int(600));
+    
+    let program = compile("fn forever(n: int) -> int {
+    return forever(n + 1);
+}
+
+fn main() -> int {
+    return forever(0);
+}
+");
+    let err = Vm::new(&program).run().expect_err("çağrı derinliği sınırı bekleniyordu");
+    assert!(err.message.contains(&MAX_CALL_DEPTH.to_string()), "{}", err.message);
+}
+
+#[test]
+fn negative_integer_exponents_match_the_compiled_runtime() {
+    let source = "fn p(a: int, b: int) -> int {
+    return a ^ b;
+}
+
+fn main() -> int {
+    return p(2, -1) * 1000 + p(1, -5) * 100 + p(-1, -3) * 10 + p(-1, -4) + (2 ^ -1);
+}
+";
+    // rv_int_pow: 2 ^ -1 = 0, 1 ^ -5 = 1, -1 ^ -3 = -1, -1 ^ -4 = 1.
+    let program = compile(source);
+    assert_eq!(Vm::new(&program).run().expect("çalışma zamanı hatası"), Value::Int(91));
+    
+    let ast = Parser::new(Lexer::new(source).tokenize()).parse().expect("parse hatası");
+    assert_eq!(Interpreter::new().run(&ast).expect("çalışma zamanı hatası"), Value::Int(91));
+    
+    let mut analyzer = SemanticAnalyzer::new();
+    analyzer.load_std_library().expect("standart kütüphane yüklenemedi");
+    analyzer.analyze(&ast);
+    let mut module = lower_program(&ast, analyzer.get_symbol_table()).expect("IR üretim hatası");
+    let mut manager = PassManager::new();
+    manager.add_pass(Box::new(ConstantFolding));
+    manager.run(&mut module).expect("optimizasyon hatası");
+    let main = module.function("main").expect("main fonksiyonu bekleniyor").to_string();
+    assert!(!main.contains("pow int 2"), "2 ^ -1 katlanmalıydı:\n{}", main);
+}
+
+#[test]
+fn break_and_continue_agree_across_engines() {
+    let source = "fn main() -> int {
+    let mut toplam = 0;
+    let mut i = 0;
+    while true {
+        i += 1;
+        if i > 10 {
+            break;
+        }
+        if i % 2 == 0 {
+            continue;
+        }
+        toplam += i;
+    }
+    for j in 0..10 {
+        if j == 3 {
+            continue;
+        }
+        if j == 7 {
+            break;
+        }
+        toplam += j * 100;
+    }
+    return toplam;
+}
+";
+    let program = compile(source);
+    assert_eq!(Vm::new(&program).run().expect("çalışma zamanı hatası"), Value::Int(1825));
+    
+    let ast = Parser::new(Lexer::new(source).tokenize()).parse().expect("parse hatası");
+    assert_eq!(Interpreter::new().run(&ast).expect("çalışma zamanı hatası"), Value::Int(1825));
+    
+    let mut analyzer = SemanticAnalyzer::new();
+    analyzer.load_std_library().expect("standart kütüphane yüklenemedi");
+    assert!(analyzer.analyze(&ast).iter().all(|error| error.is_warning()));
+    let module = lower_program(&ast, analyzer.get_symbol_table()).expect("IR üretim hatası");
+    verify_module(&module).expect("IR doğrulanamadı");
+}
@@ -0,0 +1,103 @@
+mod common;
+
+use std::process::{Command, Output};
+
+use common::{ravun, source_file, TempFile};
+use ravun::codegen::c::toolchain::c_compiler;
+
+const PROGRAM: &str = "let base = 7;
+let mut calls = 0;
+
+fn fib(n: int) -> int {
+    calls += 1;
+    if n < 2 {
+        return n;
+    }
+    return fib(n - 1) + fib(n - 2);
+}
+
+fn label(n: int) -> string {
+    if n % 2 == 0 {
+        return \"çift\";
+    }
+    return \"tek\";
+}
+
+let limit = fib(base);
+
+fn main() -> int {
+    let mut total = 0;
+    for i in 0..limit {
+        if i == 3 {
+            continue;
+        }
+        total += i * i;
+    }
+    let mut wrapped = 2147483647;
+    wrapped += 1;
+    println(\"toplam \" + label(total));
+    if total > 100 && label(limit) == \"tek\" {
+        println(\"koşul\");
+    }
+    if wrapped == -2147483647 - 1 {
+        println(\"taşma sarıldı\");
+    }
+    if ((1 << 33) ~ (-16 >> 34)) == -2 {
+        print(\"kaydırma \");
+        println(\"maskelendi\");
+    }
+    if 1.5 * limit / 4.0 == 4.875 {
+        println(\"ondalık\");
+    }
+    return total - calls * 10;
+}
+";
+
+fn have_c_compiler() -> bool {
+    let available = Command::new(c_compiler()).arg("--version").output().is_ok_and(|output| output.status.success());
+    if !available {
+        eprintln!("C derleyicisi bulunamadı; C arka ucu testi atlanıyor");
+    }
+    available
+}
+
+fn build_and_run(path: &TempFile, level: &str) -> Output {
+    let executable = path.with_extension("bin");
+    let output = ravun().args(["build", "--backend", "c", level, "-o"]).arg(&executable).arg(path).output().expect("ravun çalıştırılamadı");
+    assert!(output.status.success(), "C derlemesi başarısız:\n{}", String::from_utf8_lossy(&output.stderr));
+    
+    Command::new(&executable).output().expect("üretilen program çalıştırılamadı")
+}
+
+#[test]
+fn compiled_programs_match_the_interpreter() {
+    if !have_c_compiler() {
+        return;
+    }
+    
+    let path = source_file("program.rv", PROGRAM);
+    let interpreted = ravun().arg("run").arg(&path).output().expect("ravun çalıştırılamadı");
+    assert!(interpreted.stderr.is_empty(), "{}", String::from_utf8_lossy(&interpreted.stderr));
+    assert_eq!(String::from_utf8_lossy(&interpreted.stdout), "toplam tek\nkoşul\ntaşma sarıldı\nkaydırma maskelendi\nondalık\n");
+    assert_eq!(interpreted.status.code(), Some(231));
+    
+    for level in ["-O0", "-O2"] {
+        let compiled = build_and_run(&path, level);
+        assert_eq!(String::from_utf8_lossy(&compiled.stdout), String::from_utf8_lossy(&interpreted.stdout), "{}", level);
+        assert_eq!(compiled.status.code(), interpreted.status.code(), "{}", level);
+    }
+}
+
+#[test]
+fn runtime_errors_stop_the_compiled_program() {
+    if !have_c_compiler() {
+        return;
+    }
+    
+    let path = source_file("trap.rv", "fn divide(a: int, b: int) -> int {\n    return a / b;\n}\n\nfn main() -> int {\n    println(\"önce\");\n    let x = divide(1, 0);\n    println(\"sonra\");\n    return x;\n}\n");
+    let compiled = build_and_run(&path, "-O0");
+    
+    assert_eq!(compiled.status.code(), Some(1));
+    assert_eq!(String::from_utf8_lossy(&compiled.stdout), "önce\n");
+    assert!(String::from_utf8_lossy(&compiled.stderr).starts_with("Çalışma zamanı hatası: "), "{}", String::from_utf8_lossy(&compiled.stderr));
+}
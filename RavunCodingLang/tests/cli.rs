@@ -0,0 +1,87 @@
+mod common;
+
+use std::fs;
+
+use common::{ravun, source_file};
+
+#[test]
+fn run_is_quiet_and_returns_main_exit_code() {
+    let path = source_file("run.rv", "fn main() -> int {\n    println(\"merhaba\");\n    return 3;\n}\n");
+    
+    for args in [vec!["run"], vec![]] {
+        let output = ravun().args(args).arg(&path).output().expect("ravun çalıştırılamadı");
+        assert_eq!(output.status.code(), Some(3));
+        assert_eq!(String::from_utf8_lossy(&output.stdout), "merhaba\n");
+        assert!(output.stderr.is_empty());
+    }
+}
+
+#[test]
+fn check_reports_semantic_errors_with_exit_code() {
+    let good = source_file("good.rv", "fn main() -> int {\n    return 0;\n}\n");
+    let bad = source_file("bad.rv", "fn main() -> int {\n    return y;\n}\n");
+    
+    let output = ravun().arg("check").arg(&good).output().expect("ravun çalıştırılamadı");
+    assert!(output.status.success());
+    assert!(output.stdout.is_empty());
+    
+    let output = ravun().arg("check").arg(&bad).output().expect("ravun çalıştırılamadı");
+    assert_eq!(output.status.code(), Some(1));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("hata[RV0200]: 'y' tanımlı değil"));
+    assert!(stderr.contains("bad.rv:2:12"));
+}
+
+#[test]
+fn fmt_check_detects_and_fixes_unformatted_files() {
+    let path = source_file("fmt.rv", "fn main()->int{return 0;}\n");
+    
+    let output = ravun().args(["fmt", "--check"]).arg(&path).output().expect("ravun çalıştırılamadı");
+    assert_eq!(output.status.code(), Some(1));
+    
+    let output = ravun().arg("fmt").arg(&path).output().expect("ravun çalıştırılamadı");
+    assert!(output.status.success());
+    assert_eq!(fs::read_to_string(&path).unwrap(), "fn main() -> int {\n    return 0;\n}\n");
+    
+    let output = ravun().args(["fmt", "--check"]).arg(&path).output().expect("ravun çalıştırılamadı");
+    assert!(output.status.success());
+}
+
+#[test]
+fn unknown_command_prints_usage() {
+    let output = ravun().arg("derle").output().expect("ravun çalıştırılamadı");
+    assert_eq!(output.status.code(), Some(2));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Kullanım"));
+}
+
+#[test]
+fn emit_options_reject_a_different_backend() {
+    let path = source_file("emit.rv", "fn main() -> int {\n    return 4;\n}\n");
+    
+    for args in [["--emit-asm", "--backend", "c"], ["--backend", "wasm", "--emit-llvm"]] {
+        let output = ravun().arg("build").args(args).arg(&path).output().expect("ravun çalıştırılamadı");
+        assert_eq!(output.status.code(), Some(2), "{:?}", args);
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        assert!(stderr.contains("seçeneği '--backend"), "{:?} çakışması bildirilmeli:\n{}", args, stderr);
+    }
+    
+    let wat = path.with_extension("wat");
+    let output = ravun().args(["build", "--emit-wat", "--backend=wasm", "-o"]).arg(&wat).arg(&path).output().expect("ravun çalıştırılamadı");
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    assert!(fs::read_to_string(&wat).unwrap().contains("(module"));
+}
+
+#[test]
+fn ir_initializes_non_constant_globals_before_main() {
+    let path = source_file("globals.rv", "fn f(n: int) -> int {\n    return n * 10;\n}\n\nlet g = f(2) + 1;\n\nfn main() -> int {\n    return g + 1;\n}\n");
+    
+    let output = ravun().args(["ir", "-O0"]).arg(&path).output().expect("ravun çalıştırılamadı");
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    let ir = String::from_utf8_lossy(&output.stdout);
+    assert!(ir.contains("global mut @g: int = 0"), "{}", ir);
+    assert!(ir.contains("fn @ravun.init() -> void {\nbb0:\n    %0 = call int @f(2)\n    %1 = add int %0, 1\n    store @g, %1\n    ret\n}"), "{}", ir);
+    assert!(ir.contains("fn @main() -> int {\nbb0:\n    call void @ravun.init()\n"), "{}", ir);
+    
+    let output = ravun().arg("run").arg(&path).output().expect("ravun çalıştırılamadı");
+    assert_eq!(output.status.code(), Some(22));
+}
@@ -0,0 +1,93 @@
+// Her test dosyası bu yardımcıların yalnızca bir kısmını kullanır.
+#![allow(dead_code)]
+
+use std::ffi::OsStr;
+use std::fs;
+use std::ops::Deref;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use ravun::ir::{lower_program, Module};
+use ravun::lexer::lexer::Lexer;
+use ravun::parser::ast::{AstNode, AstNodeType};
+use ravun::parser::parser::Parser;
+use ravun::semantics::analyzer::SemanticAnalyzer;
+use ravun::semantics::error::SemanticError;
+
+pub fn parse(source: &str) -> AstNode {
+    Parser::new(Lexer::new(source).tokenize()).parse().expect("parse hatası")
+}
+
+pub fn analyze(source: &str) -> (AstNode, SemanticAnalyzer, Vec<SemanticError>) {
+    let ast = parse(source);
+    let mut analyzer = SemanticAnalyzer::new();
+    analyzer.load_std_library().expect("standart kütüphane yüklenemedi");
+    let errors = analyzer.analyze(&ast).into_iter().filter(|e| !e.is_warning()).collect();
+    (ast, analyzer, errors)
+}
+
+pub fn lower(source: &str) -> Module {
+    let (ast, analyzer, errors) = analyze(source);
+    assert!(errors.is_empty(), "semantik hatalar: {:?}", errors);
+    lower_program(&ast, analyzer.get_symbol_table()).expect("IR üretim hatası")
+}
+
+pub fn tree(node: &AstNode) -> String {
+    match node.node_type {
+        AstNodeType::BinaryExpr => format!("({} {} {})", node.value.as_deref().unwrap_or("?"), tree(&node.children[0]), tree(&node.children[1])),
+        AstNodeType::UnaryExpr => format!("({} {})", node.value.as_deref().unwrap_or("?"), tree(&node.children[0])),
+        AstNodeType::GroupExpr => tree(&node.children[0]),
+        _ => node.value.clone().unwrap_or_default(),
+    }
+}
+
+pub fn ravun() -> Command {
+    let mut command = Command::new(env!("CARGO_BIN_EXE_RavunCodingLang"));
+    command.env("LANG", "tr_TR.UTF-8").env_remove("LC_ALL").env_remove("LC_MESSAGES");
+    command
+}
+
+static NEXT_DIR: AtomicUsize = AtomicUsize::new(0);
+
+// Dosya düşürüldüğünde, derleyicinin yanına yazdığı çıktılarla birlikte dizini de silinir.
+pub struct TempFile {
+    dir: PathBuf,
+    path: PathBuf,
+}
+
+impl Deref for TempFile {
+    type Target = Path;
+    
+    fn deref(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl AsRef<Path> for TempFile {
+    fn as_ref(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl AsRef<OsStr> for TempFile {
+    fn as_ref(&self) -> &OsStr {
+        self.path.as_os_str()
+    }
+}
+
+impl Drop for TempFile {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.dir);
+    }
+}
+
+pub fn source_file(name: &str, source: &str) -> TempFile {
+    let id = NEXT_DIR.fetch_add(1, Ordering::Relaxed);
+    let dir = std::env::temp_dir().join(format!("ravun-test-{}-{}", std::process::id(), id));
+    fs::create_dir_all(&dir).expect("geçici dizin oluşturulamadı");
+    
+    let path = dir.join(name);
+    fs::write(&path, source).expect("kaynak dosya yazılamadı");
+    TempFile { dir, path }
+}
@@ -0,0 +1,91 @@
+use ravun::diagnostics::{Diagnostic, Renderer, Severity, SourceFile};
+use ravun::lexer::lexer::Lexer;
+use ravun::lexer::span::Span;
+use ravun::parser::error::ParseError;
+use ravun::parser::parser::Parser;
+use ravun::semantics::analyzer::SemanticAnalyzer;
+
+const SOURCE: &str = "fn main() -> int {
+    let sayı = 1;
+    let metin = \"a\";
+    return sayı + metin;
+}
+";
+
+#[test]
+fn renders_snippet_with_labels_notes_and_help() {
+    let file = SourceFile::new(0, "örnek.rv", SOURCE);
+    let left = SOURCE.find("sayı + ").expect("ifade bekleniyor");
+    let right = SOURCE.find("metin;").expect("ifade bekleniyor");
+    let declaration = SOURCE.find("metin =").expect("tanım bekleniyor");
+    
+    let diagnostic = Diagnostic::error("tip uyuşmazlığı")
+        .with_code("RV0001")
+        .with_label(Span::new(0, left, right + "metin".len()), "int ile string toplanamaz")
+        .with_secondary_label(Span::new(0, declaration, declaration + "metin".len()), "string olarak tanımlandı")
+        .with_note("'+' işleci aynı tipte iki sayı bekler")
+        .with_help("dönüşüm fonksiyonu kullanın");
+    
+    let expected = "\
+hata[RV0001]: tip uyuşmazlığı
+ --> örnek.rv:4:12
+  |
+3 |     let metin = \"a\";
+  |         ----- string olarak tanımlandı
+4 |     return sayı + metin;
+  |            ^^^^^^^^^^^^ int ile string toplanamaz
+  = not: '+' işleci aynı tipte iki sayı bekler
+  = yardım: dönüşüm fonksiyonu kullanın
+";
+    assert_eq!(Renderer::new(false).render(&diagnostic, &file), expected);
+}
+
+#[test]
+fn parser_errors_point_at_offending_token() {
+    let source = "fn main() -> int {\n    let x = ;\n    return 0;\n}\n";
+    let errors: Vec<Diagnostic> = Parser::new(Lexer::new(source).tokenize()).parse().expect_err("parser hatası bekleniyor")
+        .iter().map(ParseError::to_diagnostic).collect();
+    
+    let span = errors[0].primary_span().expect("birincil etiket bekleniyor");
+    assert_eq!(span.text(source), Some(";"));
+    assert_eq!(errors[0].severity, Severity::Error);
+    
+    let rendered = Renderer::new(false).render(&errors[0], &SourceFile::new(0, "x.rv", source));
+    assert!(rendered.contains(" --> x.rv:2:13\n"));
+    assert!(rendered.contains("  |             ^ ifade bekleniyor\n"));
+}
+
+#[test]
+fn semantic_errors_convert_to_labelled_diagnostics() {
+    let source = "fn f() -> int {\n    return 1;\n}\nfn f() -> int {\n    return 2;\n}\nfn main() -> int {\n    return y;\n}\n";
+    let file = SourceFile::new(0, "x.rv", source);
+    let ast = Parser::new(Lexer::new(source).tokenize()).parse().expect("parse hatası");
+    
+    let mut analyzer = SemanticAnalyzer::new();
+    let diagnostics: Vec<Diagnostic> = analyzer.analyze(&ast).iter()
+        .map(|error| error.to_diagnostic(&file))
+        .collect();
+    
+    let redefinition = diagnostics.iter().find(|d| d.message.contains("daha önce")).expect("yeniden tanım hatası bekleniyor");
+    assert_eq!(redefinition.labels.len(), 2);
+    assert!(redefinition.labels.iter().any(|label| !label.primary && label.message == "ilk tanım burada"));
+    
+    let undefined = diagnostics.iter().find(|d| d.message.contains("'y'")).expect("tanımsız değişken hatası bekleniyor");
+    assert_eq!(undefined.primary_span().and_then(|span| span.text(source)), Some("y"));
+    
+    let rendered = analyzer.format_diagnostics(&file, &Renderer::new(false));
+    assert!(rendered.contains(" --> x.rv:8:12\n"));
+}
+
+#[test]
+fn color_is_optional() {
+    let file = SourceFile::new(0, "x.rv", "let a = 1;\n");
+    let diagnostic = Diagnostic::warning("kullanılmayan değişken").with_label(Span::new(0, 4, 5), "");
+    
+    let plain = Renderer::new(false).render(&diagnostic, &file);
+    assert!(plain.starts_with("uyarı: kullanılmayan değişken\n"));
+    assert!(!plain.contains('\x1b'));
+    
+    let colored = Renderer::new(true).render(&diagnostic, &file);
+    assert!(colored.starts_with("\x1b[1;33muyarı\x1b[0m"));
+}
@@ -0,0 +1,95 @@
+mod common;
+
+use std::collections::HashSet;
+
+use common::ravun;
+use ravun::diagnostics::{Diagnostic, ErrorCode, Lang, SourceFile};
+use ravun::lexer::lexer::Lexer;
+use ravun::parser::error::ParseError;
+use ravun::parser::parser::Parser;
+use ravun::semantics::analyzer::SemanticAnalyzer;
+
+fn diagnostics(source: &str) -> Vec<Diagnostic> {
+    let file = SourceFile::new(0, "ornek.rv", source);
+    let mut lexer = Lexer::new(source);
+    let tokens = lexer.tokenize();
+    let mut errors = lexer.errors().to_vec();
+    
+    match Parser::new(tokens).parse() {
+        Ok(ast) if errors.is_empty() => {
+            let mut analyzer = SemanticAnalyzer::new();
+            analyzer.load_std_library().expect("standart kütüphane yüklenemedi");
+            analyzer.analyze(&ast).iter().map(|error| error.to_diagnostic(&file)).collect()
+        },
+        result => {
+            errors.extend(result.err().unwrap_or_default().iter().map(ParseError::to_diagnostic));
+            errors
+        },
+    }
+}
+
+#[test]
+fn codes_are_unique_and_round_trip() {
+    let mut numbers = HashSet::new();
+    
+    for &code in ErrorCode::ALL {
+        assert!(numbers.insert(code.number()), "{} birden fazla kez kullanılmış", code);
+        assert_eq!(ErrorCode::parse(&code.to_string()), Some(code));
+        
+        for lang in [Lang::Tr, Lang::En] {
+            assert!(!code.title_in(lang).is_empty(), "{} için başlık eksik", code);
+            assert!(!code.explanation_in(lang).is_empty(), "{} için açıklama eksik", code);
+        }
+    }
+    
+    assert_eq!(ErrorCode::UndefinedVariable.to_string(), "RV0200");
+    assert_eq!(ErrorCode::parse("rv0200"), Some(ErrorCode::UndefinedVariable));
+    assert_eq!(ErrorCode::parse("RV9998"), None);
+}
+
+#[test]
+fn examples_trigger_their_code_and_fixes_compile() {
+    for &code in ErrorCode::ALL {
+        assert!(!code.failing_example().is_empty() && !code.fixed_example().is_empty(), "{} için örnek eksik", code);
+        
+        let failing = diagnostics(code.failing_example());
+        assert!(failing.iter().any(|diagnostic| diagnostic.code.as_deref() == Some(code.to_string().as_str())),
+            "{} hatalı örneği bu kodu üretmedi: {:?}", code, failing);
+        
+        let fixed = diagnostics(code.fixed_example());
+        assert!(fixed.iter().all(|diagnostic| !diagnostic.is_error() && diagnostic.code.as_deref() != Some(code.to_string().as_str())),
+            "{} düzeltilmiş örneği hâlâ hata veriyor: {:?}", code, fixed);
+    }
+}
+
+#[test]
+fn every_diagnostic_carries_a_code() {
+    let source = "fn main() -> int {\n    let x = 1 $ 2;\n    let y: int = \"a\";\n    return z;\n}\n";
+    let lexed = diagnostics(source);
+    assert!(!lexed.is_empty() && lexed.iter().all(|diagnostic| diagnostic.code.is_some()));
+    
+    let source = "let kullanilmayan = 1;\n\nfn main() -> int {\n    let y: int = \"a\";\n    if y {\n        return z;\n    }\n    return topla(1);\n}\n";
+    let analyzed = diagnostics(source);
+    let codes: Vec<&str> = analyzed.iter().filter_map(|diagnostic| diagnostic.code.as_deref()).collect();
+    assert_eq!(codes.len(), analyzed.len());
+    for expected in ["RV0500", "RV0300", "RV0303", "RV0200", "RV0201"] {
+        assert!(codes.contains(&expected), "{} bekleniyordu: {:?}", expected, codes);
+    }
+}
+
+#[test]
+fn explain_prints_explanation_and_examples() {
+    let output = ravun().args(["explain", "RV0204"]).output().expect("ravun çalıştırılamadı");
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.starts_with("RV0204: Değiştirilemez değişkene atama"));
+    assert!(stdout.contains("Hatalı örnek:\n\n    fn main() -> int {\n        let x = 1;"));
+    assert!(stdout.contains("Düzeltilmiş örnek:\n\n    fn main() -> int {\n        let mut x = 1;"));
+    
+    let output = ravun().args(["explain", "RV0204", "--lang", "en"]).output().expect("ravun çalıştırılamadı");
+    assert!(String::from_utf8_lossy(&output.stdout).contains("Failing example:"));
+    
+    let output = ravun().args(["explain", "RV1234"]).output().expect("ravun çalıştırılamadı");
+    assert_eq!(output.status.code(), Some(1));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Bilinmeyen hata kodu: RV1234"));
+}
@@ -0,0 +1,89 @@
+mod common;
+
+use common::{ravun, source_file};
+use ravun::diagnostics::{to_json, to_sarif, Diagnostic, SourceFile};
+use ravun::lexer::lexer::Lexer;
+use ravun::lexer::span::Span;
+use ravun::utils::Json;
+
+const BAD: &str = "fn main() -> int {\n    let x = 1;\n    return x + y;\n}\n";
+
+#[test]
+fn json_lines_carry_code_severity_span_and_notes() {
+    let file = SourceFile::new(0, "a.rv", "let ğ = 1;\n");
+    let diagnostic = Diagnostic::warning("kullanılmıyor")
+        .with_code("RV0001")
+        .with_label(Span::new(0, 4, 6), "")
+        .with_note("not metni");
+    
+    let json = Json::parse(&to_json(&diagnostic, Some(&file)).to_string()).expect("geçerli JSON bekleniyor");
+    assert_eq!(json.get("code").and_then(Json::as_str), Some("RV0001"));
+    assert_eq!(json.get("severity").and_then(Json::as_str), Some("warning"));
+    assert_eq!(json.get("is_warning"), Some(&Json::Bool(true)));
+    assert_eq!(json.get("file").and_then(Json::as_str), Some("a.rv"));
+    assert_eq!(json.path(&["span", "start"]).and_then(Json::as_u64), Some(4));
+    assert_eq!(json.path(&["span", "end_column"]).and_then(Json::as_u64), Some(6));
+    assert_eq!(json.get("notes"), Some(&Json::Array(vec![Json::string("not metni")])));
+}
+
+#[test]
+fn sarif_log_lists_results_with_regions() {
+    let source = "let a = 1 @ 2;\n";
+    let file = SourceFile::new(0, "b.rv", source);
+    let mut lexer = Lexer::new(source);
+    lexer.tokenize();
+    
+    let sarif = to_sarif(lexer.errors(), &file);
+    assert_eq!(sarif.get("version").and_then(Json::as_str), Some("2.1.0"));
+    
+    let run = &sarif.get("runs").and_then(Json::as_array).expect("runs bekleniyor")[0];
+    assert_eq!(run.path(&["tool", "driver", "name"]).and_then(Json::as_str), Some("ravun"));
+    
+    let results = run.get("results").and_then(Json::as_array).expect("results bekleniyor");
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].get("level").and_then(Json::as_str), Some("error"));
+    
+    let region = &results[0].get("locations").and_then(Json::as_array).expect("konum bekleniyor")[0];
+    let region = region.path(&["physicalLocation", "region"]).expect("bölge bekleniyor");
+    assert_eq!(region.get("startColumn").and_then(Json::as_u64), Some(11));
+    assert_eq!(region.get("byteLength").and_then(Json::as_u64), Some(1));
+}
+
+#[test]
+fn cli_emits_one_json_object_per_line() {
+    let path = source_file("bad.rv", BAD);
+    
+    let output = ravun().arg("check").arg("--error-format=json").arg(&path).output().expect("ravun çalıştırılamadı");
+    assert_eq!(output.status.code(), Some(1));
+    
+    let lines: Vec<Json> = String::from_utf8_lossy(&output.stderr).lines()
+        .map(|line| Json::parse(line).expect("her satır JSON olmalı"))
+        .collect();
+    
+    let undefined = lines.iter()
+        .find(|line| line.get("message").and_then(Json::as_str) == Some("'y' tanımlı değil"))
+        .expect("tanımsız değişken hatası bekleniyor");
+    assert_eq!(undefined.path(&["span", "line"]).and_then(Json::as_u64), Some(3));
+    assert_eq!(undefined.path(&["span", "column"]).and_then(Json::as_u64), Some(16));
+    
+    assert!(lines.iter().all(|line| line.get("file") != Some(&Json::Null)), "özet satırı tanılama olarak yazılmamalı");
+}
+
+#[test]
+fn cli_writes_sarif_log_to_stdout() {
+    let good = source_file("good.rv", "fn main() -> int {\n    return 0;\n}\n");
+    let bad = source_file("syntax.rv", "fn main() -> int {\n    let a = ;\n}\n");
+    
+    let output = ravun().args(["check", "--error-format", "sarif"]).arg(&good).output().expect("ravun çalıştırılamadı");
+    assert!(output.status.success());
+    let sarif = Json::parse(&String::from_utf8_lossy(&output.stdout)).expect("SARIF JSON olmalı");
+    assert_eq!(sarif.path(&["runs"]).and_then(Json::as_array).map(|runs| runs.len()), Some(1));
+    
+    let output = ravun().args(["check", "--error-format=sarif"]).arg(&bad).output().expect("ravun çalıştırılamadı");
+    assert_eq!(output.status.code(), Some(1));
+    let sarif = Json::parse(&String::from_utf8_lossy(&output.stdout)).expect("SARIF JSON olmalı");
+    let results = sarif.get("runs").and_then(Json::as_array).and_then(|runs| runs[0].get("results"))
+        .and_then(Json::as_array).expect("results bekleniyor");
+    assert!(!results.is_empty());
+    assert!(output.stderr.is_empty(), "{}", String::from_utf8_lossy(&output.stderr));
+}
@@ -0,0 +1,66 @@
+use ravun::lexer::lexer::Lexer;
+use ravun::parser::parser::Parser;
+use ravun::utils::format_source;
+
+const MESSY: &str = "// başlık yorumu
+let mut total:float=0.0;   // sondaki yorum
+struct Point { x: int, y: int, }
+
+
+fn add(a:int,b:int)->int{return a+b;}
+fn main() -> int {
+    /* blok yorumu */
+    let s = \"a\\\"b\\n\";
+  if total>1.0{println(s);}else{ print(\"x\"); }
+    for i in 0..10 { total += -1.5 * (2.0 - 1.0); }
+    while false {}
+    return add(1, -2);
+}
+";
+
+const FORMATTED: &str = "// başlık yorumu
+let mut total: float = 0.0; // sondaki yorum
+struct Point {
+    x: int,
+    y: int,
+}
+
+fn add(a: int, b: int) -> int {
+    return a + b;
+}
+fn main() -> int {
+    /* blok yorumu */
+    let s = \"a\\\"b\\n\";
+    if total > 1.0 {
+        println(s);
+    } else {
+        print(\"x\");
+    }
+    for i in 0..10 {
+        total += -1.5 * (2.0 - 1.0);
+    }
+    while false {}
+    return add(1, -2);
+}
+";
+
+#[test]
+fn formats_messy_source_and_keeps_comments() {
+    let formatted = format_source(MESSY).expect("biçimlendirme hatası");
+    assert_eq!(formatted, FORMATTED);
+}
+
+#[test]
+fn formatting_is_idempotent_and_preserves_the_ast() {
+    let formatted = format_source(MESSY).expect("biçimlendirme hatası");
+    assert_eq!(format_source(&formatted).expect("biçimlendirme hatası"), formatted);
+    
+    let original = Parser::new(Lexer::new(MESSY).tokenize()).parse().expect("parse hatası");
+    let reformatted = Parser::new(Lexer::new(&formatted).tokenize()).parse().expect("parse hatası");
+    assert_eq!(format!("{:?}", original), format!("{:?}", reformatted));
+}
+
+#[test]
+fn invalid_tokens_are_rejected() {
+    assert!(format_source("let x = 1 $ 2;").is_err());
+}
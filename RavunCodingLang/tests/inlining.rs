@@ -0,0 +1,162 @@
+mod common;
+
+use common::analyze;
+use ravun::diagnostics::MessageId;
+use ravun::ir::{lower_program, parse_module, Module};
+use ravun::optimizer::inline::{inline_cost, Inliner, INLINE_THRESHOLD};
+use ravun::optimizer::{OptimizationHints, PassManager};
+
+fn run(module: &mut Module, inliner: Inliner) -> Vec<String> {
+    let mut manager = PassManager::new();
+    manager.set_verify(true);
+    manager.add_pass(Box::new(inliner));
+    manager.run(module).expect("optimizasyon hatası");
+    manager.remarks().iter().map(|remark| remark.to_string()).collect()
+}
+
+fn inline_source(source: &str) -> (Module, Vec<String>) {
+    let (ast, analyzer, errors) = analyze(source);
+    assert!(errors.is_empty(), "semantik hatalar: {:?}", errors);
+    
+    let mut module = lower_program(&ast, analyzer.get_symbol_table()).expect("IR üretim hatası");
+    let hints = OptimizationHints::from_analyzer(&analyzer);
+    let remarks = run(&mut module, Inliner::new(&hints.small_functions));
+    (module, remarks)
+}
+
+fn calls(module: &Module, caller: &str, callee: &str) -> bool {
+    let function = module.function(caller).expect("fonksiyon bekleniyor").to_string();
+    function.contains(&format!("@{}(", callee))
+}
+
+fn inlined(callee: &str, reason: &str) -> String {
+    format!("[inline] @main: {}", MessageId::RemarkInlined.format(&[&callee, &reason]))
+}
+
+fn not_inlined(callee: &str, reason: &str) -> String {
+    format!("[inline] @main: {}", MessageId::RemarkNotInlined.format(&[&callee, &reason]))
+}
+
+#[test]
+fn inline_attribute_forces_functions_over_the_threshold() {
+    let (module, remarks) = inline_source("#[inline]
+fn big(x: int) -> int {
+    let mut y = x;
+    y = y * 3 + 1;
+    y = y * 5 - 2;
+    y = y * 7 + 3;
+    y = y * 11 - 4;
+    y = y * 13 + 5;
+    y = y * 17 - 6;
+    y = y * 19 + 7;
+    y = y * 23 - 8;
+    y = y * 29 + 9;
+    y = y * 31 - 10;
+    y = y * 37 + 11;
+    y = y * 41 - 12;
+    y = y * 43 + 13;
+    return y;
+}
+
+fn main() -> int {
+    return big(2);
+}
+");
+    
+    let big = module.function("big").expect("big fonksiyonu bekleniyor");
+    assert!(inline_cost(big, &[]) > INLINE_THRESHOLD, "fonksiyon eşiği aşmalı:\n{}", big);
+    assert!(!calls(&module, "main", "big"), "{}", module);
+    assert_eq!(remarks, [inlined("big", MessageId::InlineMarkedInline.text())]);
+}
+
+#[test]
+fn noinline_attribute_keeps_small_functions_as_calls() {
+    let (module, remarks) = inline_source("#[noinline]
+fn one() -> int {
+    return 1;
+}
+
+fn two() -> int {
+    return 2;
+}
+
+fn main() -> int {
+    return one() + two();
+}
+");
+    
+    assert!(calls(&module, "main", "one"), "{}", module);
+    assert!(!calls(&module, "main", "two"), "{}", module);
+    assert_eq!(remarks, [
+        not_inlined("one", MessageId::InlineMarkedNoinline.text()),
+        inlined("two", &MessageId::InlineWithinThreshold.format(&[&1, &INLINE_THRESHOLD])),
+    ]);
+}
+
+#[test]
+fn recursive_functions_are_never_inlined() {
+    let (module, remarks) = inline_source("#[inline]
+fn fact(n: int) -> int {
+    if n < 2 {
+        return 1;
+    }
+    return n * fact(n - 1);
+}
+
+fn even(n: int) -> bool {
+    return n == 0 || !even(n - 1);
+}
+
+fn main() -> int {
+    if even(4) {
+        return fact(5);
+    }
+    return 0;
+}
+");
+    
+    assert!(calls(&module, "main", "even") && calls(&module, "main", "fact"), "{}", module);
+    assert!(calls(&module, "fact", "fact") && calls(&module, "even", "even"), "{}", module);
+    
+    let recursive = MessageId::InlineRecursive.text();
+    for remark in [not_inlined("even", recursive), not_inlined("fact", recursive)] {
+        assert!(remarks.contains(&remark), "'{}' bekleniyordu: {:?}", remark, remarks);
+    }
+    assert!(remarks.iter().all(|remark| remark.ends_with(recursive)), "{:?}", remarks);
+}
+
+#[test]
+fn small_functions_are_inlined_only_within_the_threshold() {
+    let source = "fn @scale(%0: int, %1: int) -> int {
+bb0:
+    %2 = mul int %0, %1
+    %3 = add int %2, %0
+    ret %3
+}
+
+fn @other(%0: int) -> int {
+bb0:
+    ret %0
+}
+
+fn @main() -> int {
+bb0:
+    %0 = call int @scale(4, 5)
+    %1 = call int @scale(%0, %0)
+    %2 = call int @other(%1)
+    ret %2
+}
+";
+    let mut module = parse_module(source).expect("IR ayrıştırılamadı");
+    let remarks = run(&mut module, Inliner::new(&["scale".to_string()]).with_threshold(2));
+    
+    // Sabit argümanlar maliyeti düşürdüğü için yalnızca ilk çağrı eşiğin altında kalır.
+    assert_eq!(remarks, [
+        inlined("scale", &MessageId::InlineWithinThreshold.format(&[&1, &2])),
+        not_inlined("scale", &MessageId::InlineOverThreshold.format(&[&3, &2])),
+        not_inlined("other", MessageId::InlineNotSmall.text()),
+    ]);
+    let main = module.function("main").expect("main fonksiyonu bekleniyor").to_string();
+    assert_eq!(main.matches("call int @scale(").count(), 1, "{}", main);
+    assert!(main.contains("mul int 4, 5"), "{}", main);
+}
@@ -0,0 +1,90 @@
+mod common;
+
+use common::analyze;
+use ravun::interpreter::{Interpreter, Value};
+
+fn run(source: &str) -> Value {
+    let (ast, _, errors) = analyze(source);
+    assert!(errors.is_empty(), "semantik hatalar: {:?}", errors);
+    Interpreter::new().run(&ast).expect("çalışma zamanı hatası")
+}
+
+#[test]
+fn break_and_continue_affect_only_the_innermost_loop() {
+    let source = "fn main() -> int {
+    let mut toplam = 0;
+    for i in 0..5 {
+        let mut j = 0;
+        while true {
+            j += 1;
+            if j > i {
+                break;
+            }
+            if j % 2 == 0 {
+                continue;
+            }
+            toplam += 10;
+        }
+        if i == 3 {
+            continue;
+        }
+        toplam += 1;
+    }
+    return toplam;
+}
+";
+    // i = 0..4 için tek j sayısı: 0, 1, 1, 2, 2; i == 3 dışında her turda 1 eklenir.
+    assert_eq!(run(source), Value::Int(64));
+}
+
+#[test]
+fn break_leaves_a_for_loop_over_a_string() {
+    let source = "fn main() -> int {
+    let mut n = 0;
+    for c in \"abcxyz\" {
+        if c == \"x\" {
+            break;
+        }
+        n += 1;
+    }
+    return n;
+}
+";
+    assert_eq!(run(source), Value::Int(3));
+}
+
+#[test]
+fn compound_assignment_reads_the_target_before_the_right_operand() {
+    let source = "let mut acc: int = 1;
+
+fn bump() -> int {
+    acc = 100;
+    return 5;
+}
+
+fn main() -> int {
+    acc += bump();
+    return acc;
+}
+";
+    assert_eq!(run(source), Value::Int(6));
+}
+
+#[test]
+fn compound_assignment_keeps_the_target_type() {
+    let source = "fn main() -> int {
+    let mut f: float = 1.0;
+    f += 2;
+    f *= 1.5;
+    let mut x = 7;
+    x -= 2;
+    x /= 2;
+    x <<= 3;
+    if f == 4.5 {
+        return x;
+    }
+    return 0;
+}
+";
+    assert_eq!(run(source), Value::Int(16));
+}
@@ -0,0 +1,84 @@
+mod common;
+
+use common::{analyze, lower};
+use ravun::ir::{lower_program, parse_module, verify_module, Module};
+use ravun::optimizer::{OptLevel, OptimizationHints, PassManager};
+
+const PROGRAM: &str = "let limit = 10;
+let mut total = 0;
+#[inline]
+fn twice(n: int) -> int {
+    return n * 2;
+}
+
+let scale = twice(limit) + 1;
+
+#[noinline]
+fn mean(a: float, b: int) -> float {
+    return (a + b) / 2.0;
+}
+
+fn describe(flag: bool) -> string {
+    if flag && total >= 0 || !flag {
+        return \"evet \\\"tırnak\\\"\\n\\tsekme\";
+    }
+    return \"\";
+}
+
+fn main() -> int {
+    let mut i = 0;
+    while i < limit {
+        i += 1;
+        if i % 3 == 0 {
+            continue;
+        }
+        if i > 8 {
+            break;
+        }
+        total += -i;
+    }
+    for j in 0..4 {
+        total ~= ~j << 2;
+    }
+    let ratio = mean(0.001, scale) * 0.1;
+    println(describe(ratio > 0.5));
+    return total;
+}
+";
+
+fn round_trip(module: &Module) -> Module {
+    let printed = module.to_string();
+    let parsed = parse_module(&printed).unwrap_or_else(|error| panic!("IR yeniden ayrıştırılamadı: {}\n{}", error, printed));
+    
+    assert_eq!(parsed.to_string(), printed);
+    verify_module(&parsed).expect("ayrıştırılan modül doğrulanamadı");
+    parsed
+}
+
+#[test]
+fn lowered_programs_print_parse_and_print_back_identically() {
+    let module = lower(PROGRAM);
+    let printed = module.to_string();
+    for expected in ["@ravun.init", " inline", " noinline", "phi int", "itof float", "\"evet \\\"tırnak\\\"\\n\\tsekme\"", "0.001"] {
+        assert!(printed.contains(expected), "'{}' bekleniyordu:\n{}", expected, printed);
+    }
+    
+    // İndirgenmiş modülde değer ve blok sayaçları da metinden yeniden kurulabilmeli.
+    assert_eq!(round_trip(&module), module);
+}
+
+#[test]
+fn optimized_programs_round_trip_at_o1_and_o2() {
+    let (ast, analyzer, errors) = analyze(PROGRAM);
+    assert!(errors.is_empty(), "semantik hatalar: {:?}", errors);
+    let hints = OptimizationHints::from_analyzer(&analyzer);
+    
+    for level in [OptLevel::O1, OptLevel::O2] {
+        let mut module = lower_program(&ast, analyzer.get_symbol_table()).expect("IR üretim hatası");
+        let mut manager = PassManager::for_level(level, &hints);
+        manager.set_verify(true);
+        manager.run(&mut module).expect("optimizasyon hatası");
+        
+        round_trip(&module);
+    }
+}
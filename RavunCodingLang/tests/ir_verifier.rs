@@ -0,0 +1,52 @@
+use ravun::diagnostics::MessageId;
+use ravun::ir::{parse_module, verify_module, Module};
+
+fn messages(module: &Module) -> Vec<String> {
+    let errors = verify_module(module).expect_err("doğrulayıcı modülü reddetmeli");
+    errors.into_iter().map(|error| error.message).collect()
+}
+
+#[test]
+fn rejects_calls_to_undefined_functions() {
+    let module = parse_module("fn @main() -> int {\nbb0:\n    %0 = call int @nope(1)\n    ret %0\n}\n").expect("IR ayrıştırılamadı");
+    let errors = verify_module(&module).expect_err("tanımsız çağrı reddedilmeli");
+    assert!(errors.iter().any(|error| error.message.contains("@nope")), "{:?}", errors);
+    
+    let module = parse_module("fn @ravun.init() -> void {\nbb0:\n    ret\n}\n\nfn @main() -> int {\nbb0:\n    call void @ravun.init()\n    call void @println(\"x\")\n    ret 0\n}\n").expect("IR ayrıştırılamadı");
+    verify_module(&module).expect("modül fonksiyonları ve yerleşikler kabul edilmeli");
+    
+    let module = parse_module("fn @main() -> int {\nbb0:\n    %0 = call int @print(1)\n    ret %0\n}\n").expect("IR ayrıştırılamadı");
+    assert!(verify_module(&module).is_err(), "yerleşikler değer döndürmez");
+}
+
+#[test]
+fn rejects_uses_not_dominated_by_their_definition() {
+    let module = parse_module("fn @main() -> int {\nbb0:\n    %0 = gt bool 3, 2\n    br %0, bb1, bb2\nbb1:\n    %1 = add int 1, 2\n    jmp bb2\nbb2:\n    ret %1\n}\n").expect("IR ayrıştırılamadı");
+    
+    assert_eq!(messages(&module), vec![MessageId::VerifyNotDominated.format(&[&"%1"])]);
+}
+
+#[test]
+fn rejects_blocks_without_a_terminator() {
+    let mut module = parse_module("fn @main() -> int {\nbb0:\n    jmp bb1\nbb1:\n    ret 0\n}\n").expect("IR ayrıştırılamadı");
+    module.functions[0].blocks[1].terminator = None;
+    
+    assert_eq!(messages(&module), vec![MessageId::VerifyMissingTerminator.text().to_string()]);
+}
+
+#[test]
+fn rejects_phi_incomings_that_do_not_match_the_predecessors() {
+    let module = parse_module("fn @main() -> int {\nbb0:\n    %0 = gt bool 3, 2\n    br %0, bb1, bb2\nbb1:\n    jmp bb2\nbb2:\n    %1 = phi int [1, bb0], [2, bb3]\n    ret %1\nbb3:\n    ret 0\n}\n").expect("IR ayrıştırılamadı");
+    let errors = messages(&module);
+    
+    let phi = "%1 = phi int [1, bb0], [2, bb3]";
+    assert!(errors.contains(&MessageId::VerifyPhiNotPredecessor.format(&[&phi, &"bb3"])), "{:?}", errors);
+    assert!(errors.contains(&MessageId::VerifyPhiMissingIncoming.format(&[&phi, &"bb1"])), "{:?}", errors);
+}
+
+#[test]
+fn rejects_operands_of_the_wrong_type() {
+    let module = parse_module("fn @main() -> int {\nbb0:\n    %0 = gt bool 3, 2\n    %1 = add int 1, %0\n    ret %1\n}\n").expect("IR ayrıştırılamadı");
+    
+    assert_eq!(messages(&module), vec![MessageId::VerifyTypeMismatch.format(&[&"%1 = add int 1, %0", &"int", &"bool"])]);
+}
@@ -0,0 +1,81 @@
+mod common;
+
+use common::{analyze, parse, tree};
+use ravun::bytecode::{compile_program, Vm};
+use ravun::interpreter::{Interpreter, Value};
+use ravun::ir::{lower_program, verify_module};
+use ravun::lexer::lexer::Lexer;
+use ravun::lexer::token::TokenType;
+
+const PROGRAM: &str = "let mut calls = 0;
+
+fn touch(result: bool) -> bool {
+    calls += 1;
+    return result;
+}
+
+fn main() -> int {
+    let a = false && touch(true);
+    let b = true || touch(false);
+    let c = true && touch(true);
+    let d = false || touch(true);
+    let mut r = 0;
+    if !a {
+        r += 1;
+    }
+    if b {
+        r += 2;
+    }
+    if c && !(a || !d) {
+        r += 4;
+    }
+    return r * 10 + calls;
+}
+";
+
+#[test]
+fn lexes_and_binds_logical_operators() {
+    let types: Vec<TokenType> = Lexer::new("!a && b || c != d").tokenize().into_iter().map(|t| t.token_type).collect();
+    assert_eq!(&types[..8], &[
+        TokenType::Not, TokenType::Identifier, TokenType::And, TokenType::Identifier,
+        TokenType::Or, TokenType::Identifier, TokenType::NotEqual, TokenType::Identifier,
+    ]);
+    
+    let program = parse("fn main() -> int {\n    a || !b && c == d || e;\n}\n");
+    let body = program.children[0].children.last().expect("gövde bekleniyor");
+    assert_eq!(tree(&body.children[0].children[0]), "(|| (|| a (&& (! b) (== c d))) e)");
+}
+
+#[test]
+fn interpreter_and_vm_skip_the_right_operand() {
+    let (ast, _, errors) = analyze(PROGRAM);
+    assert!(errors.is_empty(), "semantik hatalar: {:?}", errors);
+    
+    assert_eq!(Interpreter::new().run(&ast).expect("çalışma zamanı hatası"), Value::Int(72));
+    
+    let program = compile_program(&ast).expect("bytecode üretim hatası");
+    let mut vm = Vm::new(&program);
+    assert_eq!(vm.run().expect("çalışma zamanı hatası"), Value::Int(72));
+    assert_eq!(vm.global("calls"), Some(&Value::Int(2)), "sağ taraf yalnızca iki kez değerlendirilmeli");
+}
+
+#[test]
+fn lowers_to_branches_merged_by_a_phi() {
+    let source = "fn both(a: bool, b: bool) -> bool {\n    return a && b;\n}\n\nfn either(a: bool, b: bool) -> bool {\n    return a || !b;\n}\n";
+    // Kaynakta main yok; analiz yalnızca sembol tablosu için çalıştırılır.
+    let (ast, analyzer, _) = analyze(source);
+    let module = lower_program(&ast, analyzer.get_symbol_table()).expect("IR üretim hatası");
+    verify_module(&module).expect("IR doğrulanamadı");
+    
+    let ir = module.to_string();
+    assert!(ir.contains("bb0:\n    br %0, bb1, bb2\nbb1:\n    jmp bb2\nbb2:\n    %2 = phi bool [%0, bb0], [%1, bb1]"), "{}", ir);
+    assert!(ir.contains("bb0:\n    br %0, bb2, bb1\nbb1:\n    %2 = not bool %1\n    jmp bb2\nbb2:\n    %3 = phi bool [%0, bb0], [%2, bb1]"), "{}", ir);
+}
+
+#[test]
+fn rejects_non_bool_operands() {
+    let (_, _, errors) = analyze("fn main() -> int {\n    let a = 1 && true;\n    let b = !2;\n    return 0;\n}\n");
+    assert_eq!(errors.len(), 2, "iki hata bekleniyordu: {:?}", errors);
+    assert!(errors[0].message.contains("'&&'") && errors[0].message.contains("int"), "{}", errors[0].message);
+    assert!(errors[1].message.contains("'!'") && errors[1].message.contains("int"), "{}", errors[1].message);
+}
@@ -0,0 +1,222 @@
+mod common;
+
+use common::lower;
+use ravun::optimizer::constant_folding::ConstantFolding;
+use ravun::optimizer::dead_code::DeadCodeElimination;
+use ravun::optimizer::licm::LoopInvariantCodeMotion;
+use ravun::optimizer::loop_unroll::LoopUnroll;
+use ravun::optimizer::simplify_cfg::SimplifyCfg;
+use ravun::optimizer::{Pass, PassManager};
+
+fn optimize(source: &str, passes: Vec<Box<dyn Pass>>) -> (String, Vec<String>) {
+    let mut module = lower(source);
+    let mut manager = PassManager::new();
+    manager.set_verify(true);
+    for pass in passes {
+        manager.add_pass(pass);
+    }
+    manager.run(&mut module).expect("optimizasyon hatası");
+    
+    for function in &mut module.functions {
+        function.renumber();
+    }
+    let remarks = manager.remarks().iter().map(|r| r.to_string()).collect();
+    (module.to_string(), remarks)
+}
+
+fn with_cleanup(pass: Box<dyn Pass>) -> Vec<Box<dyn Pass>> {
+    vec![pass, Box::new(SimplifyCfg), Box::new(DeadCodeElimination)]
+}
+
+#[test]
+fn fully_unrolls_small_constant_range() {
+    let source = "fn main() -> int {
+    let mut s = 0;
+    for i in 0..3 {
+        s += i * 3;
+    }
+    return s;
+}
+";
+    let (ir, remarks) = optimize(source, with_cleanup(Box::new(LoopUnroll::new())));
+    
+    assert_eq!(ir, "fn @main() -> int {
+bb0:
+    %0 = mul int 0, 3
+    %1 = add int 0, %0
+    %2 = add int 0, 1
+    %3 = mul int %2, 3
+    %4 = add int %1, %3
+    %5 = add int %2, 1
+    %6 = mul int %5, 3
+    %7 = add int %4, %6
+    ret %7
+}
+");
+    assert_eq!(remarks, ["[loop-unroll] @main: bb1 başlıklı döngü tamamen açıldı (3 yineleme)"]);
+}
+
+#[test]
+fn fully_unrolled_loop_folds_to_constant() {
+    let source = "fn main() -> int {
+    let mut s = 0;
+    for i in 0..4 {
+        for j in 0..2 {
+            s += i * j + 1;
+        }
+    }
+    return s;
+}
+";
+    let passes: Vec<Box<dyn Pass>> = vec![
+        Box::new(LoopUnroll::new()),
+        Box::new(ConstantFolding),
+        Box::new(SimplifyCfg),
+        Box::new(DeadCodeElimination),
+    ];
+    let (ir, _) = optimize(source, passes);
+    
+    assert_eq!(ir, "fn @main() -> int {
+bb0:
+    ret 14
+}
+");
+}
+
+#[test]
+fn partially_unrolls_larger_constant_range() {
+    let source = "fn main() -> int {
+    let mut s = 0;
+    for i in 0..6 {
+        s += i;
+    }
+    return s;
+}
+";
+    let (ir, remarks) = optimize(source, with_cleanup(Box::new(LoopUnroll::new())));
+    
+    assert_eq!(ir, "fn @main() -> int {
+bb0:
+    jmp bb1
+bb1:
+    %0 = phi int [0, bb0], [%5, bb2]
+    %1 = phi int [0, bb0], [%6, bb2]
+    %2 = lt bool %1, 6
+    br %2, bb2, bb3
+bb2:
+    %3 = add int %0, %1
+    %4 = add int %1, 1
+    %5 = add int %3, %4
+    %6 = add int %4, 1
+    jmp bb1
+bb3:
+    ret %0
+}
+");
+    assert_eq!(remarks, ["[loop-unroll] @main: bb1 başlıklı döngü 2 katsayısıyla açıldı (6 yineleme)"]);
+}
+
+#[test]
+fn partial_unroll_factor_must_divide_trip_count() {
+    let source = "fn main() -> int {
+    let mut s = 0;
+    for i in 0..7 {
+        s += i;
+    }
+    return s;
+}
+";
+    let (before, _) = optimize(source, Vec::new());
+    let (after, remarks) = optimize(source, vec![Box::new(LoopUnroll::new().with_factor(3))]);
+    
+    assert_eq!(before, after);
+    assert_eq!(remarks, ["[loop-unroll] @main: bb1 başlıklı döngü açılmadı: 7 yineleme, boyut 5"]);
+}
+
+#[test]
+fn keeps_loops_with_unknown_trip_count() {
+    let source = "fn main() -> int {
+    let mut s = 0;
+    let n = 5;
+    let mut i = 0;
+    while i < n * s {
+        s += 1;
+        i += 1;
+    }
+    return s;
+}
+";
+    let (before, _) = optimize(source, Vec::new());
+    let (after, remarks) = optimize(source, vec![Box::new(LoopUnroll::new())]);
+    
+    assert_eq!(before, after);
+    assert_eq!(remarks, [
+        "[loop-unroll] @main: bb1 başlıklı döngü açılmadı: döngü koşulu sabit bir sınırla karşılaştırma değil",
+    ]);
+}
+
+#[test]
+fn hoists_loop_invariant_computations() {
+    let source = "fn f(n: int, k: int) -> int {
+    let mut s = 0;
+    let mut i = 0;
+    while i < n {
+        s += k * 4 + 1;
+        i += 1;
+    }
+    return s;
+}
+
+fn main() -> int {
+    return f(3, 2);
+}
+";
+    let (ir, remarks) = optimize(source, vec![Box::new(LoopInvariantCodeMotion::new())]);
+    
+    assert_eq!(ir, "fn @f(%0: int, %1: int) -> int {
+bb0:
+    %2 = mul int %1, 4
+    %3 = add int %2, 1
+    jmp bb1
+bb1:
+    %4 = phi int [0, bb0], [%7, bb2]
+    %5 = phi int [0, bb0], [%8, bb2]
+    %6 = lt bool %5, %0
+    br %6, bb2, bb3
+bb2:
+    %7 = add int %4, %3
+    %8 = add int %5, 1
+    jmp bb1
+bb3:
+    ret %4
+}
+
+fn @main() -> int {
+bb0:
+    %0 = call int @f(3, 2)
+    ret %0
+}
+");
+    assert_eq!(remarks, ["[licm] @f: bb1 başlıklı döngüden 2 değişmez komut bb0 bloğuna taşındı"]);
+}
+
+#[test]
+fn does_not_hoist_trapping_or_variant_instructions() {
+    let source = "fn f(n: int, d: int) -> int {
+    let mut s = 0;
+    for i in 0..n {
+        s += n / d + i * 2;
+    }
+    return s;
+}
+
+fn main() -> int {
+    return f(3, 1);
+}
+";
+    let (before, _) = optimize(source, Vec::new());
+    let (after, remarks) = optimize(source, vec![Box::new(LoopInvariantCodeMotion::new())]);
+    
+    assert_eq!(before, after);
+    assert!(remarks.is_empty());
+}
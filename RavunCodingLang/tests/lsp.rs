@@ -0,0 +1,102 @@
+use std::io::Cursor;
+
+use ravun::lsp::{run, Json, Server};
+
+const SOURCE: &str = "let mut total = 0;\nfn sq(a: int) -> int {\n    return a * a;\n}\nfn main() -> int {\n    let çş = sq(3);\n    total += çş;\n    return y;\n}\n";
+
+fn message(text: &str) -> Json {
+    Json::parse(text).expect("geçersiz JSON")
+}
+
+fn open_document(server: &mut Server, text: &str) -> Json {
+    server.handle(&message(r#"{"jsonrpc":"2.0","id":1,"method":"initialize","params":{}}"#));
+    
+    let open = Json::object(vec![
+        ("jsonrpc", Json::string("2.0")),
+        ("method", Json::string("textDocument/didOpen")),
+        ("params", Json::object(vec![
+            ("textDocument", Json::object(vec![
+                ("uri", Json::string("file:///a.rv")),
+                ("text", Json::string(text)),
+            ])),
+        ])),
+    ]);
+    server.handle(&open).pop().expect("tanılama bildirimi bekleniyor")
+}
+
+fn position_request(server: &mut Server, method: &str, line: u32, character: u32) -> Json {
+    let request = message(&format!(
+        r#"{{"jsonrpc":"2.0","id":2,"method":"{}","params":{{"textDocument":{{"uri":"file:///a.rv"}},"position":{{"line":{},"character":{}}}}}}}"#,
+        method, line, character
+    ));
+    let response = server.handle(&request).pop().expect("yanıt bekleniyor");
+    response.get("result").cloned().expect("sonuç bekleniyor")
+}
+
+#[test]
+fn publishes_semantic_diagnostics_as_lsp_ranges() {
+    let mut server = Server::new();
+    let notification = open_document(&mut server, SOURCE);
+    
+    let diagnostics = notification.path(&["params", "diagnostics"]).and_then(Json::as_array).unwrap();
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].path(&["range", "start", "line"]).and_then(Json::as_u64), Some(7));
+    assert_eq!(diagnostics[0].path(&["range", "start", "character"]).and_then(Json::as_u64), Some(11));
+    assert_eq!(diagnostics[0].path(&["range", "end", "character"]).and_then(Json::as_u64), Some(12));
+    assert_eq!(diagnostics[0].get("severity").and_then(Json::as_u64), Some(1));
+}
+
+#[test]
+fn hover_and_definition_use_symbol_positions() {
+    let mut server = Server::new();
+    open_document(&mut server, SOURCE);
+    
+    let hover = position_request(&mut server, "textDocument/hover", 5, 14);
+    let contents = hover.path(&["contents", "value"]).and_then(Json::as_str).unwrap();
+    assert!(contents.contains("fn sq(a: int) -> int"));
+    
+    let definition = position_request(&mut server, "textDocument/definition", 6, 14);
+    assert_eq!(definition.path(&["range", "start", "line"]).and_then(Json::as_u64), Some(5));
+    assert_eq!(definition.path(&["range", "start", "character"]).and_then(Json::as_u64), Some(8));
+    
+    let definition = position_request(&mut server, "textDocument/definition", 2, 11);
+    assert_eq!(definition.path(&["range", "start", "line"]).and_then(Json::as_u64), Some(1));
+    assert_eq!(definition.path(&["range", "start", "character"]).and_then(Json::as_u64), Some(6));
+    
+    assert_eq!(position_request(&mut server, "textDocument/hover", 3, 0), Json::Null);
+}
+
+#[test]
+fn completion_offers_keywords_and_visible_symbols() {
+    let mut server = Server::new();
+    open_document(&mut server, SOURCE);
+    
+    let completions = position_request(&mut server, "textDocument/completion", 6, 4);
+    let labels: Vec<&str> = completions.as_array().unwrap().iter()
+        .filter_map(|item| item.get("label").and_then(Json::as_str))
+        .collect();
+    
+    for expected in ["let", "println", "total", "sq", "çş"] {
+        assert!(labels.contains(&expected), "'{}' tamamlama önerisi bekleniyor", expected);
+    }
+    assert!(!labels.contains(&"a"), "başka fonksiyonun parametresi önerilmemeli");
+}
+
+#[test]
+fn stdio_session_follows_content_length_framing() {
+    let frame = |body: &str| format!("Content-Length: {}\r\n\r\n{}", body.len(), body);
+    let input = [
+        frame(r#"{"jsonrpc":"2.0","id":1,"method":"initialize","params":{}}"#),
+        frame(r#"{"jsonrpc":"2.0","id":2,"method":"shutdown"}"#),
+        frame(r#"{"jsonrpc":"2.0","method":"exit"}"#),
+    ].concat();
+    
+    let mut output = Vec::new();
+    let code = run(&mut Cursor::new(input.into_bytes()), &mut output).expect("G/Ç hatası");
+    assert_eq!(code, 0);
+    
+    let output = String::from_utf8(output).unwrap();
+    assert_eq!(output.matches("Content-Length: ").count(), 2);
+    assert!(output.contains(r#""hoverProvider":true"#));
+    assert!(output.ends_with(r#"{"jsonrpc":"2.0","id":2,"result":null}"#));
+}
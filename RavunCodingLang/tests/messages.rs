@@ -0,0 +1,104 @@
+mod common;
+
+use std::collections::BTreeSet;
+
+use common::{ravun, source_file};
+use ravun::diagnostics::{Lang, MessageId};
+use ravun::interpreter::interpreter::binary_operation;
+use ravun::interpreter::Value;
+
+fn placeholders(template: &str) -> BTreeSet<usize> {
+    template.split('{').skip(1)
+        .filter_map(|part| part.split_once('}'))
+        .filter_map(|(index, _)| index.parse().ok())
+        .collect()
+}
+
+#[test]
+fn every_message_has_matching_placeholders_in_both_languages() {
+    for id in MessageId::ALL {
+        let turkish = id.template(Lang::Tr);
+        let english = id.template(Lang::En);
+        
+        assert!(!turkish.is_empty() && !english.is_empty(), "{:?} için boş çeviri", id);
+        assert_eq!(placeholders(turkish), placeholders(english), "{:?} yer tutucuları uyuşmuyor", id);
+    }
+}
+
+#[test]
+fn arguments_are_placed_by_index() {
+    let turkish = MessageId::AssignTypeMismatch.format_in(Lang::Tr, &[&"int", &"string"]);
+    let english = MessageId::AssignTypeMismatch.format_in(Lang::En, &[&"int", &"string"]);
+    
+    assert_eq!(turkish, "Tip uyuşmazlığı: 'int' tipine 'string' tipi atanamaz");
+    assert_eq!(english, "Type mismatch: a value of type 'string' cannot be assigned to 'int'");
+    assert_eq!(Lang::parse("en_US.UTF-8"), Some(Lang::En));
+    assert_eq!(Lang::parse("tr"), Some(Lang::Tr));
+    assert_eq!(Lang::parse("de"), None);
+}
+
+#[test]
+fn lang_option_switches_diagnostics_to_english() {
+    let path = source_file("lang.rv", "fn main() -> int {\n    return y;\n}\n");
+    
+    let output = ravun().args(["check", "--lang", "en"]).arg(&path).output().expect("ravun çalıştırılamadı");
+    assert_eq!(output.status.code(), Some(1));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("error[RV0200]: 'y' is not defined"));
+    assert!(stderr.contains("error: Aborting due to 1 semantic error(s)"));
+    
+    let output = ravun().args(["check", "--lang=tr"]).arg(&path).env("LANG", "en_US.UTF-8").output().expect("ravun çalıştırılamadı");
+    assert!(String::from_utf8_lossy(&output.stderr).contains("hata[RV0200]: 'y' tanımlı değil"));
+}
+
+#[test]
+fn lang_environment_selects_usage_language() {
+    let output = ravun().arg("derle").env("LANG", "en_GB.UTF-8").output().expect("ravun çalıştırılamadı");
+    assert_eq!(output.status.code(), Some(2));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Unknown command: derle"));
+    assert!(stderr.contains("Usage: ravun"));
+    
+    let output = ravun().arg("derle").env("LANG", "en_GB.UTF-8").env("LC_ALL", "tr_TR.UTF-8").output().expect("ravun çalıştırılamadı");
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Kullanım: ravun"));
+}
+
+#[test]
+fn runtime_errors_and_remarks_follow_the_language() {
+    let path = source_file("runtime.rv", "fn f(n: int) -> int {\n    if n < 1 {\n        return 0;\n    }\n    return f(n - 1);\n}\n\nfn main() -> int {\n    return 10 / f(3);\n}\n");
+    
+    let output = ravun().args(["run", "--lang", "en"]).arg(&path).output().expect("ravun çalıştırılamadı");
+    assert_eq!(String::from_utf8_lossy(&output.stderr).trim_end(), "error: Runtime error: line 9, column 15 - Division by zero");
+    
+    let output = ravun().args(["ir", "-O2", "--remarks", "--dump-ir-passes", "--lang", "en"]).arg(&path).output().expect("ravun çalıştırılamadı");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("note: [inline] @main: @f was not inlined: recursive function"), "{}", stderr);
+    assert!(stderr.contains("; ---- after pass 'inline' (unchanged) ----"), "{}", stderr);
+    assert!(!stderr.contains("geçiş") && !stderr.contains("edilmedi"), "{}", stderr);
+    
+    let error = binary_operation("&", Value::Float(1.5), Value::Int(2)).expect_err("float & int hata vermeli");
+    assert_eq!(error, MessageId::InvalidIntegerOperands.format(&[&"&", &"float", &"int"]));
+}
+
+#[test]
+fn ir_and_bytecode_errors_follow_the_language() {
+    let path = source_file("nested.rv", "fn main() -> int {\n    fn inner() -> int {\n        return 1;\n    }\n    return inner();\n}\n");
+    
+    let output = ravun().args(["ir", "--lang", "en"]).arg(&path).output().expect("ravun çalıştırılamadı");
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(
+        String::from_utf8_lossy(&output.stderr).trim_end(),
+        "error: IR generation error: line 2, column 5 - Nested declarations cannot be lowered to IR",
+    );
+    
+    let bytecode = path.with_extension("rvc");
+    let output = ravun().args(["build", "--emit-bytecode", "--lang", "en", "-o"]).arg(&bytecode).arg(&path).output().expect("ravun çalıştırılamadı");
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(
+        String::from_utf8_lossy(&output.stderr).trim_end(),
+        "error: Bytecode generation error: line 2, column 5 - Nested function declarations cannot be compiled to bytecode",
+    );
+    
+    let output = ravun().arg("ir").arg(&path).output().expect("ravun çalıştırılamadı");
+    assert!(String::from_utf8_lossy(&output.stderr).contains("satır 2, sütun 5 - İç içe tanımlamalar IR'a dönüştürülemez"));
+}
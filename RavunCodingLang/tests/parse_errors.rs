@@ -0,0 +1,69 @@
+use ravun::diagnostics::{ErrorCode, Lang, set_language};
+use ravun::lexer::lexer::Lexer;
+use ravun::lexer::token::TokenType;
+use ravun::parser::error::{ParseContext, ParseError};
+use ravun::parser::parser::Parser;
+
+fn parse_errors(source: &str) -> Vec<ParseError> {
+    Parser::new(Lexer::new(source).tokenize()).parse().expect_err("parser hatası bekleniyor")
+}
+
+#[test]
+fn missing_separator_reports_every_accepted_token() {
+    let source = "fn topla(a: int b: int) -> int {\n    return a + b;\n}\n";
+    let errors = parse_errors(source);
+    
+    match &errors[0] {
+        ParseError::UnexpectedToken { span, found, expected, context } => {
+            assert_eq!(span.text(source), Some("b"));
+            assert_eq!(found, &TokenType::Identifier);
+            assert_eq!(expected, &vec![TokenType::Comma, TokenType::RightParen]);
+            assert_eq!(context, &Some(ParseContext::FunctionParameters));
+        },
+        other => panic!("beklenmeyen hata: {:?}", other),
+    }
+    assert_eq!(errors[0].code(), ErrorCode::ExpectedToken);
+}
+
+#[test]
+fn expression_errors_keep_the_innermost_context() {
+    let source = "fn main() -> int {\n    return topla(1, );\n}\n";
+    let errors = parse_errors(source);
+    
+    assert!(matches!(errors[0], ParseError::ExpectedExpression { found: TokenType::RightParen, .. }));
+    assert_eq!(errors[0].context(), Some(ParseContext::CallArguments));
+    assert_eq!(errors[0].span().text(source), Some(")"));
+    
+    let errors = parse_errors("fn main() -> int {\n    let x = (1 + 2;\n    return x;\n}\n");
+    assert_eq!(errors[0].context(), Some(ParseContext::GroupExpression));
+    assert!(errors[0].expected().contains(&TokenType::RightParen));
+}
+
+#[test]
+fn end_of_input_is_reported_as_unexpected_eof() {
+    let source = "fn main() -> int {\n    return 1;\n";
+    let errors = parse_errors(source);
+    
+    match &errors[0] {
+        ParseError::UnexpectedEof { span, expected, context } => {
+            assert_eq!(span.start, source.rfind(';').unwrap() + 1);
+            assert!(expected.contains(&TokenType::RightBrace));
+            assert_eq!(context, &Some(ParseContext::Block));
+        },
+        other => panic!("beklenmeyen hata: {:?}", other),
+    }
+    assert_eq!(errors[0].code(), ErrorCode::UnexpectedEof);
+}
+
+#[test]
+fn parse_errors_convert_to_diagnostics() {
+    set_language(Lang::Tr);
+    let errors = parse_errors("fn topla(a: int b: int) -> int {\n    return a + b;\n}\n");
+    let diagnostic = errors[0].to_diagnostic();
+    
+    assert_eq!(diagnostic.message, "Beklenen: ',' veya ')', bulunan: tanımlayıcı");
+    assert_eq!(diagnostic.code.as_deref(), Some("RV0100"));
+    assert_eq!(diagnostic.labels[0].message, "',' veya ')' bekleniyor");
+    assert_eq!(diagnostic.notes, vec!["fonksiyon parametreleri ayrıştırılırken".to_string()]);
+    assert_eq!(errors[0].to_string(), diagnostic.message);
+}
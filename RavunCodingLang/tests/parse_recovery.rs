@@ -0,0 +1,98 @@
+mod common;
+
+use common::{ravun, source_file};
+use ravun::lexer::lexer::Lexer;
+use ravun::parser::ast::{AstNode, AstNodeType};
+use ravun::parser::error::ParseError;
+use ravun::parser::parser::Parser;
+use ravun::semantics::analyzer::SemanticAnalyzer;
+
+fn parse_recovering(source: &str) -> (AstNode, Vec<ParseError>) {
+    Parser::new(Lexer::new(source).tokenize()).parse_recovering()
+}
+
+fn function<'a>(program: &'a AstNode, name: &str) -> &'a AstNode {
+    program.children.iter()
+        .find(|item| item.node_type == AstNodeType::FuncDecl && item.value.as_deref() == Some(name))
+        .unwrap_or_else(|| panic!("'{}' fonksiyonu bulunamadı", name))
+}
+
+#[test]
+fn errors_inside_blocks_do_not_abort_the_function() {
+    let source = "fn main() -> int {\n    let x = ;\n    let y = 2;\n    y + * 3;\n    return y;\n}\n\nfn ikinci() -> int {\n    return 1;\n}\n";
+    let (program, errors) = parse_recovering(source);
+    
+    assert_eq!(errors.len(), 2, "iki sözdizimi hatası bekleniyordu: {:?}", errors);
+    assert_eq!(errors[0].span().text(source), Some(";"));
+    assert_eq!(errors[1].span().text(source), Some("*"));
+    
+    let body = function(&program, "main").children.last().expect("gövde bekleniyor");
+    let kinds: Vec<&AstNodeType> = body.children.iter().map(|statement| &statement.node_type).collect();
+    assert_eq!(kinds, [&AstNodeType::VarDecl, &AstNodeType::VarDecl, &AstNodeType::Error, &AstNodeType::ReturnStmt]);
+    assert_eq!(body.children[0].span().text(source), Some("let x = ;"));
+    assert!(body.children[0].children[0].is_error(), "başlangıç değeri hata düğümü olmalı");
+    
+    function(&program, "ikinci");
+}
+
+#[test]
+fn list_items_recover_at_commas_and_closing_parens() {
+    let source = "fn main() -> int {\n    return topla(1, , 3) + (2 * );\n}\n";
+    let (program, errors) = parse_recovering(source);
+    
+    assert_eq!(errors.len(), 2, "{:?}", errors);
+    let body = function(&program, "main").children.last().expect("gövde bekleniyor");
+    let value = &body.children[0].children[0];
+    let call = &value.children[0];
+    assert_eq!(call.node_type, AstNodeType::CallExpr);
+    assert!(call.children[1].is_error());
+    assert_eq!(call.children.len(), 3);
+    assert!(value.children[1].children[0].is_error());
+}
+
+#[test]
+fn missing_separators_keep_the_rest_of_the_list() {
+    let source = "fn topla(a: int b: int) -> int {\n    return a + b;\n}\n";
+    let (program, errors) = parse_recovering(source);
+    
+    assert_eq!(errors.len(), 1, "{:?}", errors);
+    assert_eq!(errors[0].span().text(source), Some("b"));
+    
+    let parameters: Vec<&str> = function(&program, "topla").children.iter()
+        .filter(|child| child.node_type == AstNodeType::ParamDecl)
+        .filter_map(|child| child.value.as_deref())
+        .collect();
+    assert_eq!(parameters, ["a", "b"]);
+}
+
+#[test]
+fn check_reports_syntax_and_semantic_errors_in_one_run() {
+    let path = source_file("karisik.rv", "fn main() -> int {\n    let x = 1 +;\n    let y: int = \"a\";\n    return x * y + z;\n}\n");
+    
+    let output = ravun().arg("check").arg(&path).output().expect("ravun çalıştırılamadı");
+    assert_eq!(output.status.code(), Some(1));
+    
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let syntax = stderr.find("hata[RV0102]").expect("sözdizimi hatası bekleniyordu");
+    let mismatch = stderr.find("hata[RV0300]").expect("tip hatası bekleniyordu");
+    let undefined = stderr.find("hata[RV0200]: 'z' tanımlı değil").expect("tanımsız değişken hatası bekleniyordu");
+    assert!(syntax < mismatch && mismatch < undefined);
+    assert!(!stderr.contains("'x' tanımlı değil"), "okunamayan bildirim tanımsız sayılmamalı:\n{}", stderr);
+    assert!(stderr.contains("hata: 1 sözdizimi hatası nedeniyle durduruldu"));
+    assert!(!stderr.contains("uyarı"));
+}
+
+#[test]
+fn truncated_main_keeps_its_declaration() {
+    let source = "fn main() -> int {\n    let x = 1;\n";
+    let (program, errors) = parse_recovering(source);
+    
+    assert_eq!(errors.len(), 1, "{:?}", errors);
+    assert!(matches!(errors[0], ParseError::UnexpectedEof { .. }));
+    assert!(function(&program, "main").children.last().is_some_and(AstNode::is_error));
+    
+    let mut analyzer = SemanticAnalyzer::new();
+    analyzer.load_std_library().expect("standart kütüphane yüklenemedi");
+    let semantic_errors: Vec<_> = analyzer.analyze(&program).into_iter().filter(|e| !e.is_warning()).collect();
+    assert!(semantic_errors.is_empty(), "takip eden hata beklenmiyordu: {:?}", semantic_errors);
+}
@@ -0,0 +1,72 @@
+mod common;
+
+use std::collections::HashSet;
+
+use common::tree;
+use ravun::lexer::lexer::Lexer;
+use ravun::parser::ast::AstNode;
+use ravun::parser::error::ParseError;
+use ravun::parser::parser::Parser;
+use ravun::parser::precedence::{infix_operator, Associativity, INFIX_OPERATORS, PREFIX_OPERATORS};
+
+fn parse_expression(expression: &str) -> Result<AstNode, Vec<ParseError>> {
+    let source = format!("fn main() -> int {{\n    {};\n}}\n", expression);
+    let program = Parser::new(Lexer::new(&source).tokenize()).parse()?;
+    let body = program.children[0].children.last().expect("gövde bekleniyor");
+    Ok(body.children[0].children[0].clone())
+}
+
+fn shape(expression: &str) -> String {
+    tree(&parse_expression(expression).expect("parse hatası"))
+}
+
+#[test]
+fn power_is_right_associative_and_others_are_left_associative() {
+    assert_eq!(shape("2 ^ 3 ^ 2"), "(^ 2 (^ 3 2))");
+    assert_eq!(shape("10 - 4 - 3"), "(- (- 10 4) 3)");
+    assert_eq!(shape("8 / 4 / 2"), "(/ (/ 8 4) 2)");
+    assert_eq!(shape("a < b == c > d"), "(== (< a b) (> c d))");
+    assert_eq!(shape("1 + 2 * 3 ^ 2 % 4"), "(+ 1 (% (* 2 (^ 3 2)) 4))");
+    assert_eq!(shape("(1 + 2) * 3"), "(* (+ 1 2) 3)");
+}
+
+#[test]
+fn prefix_minus_binds_between_factor_and_power() {
+    assert_eq!(shape("-2 ^ 2"), "(- (^ 2 2))");
+    assert_eq!(shape("-a * b"), "(* (- a) b)");
+    assert_eq!(shape("2 * -3 + 1"), "(+ (* 2 (- 3)) 1)");
+    assert_eq!(shape("2 ^ -1"), "(^ 2 (- 1))");
+    assert_eq!(shape("--x"), "(- (- x))");
+}
+
+#[test]
+fn assignment_is_lowest_and_right_associative() {
+    assert_eq!(shape("x = y = 1 + 2"), "(= x (= y (+ 1 2)))");
+    assert_eq!(shape("x += y * 2"), "(+= x (* y 2))");
+    
+    let errors = parse_expression("a + b = 1").expect_err("geçersiz atama hedefi bekleniyordu");
+    assert!(matches!(errors[0], ParseError::InvalidAssignmentTarget { .. }));
+    let errors = parse_expression("-x = 1").expect_err("geçersiz atama hedefi bekleniyordu");
+    assert!(matches!(errors[0], ParseError::InvalidAssignmentTarget { .. }));
+}
+
+#[test]
+fn operator_table_is_consistent() {
+    let mut seen = HashSet::new();
+    
+    for operator in INFIX_OPERATORS {
+        assert!(seen.insert(operator.symbol), "'{}' tabloda birden fazla kez var", operator.symbol);
+        assert_eq!(infix_operator(&operator.token_type), Some(operator));
+        
+        let (left, right) = operator.binding_power();
+        match operator.associativity {
+            Associativity::Left => assert!(left < right, "'{}' sola bağlı olmalı", operator.symbol),
+            Associativity::Right => assert!(left > right, "'{}' sağa bağlı olmalı", operator.symbol),
+        }
+    }
+    
+    for operator in PREFIX_OPERATORS {
+        let (_, right) = operator.binding_power();
+        assert!(INFIX_OPERATORS.iter().any(|infix| infix.binding_power().0 > right), "önek operatörü her şeyden sıkı bağlanmamalı");
+    }
+}
@@ -0,0 +1,74 @@
+use ravun::interpreter::Value;
+use ravun::repl::{is_complete, Session};
+use ravun::semantics::types::Type;
+
+#[test]
+fn symbols_and_values_persist_across_inputs() {
+    let mut session = Session::new().expect("oturum oluşturulamadı");
+    
+    assert_eq!(session.eval("let x = 3").expect("değerlendirme hatası").value, None);
+    session.eval("fn sq(a: int) -> int {\n    return a * a;\n}\n").expect("değerlendirme hatası");
+    
+    let evaluation = session.eval("sq(x) + 1").expect("değerlendirme hatası");
+    assert_eq!(evaluation.value, Some((Value::Int(10), Type::Int)));
+    
+    let evaluation = session.eval("sq(x) + 0.5").expect("değerlendirme hatası");
+    assert_eq!(evaluation.value, Some((Value::Float(9.5), Type::Float)));
+    
+    let symbols = session.symbols();
+    assert!(symbols.contains("x : int"));
+    assert!(symbols.contains("sq : fn(int) -> int"));
+}
+
+#[test]
+fn failed_inputs_do_not_leave_symbols_behind() {
+    let mut session = Session::new().expect("oturum oluşturulamadı");
+    
+    assert!(session.eval("let y: int = \"metin\"").is_err());
+    assert!(session.eval("let z = 1 / 0").is_err());
+    assert!(!session.symbols().contains("y :"));
+    assert!(!session.symbols().contains("z :"));
+    
+    session.eval("let z = 2").expect("başarısız girdi sembol bırakmamalı");
+}
+
+#[test]
+fn a_failing_statement_rolls_back_the_whole_input() {
+    let mut session = Session::new().expect("oturum oluşturulamadı");
+    session.eval("let mut total = 1").expect("değerlendirme hatası");
+    
+    assert!(session.eval("let q = 1; total = 5; let r = 1 / 0;").is_err());
+    assert!(session.eval("q").is_err(), "başarısız girdideki 'q' tanımlı kalmamalı");
+    assert!(!session.symbols().contains("q :"));
+    
+    let evaluation = session.eval("total").expect("değerlendirme hatası");
+    assert_eq!(evaluation.value, Some((Value::Int(1), Type::Int)), "atama da geri alınmalı");
+    
+    session.eval("let q = 2").expect("'q' yeniden tanımlanabilmeli");
+    assert_eq!(session.eval("q + total").expect("değerlendirme hatası").value, Some((Value::Int(3), Type::Int)));
+}
+
+#[test]
+fn type_and_ast_commands_do_not_evaluate() {
+    let mut session = Session::new().expect("oturum oluşturulamadı");
+    session.eval("let mut n = 1").expect("değerlendirme hatası");
+    
+    assert_eq!(session.type_of("n > 2").expect("tip hatası"), Type::Bool);
+    assert_eq!(session.type_of("n * 1.5").expect("tip hatası"), Type::Float);
+    assert!(session.type_of("bilinmeyen").is_err());
+    assert!(session.type_of("let a = 1").is_err());
+    
+    let ast = session.ast("-n + 1").expect("ast hatası");
+    assert_eq!(format!("{:?}", ast), "BinaryExpr(+) { UnaryExpr(-) { IdentifierExpr(n) }, LiteralExpr(1) }");
+    
+    let evaluation = session.eval("n").expect("değerlendirme hatası");
+    assert_eq!(evaluation.value, Some((Value::Int(1), Type::Int)));
+}
+
+#[test]
+fn unbalanced_braces_need_more_input() {
+    assert!(!is_complete("fn f() -> int {\n"));
+    assert!(!is_complete("if true { println(\"}\");\n"));
+    assert!(is_complete("fn f() -> int {\n    return 1;\n}\n"));
+    assert!(is_complete("1 + 2"));
+}
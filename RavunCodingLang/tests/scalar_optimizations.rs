@@ -0,0 +1,147 @@
+use ravun::ir::parse_module;
+use ravun::optimizer::cse::CommonSubexpressionElimination;
+use ravun::optimizer::dead_code::DeadCodeElimination;
+use ravun::optimizer::{Pass, PassManager};
+
+const NEXT: &str = "fn @next(%0: int) -> int {
+bb0:
+    call void @println(\"next\")
+    %1 = add int %0, 1
+    ret %1
+}
+";
+
+fn optimize(function: &str, pass: Box<dyn Pass>) -> (String, bool) {
+    let mut module = parse_module(&format!("{}\n{}", NEXT, function)).expect("IR ayrıştırılamadı");
+    let mut manager = PassManager::new();
+    manager.set_verify(true);
+    manager.add_pass(pass);
+    let changed = manager.run(&mut module).expect("optimizasyon hatası");
+    
+    let function = module.functions.last_mut().expect("fonksiyon bekleniyor");
+    function.renumber();
+    (function.to_string(), changed)
+}
+
+#[test]
+fn dce_removes_unused_values_and_unreachable_blocks() {
+    let before = "fn @main() -> int {
+bb0:
+    %0 = add int 1, 2
+    %1 = mul int %0, 3
+    %2 = neg int %1
+    %3 = sub int %0, 1
+    jmp bb2
+bb1:
+    %4 = add int %0, 5
+    ret %4
+bb2:
+    ret %3
+}
+";
+    let (after, changed) = optimize(before, Box::new(DeadCodeElimination));
+    
+    assert!(changed);
+    assert_eq!(after, "fn @main() -> int {
+bb0:
+    %0 = add int 1, 2
+    %1 = sub int %0, 1
+    jmp bb1
+bb1:
+    ret %1
+}
+");
+}
+
+#[test]
+fn dce_keeps_calls_stores_and_trapping_division_with_unused_results() {
+    let before = "global mut @count: int = 0
+
+fn @main() -> int {
+bb0:
+    %0 = call int @next(1)
+    %1 = add int %0, 1
+    store @count, %1
+    %2 = div int 10, %0
+    %3 = div int 10, 2
+    %4 = call int @next(%1)
+    ret 0
+}
+";
+    let (after, changed) = optimize(before, Box::new(DeadCodeElimination));
+    
+    assert!(changed);
+    assert_eq!(after, "fn @main() -> int {
+bb0:
+    %0 = call int @next(1)
+    %1 = add int %0, 1
+    store @count, %1
+    %2 = div int 10, %0
+    %3 = call int @next(%1)
+    ret 0
+}
+");
+}
+
+#[test]
+fn cse_reuses_dominating_expressions_but_not_calls() {
+    let before = "fn @main() -> int {
+bb0:
+    %0 = call int @next(1)
+    %1 = add int %0, 2
+    %2 = add int 2, %0
+    %3 = call int @next(%0)
+    %4 = call int @next(%0)
+    %5 = gt bool %1, 0
+    br %5, bb1, bb2
+bb1:
+    %6 = add int %0, 2
+    %7 = sub int %0, 1
+    %8 = mul int %6, %7
+    ret %8
+bb2:
+    %9 = sub int %0, 1
+    %10 = add int %2, %3
+    %11 = add int %10, %4
+    %12 = add int %11, %9
+    ret %12
+}
+";
+    let (after, changed) = optimize(before, Box::new(CommonSubexpressionElimination));
+    
+    // Kardeş bloklardaki 'sub' birbirini baskılamadığı için ikisi de kalır.
+    assert!(changed);
+    assert_eq!(after, "fn @main() -> int {
+bb0:
+    %0 = call int @next(1)
+    %1 = add int %0, 2
+    %2 = call int @next(%0)
+    %3 = call int @next(%0)
+    %4 = gt bool %1, 0
+    br %4, bb1, bb2
+bb1:
+    %5 = sub int %0, 1
+    %6 = mul int %1, %5
+    ret %6
+bb2:
+    %7 = sub int %0, 1
+    %8 = add int %1, %2
+    %9 = add int %8, %3
+    %10 = add int %9, %7
+    ret %10
+}
+");
+}
+
+#[test]
+fn passes_report_no_change_on_already_optimal_code() {
+    let before = "fn @main() -> int {
+bb0:
+    %0 = call int @next(1)
+    %1 = add int %0, 2
+    ret %1
+}
+";
+    assert_eq!(optimize(before, Box::new(DeadCodeElimination)), (before.to_string(), false));
+    assert_eq!(optimize(before, Box::new(CommonSubexpressionElimination)), (before.to_string(), false));
+}
@@ -0,0 +1,79 @@
+mod common;
+
+use common::parse;
+use ravun::lexer::lexer::Lexer;
+use ravun::lexer::span::Span;
+use ravun::parser::ast::{AstNode, AstNodeType};
+
+const SOURCE: &str = "// çağrı örneği
+fn kare(x: int) -> int {
+    return (x + 1) * x;
+}
+let sonuç = kare(3) + 2;
+";
+
+fn find(node: &AstNode, node_type: &AstNodeType) -> Option<AstNode> {
+    if &node.node_type == node_type {
+        return Some(node.clone());
+    }
+    node.children.iter().find_map(|child| find(child, node_type))
+}
+
+#[test]
+fn token_spans_are_byte_offsets() {
+    let tokens = Lexer::with_file_id("let ğüş = 1;", 7).tokenize();
+    
+    let name = &tokens[1];
+    assert_eq!(name.span, Span::new(7, 4, 10));
+    assert_eq!(name.span.text("let ğüş = 1;"), Some("ğüş"));
+    assert_eq!(name.column, 5);
+    
+    let eof = tokens.last().expect("EOF bekleniyor");
+    assert!(eof.span.is_empty());
+    assert_eq!(eof.span.start, "let ğüş = 1;".len());
+}
+
+#[test]
+fn node_spans_cover_source_text() {
+    let ast = parse(SOURCE);
+    
+    let function = &ast.children[0];
+    assert!(function.span.text(SOURCE).expect("geçerli aralık").starts_with("fn kare("));
+    assert!(function.span.text(SOURCE).expect("geçerli aralık").ends_with('}'));
+    
+    let var_decl = &ast.children[1];
+    assert_eq!(var_decl.span.text(SOURCE), Some("let sonuç = kare(3) + 2;"));
+    
+    let initializer = var_decl.children.last().expect("ilk değer bekleniyor");
+    assert_eq!(initializer.span.text(SOURCE), Some("kare(3) + 2"));
+    assert_eq!(initializer.children[0].span.text(SOURCE), Some("kare(3)"));
+    
+    assert_eq!(ast.span.start, SOURCE.find("fn").expect("fn bekleniyor"));
+    assert_eq!(ast.span.end, SOURCE.trim_end().len());
+}
+
+#[test]
+fn tokenless_nodes_get_span_and_position() {
+    let ast = parse(SOURCE);
+    
+    let group = find(&ast, &AstNodeType::GroupExpr).expect("grup ifadesi bekleniyor");
+    assert_eq!(group.span.text(SOURCE), Some("(x + 1)"));
+    assert_eq!((group.line, group.column), (3, 12));
+    
+    let source = "fn main() {\n    yaz(1);\n}\n";
+    let ast = parse(source);
+    let statement = find(&ast, &AstNodeType::ExprStmt).expect("ifade deyimi bekleniyor");
+    assert_eq!(statement.span.text(source), Some("yaz(1);"));
+    assert_eq!((statement.line, statement.column), (2, 5));
+}
+
+#[test]
+fn merged_span_covers_both_ranges() {
+    let left = Span::new(0, 4, 9);
+    let right = Span::new(0, 12, 15);
+    
+    assert_eq!(left.to(right), Span::new(0, 4, 15));
+    assert_eq!(right.to(left), Span::new(0, 4, 15));
+    assert_eq!(left.to(right).len(), 11);
+    assert_eq!(left.to(right).to_string(), "4..15");
+}
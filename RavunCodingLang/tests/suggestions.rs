@@ -0,0 +1,62 @@
+mod common;
+
+use common::analyze;
+use ravun::semantics::error::SemanticError;
+use ravun::semantics::symbol_table::{StructDefinition, StructField, SymbolTable};
+use ravun::semantics::types::Type;
+use ravun::utils::{best_match, edit_distance};
+
+fn help_for(errors: &[SemanticError], name: &str) -> Option<String> {
+    errors.iter()
+        .find(|error| error.message.contains(&format!("'{}'", name)))
+        .unwrap_or_else(|| panic!("'{}' için hata bulunamadı: {:?}", name, errors))
+        .help.clone()
+}
+
+#[test]
+fn edit_distance_counts_transpositions_as_one_edit() {
+    assert_eq!(edit_distance("print", "print"), 0);
+    assert_eq!(edit_distance("prnt", "print"), 1);
+    assert_eq!(edit_distance("topal", "topla"), 1);
+    assert_eq!(edit_distance("kitten", "sitting"), 3);
+    
+    assert_eq!(best_match("prnt", ["println", "print", "parse"]), Some("print"));
+    assert_eq!(best_match("Sayac", ["sayac", "sayi"]), Some("sayac"));
+    assert_eq!(best_match("x", ["y", "z"]), None);
+    assert_eq!(best_match("print", ["print"]), None);
+}
+
+#[test]
+fn undefined_names_suggest_visible_symbols() {
+    let source = "fn topla(a: int, b: int) -> int {\n    return a + b;\n}\n\nfn main() -> int {\n    let sayac = 1;\n    prnt(\"x\");\n    let y: int = topal(1, 2);\n    return sayca + y;\n}\n";
+    let (_, _, errors) = analyze(source);
+    
+    assert_eq!(help_for(&errors, "prnt").as_deref(), Some("şunu mu demek istediniz: 'print'?"));
+    assert_eq!(help_for(&errors, "topal").as_deref(), Some("şunu mu demek istediniz: 'topla'?"));
+    assert_eq!(help_for(&errors, "sayca").as_deref(), Some("şunu mu demek istediniz: 'sayac'?"));
+}
+
+#[test]
+fn distant_names_get_no_suggestion() {
+    let source = "fn main() -> int {\n    let sayac = 1;\n    return tamamen_farkli;\n}\n";
+    let (_, _, errors) = analyze(source);
+    
+    assert_eq!(help_for(&errors, "tamamen_farkli"), None);
+}
+
+#[test]
+fn struct_fields_are_suggested() {
+    let mut table = SymbolTable::new();
+    table.define_struct(StructDefinition {
+        name: "Nokta".to_string(),
+        fields: vec![
+            StructField { name: "genislik".to_string(), type_info: Type::Int },
+            StructField { name: "yukseklik".to_string(), type_info: Type::Int },
+        ],
+    });
+    
+    assert_eq!(table.suggest_field("Nokta", "genislk"), Some("genislik"));
+    assert_eq!(table.suggest_field("Nokta", "yuksekilk"), Some("yukseklik"));
+    assert_eq!(table.suggest_field("Nokta", "renk"), None);
+    assert_eq!(table.suggest_field("Kare", "genislk"), None);
+}
@@ -0,0 +1,64 @@
+use ravun::codegen::wasm::module::ExportKind;
+use ravun::codegen::wasm::{emit_wasm, encode_module, print_wat};
+use ravun::ir::parse_module;
+
+const LOOP_PROGRAM: &str = "global mut @total: int = 0
+
+fn @sum(%0: int) -> int {
+bb0:
+    jmp bb1
+bb1:
+    %1 = phi int [0, bb0], [%4, bb2]
+    %2 = phi int [0, bb0], [%5, bb2]
+    %3 = lt bool %2, %0
+    br %3, bb2, bb3
+bb2:
+    %4 = add int %1, %2
+    %5 = add int %2, 1
+    jmp bb1
+bb3:
+    store @total, %1
+    ret %1
+}
+
+fn @main() -> int {
+bb0:
+    call void @println(\"bitti\")
+    %0 = call int @sum(10)
+    ret %0
+}
+";
+
+#[test]
+fn encodes_a_valid_module_header_and_exports() {
+    let module = parse_module(LOOP_PROGRAM).expect("IR ayrıştırılamadı");
+    let wasm = emit_wasm(&module).expect("wasm üretimi");
+    
+    let export_names: Vec<&str> = wasm.exports.iter().map(|e| e.name.as_str()).collect();
+    assert!(export_names.contains(&"memory"));
+    assert!(export_names.contains(&"_start"));
+    assert!(export_names.contains(&"sum"));
+    assert!(wasm.exports.iter().any(|e| e.name == "memory" && e.kind == ExportKind::Memory));
+    assert!(wasm.imports.iter().any(|i| i.name == "fd_write"));
+    
+    let bytes = encode_module(&wasm);
+    assert_eq!(&bytes[..8], &[0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00]);
+    assert!(bytes.windows(5).any(|w| w == b"bitti"));
+}
+
+#[test]
+fn lowers_control_flow_to_a_dispatch_loop() {
+    let module = parse_module(LOOP_PROGRAM).expect("IR ayrıştırılamadı");
+    let wat = print_wat(&emit_wasm(&module).expect("wasm üretimi"));
+    
+    let sum = &wat[wat.find("(func $rv_f_sum (type").expect("sum fonksiyonu")..];
+    let sum = &sum[..sum.find("\n  )").unwrap()];
+    assert!(sum.contains("(local $pc i32)"));
+    assert!(sum.contains("loop"));
+    assert!(sum.contains("br_table 0 1 2 3 3"));
+    assert!(sum.contains("global.set $rv_g_total"));
+    
+    let main = &wat[wat.find("(func $rv_f_main (type").expect("main fonksiyonu")..];
+    let main = &main[..main.find("\n  )").unwrap()];
+    assert!(!main.contains("br_table"));
+}
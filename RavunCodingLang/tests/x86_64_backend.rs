@@ -0,0 +1,83 @@
+use ravun::codegen::x86_64::abi::ArgLocation;
+use ravun::codegen::x86_64::registers::{Gpr, RegClass, CALLEE_SAVED_POOL};
+use ravun::codegen::x86_64::{allocate_registers, emit_asm, CallingConvention, Location};
+use ravun::ir::instruction::ValueId;
+use ravun::ir::parse_module;
+use ravun::semantics::types::Type;
+
+#[test]
+fn classifies_arguments_by_register_class() {
+    let mut params = vec![Type::Int; 7];
+    params.insert(2, Type::Float);
+    params.push(Type::Bool);
+    let convention = CallingConvention::for_signature(&Type::Function(params, Box::new(Type::Float)))
+        .expect("geçerli imza");
+    
+    let locations: Vec<ArgLocation> = convention.args.iter().map(|(l, _)| *l).collect();
+    assert_eq!(locations, vec![
+        ArgLocation::Gpr(Gpr::Rdi),
+        ArgLocation::Gpr(Gpr::Rsi),
+        ArgLocation::Xmm(0),
+        ArgLocation::Gpr(Gpr::Rdx),
+        ArgLocation::Gpr(Gpr::Rcx),
+        ArgLocation::Gpr(Gpr::R8),
+        ArgLocation::Gpr(Gpr::R9),
+        ArgLocation::Stack(0),
+        ArgLocation::Stack(1),
+    ]);
+    assert_eq!(convention.stack_slots, 2);
+    assert_eq!(convention.return_class, Some(RegClass::Float));
+}
+
+#[test]
+fn values_live_across_calls_use_callee_saved_registers() {
+    let module = parse_module("fn @id(%0: int) -> int {
+bb0:
+    ret %0
+}
+
+fn @main() -> int {
+bb0:
+    %0 = add int 1, 2
+    %1 = call int @id(%0)
+    %2 = add int %0, %1
+    %3 = itof float %2
+    %4 = add float %3, 1.5
+    %5 = call int @id(%2)
+    %6 = lt bool %4, 2.0
+    br %6, bb1, bb2
+bb1:
+    ret %5
+bb2:
+    ret 0
+}
+").expect("IR ayrıştırılamadı");
+    
+    let main = module.function("main").unwrap();
+    let allocation = allocate_registers(main, &main.reverse_postorder()).expect("register ataması");
+    
+    let Some(Location::Gpr(register)) = allocation.registers.get(&ValueId(0)).copied() else {
+        panic!("%0 registera atanmadı");
+    };
+    assert!(CALLEE_SAVED_POOL.contains(&register));
+    assert!(allocation.spilled.contains(&ValueId(4)), "çağrı boyunca yaşayan ondalık değer yığına taşınmalı");
+    
+    let asm = emit_asm(&module).expect("assembly üretilemedi");
+    assert!(asm.contains(&format!("pushq {}", register.name64())));
+    assert!(asm.contains("\n    .globl main\n"));
+    assert!(asm.contains("call rv_f_id\n"));
+}
+
+#[test]
+fn unused_parameters_keep_their_own_register() {
+    for body in ["ret %0", "ret %1"] {
+        let module = parse_module(&format!("fn @f0(%0: int, %1: int) -> int {{\nbb0:\n    {}\n}}\n", body))
+            .expect("IR ayrıştırılamadı");
+        let function = module.function("f0").unwrap();
+        let allocation = allocate_registers(function, &function.reverse_postorder()).expect("register ataması");
+        
+        let first = allocation.registers.get(&ValueId(0)).copied();
+        let second = allocation.registers.get(&ValueId(1)).copied();
+        assert!(first.is_some() && second.is_some() && first != second, "{}: parametreler aynı yere atandı: {:?} {:?}", body, first, second);
+    }
+}